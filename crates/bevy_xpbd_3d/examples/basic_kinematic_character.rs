@@ -1,10 +1,13 @@
 //! A very basic implementation of a character controller for a kinematic rigid body.
-//! Supports directional movement and jumping.
+//! Supports directional movement, jumping and pushing dynamic bodies.
 //!
 //! Bevy XPBD does not have a built-in character controller yet, so you will have to implement
 //! the logic yourself. For kinematic bodies, collision response has to be handled manually, as shown in
 //! this example.
 //!
+//! Pushing dynamic bodies and being pushed by moving colliders are only implemented in this example
+//! and not in the `CharacterPlugin`, which only provides helpers like resizable capsules and wall detection.
+//!
 //! Using dynamic bodies is often easier, as they handle most of the physics for you.
 //! For a dynamic character controller, see the `basic_dynamic_character` example.
 
@@ -17,7 +20,13 @@ fn main() {
     App::new()
         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
         .add_systems(Startup, setup)
-        .add_systems(PhysicsSchedule, movement.before(PhysicsStepSet::BroadPhase))
+        .add_systems(Update, swing_door)
+        .add_systems(
+            PhysicsSchedule,
+            (movement, push_dynamic_bodies)
+                .chain()
+                .before(PhysicsStepSet::BroadPhase),
+        )
         .add_systems(
             // Run collision handling in substep schedule
             SubstepSchedule,
@@ -29,6 +38,19 @@ fn main() {
 #[derive(Component)]
 struct Player;
 
+#[derive(Component)]
+struct Door;
+
+/// Makes the character push the dynamic bodies that it touches instead of stopping dead.
+#[derive(Component)]
+struct PushDynamicBodies {
+    /// The mass that the character pushes with. Bodies that are much lighter than this are shoved
+    /// at the character's speed, while much heavier bodies barely move and slow the character down.
+    mass: Scalar,
+    /// A multiplier for the impulses applied to the pushed bodies.
+    strength: Scalar,
+}
+
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -68,9 +90,42 @@ fn setup(
         .with_ignore_origin_penetration(true) // Don't count player's collider
         .with_max_time_of_impact(0.11)
         .with_max_hits(1),
+        PushDynamicBodies {
+            mass: 20.0,
+            strength: 1.0,
+        },
         Player,
     ));
 
+    // A kinematic revolving door that swings back and forth and pushes the player out of the way
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Mesh::from(shape::Box::new(2.0, 2.0, 0.2))),
+            material: materials.add(Color::rgb(0.4, 0.4, 0.6).into()),
+            ..default()
+        },
+        RigidBody::Kinematic,
+        Position(Vector::new(2.0, 1.0, 2.0)),
+        Collider::cuboid(2.0, 2.0, 0.2),
+        AngularVelocity(Vector::Y * 1.0),
+        Door,
+    ));
+
+    // Crates of different weights that the player can push
+    for (i, density) in [2.0, 10.0, 100.0].into_iter().enumerate() {
+        commands.spawn((
+            PbrBundle {
+                mesh: meshes.add(Mesh::from(shape::Cube { size: 1.0 })),
+                material: materials.add(Color::rgb(0.6, 0.4, 0.2).into()),
+                ..default()
+            },
+            RigidBody::Dynamic,
+            Position(Vector::new(-2.0 + i as Scalar * 2.0, 0.5, -2.0)),
+            Collider::cuboid(1.0, 1.0, 1.0),
+            ColliderDensity(density),
+        ));
+    }
+
     // Light
     commands.spawn(PointLightBundle {
         point_light: PointLight {
@@ -127,9 +182,86 @@ fn movement(
     }
 }
 
+/// Swings the door back and forth by changing its angular velocity.
+fn swing_door(time: Res<Time>, mut doors: Query<&mut AngularVelocity, With<Door>>) {
+    for mut angular_velocity in &mut doors {
+        angular_velocity.0 = Vector::Y * 1.5 * (time.elapsed_seconds() as Scalar).cos();
+    }
+}
+
+/// Applies impulses to the dynamic bodies that the player touches, based on the player's velocity
+/// towards them and the ratio of the masses.
+fn push_dynamic_bodies(
+    collisions: Res<Collisions>,
+    mut players: Query<(&mut LinearVelocity, &PushDynamicBodies), With<Player>>,
+    mut bodies: Query<(&RigidBody, &Rotation, &Mass, &mut LinearVelocity), Without<Player>>,
+) {
+    for contacts in collisions.iter() {
+        // Only push bodies that were touched during the last frame
+        if !contacts.during_current_frame {
+            continue;
+        }
+
+        // Get the player and the other body, and the direction from the player towards the body
+        let (player_entity, body_entity, is_first) = if players.contains(contacts.entity1) {
+            (contacts.entity1, contacts.entity2, true)
+        } else if players.contains(contacts.entity2) {
+            (contacts.entity2, contacts.entity1, false)
+        } else {
+            continue;
+        };
+        let (Ok((mut player_velocity, push)), Ok((rb, rotation, mass, mut body_velocity))) =
+            (players.get_mut(player_entity), bodies.get_mut(body_entity))
+        else {
+            continue;
+        };
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        for manifold in contacts.manifolds.iter() {
+            // The normals are stored in the local space of the colliders
+            let normal = if is_first {
+                -rotation.rotate(manifold.normal2)
+            } else {
+                -rotation.rotate(manifold.normal1)
+            };
+
+            // Only push horizontally, so the player can't push bodies into the ground
+            let normal = Vector::new(normal.x, 0.0, normal.z).normalize_or_zero();
+
+            // The speed at which the player moves into the body
+            let speed = (player_velocity.0 - body_velocity.0).dot(normal);
+            if speed <= 0.0 {
+                continue;
+            }
+
+            // Light bodies are shoved at the player's speed, while heavy bodies slow the player down
+            let push_ratio = push.mass / (push.mass + mass.0);
+            let impulse = normal * speed * push_ratio * mass.0 * push.strength;
+
+            body_velocity.0 += impulse / mass.0;
+            player_velocity.0 -= normal * speed * (1.0 - push_ratio);
+        }
+    }
+}
+
+/// Moves the player out of the colliders that it penetrates. Colliders that are moving, like doors and
+/// rotating platforms, also push the player along with them using the velocity at the contact point.
+#[allow(clippy::type_complexity)]
 fn kinematic_collision(
     collisions: Res<Collisions>,
-    mut bodies: Query<(&RigidBody, &mut Position, &Rotation)>,
+    mut players: Query<(&mut Position, &Rotation, &mut LinearVelocity), With<Player>>,
+    bodies: Query<
+        (
+            &RigidBody,
+            &Position,
+            &Rotation,
+            &LinearVelocity,
+            &AngularVelocity,
+        ),
+        Without<Player>,
+    >,
 ) {
     // Iterate through collisions and move the kinematic body to resolve penetration
     for contacts in collisions.iter() {
@@ -137,19 +269,56 @@ fn kinematic_collision(
         if !contacts.during_current_substep {
             continue;
         }
-        if let Ok([(rb1, mut position1, rotation1), (rb2, mut position2, _)]) =
-            bodies.get_many_mut([contacts.entity1, contacts.entity2])
-        {
-            for manifold in contacts.manifolds.iter() {
-                for contact in manifold.contacts.iter() {
-                    if contact.penetration <= Scalar::EPSILON {
-                        continue;
-                    }
-                    if rb1.is_kinematic() && !rb2.is_kinematic() {
-                        position1.0 -= contact.global_normal1(rotation1) * contact.penetration;
-                    } else if rb2.is_kinematic() && !rb1.is_kinematic() {
-                        position2.0 += contact.global_normal1(rotation1) * contact.penetration;
-                    }
+
+        // Get the player and the other body
+        let (player_entity, body_entity, is_first) = if players.contains(contacts.entity1) {
+            (contacts.entity1, contacts.entity2, true)
+        } else if players.contains(contacts.entity2) {
+            (contacts.entity2, contacts.entity1, false)
+        } else {
+            continue;
+        };
+        let (
+            Ok((mut player_position, player_rotation, mut player_velocity)),
+            Ok((rb, body_position, body_rotation, body_lin_vel, body_ang_vel)),
+        ) = (players.get_mut(player_entity), bodies.get(body_entity))
+        else {
+            continue;
+        };
+
+        for manifold in contacts.manifolds.iter() {
+            for contact in manifold.contacts.iter() {
+                if contact.penetration <= Scalar::EPSILON {
+                    continue;
+                }
+
+                // The direction in which the player is pushed out of the other body,
+                // and the contact point on the other body
+                let (normal, point) = if is_first {
+                    (
+                        -contact.global_normal1(player_rotation),
+                        contact.global_point2(body_position, body_rotation),
+                    )
+                } else {
+                    (
+                        contact.global_normal1(body_rotation),
+                        contact.global_point1(body_position, body_rotation),
+                    )
+                };
+
+                player_position.0 += normal * contact.penetration;
+
+                // Dynamic bodies are pushed by the solver, so they don't push the player
+                if rb.is_dynamic() {
+                    continue;
+                }
+
+                // If the other body is moving towards the player faster than the player is moving away from it,
+                // the player is carried along so that it doesn't end up inside the body on the next substep
+                let point_velocity = body_lin_vel.0 + body_ang_vel.0.cross(point - body_position.0);
+                let separating_speed = (point_velocity - player_velocity.0).dot(normal);
+                if separating_speed > 0.0 {
+                    player_velocity.0 += normal * separating_speed;
                 }
             }
         }
@@ -2,23 +2,16 @@ use bevy::{
     ecs::entity::{EntityMapper, MapEntities},
     prelude::*,
 };
-use bevy_xpbd_3d::{math::*, prelude::*, SubstepSchedule, SubstepSet};
+use bevy_xpbd_3d::{math::*, prelude::*};
 
 fn main() {
     let mut app = App::new();
 
-    // Add plugins and startup system
+    // Add plugins, our custom distance constraint and startup system
     app.add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+        .add_constraint::<CustomDistanceConstraint, 2>()
         .add_systems(Startup, setup);
 
-    // Get physics substep schedule and add our custom distance constraint
-    let substeps = app
-        .get_schedule_mut(SubstepSchedule)
-        .expect("add SubstepSchedule first");
-    substeps.add_systems(
-        solve_constraint::<CustomDistanceConstraint, 2>.in_set(SubstepSet::SolveUserConstraints),
-    );
-
     // Run the app
     app.run();
 }
@@ -0,0 +1,221 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A bundle containing the commonly configured components of a [rigid body](RigidBody).
+///
+/// The bundle is usually created using a [`RigidBodyBuilder`], but it can also be constructed directly.
+/// Components that aren't explicitly configured use values that behave the same as if they were missing.
+#[allow(missing_docs)]
+#[derive(Bundle, Clone, Debug)]
+pub struct RigidBodyBundle {
+    pub rigid_body: RigidBody,
+    pub linear_velocity: LinearVelocity,
+    pub angular_velocity: AngularVelocity,
+    pub gravity_scale: GravityScale,
+    pub linear_damping: LinearDamping,
+    pub angular_damping: AngularDamping,
+    pub locked_axes: LockedAxes,
+    pub friction: Friction,
+    pub restitution: Restitution,
+    pub collider_density: ColliderDensity,
+}
+
+impl Default for RigidBodyBundle {
+    fn default() -> Self {
+        Self {
+            rigid_body: RigidBody::default(),
+            linear_velocity: LinearVelocity::ZERO,
+            angular_velocity: AngularVelocity::ZERO,
+            gravity_scale: GravityScale(1.0),
+            linear_damping: LinearDamping::default(),
+            angular_damping: AngularDamping::default(),
+            locked_axes: LockedAxes::default(),
+            friction: Friction::default(),
+            restitution: Restitution::default(),
+            collider_density: ColliderDensity::default(),
+        }
+    }
+}
+
+/// A builder for creating [rigid bodies](RigidBody) without assembling each of their components by hand.
+///
+/// The builder is started with [`RigidBodyBuilder::dynamic`], [`RigidBodyBuilder::kinematic`] or
+/// [`RigidBodyBuilder::static_body`], configured using chained methods, and turned into a bundle
+/// using [`RigidBodyBuilder::build`]. The returned bundle also contains the [`Collider`] if one was given.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBodyBuilder::dynamic()
+///             .collider(Collider::ball(0.5))
+///             .density(2.0)
+///             .gravity_scale(0.5)
+///             .build(),
+///         TransformBundle::default(),
+///     ));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RigidBodyBuilder<C = ()> {
+    bundle: RigidBodyBundle,
+    collider: C,
+}
+
+impl RigidBodyBuilder {
+    /// Creates a builder for a rigid body of the given type.
+    pub fn new(rigid_body: RigidBody) -> Self {
+        Self {
+            bundle: RigidBodyBundle {
+                rigid_body,
+                ..default()
+            },
+            collider: (),
+        }
+    }
+
+    /// Creates a builder for a [dynamic](RigidBody::Dynamic) rigid body.
+    pub fn dynamic() -> Self {
+        Self::new(RigidBody::Dynamic)
+    }
+
+    /// Creates a builder for a [kinematic](RigidBody::Kinematic) rigid body.
+    pub fn kinematic() -> Self {
+        Self::new(RigidBody::Kinematic)
+    }
+
+    /// Creates a builder for a [static](RigidBody::Static) rigid body.
+    pub fn static_body() -> Self {
+        Self::new(RigidBody::Static)
+    }
+}
+
+impl<C> RigidBodyBuilder<C> {
+    /// Sets the [`Collider`] of the body, replacing any previously set collider.
+    pub fn collider(self, collider: Collider) -> RigidBodyBuilder<Collider> {
+        RigidBodyBuilder {
+            bundle: self.bundle,
+            collider,
+        }
+    }
+
+    /// Sets the [`ColliderDensity`] used for computing the mass properties of the body's collider.
+    pub fn density(mut self, density: Scalar) -> Self {
+        self.bundle.collider_density = ColliderDensity(density);
+        self
+    }
+
+    /// Sets the [`GravityScale`] of the body.
+    pub fn gravity_scale(mut self, gravity_scale: Scalar) -> Self {
+        self.bundle.gravity_scale = GravityScale(gravity_scale);
+        self
+    }
+
+    /// Sets the initial [`LinearVelocity`] of the body.
+    pub fn linear_velocity(mut self, velocity: Vector) -> Self {
+        self.bundle.linear_velocity = LinearVelocity(velocity);
+        self
+    }
+
+    /// Sets the initial [`AngularVelocity`] of the body.
+    #[cfg(feature = "2d")]
+    pub fn angular_velocity(mut self, velocity: Scalar) -> Self {
+        self.bundle.angular_velocity = AngularVelocity(velocity);
+        self
+    }
+
+    /// Sets the initial [`AngularVelocity`] of the body.
+    #[cfg(feature = "3d")]
+    pub fn angular_velocity(mut self, velocity: Vector) -> Self {
+        self.bundle.angular_velocity = AngularVelocity(velocity);
+        self
+    }
+
+    /// Sets the [`LinearDamping`] of the body.
+    pub fn linear_damping(mut self, damping: Scalar) -> Self {
+        self.bundle.linear_damping = LinearDamping(damping);
+        self
+    }
+
+    /// Sets the [`AngularDamping`] of the body.
+    pub fn angular_damping(mut self, damping: Scalar) -> Self {
+        self.bundle.angular_damping = AngularDamping(damping);
+        self
+    }
+
+    /// Sets the [`LockedAxes`] of the body.
+    pub fn locked_axes(mut self, locked_axes: LockedAxes) -> Self {
+        self.bundle.locked_axes = locked_axes;
+        self
+    }
+
+    /// Sets the [`Friction`] of the body.
+    pub fn friction(mut self, friction: Friction) -> Self {
+        self.bundle.friction = friction;
+        self
+    }
+
+    /// Sets the [`Restitution`] of the body.
+    pub fn restitution(mut self, restitution: Restitution) -> Self {
+        self.bundle.restitution = restitution;
+        self
+    }
+}
+
+impl<C: Bundle> RigidBodyBuilder<C> {
+    /// Returns a bundle containing the configured components and the [`Collider`], if one was set.
+    pub fn build(self) -> (RigidBodyBundle, C) {
+        (self.bundle, self.collider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn rigid_body_builder_configures_body() {
+        let mut app = create_app();
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBodyBuilder::dynamic()
+                    .collider(Collider::ball(0.5))
+                    .density(2.0)
+                    .gravity_scale(0.5)
+                    .build(),
+            ))
+            .id();
+        let reference = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 5.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        let expected_mass = ColliderMassProperties::new(&Collider::ball(0.5), 2.0).mass;
+        assert_relative_eq!(app.world.get::<Mass>(body).unwrap().0, expected_mass.0);
+
+        // The body has half the gravity of the reference body
+        let velocity = app.world.get::<LinearVelocity>(body).unwrap().y;
+        let reference_velocity = app.world.get::<LinearVelocity>(reference).unwrap().y;
+        assert!(velocity < 0.0);
+        assert_relative_eq!(velocity, reference_velocity * 0.5, epsilon = 0.0001);
+    }
+}
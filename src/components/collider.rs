@@ -10,7 +10,7 @@ use bevy::{
 use collision::contact_query::UnsupportedShape;
 use itertools::Either;
 use parry::{
-    bounding_volume::Aabb,
+    bounding_volume::{Aabb, BoundingVolume},
     shape::{RoundShape, SharedShape, TypedShape},
 };
 
@@ -22,6 +22,12 @@ pub type VHACDParameters = parry::transformation::vhacd::VHACDParameters;
 /// Flags used for the preprocessing of a triangle mesh collider.
 pub type TriMeshFlags = parry::shape::TriMeshFlags;
 
+/// Flags controlling which triangles of a heightfield cell are removed and how the cell is split into triangles.
+///
+/// See [`Collider::set_heightfield_cell_status`].
+#[cfg(feature = "3d")]
+pub type HeightFieldCellStatus = parry::shape::HeightFieldCellStatus;
+
 /// A collider used for detecting collisions and generating contacts.
 ///
 /// ## Creation
@@ -43,6 +49,11 @@ pub type TriMeshFlags = parry::shape::TriMeshFlags;
 /// # }
 /// ```
 ///
+/// With the `collider-from-mesh` feature, colliders can also be created from Bevy's mesh shapes
+/// like `shape::Cube`, `shape::Capsule` and `shape::Torus` (or `shape::Quad` and `shape::Circle` in 2D)
+/// using `Collider::from`, so that a primitive mesh can get a matching collider with one call.
+/// Shapes that have no exact equivalent, like a torus, are approximated.
+///
 /// Colliders on their own only detect contacts and generate [collision events](#collision-events).
 /// To make colliders apply contact forces, they have to be attached to [rigid bodies](RigidBody):
 ///
@@ -107,7 +118,11 @@ pub type TriMeshFlags = parry::shape::TriMeshFlags;
 /// ```
 ///
 /// Colliders can be arbitrarily nested and transformed relative to the parent.
-/// The rigid body that a collider is attached to can be accessed using the [`ColliderParent`] component.
+/// Each collider is attached to its closest ancestor with a [`RigidBody`], and the entities in between
+/// don't need any physics components. This means that hierarchies like glTF scenes can be used as is.
+/// The rigid body that a collider is attached to can be accessed using the [`ColliderParent`] component,
+/// and the [`body_entity1`](Contacts::body_entity1) and [`body_entity2`](Contacts::body_entity2)
+/// properties of [`Contacts`].
 ///
 /// The benefit of using separate entities for the colliders is that each collider can have its own
 /// [friction](Friction), [restitution](Restitution), [collision layers](CollisionLayers),
@@ -189,6 +204,9 @@ pub type TriMeshFlags = parry::shape::TriMeshFlags;
 /// The entities that are colliding with a given entity can also be accessed using
 /// the [`CollidingEntities`] component.
 ///
+/// Which colliders send collision events can be configured separately from which colliders collide
+/// using the [`CollisionEventLayers`] component.
+///
 /// ## Querying and modifying contacts
 ///
 /// The [`Collisions`] resource grants access to all collisions.
@@ -201,7 +219,12 @@ pub type TriMeshFlags = parry::shape::TriMeshFlags;
 /// Internally, `Collider` uses the shapes provided by `parry`. If you want to create a collider
 /// using these shapes, you can simply use `Collider::from(SharedShape::some_method())`.
 ///
-/// To get a reference to the internal [`SharedShape`], you can use the [`get_shape`](#method.get_shape) method.
+/// To get a reference to the internal [`SharedShape`], you can use the [`shape`](#method.shape) method.
+///
+/// To read the parameters of a specific shape, like the radius of a capsule, you can use typed accessors
+/// like [`as_ball`](#method.as_ball), [`as_cuboid`](#method.as_cuboid), [`as_capsule`](#method.as_capsule)
+/// and [`as_trimesh`](#method.as_trimesh). They return `None` if the collider has a different shape.
+/// The returned shapes have the collider's scale applied.
 #[derive(Clone, Component)]
 pub struct Collider {
     /// The raw unscaled collider shape.
@@ -290,6 +313,80 @@ impl Collider {
         self.scale
     }
 
+    /// Returns the scaled shape as a ball, or `None` if the collider is not a ball.
+    pub fn as_ball(&self) -> Option<&parry::shape::Ball> {
+        self.scaled_shape.as_ball()
+    }
+
+    /// Returns the scaled shape as a cuboid, or `None` if the collider is not a cuboid.
+    pub fn as_cuboid(&self) -> Option<&parry::shape::Cuboid> {
+        self.scaled_shape.as_cuboid()
+    }
+
+    /// Returns the scaled shape as a capsule, or `None` if the collider is not a capsule.
+    pub fn as_capsule(&self) -> Option<&parry::shape::Capsule> {
+        self.scaled_shape.as_capsule()
+    }
+
+    /// Returns the scaled shape as a segment, or `None` if the collider is not a segment.
+    pub fn as_segment(&self) -> Option<&parry::shape::Segment> {
+        self.scaled_shape.as_segment()
+    }
+
+    /// Returns the scaled shape as a triangle, or `None` if the collider is not a triangle.
+    pub fn as_triangle(&self) -> Option<&parry::shape::Triangle> {
+        self.scaled_shape.as_triangle()
+    }
+
+    /// Returns the scaled shape as a cylinder, or `None` if the collider is not a cylinder.
+    #[cfg(feature = "3d")]
+    pub fn as_cylinder(&self) -> Option<&parry::shape::Cylinder> {
+        self.scaled_shape.as_cylinder()
+    }
+
+    /// Returns the scaled shape as a cone, or `None` if the collider is not a cone.
+    #[cfg(feature = "3d")]
+    pub fn as_cone(&self) -> Option<&parry::shape::Cone> {
+        self.scaled_shape.as_cone()
+    }
+
+    /// Returns the scaled shape as a half-space, or `None` if the collider is not a half-space.
+    pub fn as_halfspace(&self) -> Option<&parry::shape::HalfSpace> {
+        self.scaled_shape.as_halfspace()
+    }
+
+    /// Returns the scaled shape as a convex polygon, or `None` if the collider is not a convex polygon.
+    #[cfg(feature = "2d")]
+    pub fn as_convex_polygon(&self) -> Option<&parry::shape::ConvexPolygon> {
+        self.scaled_shape.as_convex_polygon()
+    }
+
+    /// Returns the scaled shape as a convex polyhedron, or `None` if the collider is not a convex polyhedron.
+    #[cfg(feature = "3d")]
+    pub fn as_convex_polyhedron(&self) -> Option<&parry::shape::ConvexPolyhedron> {
+        self.scaled_shape.as_convex_polyhedron()
+    }
+
+    /// Returns the scaled shape as a polyline, or `None` if the collider is not a polyline.
+    pub fn as_polyline(&self) -> Option<&parry::shape::Polyline> {
+        self.scaled_shape.as_polyline()
+    }
+
+    /// Returns the scaled shape as a triangle mesh, or `None` if the collider is not a triangle mesh.
+    pub fn as_trimesh(&self) -> Option<&parry::shape::TriMesh> {
+        self.scaled_shape.as_trimesh()
+    }
+
+    /// Returns the scaled shape as a heightfield, or `None` if the collider is not a heightfield.
+    pub fn as_heightfield(&self) -> Option<&parry::shape::HeightField> {
+        self.scaled_shape.as_heightfield()
+    }
+
+    /// Returns the scaled shape as a compound shape, or `None` if the collider is not a compound shape.
+    pub fn as_compound(&self) -> Option<&parry::shape::Compound> {
+        self.scaled_shape.as_compound()
+    }
+
     /// Sets the unscaled shape of the collider. The collider's scale will be applied to this shape.
     pub fn set_shape(&mut self, shape: SharedShape) {
         if self.scale != Vector::ONE {
@@ -445,15 +542,75 @@ impl Collider {
         SharedShape::trimesh(vertices, indices).into()
     }
 
+    /// Creates a collider with a triangle mesh shape defined by its vertex and index buffers
+    /// and [`TriMeshFlags`] controlling the preprocessing of the mesh.
+    ///
+    /// Meshes exported from modeling tools often contain duplicate vertices, zero-area triangles
+    /// or inconsistent winding, which can make contacts against the mesh silently fail or produce
+    /// wrong normals. The flags can be used for cleaning up such meshes:
+    ///
+    /// - [`TriMeshFlags::MERGE_DUPLICATE_VERTICES`] merges vertices with the exact same coordinates.
+    /// - [`TriMeshFlags::DELETE_DEGENERATE_TRIANGLES`] removes triangles with zero area.
+    /// - [`TriMeshFlags::DELETE_DUPLICATE_TRIANGLES`] removes triangles that appear more than once.
+    /// - [`TriMeshFlags::ORIENTED`] treats the mesh as one-way, with normals pointing outwards from the
+    /// counterclockwise side of each triangle.
+    /// - [`TriMeshFlags::HALF_EDGE_TOPOLOGY`] computes the topology of the mesh, and
+    /// [`TriMeshFlags::DELETE_BAD_TOPOLOGY_TRIANGLES`] removes triangles for which it fails.
+    ///
+    /// A warning is logged if no triangles are left after the preprocessing.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// # #[cfg(feature = "3d")]
+    /// fn setup(mut commands: Commands) {
+    ///     let vertices = vec![
+    ///         Vector::ZERO,
+    ///         Vector::X,
+    ///         Vector::Z,
+    ///         // A duplicate of the first vertex
+    ///         Vector::ZERO,
+    ///     ];
+    ///     commands.spawn(Collider::trimesh_with_flags(
+    ///         vertices,
+    ///         vec![[0, 2, 1], [3, 2, 1]],
+    ///         TriMeshFlags::MERGE_DUPLICATE_VERTICES | TriMeshFlags::DELETE_DUPLICATE_TRIANGLES,
+    ///     ));
+    /// }
+    /// ```
+    pub fn trimesh_with_flags(
+        vertices: Vec<Vector>,
+        indices: Vec<[u32; 3]>,
+        flags: TriMeshFlags,
+    ) -> Self {
+        let vertices = vertices.into_iter().map(|v| v.into()).collect();
+        let shape = SharedShape::trimesh_with_flags(vertices, indices, flags);
+
+        if shape
+            .as_trimesh()
+            .map_or(false, |trimesh| trimesh.indices().is_empty())
+        {
+            log::warn!("Triangle mesh collider has no triangles left after preprocessing.");
+        }
+
+        shape.into()
+    }
+
     /// Creates a collider with a triangle mesh shape defined by its vertex and index buffers
     /// and flags controlling the preprocessing.
+    #[deprecated(since = "0.3.0", note = "renamed to `Collider::trimesh_with_flags`")]
     pub fn trimesh_with_config(
         vertices: Vec<Vector>,
         indices: Vec<[u32; 3]>,
         flags: TriMeshFlags,
     ) -> Self {
-        let vertices = vertices.into_iter().map(|v| v.into()).collect();
-        SharedShape::trimesh_with_flags(vertices, indices, flags).into()
+        Self::trimesh_with_flags(vertices, indices, flags)
     }
 
     /// Creates a collider shape with a compound shape obtained from the decomposition of a given polyline
@@ -550,6 +707,119 @@ impl Collider {
         SharedShape::heightfield(heights, scale.into()).into()
     }
 
+    /// Marks a segment of a heightfield as a hole or removes the hole. Holes have no collision,
+    /// which can be used for things like pits and removable tiles without having to use a polyline.
+    ///
+    /// The `cell` is the index of the segment between the heights at indices `cell` and `cell + 1`.
+    ///
+    /// Returns false if the collider is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "2d")]
+    pub fn set_heightfield_hole(&mut self, cell: usize, is_hole: bool) -> bool {
+        self.modify_heightfield(|heightfield| {
+            if cell >= heightfield.num_cells() {
+                return false;
+            }
+            heightfield.set_segment_removed(cell, is_hole);
+            true
+        })
+    }
+
+    /// Returns true if the given segment of a heightfield is a hole, or `None` if the collider
+    /// is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "2d")]
+    pub fn is_heightfield_hole(&self, cell: usize) -> Option<bool> {
+        let heightfield = self.shape.as_heightfield()?;
+        (cell < heightfield.num_cells()).then(|| heightfield.is_segment_removed(cell))
+    }
+
+    /// Marks a cell of a heightfield as a hole or removes the hole. Holes have no collision,
+    /// which can be used for things like caves and removable tiles in terrain without having to use a triangle mesh.
+    ///
+    /// The cell at `row` and `column` is the cell between the heights at `(row, column)` and `(row + 1, column + 1)`
+    /// in the `heights` matrix. To remove only one of the two triangles of a cell, see
+    /// [`set_heightfield_cell_status`](Self::set_heightfield_cell_status).
+    ///
+    /// Returns false if the collider is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "3d")]
+    pub fn set_heightfield_hole(&mut self, row: usize, column: usize, is_hole: bool) -> bool {
+        let Some(mut status) = self.heightfield_cell_status(row, column) else {
+            return false;
+        };
+        status.set(HeightFieldCellStatus::CELL_REMOVED, is_hole);
+        self.set_heightfield_cell_status(row, column, status)
+    }
+
+    /// Returns true if both triangles of the given cell of a heightfield are removed, or `None`
+    /// if the collider is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "3d")]
+    pub fn is_heightfield_hole(&self, row: usize, column: usize) -> Option<bool> {
+        self.heightfield_cell_status(row, column)
+            .map(|status| status.contains(HeightFieldCellStatus::CELL_REMOVED))
+    }
+
+    /// Sets the [`HeightFieldCellStatus`] of a cell of a heightfield. The status determines which of the
+    /// cell's triangles are removed and how the cell is split into triangles.
+    ///
+    /// Returns false if the collider is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "3d")]
+    pub fn set_heightfield_cell_status(
+        &mut self,
+        row: usize,
+        column: usize,
+        status: HeightFieldCellStatus,
+    ) -> bool {
+        self.modify_heightfield(|heightfield| {
+            let (rows, columns) = heightfield.num_cells_ij();
+            if row >= rows || column >= columns {
+                return false;
+            }
+            heightfield.set_cell_status(row, column, status);
+            true
+        })
+    }
+
+    /// Returns the [`HeightFieldCellStatus`] of a cell of a heightfield, or `None` if the collider
+    /// is not a heightfield or the cell doesn't exist.
+    #[cfg(feature = "3d")]
+    pub fn heightfield_cell_status(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> Option<HeightFieldCellStatus> {
+        let heightfield = self.shape.as_heightfield()?;
+        let (rows, columns) = heightfield.num_cells_ij();
+        (row < rows && column < columns).then(|| heightfield.cell_status(row, column))
+    }
+
+    /// Applies the given function to both the unscaled and scaled heightfield shapes of the collider.
+    ///
+    /// Returns false if the collider is not a heightfield or the function returns false.
+    fn modify_heightfield(&mut self, f: impl Fn(&mut parry::shape::HeightField) -> bool) -> bool {
+        // Avoid cloning shared shapes that aren't heightfields
+        if self.shape.as_heightfield().is_none() {
+            return false;
+        }
+
+        let Some(heightfield) = self
+            .shape
+            .make_mut()
+            .as_shape_mut::<parry::shape::HeightField>()
+        else {
+            return false;
+        };
+        if !f(heightfield) {
+            return false;
+        }
+        if let Some(heightfield) = self
+            .scaled_shape
+            .make_mut()
+            .as_shape_mut::<parry::shape::HeightField>()
+        {
+            f(heightfield);
+        }
+        true
+    }
+
     /// Creates a collider with a triangle mesh shape from a `Mesh`.
     ///
     /// ## Example
@@ -694,6 +964,151 @@ impl Collider {
     }
 }
 
+// Conversions from Bevy's mesh shapes, so that a spawned primitive mesh can get a matching collider with one call.
+#[cfg(all(feature = "3d", feature = "collider-from-mesh"))]
+mod mesh_shapes {
+    use super::*;
+    use bevy::render::mesh::shape;
+
+    impl From<shape::Cube> for Collider {
+        fn from(cube: shape::Cube) -> Self {
+            let size = cube.size as Scalar;
+            Collider::cuboid(size, size, size)
+        }
+    }
+
+    impl From<shape::Box> for Collider {
+        fn from(b: shape::Box) -> Self {
+            let min = Vector::new(b.min_x as Scalar, b.min_y as Scalar, b.min_z as Scalar);
+            let max = Vector::new(b.max_x as Scalar, b.max_y as Scalar, b.max_z as Scalar);
+            let size = max - min;
+            let cuboid = Collider::cuboid(size.x, size.y, size.z);
+            let center = (min + max) * 0.5;
+
+            if center == Vector::ZERO {
+                cuboid
+            } else {
+                Collider::compound(vec![(center, Rotation::default(), cuboid)])
+            }
+        }
+    }
+
+    impl From<shape::Capsule> for Collider {
+        fn from(capsule: shape::Capsule) -> Self {
+            Collider::capsule(capsule.depth as Scalar, capsule.radius as Scalar)
+        }
+    }
+
+    impl From<shape::Cylinder> for Collider {
+        fn from(cylinder: shape::Cylinder) -> Self {
+            Collider::cylinder(cylinder.height as Scalar, cylinder.radius as Scalar)
+        }
+    }
+
+    impl From<shape::UVSphere> for Collider {
+        fn from(sphere: shape::UVSphere) -> Self {
+            Collider::ball(sphere.radius as Scalar)
+        }
+    }
+
+    impl From<shape::Icosphere> for Collider {
+        fn from(sphere: shape::Icosphere) -> Self {
+            Collider::ball(sphere.radius as Scalar)
+        }
+    }
+
+    /// A torus is approximated by a ring of capsules, one for each of its segments.
+    impl From<shape::Torus> for Collider {
+        fn from(torus: shape::Torus) -> Self {
+            let segments = torus.subdivisions_segments.max(3);
+            let radius = torus.radius as Scalar;
+            let ring_radius = torus.ring_radius as Scalar;
+            let point = |i: usize| {
+                let angle = i as Scalar / segments as Scalar * 2.0 * PI;
+                Vector::new(angle.cos(), 0.0, angle.sin()) * radius
+            };
+
+            Collider::compound(
+                (0..segments)
+                    .map(|i| {
+                        (
+                            Vector::ZERO,
+                            Rotation::default(),
+                            Collider::capsule_endpoints(point(i), point(i + 1), ring_radius),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+    }
+
+    /// A plane is a flat square on the `XZ` plane, so it is represented by a triangle mesh.
+    impl From<shape::Plane> for Collider {
+        fn from(plane: shape::Plane) -> Self {
+            let half_size = plane.size as Scalar * 0.5;
+            Collider::trimesh(
+                vec![
+                    Vector::new(-half_size, 0.0, -half_size),
+                    Vector::new(half_size, 0.0, -half_size),
+                    Vector::new(half_size, 0.0, half_size),
+                    Vector::new(-half_size, 0.0, half_size),
+                ],
+                vec![[0, 2, 1], [0, 3, 2]],
+            )
+        }
+    }
+
+    /// A quad is a flat rectangle on the `XY` plane, so it is represented by a triangle mesh.
+    impl From<shape::Quad> for Collider {
+        fn from(quad: shape::Quad) -> Self {
+            let half_size = Vector2::new(quad.size.x as Scalar, quad.size.y as Scalar) * 0.5;
+            Collider::trimesh(
+                vec![
+                    Vector::new(-half_size.x, -half_size.y, 0.0),
+                    Vector::new(half_size.x, -half_size.y, 0.0),
+                    Vector::new(half_size.x, half_size.y, 0.0),
+                    Vector::new(-half_size.x, half_size.y, 0.0),
+                ],
+                vec![[0, 1, 2], [0, 2, 3]],
+            )
+        }
+    }
+}
+
+#[cfg(all(feature = "2d", feature = "collider-from-mesh"))]
+mod mesh_shapes {
+    use super::*;
+    use bevy::render::mesh::shape;
+
+    impl From<shape::Quad> for Collider {
+        fn from(quad: shape::Quad) -> Self {
+            Collider::cuboid(quad.size.x as Scalar, quad.size.y as Scalar)
+        }
+    }
+
+    impl From<shape::Circle> for Collider {
+        fn from(circle: shape::Circle) -> Self {
+            Collider::ball(circle.radius as Scalar)
+        }
+    }
+
+    impl From<shape::RegularPolygon> for Collider {
+        fn from(polygon: shape::RegularPolygon) -> Self {
+            let sides = polygon.sides.max(3);
+            let radius = polygon.radius as Scalar;
+
+            // Use the same vertices as the mesh
+            let vertices = (0..sides)
+                .map(|i| {
+                    let angle = PI * 0.5 - i as Scalar / sides as Scalar * 2.0 * PI;
+                    Vector::new(angle.cos(), angle.sin()) * radius
+                })
+                .collect();
+            Collider::convex_hull(vertices).unwrap_or_else(|| Collider::ball(radius))
+        }
+    }
+}
+
 #[cfg(all(feature = "3d", feature = "collider-from-mesh"))]
 type VerticesIndices = (Vec<nalgebra::Point3<Scalar>>, Vec<[u32; 3]>);
 
@@ -1199,15 +1614,127 @@ impl From<Transform> for ColliderTransform {
 #[reflect(Component)]
 pub struct Sensor;
 
-/// The Axis-Aligned Bounding Box of a collider.
+/// A component that makes a [`Collider`] only take part in [spatial queries](spatial_query),
+/// like [ray casts](spatial_query#ray-casting) and [shape casts](spatial_query#shape-casting).
+///
+/// Query-only colliders are completely ignored by the simulation. Unlike [sensors](Sensor), they don't
+/// generate any contacts or [collision events](Collider#collision-events), and they don't contribute
+/// to the mass properties of the [rigid body](RigidBody) they are attached to. This makes them useful
+/// for things like visibility meshes, navigation probes and camera collision volumes.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A volume that blocks the camera, but doesn't affect bodies moving through it
+///     commands.spawn((Collider::ball(5.0), QueryOnly));
+/// }
+///
+/// fn check_camera_obstruction(spatial_query: SpatialQuery) {
+///     // Query-only colliders are hit by ray casts like any other collider
+///     if let Some(hit) = spatial_query.cast_ray(
+///         Vector::ZERO,
+///         Vector::X,
+///         100.0,
+///         true,
+///         SpatialQueryFilter::default(),
+///     ) {
+///         println!("Camera obstructed by {:?}", hit.entity);
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct QueryOnly;
+
+/// The Axis-Aligned Bounding Box of a collider in world space.
+///
+/// The AABB is updated by the [broad phase](BroadPhasePlugin) and covers the space that the collider
+/// could occupy during the next physics frame. It is enlarged by a safety margin that can be configured
+/// using [`BroadPhaseConfig`].
+///
+/// This component is added automatically for all entities with a [`Collider`], and it can be
+/// used for things like streaming and custom culling.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn print_bounds(query: Query<(Entity, &ColliderAabb)>) {
+///     for (entity, aabb) in &query {
+///         println!("{:?}: min {}, max {}", entity, aabb.min(), aabb.max());
+///     }
+/// }
+/// ```
 #[derive(Clone, Copy, Component, Debug, Deref, DerefMut, PartialEq)]
 pub struct ColliderAabb(pub Aabb);
 
 impl ColliderAabb {
+    /// Creates a new AABB from its center and half-extents.
+    pub fn new(center: Vector, half_size: Vector) -> Self {
+        Self::from_min_max(center - half_size, center + half_size)
+    }
+
+    /// Creates a new AABB from its minimum and maximum points.
+    pub fn from_min_max(min: Vector, max: Vector) -> Self {
+        Self(Aabb::new(min.into(), max.into()))
+    }
+
     /// Creates a new collider from a given [`SharedShape`] with a default density of 1.0.
     pub fn from_shape(shape: &SharedShape) -> Self {
         Self(shape.compute_local_aabb())
     }
+
+    /// Returns the minimum point of the AABB.
+    pub fn min(&self) -> Vector {
+        self.0.mins.into()
+    }
+
+    /// Returns the maximum point of the AABB.
+    pub fn max(&self) -> Vector {
+        self.0.maxs.into()
+    }
+
+    /// Returns the center point of the AABB.
+    pub fn center(&self) -> Vector {
+        self.0.center().into()
+    }
+
+    /// Returns the full extents of the AABB.
+    pub fn size(&self) -> Vector {
+        self.max() - self.min()
+    }
+
+    /// Returns the half-extents of the AABB.
+    pub fn half_size(&self) -> Vector {
+        self.size() * 0.5
+    }
+
+    /// Returns a copy of the AABB enlarged by the given `padding` on each side.
+    pub fn padded(&self, padding: Scalar) -> Self {
+        Self(self.0.loosened(padding))
+    }
+
+    /// Returns true if the AABB intersects the `other` AABB.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.0.intersects(&other.0)
+    }
+
+    /// Returns true if the AABB contains the given point.
+    pub fn contains_point(&self, point: Vector) -> bool {
+        self.min().cmple(point).all() && self.max().cmpge(point).all()
+    }
 }
 
 impl Default for ColliderAabb {
@@ -1218,7 +1745,14 @@ impl Default for ColliderAabb {
 
 /// Contains the entities that are colliding with an entity.
 ///
-/// This component is automatically added for all entities with a [`Collider`].
+/// This component is automatically added for all entities with a [`Collider`], and it is kept up to date
+/// by the [`ContactReportingPlugin`] based on the same collision start and end detection as the
+/// [collision events](Collider#collision-events). Overlaps with [sensors](Sensor) are included, so trigger zones
+/// can simply read the set of entities inside them instead of keeping track of [`CollisionStarted`]
+/// and [`CollisionEnded`] events.
+///
+/// Unlike the collision events, the component is updated even if the [`CollisionEventLayers`] of the
+/// entities don't interact.
 ///
 /// ## Example
 ///
@@ -1242,3 +1776,169 @@ impl Default for ColliderAabb {
 #[derive(Reflect, Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, Eq)]
 #[reflect(Component)]
 pub struct CollidingEntities(pub HashSet<Entity>);
+
+/// A component that prevents an entity from colliding with specific other entities,
+/// without having to create new [`CollisionLayers`] for each case.
+///
+/// The entities can be [colliders](Collider) or [rigid bodies](RigidBody). Ignoring a rigid body also ignores all
+/// of the colliders attached to it, and the component can be added to either a collider or its rigid body.
+/// It is enough for one of the two entities to ignore the other.
+///
+/// Ignored pairs are removed from the [`BroadCollisionPairs`] during the [broad phase](BroadPhasePlugin),
+/// so no contacts or [collision events](Collider#collision-events) are generated for them.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let vehicle = commands.spawn((RigidBody::Dynamic, Collider::ball(2.0))).id();
+///
+///     // The player never collides with their own vehicle
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         IgnoreCollisions::new([vehicle]),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct IgnoreCollisions(pub HashSet<Entity>);
+
+impl IgnoreCollisions {
+    /// Creates a new [`IgnoreCollisions`] component that ignores collisions with the given entities.
+    pub fn new(entities: impl IntoIterator<Item = Entity>) -> Self {
+        Self(HashSet::from_iter(entities))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, tests::cube_collider};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn typed_shape_accessors_return_scaled_shapes() {
+        let mut ball = Collider::ball(0.5);
+        assert!(ball.as_cuboid().is_none());
+        ball.set_scale(Vector::splat(2.0), 10);
+        assert_relative_eq!(ball.as_ball().unwrap().radius, 1.0);
+
+        let mut cuboid = cube_collider(2.0);
+        assert!(cuboid.as_ball().is_none());
+        cuboid.set_scale(Vector::splat(0.5), 10);
+        assert_eq!(
+            cuboid.as_cuboid().unwrap().half_extents,
+            Vector::splat(0.5).into()
+        );
+
+        let capsule = Collider::capsule(2.0, 0.5);
+        assert!(capsule.as_ball().is_none());
+        let shape = capsule.as_capsule().unwrap();
+        assert_relative_eq!(shape.radius, 0.5);
+        assert_relative_eq!(shape.height(), 2.0);
+    }
+
+    #[test]
+    fn trimesh_flags_clean_up_meshes() {
+        let vertices = vec![Vector::ZERO, Vector::X, Vector::Y, Vector::ZERO];
+        let indices = vec![[0, 1, 2], [3, 1, 2]];
+
+        let collider = Collider::trimesh(vertices.clone(), indices.clone());
+        assert_eq!(collider.as_trimesh().unwrap().vertices().len(), 4);
+
+        let collider = Collider::trimesh_with_flags(
+            vertices,
+            indices,
+            TriMeshFlags::MERGE_DUPLICATE_VERTICES | TriMeshFlags::DELETE_DUPLICATE_TRIANGLES,
+        );
+        let trimesh = collider.as_trimesh().unwrap();
+        assert_eq!(trimesh.vertices().len(), 3);
+        assert_eq!(trimesh.indices().len(), 1);
+    }
+
+    #[test]
+    fn heightfield_holes_have_no_collision() {
+        #[cfg(feature = "2d")]
+        let mut terrain = Collider::heightfield(vec![0.0; 5], 4.0);
+        #[cfg(feature = "2d")]
+        {
+            assert!(terrain.set_heightfield_hole(2, true));
+            assert!(!terrain.set_heightfield_hole(10, true));
+            assert_eq!(terrain.is_heightfield_hole(2), Some(true));
+        }
+
+        #[cfg(feature = "3d")]
+        let mut terrain = Collider::heightfield(vec![vec![0.0; 5]; 5], Vector::splat(4.0));
+        #[cfg(feature = "3d")]
+        {
+            assert!(terrain.set_heightfield_hole(2, 2, true));
+            assert!(!terrain.set_heightfield_hole(10, 2, true));
+            assert_eq!(terrain.is_heightfield_hole(2, 2), Some(true));
+        }
+
+        let ball = Collider::ball(0.1);
+        let contacts_at = |position: Vector| {
+            contact_query::contact_manifolds(
+                &terrain,
+                Vector::ZERO,
+                Rotation::default(),
+                &ball,
+                position,
+                Rotation::default(),
+                0.0,
+            )
+        };
+
+        // The heightfield is centered at the origin with cells of size 1, so the hole starts at the origin
+        #[cfg(feature = "2d")]
+        let (hole, ground) = (Vector::new(0.5, 0.05), Vector::new(-1.5, 0.05));
+        #[cfg(feature = "3d")]
+        let (hole, ground) = (Vector::new(0.5, 0.05, 0.5), Vector::new(-1.5, 0.05, -1.5));
+
+        assert!(contacts_at(hole).is_empty());
+        assert!(!contacts_at(ground).is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "3d", feature = "collider-from-mesh"))]
+    fn colliders_from_mesh_shapes_match_meshes() {
+        use bevy::{prelude::*, render::mesh::shape};
+
+        let cube = Collider::from(shape::Cube { size: 2.0 });
+        assert_eq!(cube.as_cuboid().unwrap().half_extents, Vector::ONE.into());
+
+        // The collider of an off-center box is offset to match the mesh
+        let aabb = Collider::from(shape::Box::new(1.0, 2.0, 3.0))
+            .compute_aabb(Vector::ZERO, Quaternion::IDENTITY);
+        let offset_aabb = Collider::from(shape::Box {
+            min_x: 0.0,
+            max_x: 1.0,
+            min_y: 0.0,
+            max_y: 2.0,
+            min_z: 0.0,
+            max_z: 3.0,
+        })
+        .compute_aabb(Vector::ZERO, Quaternion::IDENTITY);
+        assert_relative_eq!(
+            Vector::from(offset_aabb.center()) - Vector::from(aabb.center()),
+            Vector::new(0.5, 1.0, 1.5),
+            epsilon = 0.0001
+        );
+
+        let torus = shape::Torus {
+            radius: 2.0,
+            ring_radius: 0.5,
+            ..default()
+        };
+        let aabb = Collider::from(torus).compute_aabb(Vector::ZERO, Quaternion::IDENTITY);
+        assert_relative_eq!(aabb.maxs.x, 2.5, epsilon = 0.0001);
+        assert_relative_eq!(aabb.maxs.y, 0.5, epsilon = 0.0001);
+    }
+}
@@ -138,6 +138,16 @@ impl ExternalForce {
         Self { force, ..default() }
     }
 
+    /// Creates a new [`ExternalForce`] component with a given world-space `force` applied at a `point`,
+    /// which will also cause torque to be applied.
+    ///
+    /// The `point` and `center_of_mass` must be in the same space, typically world space.
+    pub fn at_point(force: Vector, point: Vector, center_of_mass: Vector) -> Self {
+        let mut external_force = Self::default();
+        external_force.apply_force_at_point(force, point, center_of_mass);
+        external_force
+    }
+
     /// Sets the world-space force. Note that the torque caused by any forces will not be reset.
     pub fn set_force(&mut self, force: Vector) -> &mut Self {
         **self = force;
@@ -150,7 +160,9 @@ impl ExternalForce {
         self
     }
 
-    /// Applies the given world-space `force` at a local `point`, which will also cause torque to be applied.
+    /// Applies the given world-space `force` at a `point`, which will also cause torque to be applied.
+    ///
+    /// The `point` and `center_of_mass` must be in the same space, typically world space.
     pub fn apply_force_at_point(
         &mut self,
         force: Vector,
@@ -340,6 +352,12 @@ impl ExternalTorque {
 ///     let mut impulse = ExternalImpulse::default();
 ///     impulse.apply_impulse_at_point(Vec3::Y, Vec3::X, Vec3::ZERO);
 ///     commands.spawn((RigidBody::Dynamic, impulse));
+///
+///     // The same impulse using a constructor.
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         ExternalImpulse::at_point(Vec3::Y, Vec3::X, Vec3::ZERO),
+///     ));
 /// }
 /// ```
 ///
@@ -422,6 +440,16 @@ impl ExternalImpulse {
         }
     }
 
+    /// Creates a new [`ExternalImpulse`] component with a given world-space `impulse` applied at a `point`,
+    /// which will also cause an angular impulse to be applied.
+    ///
+    /// The `point` and `center_of_mass` must be in the same space, typically world space.
+    pub fn at_point(impulse: Vector, point: Vector, center_of_mass: Vector) -> Self {
+        let mut external_impulse = Self::default();
+        external_impulse.apply_impulse_at_point(impulse, point, center_of_mass);
+        external_impulse
+    }
+
     /// Sets the world-space impulse. Note that the angular impulse caused by any impulses will not be reset.
     pub fn set_impulse(&mut self, impulse: Vector) -> &mut Self {
         **self = impulse;
@@ -434,7 +462,9 @@ impl ExternalImpulse {
         self
     }
 
-    /// Applies the given world-space `impulse` at a local `point`, which will also cause an angular impulse to be applied.
+    /// Applies the given world-space `impulse` at a `point`, which will also cause an angular impulse to be applied.
+    ///
+    /// The `point` and `center_of_mass` must be in the same space, typically world space.
     pub fn apply_impulse_at_point(
         &mut self,
         impulse: Vector,
@@ -587,3 +617,52 @@ impl ExternalAngularImpulse {
         self.impulse = Torque::ZERO;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn impulse_at_point_rotates_body() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // An upward impulse on the right side of the body makes it rotate counterclockwise
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ExternalImpulse::at_point(Vector::Y, Vector::X, Vector::ZERO),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        let inverse_mass = app.world.get::<InverseMass>(body).unwrap().0;
+        let inverse_inertia = app.world.get::<InverseInertia>(body).unwrap().0;
+        let linear_velocity = app.world.get::<LinearVelocity>(body).unwrap().0;
+        let angular_velocity = app.world.get::<AngularVelocity>(body).unwrap().0;
+        assert_relative_eq!(linear_velocity, Vector::Y * inverse_mass, epsilon = 1e-5);
+        #[cfg(feature = "2d")]
+        assert_relative_eq!(angular_velocity, inverse_inertia, epsilon = 1e-5);
+        #[cfg(feature = "3d")]
+        assert_relative_eq!(
+            angular_velocity,
+            inverse_inertia * Vector::Z,
+            epsilon = 1e-5
+        );
+
+        // The impulse is only applied once
+        app.step_physics(1);
+
+        assert_relative_eq!(
+            app.world.get::<AngularVelocity>(body).unwrap().0,
+            angular_velocity,
+            epsilon = 1e-5
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::system::{Command, EntityCommands},
+    hierarchy::despawn_with_children_recursive,
+    prelude::*,
+};
+use parry::shape::{SharedShape, TypedShape};
+
+/// The number of subdivisions used for approximating curved shapes like balls and capsules when fracturing them.
+const FRACTURE_SUBDIVISIONS: u32 = 16;
+
+/// Determines how a [`Collider`] is split into fragments when it's [fractured](Collider::fracture).
+#[derive(Clone, Debug, PartialEq)]
+pub enum FracturePattern {
+    /// Splits the collider into two fragments along a plane (a line in 2D) going through `point`.
+    Plane {
+        /// A point on the plane.
+        point: Vector,
+        /// The normal of the plane.
+        normal: Vector,
+    },
+    /// Splits the collider into the cells of a [Voronoi diagram](https://en.wikipedia.org/wiki/Voronoi_diagram)
+    /// with the given sites. Each fragment contains the part of the collider closer to its site than to any other site.
+    Voronoi(Vec<Vector>),
+}
+
+impl Collider {
+    /// Splits the collider into fragments according to the given [`FracturePattern`]. The pattern is given
+    /// in the local space of the collider, and the fragments are in the same space as the original collider.
+    ///
+    /// The fragments are convex, so non-convex shapes like triangle meshes are approximated by the convex hulls
+    /// of their pieces, and curved shapes are approximated by polygons or polyhedra. Pieces that are empty or degenerate
+    /// are skipped.
+    ///
+    /// To replace a rigid body with its fragments at runtime, see [`FractureCommandsExt::fracture`].
+    pub fn fracture(&self, pattern: &FracturePattern) -> Vec<Collider> {
+        let mut points = vec![];
+        shape_points(self.shape_scaled(), &mut points);
+
+        if points.is_empty() {
+            return vec![];
+        }
+
+        let cells = match pattern {
+            FracturePattern::Plane { point, normal } => vec![
+                clip_points(&points, *point, *normal),
+                clip_points(&points, *point, -*normal),
+            ],
+            FracturePattern::Voronoi(sites) => sites
+                .iter()
+                .enumerate()
+                .map(|(i, site)| {
+                    let mut cell = points.clone();
+                    for (j, other) in sites.iter().enumerate() {
+                        if i == j || cell.is_empty() {
+                            continue;
+                        }
+                        // Keep the part that is closer to this site than to the other one
+                        let bisector = (*site + *other) * 0.5;
+                        cell = clip_points(&cell, bisector, *other - *site);
+
+                        // Keep only the vertices of the cell to avoid accumulating redundant points
+                        cell = Collider::convex_hull(cell).map_or(vec![], |hull| {
+                            let mut vertices = vec![];
+                            shape_points(hull.shape(), &mut vertices);
+                            vertices
+                        });
+                    }
+                    cell
+                })
+                .collect(),
+        };
+
+        cells
+            .into_iter()
+            .filter_map(Collider::convex_hull)
+            .collect()
+    }
+}
+
+/// Appends the vertices of the given shape to `points`. Curved shapes are approximated.
+fn shape_points(shape: &SharedShape, points: &mut Vec<Vector>) {
+    #[cfg(feature = "2d")]
+    match shape.as_typed_shape() {
+        TypedShape::Ball(s) => points.extend(
+            s.to_polyline(FRACTURE_SUBDIVISIONS)
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::Cuboid(s) => points.extend(s.to_polyline().into_iter().map(Vector::from)),
+        TypedShape::Capsule(s) => points.extend(
+            s.to_polyline(FRACTURE_SUBDIVISIONS)
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::ConvexPolygon(s) => points.extend(s.points().iter().map(|p| Vector::from(*p))),
+        TypedShape::Triangle(s) => points.extend(s.vertices().iter().map(|p| Vector::from(*p))),
+        TypedShape::Polyline(s) => points.extend(s.vertices().iter().map(|p| Vector::from(*p))),
+        TypedShape::Compound(s) => compound_points(s, points),
+        _ => (),
+    }
+
+    #[cfg(feature = "3d")]
+    match shape.as_typed_shape() {
+        TypedShape::Ball(s) => points.extend(
+            s.to_trimesh(FRACTURE_SUBDIVISIONS, FRACTURE_SUBDIVISIONS / 2)
+                .0
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::Cuboid(s) => points.extend(s.to_trimesh().0.into_iter().map(Vector::from)),
+        TypedShape::Capsule(s) => points.extend(
+            s.to_trimesh(FRACTURE_SUBDIVISIONS, FRACTURE_SUBDIVISIONS / 2)
+                .0
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::Cylinder(s) => points.extend(
+            s.to_trimesh(FRACTURE_SUBDIVISIONS)
+                .0
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::Cone(s) => points.extend(
+            s.to_trimesh(FRACTURE_SUBDIVISIONS)
+                .0
+                .into_iter()
+                .map(Vector::from),
+        ),
+        TypedShape::ConvexPolyhedron(s) => {
+            points.extend(s.points().iter().map(|p| Vector::from(*p)))
+        }
+        TypedShape::TriMesh(s) => points.extend(s.vertices().iter().map(|p| Vector::from(*p))),
+        TypedShape::Triangle(s) => points.extend(s.vertices().iter().map(|p| Vector::from(*p))),
+        TypedShape::Compound(s) => compound_points(s, points),
+        _ => (),
+    }
+}
+
+/// Appends the vertices of the shapes in a compound shape to `points`, transformed to the space of the compound shape.
+fn compound_points(compound: &parry::shape::Compound, points: &mut Vec<Vector>) {
+    for (isometry, shape) in compound.shapes() {
+        let start = points.len();
+        shape_points(shape, points);
+        for point in &mut points[start..] {
+            *point = (isometry * parry::math::Point::from(*point)).into();
+        }
+    }
+}
+
+/// Clips the convex hull of the given points with the half-space on the negative side of a plane.
+///
+/// Returns the points of the hull on the negative side, along with the points where the plane
+/// crosses the segments between the points on either side.
+fn clip_points(points: &[Vector], plane_point: Vector, normal: Vector) -> Vec<Vector> {
+    let distances = points
+        .iter()
+        .map(|point| (*point - plane_point).dot(normal))
+        .collect::<Vec<_>>();
+
+    let mut clipped = points
+        .iter()
+        .zip(&distances)
+        .filter(|(_, distance)| **distance <= 0.0)
+        .map(|(point, _)| *point)
+        .collect::<Vec<_>>();
+
+    for (point1, distance1) in points.iter().zip(&distances) {
+        if *distance1 >= 0.0 {
+            continue;
+        }
+        for (point2, distance2) in points.iter().zip(&distances) {
+            if *distance2 > 0.0 {
+                let t = distance1 / (distance1 - distance2);
+                clipped.push(point1.lerp(*point2, t));
+            }
+        }
+    }
+
+    clipped
+}
+
+/// Marks a rigid body that was created by [fracturing](FractureCommandsExt::fracture) another body.
+///
+/// This can be used for finding new fragments using `Added<FractureFragment>`, for example to give them meshes.
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct FractureFragment {
+    /// The entity that was fractured. It is despawned when the fragments are created.
+    pub source: Entity,
+}
+
+impl Default for FractureFragment {
+    /// Creates a fragment with a placeholder source entity.
+    fn default() -> Self {
+        Self {
+            source: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+/// A command that replaces a rigid body with fragments of its [`Collider`]. See [`FractureCommandsExt::fracture`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fracture {
+    /// The entity that is fractured.
+    pub entity: Entity,
+    /// The pattern in world space.
+    pub pattern: FracturePattern,
+}
+
+impl Command for Fracture {
+    fn apply(self, world: &mut World) {
+        let Some(entity_ref) = world.get_entity(self.entity) else {
+            return;
+        };
+        let Some(collider) = entity_ref.get::<Collider>().cloned() else {
+            return;
+        };
+
+        let position = entity_ref.get::<Position>().copied().unwrap_or_default();
+        let rotation = entity_ref.get::<Rotation>().copied().unwrap_or_default();
+        let lin_vel = entity_ref
+            .get::<LinearVelocity>()
+            .copied()
+            .unwrap_or_default();
+        let ang_vel = entity_ref
+            .get::<AngularVelocity>()
+            .copied()
+            .unwrap_or_default();
+        let center_of_mass = entity_ref
+            .get::<CenterOfMass>()
+            .copied()
+            .unwrap_or_default();
+        let density = entity_ref
+            .get::<ColliderDensity>()
+            .copied()
+            .unwrap_or_default();
+        let transform = entity_ref.get::<Transform>().copied().unwrap_or_default();
+        let layers = entity_ref.get::<CollisionLayers>().copied();
+        let friction = entity_ref.get::<Friction>().copied();
+        let restitution = entity_ref.get::<Restitution>().copied();
+
+        // Transform the pattern to the local space of the collider
+        let inverse_rotation = rotation.inverse();
+        let pattern = match self.pattern {
+            FracturePattern::Plane { point, normal } => FracturePattern::Plane {
+                point: inverse_rotation.rotate(point - position.0),
+                normal: inverse_rotation.rotate(normal),
+            },
+            FracturePattern::Voronoi(sites) => FracturePattern::Voronoi(
+                sites
+                    .into_iter()
+                    .map(|site| inverse_rotation.rotate(site - position.0))
+                    .collect(),
+            ),
+        };
+
+        let fragments = collider.fracture(&pattern);
+
+        for fragment in fragments {
+            // The fragments keep moving with the velocity of their point on the original body
+            let offset = rotation
+                .rotate(fragment.mass_properties(density.0).center_of_mass.0 - center_of_mass.0);
+            #[cfg(feature = "2d")]
+            let velocity = lin_vel.0 + ang_vel.0 * Vector::new(-offset.y, offset.x);
+            #[cfg(feature = "3d")]
+            let velocity = lin_vel.0 + ang_vel.0.cross(offset);
+
+            let mut fragment_entity = world.spawn((
+                RigidBody::Dynamic,
+                fragment,
+                density,
+                position,
+                rotation,
+                LinearVelocity(velocity),
+                ang_vel,
+                TransformBundle::from_transform(transform),
+                FractureFragment {
+                    source: self.entity,
+                },
+            ));
+
+            if let Some(layers) = layers {
+                fragment_entity.insert(layers);
+            }
+            if let Some(friction) = friction {
+                fragment_entity.insert(friction);
+            }
+            if let Some(restitution) = restitution {
+                fragment_entity.insert(restitution);
+            }
+        }
+
+        despawn_with_children_recursive(world, self.entity);
+    }
+}
+
+/// An extension trait for [fracturing](Fracture) rigid bodies using `EntityCommands`.
+pub trait FractureCommandsExt {
+    /// Replaces the body with dynamic bodies for the fragments of its [`Collider`], split according to the given
+    /// [`FracturePattern`] in world space.
+    ///
+    /// The fragments have the mass properties of their own shapes, and they inherit the velocity of the point
+    /// on the original body where they are located, as well as its [`CollisionLayers`], [`Friction`],
+    /// [`Restitution`] and [`ColliderDensity`]. Each fragment has a [`FractureFragment`] component.
+    /// The original entity is despawned along with its children.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Breakable;
+    ///
+    /// // Shatter breakable bodies into three pieces when they hit something hard enough
+    /// fn shatter_on_impact(
+    ///     mut commands: Commands,
+    ///     mut collision_events: EventReader<CollisionStarted>,
+    ///     query: Query<&Position, With<Breakable>>,
+    ///     collisions: Res<Collisions>,
+    /// ) {
+    ///     for CollisionStarted(entity1, entity2) in collision_events.iter() {
+    ///         let Some(contacts) = collisions.get(*entity1, *entity2) else {
+    ///             continue;
+    ///         };
+    ///         for entity in [*entity1, *entity2] {
+    ///             if let Ok(position) = query.get(entity) {
+    ///                 if contacts.impact_speed > 10.0 {
+    ///                     let sites = vec![
+    ///                         position.0 + Vector::X * 0.2,
+    ///                         position.0 - Vector::X * 0.2,
+    ///                         position.0 + Vector::Y * 0.2,
+    ///                     ];
+    ///                     commands.entity(entity).fracture(FracturePattern::Voronoi(sites));
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn fracture(&mut self, pattern: FracturePattern) -> &mut Self;
+}
+
+impl FractureCommandsExt for EntityCommands<'_, '_, '_> {
+    fn fracture(&mut self, pattern: FracturePattern) -> &mut Self {
+        let entity = self.id();
+        self.commands().add(Fracture { entity, pattern });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::{create_app, cube_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::{ecs::system::Command, prelude::*};
+
+    #[test]
+    fn fractured_bodies_are_replaced_by_fragments() {
+        let mut app = create_app();
+
+        let collider = cube_collider(2.0);
+
+        // Splitting the collider in half halves the mass of the pieces
+        let halves = collider.fracture(&FracturePattern::Plane {
+            point: Vector::ZERO,
+            normal: Vector::X,
+        });
+        assert_eq!(halves.len(), 2);
+        for half in &halves {
+            assert_relative_eq!(
+                half.mass_properties(1.0).mass(),
+                collider.mass_properties(1.0).mass() * 0.5,
+                epsilon = 0.001
+            );
+        }
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                collider,
+                LinearVelocity(Vector::Y * 2.0),
+            ))
+            .id();
+
+        let sites = vec![Vector::X * -0.5, Vector::X * 0.5, Vector::Y * 0.5];
+        Fracture {
+            entity: body,
+            pattern: FracturePattern::Voronoi(sites),
+        }
+        .apply(&mut app.world);
+
+        assert!(app.world.get_entity(body).is_none());
+
+        let mut fragments = app.world.query::<(&FractureFragment, &LinearVelocity)>();
+        assert_eq!(fragments.iter(&app.world).count(), 3);
+        for (fragment, lin_vel) in fragments.iter(&app.world) {
+            assert_eq!(fragment.source, body);
+            assert_eq!(lin_vel.0, Vector::Y * 2.0);
+        }
+    }
+}
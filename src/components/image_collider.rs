@@ -0,0 +1,397 @@
+use crate::prelude::*;
+use bevy::{
+    prelude::*,
+    render::render_resource::TextureFormat,
+    utils::{HashMap, HashSet},
+};
+
+/// Configures how [colliders](Collider) are generated from the alpha channel of an `Image`.
+///
+/// See [`Collider::convex_decomposition_from_image`], [`Collider::convex_hull_from_image`]
+/// and [`Collider::polyline_from_image`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageColliderConfig {
+    /// Pixels with an alpha value above this threshold are considered solid. The alpha values range from `0.0` to `1.0`.
+    pub alpha_threshold: f32,
+    /// The maximum distance in pixels that the simplified outline can deviate from the exact outline of the pixels.
+    /// Larger values produce fewer vertices.
+    pub tolerance: Scalar,
+    /// The size of a pixel in world units. The default of `1.0` matches the default size of a `Sprite`.
+    pub pixel_size: Scalar,
+    /// The parameters used for decomposing the outline into convex parts in [`Collider::convex_decomposition_from_image`].
+    pub decomposition: VHACDParameters,
+}
+
+impl Default for ImageColliderConfig {
+    fn default() -> Self {
+        Self {
+            alpha_threshold: 0.5,
+            tolerance: 1.0,
+            pixel_size: 1.0,
+            decomposition: VHACDParameters::default(),
+        }
+    }
+}
+
+impl Collider {
+    /// Creates a collider with a compound shape obtained by tracing the outlines of the opaque pixels
+    /// in an `Image` and decomposing them into convex parts. This produces accurate colliders for
+    /// concave sprites that work well for dynamic bodies.
+    ///
+    /// The collider is centered on the image like a `Sprite`, with the `Y` axis pointing up.
+    /// Only images with 8-bit RGBA or BGRA formats are supported.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its format is not supported.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_xpbd_2d::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct NeedsCollider(Handle<Image>);
+    ///
+    /// fn add_sprite_colliders(
+    ///     mut commands: Commands,
+    ///     query: Query<(Entity, &NeedsCollider)>,
+    ///     images: Res<Assets<Image>>,
+    /// ) {
+    ///     for (entity, NeedsCollider(handle)) in &query {
+    ///         // Wait for the image to load
+    ///         let Some(image) = images.get(handle) else {
+    ///             continue;
+    ///         };
+    ///         let mut entity_commands = commands.entity(entity);
+    ///         entity_commands.remove::<NeedsCollider>();
+    ///         let config = ImageColliderConfig::default();
+    ///         if let Some(collider) = Collider::convex_decomposition_from_image(image, &config) {
+    ///             entity_commands.insert(collider);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn convex_decomposition_from_image(
+        image: &Image,
+        config: &ImageColliderConfig,
+    ) -> Option<Self> {
+        let (vertices, indices) = image_outlines(image, config)?;
+        Some(Self::convex_decomposition_with_config(
+            vertices,
+            indices,
+            &config.decomposition,
+        ))
+    }
+
+    /// Creates a collider with a convex polygon shape obtained by computing the convex hull of the opaque pixels
+    /// in an `Image`. This is cheaper than [`Collider::convex_decomposition_from_image`], but concave
+    /// parts of the sprite are filled in.
+    ///
+    /// The collider is centered on the image like a `Sprite`, with the `Y` axis pointing up.
+    /// Only images with 8-bit RGBA or BGRA formats are supported.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its format is not supported.
+    pub fn convex_hull_from_image(image: &Image, config: &ImageColliderConfig) -> Option<Self> {
+        let (vertices, _) = image_outlines(image, config)?;
+        Self::convex_hull(vertices)
+    }
+
+    /// Creates a collider with a polyline shape that follows the outlines of the opaque pixels in an `Image`.
+    /// Polylines are hollow, so this is mostly useful for static level geometry.
+    ///
+    /// The collider is centered on the image like a `Sprite`, with the `Y` axis pointing up.
+    /// Only images with 8-bit RGBA or BGRA formats are supported.
+    ///
+    /// Returns `None` if the image has no opaque pixels or its format is not supported.
+    pub fn polyline_from_image(image: &Image, config: &ImageColliderConfig) -> Option<Self> {
+        let (vertices, indices) = image_outlines(image, config)?;
+        Some(Self::polyline(vertices, Some(indices)))
+    }
+}
+
+/// A crossing of the outline through the edge of a marching squares cell.
+///
+/// The cell corners are pixel centers. `Horizontal(x, y)` is the edge from pixel `(x, y)` to `(x + 1, y)`,
+/// and `Vertical(x, y)` is the edge from pixel `(x, y)` to `(x, y + 1)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum EdgeCrossing {
+    Horizontal(i32, i32),
+    Vertical(i32, i32),
+}
+
+impl EdgeCrossing {
+    /// Returns the position of the crossing in pixel coordinates.
+    fn position(self) -> Vector {
+        match self {
+            Self::Horizontal(x, y) => Vector::new(x as Scalar + 0.5, y as Scalar),
+            Self::Vertical(x, y) => Vector::new(x as Scalar, y as Scalar + 0.5),
+        }
+    }
+}
+
+/// Traces the outlines of the opaque pixels of an image using marching squares and simplifies them.
+///
+/// Returns the vertices and the indices of the closed outlines, or `None` if the image has no opaque pixels
+/// or its format is not supported.
+fn image_outlines(
+    image: &Image,
+    config: &ImageColliderConfig,
+) -> Option<(Vec<Vector>, Vec<[u32; 2]>)> {
+    let alpha_offset = match image.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm
+        | TextureFormat::Rgba8UnormSrgb
+        | TextureFormat::Bgra8Unorm
+        | TextureFormat::Bgra8UnormSrgb => 3,
+        _ => return None,
+    };
+
+    let width = image.texture_descriptor.size.width as i32;
+    let height = image.texture_descriptor.size.height as i32;
+    let threshold = (config.alpha_threshold.clamp(0.0, 1.0) * 255.0) as u8;
+
+    // Pixels outside of the image are transparent
+    let is_solid = |x: i32, y: i32| {
+        x >= 0
+            && y >= 0
+            && x < width
+            && y < height
+            && image
+                .data
+                .get(((y * width + x) * 4 + alpha_offset) as usize)
+                .map_or(false, |alpha| *alpha > threshold)
+    };
+
+    // The outline forms closed loops, so each crossing is connected to exactly two others
+    let mut neighbors = HashMap::<EdgeCrossing, Vec<EdgeCrossing>>::default();
+    let mut connect = |a: EdgeCrossing, b: EdgeCrossing| {
+        neighbors.entry(a).or_default().push(b);
+        neighbors.entry(b).or_default().push(a);
+    };
+
+    for y in -1..height {
+        for x in -1..width {
+            let corners = [
+                is_solid(x, y),
+                is_solid(x + 1, y),
+                is_solid(x + 1, y + 1),
+                is_solid(x, y + 1),
+            ];
+            let top = EdgeCrossing::Horizontal(x, y);
+            let right = EdgeCrossing::Vertical(x + 1, y);
+            let bottom = EdgeCrossing::Horizontal(x, y + 1);
+            let left = EdgeCrossing::Vertical(x, y);
+
+            match corners {
+                // Saddles are resolved by keeping the solid corners separate
+                [true, false, true, false] => {
+                    connect(top, left);
+                    connect(right, bottom);
+                }
+                [false, true, false, true] => {
+                    connect(top, right);
+                    connect(bottom, left);
+                }
+                _ => {
+                    let crossings = [
+                        (corners[0] != corners[1], top),
+                        (corners[1] != corners[2], right),
+                        (corners[3] != corners[2], bottom),
+                        (corners[0] != corners[3], left),
+                    ]
+                    .into_iter()
+                    .filter_map(|(crosses, edge)| crosses.then_some(edge))
+                    .collect::<Vec<_>>();
+
+                    if let [a, b] = crossings[..] {
+                        connect(a, b);
+                    }
+                }
+            }
+        }
+    }
+
+    if neighbors.is_empty() {
+        return None;
+    }
+
+    let mut vertices = vec![];
+    let mut indices = vec![];
+    let mut visited = HashSet::<EdgeCrossing>::default();
+
+    // Convert pixel coordinates to world space, centering the image and flipping the Y axis
+    let to_world = |point: Vector| {
+        Vector::new(
+            point.x + 0.5 - width as Scalar * 0.5,
+            height as Scalar * 0.5 - (point.y + 0.5),
+        ) * config.pixel_size
+    };
+
+    let mut starts = neighbors.keys().copied().collect::<Vec<_>>();
+    // Sort the starting points to make the output deterministic
+    starts.sort_unstable_by_key(|crossing| match crossing {
+        EdgeCrossing::Horizontal(x, y) => (*y, *x, 0),
+        EdgeCrossing::Vertical(x, y) => (*y, *x, 1),
+    });
+
+    for start in starts {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        // Walk along the loop
+        let mut outline = vec![];
+        let mut previous = start;
+        let mut current = start;
+        loop {
+            visited.insert(current);
+            outline.push(current.position());
+
+            let Some(next) = neighbors[&current]
+                .iter()
+                .copied()
+                .find(|next| *next != previous && !visited.contains(next))
+            else {
+                break;
+            };
+            previous = current;
+            current = next;
+        }
+
+        let outline = simplify_closed(&outline, config.tolerance);
+        if outline.len() < 3 {
+            continue;
+        }
+
+        let start_index = vertices.len() as u32;
+        let count = outline.len() as u32;
+        vertices.extend(outline.into_iter().map(to_world));
+        indices.extend((0..count).map(|i| [start_index + i, start_index + (i + 1) % count]));
+    }
+
+    (!vertices.is_empty()).then_some((vertices, indices))
+}
+
+/// Simplifies a closed outline using the [Ramer-Douglas-Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm).
+fn simplify_closed(points: &[Vector], tolerance: Scalar) -> Vec<Vector> {
+    if points.len() < 4 {
+        return points.to_vec();
+    }
+
+    // Split the loop at the point furthest from the first point and simplify both halves
+    let split = (1..points.len())
+        .max_by(|a, b| {
+            points[*a]
+                .distance_squared(points[0])
+                .total_cmp(&points[*b].distance_squared(points[0]))
+        })
+        .unwrap_or(points.len() / 2);
+
+    let mut first_half = points[..=split].to_vec();
+    let mut second_half = points[split..].to_vec();
+    second_half.push(points[0]);
+
+    first_half = simplify_open(&first_half, tolerance);
+    second_half = simplify_open(&second_half, tolerance);
+
+    // Both halves include the split point and the first point
+    first_half.pop();
+    first_half.extend(second_half);
+    first_half.pop();
+    first_half
+}
+
+/// Simplifies an open polyline using the Ramer-Douglas-Peucker algorithm, keeping the first and last points.
+fn simplify_open(points: &[Vector], tolerance: Scalar) -> Vec<Vector> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let direction = (last - first).normalize_or_zero();
+
+    let distance = |point: Vector| {
+        let offset = point - first;
+        if direction == Vector::ZERO {
+            offset.length()
+        } else {
+            (offset - direction * offset.dot(direction)).length()
+        }
+    };
+
+    let (index, max_distance) = points[1..points.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, point)| (i + 1, distance(*point)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap_or((0, 0.0));
+
+    if max_distance <= tolerance {
+        return vec![first, last];
+    }
+
+    let mut result = simplify_open(&points[..=index], tolerance);
+    result.pop();
+    result.extend(simplify_open(&points[index..], tolerance));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+    use bevy::{
+        prelude::*,
+        render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    };
+
+    #[test]
+    fn colliders_from_images_follow_opaque_pixels() {
+        // An 8x8 image with an opaque 4x4 square in the middle
+        let mut data = vec![0; 8 * 8 * 4];
+        for y in 2..6 {
+            for x in 2..6 {
+                data[(y * 8 + x) * 4 + 3] = 255;
+            }
+        }
+        let image = Image::new(
+            Extent3d {
+                width: 8,
+                height: 8,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+        );
+
+        let config = ImageColliderConfig::default();
+        let hull = Collider::convex_hull_from_image(&image, &config).unwrap();
+        let aabb = hull.compute_aabb(Vector::ZERO, 0.0);
+        assert_relative_eq!(
+            Vector::from(aabb.mins),
+            Vector::splat(-2.0),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            Vector::from(aabb.maxs),
+            Vector::splat(2.0),
+            epsilon = 0.0001
+        );
+
+        assert!(Collider::convex_decomposition_from_image(&image, &config).is_some());
+
+        // Fully transparent images have no collider
+        let empty = Image::new(
+            Extent3d {
+                width: 8,
+                height: 8,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![0; 8 * 8 * 4],
+            TextureFormat::Rgba8UnormSrgb,
+        );
+        assert!(Collider::polyline_from_image(&empty, &config).is_none());
+    }
+}
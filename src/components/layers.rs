@@ -1,3 +1,5 @@
+#[allow(unused_imports)]
+use crate::prelude::*; // For doc comments
 use bevy::prelude::*;
 
 /// A layer used for determining which entities should interact with each other.
@@ -6,17 +8,17 @@ use bevy::prelude::*;
 /// This trait can be derived for enums with `#[derive(PhysicsLayer)]`.
 pub trait PhysicsLayer: Sized {
     /// Converts the layer to a bitmask.
-    fn to_bits(&self) -> u32;
+    fn to_bits(&self) -> u64;
     /// Creates a layer bitmask with all bits set to 1.
-    fn all_bits() -> u32;
+    fn all_bits() -> u64;
 }
 
 impl<L: PhysicsLayer> PhysicsLayer for &L {
-    fn to_bits(&self) -> u32 {
+    fn to_bits(&self) -> u64 {
         L::to_bits(self)
     }
 
-    fn all_bits() -> u32 {
+    fn all_bits() -> u64 {
         L::all_bits()
     }
 }
@@ -44,8 +46,8 @@ impl<L: PhysicsLayer> PhysicsLayer for &L {
 /// These methods require the layers to implement [`PhysicsLayer`]. The easiest way to define the physics layers is to
 /// create an enum with `#[derive(PhysicsLayer)]`.
 ///
-/// Internally, the groups and masks are represented as bitmasks, so you can also use [`CollisionLayers::from_bits()`](#method.from_bits)
-/// to create collision layers.
+/// Internally, the groups and masks are represented as 64-bit bitmasks, so there can be up to 64 layers.
+/// You can also use [`CollisionLayers::from_bits()`](#method.from_bits) to create collision layers.
 ///
 /// ## Example
 ///
@@ -74,8 +76,8 @@ impl<L: PhysicsLayer> PhysicsLayer for &L {
 #[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
 #[reflect(Component)]
 pub struct CollisionLayers {
-    groups: u32,
-    masks: u32,
+    groups: u64,
+    masks: u64,
 }
 
 impl CollisionLayers {
@@ -109,10 +111,10 @@ impl CollisionLayers {
 
     /// Creates a new [`CollisionLayers`] using bits.
     ///
-    /// There is one bit per group and mask, so there are a total of 32 layers.
+    /// There is one bit per group and mask, so there are a total of 64 layers.
     /// For example, if an entity is a part of the layers `[0, 1, 3]` and can interact with the layers `[1, 2]`,
     /// the groups in bits would be `0b01011` while the masks would be `0b00110`.
-    pub const fn from_bits(groups: u32, masks: u32) -> Self {
+    pub const fn from_bits(groups: u64, masks: u64) -> Self {
         Self { groups, masks }
     }
 
@@ -193,12 +195,12 @@ impl CollisionLayers {
     }
 
     /// Returns the `groups` bitmask.
-    pub fn groups_bits(self) -> u32 {
+    pub fn groups_bits(self) -> u64 {
         self.groups
     }
 
     /// Returns the `masks` bitmask.
-    pub fn masks_bits(self) -> u32 {
+    pub fn masks_bits(self) -> u64 {
         self.masks
     }
 }
@@ -206,8 +208,86 @@ impl CollisionLayers {
 impl Default for CollisionLayers {
     fn default() -> Self {
         Self {
-            groups: 0xffff_ffff,
-            masks: 0xffff_ffff,
+            groups: u64::MAX,
+            masks: u64::MAX,
         }
     }
 }
+
+/// Determines which colliders send [collision events](Collider#collision-events) when they collide,
+/// separately from which colliders collide physically using [`CollisionLayers`].
+///
+/// The groups and masks work like the ones of [`CollisionLayers`]: the [`Collision`], [`CollisionStarted`] and
+/// [`CollisionEnded`] events are only sent for two colliders if their event layers [interact](CollisionLayers::interacts_with).
+/// Colliders without this component send events for all of their collisions.
+///
+/// This can be used for reducing the number of events that gameplay code needs to process. For example, debris can
+/// collide with everything, but only send events when it hits the player. The [`Collisions`] resource and
+/// [`CollidingEntities`] are still updated for all collisions.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(PhysicsLayer)]
+/// enum Layer {
+///     Player,
+///     Debris,
+/// }
+///
+/// fn spawn(mut commands: Commands) {
+///     // The player sends events for collisions with anything that wants to report them
+///     commands.spawn((
+///         Collider::ball(0.5),
+///         CollisionEventLayers(CollisionLayers::new([Layer::Player], [Layer::Player, Layer::Debris])),
+///     ));
+///
+///     // Debris collides with everything, but only sends events for collisions with the player
+///     commands.spawn((
+///         Collider::ball(0.1),
+///         CollisionEventLayers(CollisionLayers::new([Layer::Debris], [Layer::Player])),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct CollisionEventLayers(pub CollisionLayers);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::prelude::*;
+
+    #[test]
+    fn collision_layers_support_64_layers() {
+        struct HighLayer(u32);
+
+        impl PhysicsLayer for HighLayer {
+            fn to_bits(&self) -> u64 {
+                1 << self.0
+            }
+
+            fn all_bits() -> u64 {
+                u64::MAX
+            }
+        }
+
+        let a = CollisionLayers::new([HighLayer(40)], [HighLayer(63)]);
+        let b = CollisionLayers::new([HighLayer(63)], [HighLayer(40)]);
+        let c = CollisionLayers::new([HighLayer(31)], [HighLayer(40)]);
+
+        assert!(a.interacts_with(b));
+        assert!(!a.interacts_with(c));
+        assert!(a.contains_group(HighLayer(40)));
+        assert_eq!(CollisionLayers::default().groups_bits(), u64::MAX);
+
+        let filter = SpatialQueryFilter::new().with_masks([HighLayer(63)]);
+        assert!(filter.test(Entity::PLACEHOLDER, b));
+        assert!(!filter.test(Entity::PLACEHOLDER, a));
+    }
+}
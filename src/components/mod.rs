@@ -1,19 +1,31 @@
 //! Components used for rigid bodies, colliders and mass properties.
 
+mod builder;
 mod collider;
 mod forces;
+mod fracture;
+#[cfg(all(feature = "2d", feature = "collider-from-image"))]
+mod image_collider;
 mod layers;
 mod locked_axes;
 mod mass_properties;
 mod rotation;
+mod spring_to_target;
+mod teleport;
 mod world_queries;
 
+pub use builder::*;
 pub use collider::*;
 pub use forces::*;
+pub use fracture::*;
+#[cfg(all(feature = "2d", feature = "collider-from-image"))]
+pub use image_collider::*;
 pub use layers::*;
 pub use locked_axes::*;
 pub use mass_properties::*;
 pub use rotation::*;
+pub use spring_to_target::*;
+pub use teleport::*;
 pub use world_queries::*;
 
 use crate::prelude::*;
@@ -60,6 +72,9 @@ use derive_more::From;
 ///
 /// You can change any of these during initialization and runtime in order to alter the behaviour of the body.
 ///
+/// For configuring many properties at once, the [`RigidBodyBuilder`] can be used to create a [`RigidBodyBundle`]
+/// along with a [`Collider`].
+///
 /// Note that by default, rigid bodies don't have any mass, so dynamic bodies will gain infinite velocity upon any interaction.
 /// See the [section below](#adding-mass-properties) for how to add mass properties.
 ///
@@ -211,6 +226,28 @@ pub struct LinearVelocity(pub Vector);
 impl LinearVelocity {
     /// Zero linear velocity.
     pub const ZERO: LinearVelocity = LinearVelocity(Vector::ZERO);
+
+    /// Computes the world-space velocity of a `point` on a body that has this linear velocity
+    /// and the given `angular_velocity`. The `point` and `center_of_mass` are in world space.
+    ///
+    /// This can be used for things like making projectiles inherit the velocity of a rotating turret,
+    /// or moving objects attached to a specific point on a body.
+    pub fn at_point(
+        &self,
+        angular_velocity: AngularVelocity,
+        point: Vector,
+        center_of_mass: Vector,
+    ) -> Vector {
+        let offset = point - center_of_mass;
+        #[cfg(feature = "2d")]
+        {
+            self.0 + angular_velocity.0 * offset.perp()
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.0 + angular_velocity.cross(offset)
+        }
+    }
 }
 
 /// The linear velocity of a body before the velocity solve is performed.
@@ -218,6 +255,18 @@ impl LinearVelocity {
 #[reflect(Component)]
 pub(crate) struct PreSolveLinearVelocity(pub Vector);
 
+/// A change in linear velocity that is applied after the velocities of a body have been derived
+/// from its positional change. Used by [integrators](Integrator) that compute velocities separately from positions.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, Deref, DerefMut, PartialEq, From)]
+#[reflect(Component)]
+pub(crate) struct IntegratorVelocityCorrection(pub Vector);
+
+#[cfg(feature = "2d")]
+pub(crate) type AngularVelocityValue = Scalar;
+
+#[cfg(feature = "3d")]
+pub(crate) type AngularVelocityValue = Vector;
+
 /// The angular velocity of a body in radians. Positive values will result in counterclockwise rotation.
 #[cfg(feature = "2d")]
 #[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, From)]
@@ -553,6 +602,99 @@ impl From<Scalar> for Friction {
     }
 }
 
+/// Controls how soft the contacts of an entity are. This is the compliance of the contact constraints,
+/// the inverse of stiffness, and has the unit meters / Newton.
+///
+/// A compliance of 0.0 corresponds to rigid contacts that try to resolve all overlap immediately.
+/// Larger values let bodies sink into each other and push back like a spring, which can be used for things
+/// like foam mats, trampolines or squash-and-stretch effects. Unlike [`Restitution`], this also affects
+/// bodies that are resting on each other.
+///
+/// The component can be added to colliders or to the rigid bodies they are attached to. When two entities collide,
+/// their compliances are added together, so a contact is only rigid if neither of them is soft.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A soft foam mat
+///     commands.spawn((
+///         RigidBody::Static,
+///         # #[cfg(feature = "2d")]
+///         # Collider::cuboid(10.0, 0.5),
+///         # #[cfg(feature = "3d")]
+///         Collider::cuboid(10.0, 0.5, 10.0),
+///         ContactCompliance(0.001),
+///     ));
+/// }
+/// ```
+#[derive(
+    Component, Reflect, Debug, Clone, Copy, PartialEq, PartialOrd, Default, Deref, DerefMut, From,
+)]
+#[reflect(Component)]
+pub struct ContactCompliance(pub Scalar);
+
+impl ContactCompliance {
+    /// Rigid contacts with a compliance of 0.0.
+    pub const RIGID: Self = Self(0.0);
+
+    /// Combines the compliances of two entities. The compliances are added together,
+    /// like two springs connected in series.
+    pub fn combine(&self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+/// Makes the contacts of an entity sticky. The value is the maximum force in Newtons that the contacts
+/// can apply to hold the bodies together once they have touched.
+///
+/// As long as the force pulling the bodies apart stays below this threshold, they stick to each other.
+/// Stronger pulls make the bodies separate, after which adhesion only applies again once they touch again.
+/// This can be used for things like sticky projectiles, objects crawling on walls or wet surfaces.
+///
+/// The component can be added to colliders or to the rigid bodies they are attached to. When two entities collide,
+/// the larger adhesion is used, so a sticky body also sticks to surfaces that aren't sticky themselves.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A sticky projectile that holds onto anything it hits
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.1),
+///         Adhesion(50.0),
+///     ));
+/// }
+/// ```
+#[derive(
+    Component, Reflect, Debug, Clone, Copy, PartialEq, PartialOrd, Default, Deref, DerefMut, From,
+)]
+#[reflect(Component)]
+pub struct Adhesion(pub Scalar);
+
+impl Adhesion {
+    /// No adhesion.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Combines the adhesion of two entities by choosing the larger one.
+    pub fn combine(&self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+}
+
 /// Automatically slows down a dynamic [rigid body](RigidBody), decreasing it's [linear velocity](LinearVelocity)
 /// each frame. This can be used to simulate air resistance.
 ///
@@ -611,6 +753,40 @@ mod tests {
     use crate::prelude::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn linear_velocity_at_point_works() {
+        let center_of_mass = Vector::X;
+        let point = center_of_mass + Vector::Y;
+
+        #[cfg(feature = "2d")]
+        let angular_velocity = AngularVelocity(2.0);
+        #[cfg(feature = "3d")]
+        let angular_velocity = AngularVelocity(Vector::Z * 2.0);
+
+        // Rotating counterclockwise, a point above the center of mass moves in the -X direction
+        let velocity = LinearVelocity(Vector::Y).at_point(angular_velocity, point, center_of_mass);
+        assert_relative_eq!(velocity, Vector::Y - Vector::X * 2.0);
+
+        assert_eq!(
+            LinearVelocity(Vector::Y).at_point(angular_velocity, center_of_mass, center_of_mass),
+            Vector::Y
+        );
+    }
+
+    #[test]
+    fn impulse_at_point_applies_angular_impulse() {
+        let impulse = ExternalImpulse::at_point(Vector::Y, Vector::X * 2.0, Vector::X);
+        assert_eq!(impulse.impulse(), Vector::Y);
+        #[cfg(feature = "2d")]
+        assert_eq!(impulse.angular_impulse(), 1.0);
+        #[cfg(feature = "3d")]
+        assert_eq!(impulse.angular_impulse(), Vector::Z);
+
+        // No angular impulse at the center of mass
+        let impulse = ExternalImpulse::at_point(Vector::Y, Vector::X, Vector::X);
+        assert_eq!(impulse.angular_impulse(), Torque::ZERO);
+    }
+
     #[test]
     fn restitution_clamping_works() {
         assert_eq!(Restitution::new(-2.0).coefficient, 0.0);
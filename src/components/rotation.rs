@@ -144,6 +144,57 @@ impl Rotation {
             sin: self.sin * rhs.cos() + self.cos * rhs.sin(),
         }
     }
+
+    /// Creates a [`Rotation`] that rotates the local x axis to point in the given `direction`.
+    ///
+    /// If the direction is zero, the rotation will be [`Rotation::ZERO`].
+    pub fn look_to(direction: Vector) -> Self {
+        Self::from_radians(direction.y.atan2(direction.x))
+    }
+
+    /// Creates a [`Rotation`] that rotates the local x axis to point from `eye` towards `target`.
+    pub fn look_at(eye: Vector, target: Vector) -> Self {
+        Self::look_to(target - eye)
+    }
+
+    /// Returns the smallest angle between the rotation and `other` in radians. The angle is always positive.
+    pub fn angle_between(&self, other: Self) -> Scalar {
+        (other - *self).as_radians().abs()
+    }
+
+    /// Performs a spherical linear interpolation between the rotation and `end` along the shortest arc.
+    ///
+    /// When `t` is `0.0`, the result will be equal to the rotation. When `t` is `1.0`, the result will be equal to `end`.
+    pub fn slerp(&self, end: Self, t: Scalar) -> Self {
+        self.mul(Self::from_radians((end - *self).as_radians() * t))
+    }
+
+    /// Performs a normalized linear interpolation between the rotation and `end`.
+    /// This is faster than [`slerp`](Self::slerp), but the angular velocity is not constant.
+    ///
+    /// When `t` is `0.0`, the result will be equal to the rotation. When `t` is `1.0`, the result will be equal to `end`.
+    pub fn nlerp(&self, end: Self, t: Scalar) -> Self {
+        let cos = self.cos + (end.cos - self.cos) * t;
+        let sin = self.sin + (end.sin - self.sin) * t;
+        let length = (cos * cos + sin * sin).sqrt();
+
+        // Opposite rotations have no well-defined halfway point for a linear interpolation
+        if length <= Scalar::EPSILON {
+            return self.slerp(end, t);
+        }
+
+        Self::from_sin_cos(sin / length, cos / length)
+    }
+
+    /// Rotates the rotation towards `target` by at most `max_delta` radians,
+    /// without overshooting the target.
+    pub fn rotate_towards(&self, target: Self, max_delta: Scalar) -> Self {
+        let angle = (target - *self).as_radians();
+        if angle.abs() <= max_delta {
+            return target;
+        }
+        self.mul(Self::from_radians(max_delta.max(0.0).copysign(angle)))
+    }
 }
 
 #[cfg(feature = "3d")]
@@ -157,6 +208,59 @@ impl Rotation {
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
     }
+
+    /// Creates a [`Rotation`] that rotates the local negative z axis (forward) to point in the given `direction`,
+    /// and the local y axis to point as close to `up` as possible.
+    ///
+    /// This uses the same conventions as `Transform::looking_to`. If the direction is zero,
+    /// [`Vector::NEG_Z`] is used instead, and if `up` is zero or parallel to the direction,
+    /// an arbitrary orthogonal up axis is used.
+    pub fn look_to(direction: Vector, up: Vector) -> Self {
+        let back = -direction.try_normalize().unwrap_or(Vector::NEG_Z);
+        let up = up.try_normalize().unwrap_or(Vector::Y);
+        let right = up
+            .cross(back)
+            .try_normalize()
+            .unwrap_or_else(|| back.any_orthonormal_vector());
+        let up = back.cross(right);
+        Self(Quaternion::from_mat3(&Matrix3::from_cols(right, up, back)))
+    }
+
+    /// Creates a [`Rotation`] that rotates the local negative z axis (forward) to point from `eye` towards `target`,
+    /// and the local y axis to point as close to `up` as possible. See [`look_to`](Self::look_to).
+    pub fn look_at(eye: Vector, target: Vector, up: Vector) -> Self {
+        Self::look_to(target - eye, up)
+    }
+
+    /// Returns the smallest angle between the rotation and `other` in radians. The angle is always positive.
+    pub fn angle_between(&self, other: Self) -> Scalar {
+        self.0.angle_between(other.0)
+    }
+
+    /// Performs a spherical linear interpolation between the rotation and `end` along the shortest arc.
+    ///
+    /// When `t` is `0.0`, the result will be equal to the rotation. When `t` is `1.0`, the result will be equal to `end`.
+    pub fn slerp(&self, end: Self, t: Scalar) -> Self {
+        Self(self.0.slerp(end.0, t))
+    }
+
+    /// Performs a normalized linear interpolation between the rotation and `end` along the shortest arc.
+    /// This is faster than [`slerp`](Self::slerp), but the angular velocity is not constant.
+    ///
+    /// When `t` is `0.0`, the result will be equal to the rotation. When `t` is `1.0`, the result will be equal to `end`.
+    pub fn nlerp(&self, end: Self, t: Scalar) -> Self {
+        Self(self.0.lerp(end.0, t))
+    }
+
+    /// Rotates the rotation towards `target` by at most `max_delta` radians,
+    /// without overshooting the target.
+    pub fn rotate_towards(&self, target: Self, max_delta: Scalar) -> Self {
+        let angle = self.angle_between(target);
+        if angle <= max_delta {
+            return target;
+        }
+        self.slerp(target, max_delta.max(0.0) / angle)
+    }
 }
 
 #[cfg(feature = "2d")]
@@ -303,3 +407,67 @@ impl From<Rotation> for Matrix3x1<Scalar> {
 #[derive(Reflect, Clone, Copy, Component, Debug, Default, Deref, DerefMut, PartialEq)]
 #[reflect(Component)]
 pub struct PreviousRotation(pub Rotation);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    #[cfg(feature = "2d")]
+    fn rotation_interpolation_2d() {
+        let start = Rotation::from_degrees(170.0);
+        let end = Rotation::from_degrees(-170.0);
+
+        // Interpolation takes the shortest arc across 180 degrees
+        assert_relative_eq!(
+            start.angle_between(end),
+            20.0_f64.to_radians() as Scalar,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            start.slerp(end, 0.5).as_degrees().abs(),
+            180.0,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            start.nlerp(end, 0.5).as_degrees().abs(),
+            180.0,
+            epsilon = 1e-3
+        );
+
+        let rotated = start.rotate_towards(end, 5.0_f64.to_radians() as Scalar);
+        assert_relative_eq!(rotated.as_degrees(), 175.0, epsilon = 1e-3);
+        assert_eq!(start.rotate_towards(end, PI), end);
+
+        let look = Rotation::look_at(Vector::ZERO, Vector::Y);
+        assert_relative_eq!(look.rotate(Vector::X).y, 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    #[cfg(feature = "3d")]
+    fn rotation_interpolation_3d() {
+        let start = Rotation::default();
+        let end = Rotation(Quaternion::from_rotation_y(PI / 2.0));
+
+        assert_relative_eq!(start.angle_between(end), PI / 2.0, epsilon = 1e-5);
+        assert_relative_eq!(
+            start.slerp(end, 0.5).angle_between(end),
+            PI / 4.0,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(
+            start.nlerp(end, 0.5).angle_between(end),
+            PI / 4.0,
+            epsilon = 1e-5
+        );
+
+        let rotated = start.rotate_towards(end, PI / 8.0);
+        assert_relative_eq!(start.angle_between(rotated), PI / 8.0, epsilon = 1e-5);
+        assert_eq!(start.rotate_towards(end, PI), end);
+
+        // The forward axis points at the target
+        let look = Rotation::look_at(Vector::ZERO, Vector::X * 5.0, Vector::Y);
+        assert_relative_eq!(look.rotate(Vector::NEG_Z), Vector::X, epsilon = 1e-5);
+    }
+}
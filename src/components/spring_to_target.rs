@@ -0,0 +1,172 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Pulls a dynamic [rigid body](RigidBody) towards a target pose using a damped spring,
+/// for example to carry objects physically in first-person games like a "tractor beam".
+///
+/// The spring is characterized by its `frequency` in hertz and its `damping_ratio`. A damping ratio of `1.0`
+/// makes the spring critically damped, so that the body moves to the target as quickly as possible without
+/// overshooting it. The behavior of the spring doesn't depend on the mass of the body.
+///
+/// Unlike setting the position or velocity directly, the body still collides with the environment,
+/// and the force and torque of the spring can be limited using `max_force` and `max_torque` so that
+/// heavy objects can't be lifted and carried objects can't push through walls with infinite strength.
+///
+/// The spring is applied by the [integrator](IntegratorPlugin) during each substep, which keeps it stable
+/// even with high frequencies.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Held;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         SpringToTarget::new(Vector::ZERO).with_max_force(500.0),
+///         Held,
+///     ));
+/// }
+///
+/// // Move the target in front of the player every frame
+/// fn carry(player: Query<&Transform, Without<Held>>, mut held: Query<&mut SpringToTarget, With<Held>>) {
+///     # let Ok(player) = player.get_single() else { return };
+///     # #[cfg(all(feature = "3d", feature = "f32"))]
+///     for mut spring in &mut held {
+///         spring.position = player.translation + player.forward() * 2.0;
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct SpringToTarget {
+    /// The target position in world space.
+    pub position: Vector,
+    /// The target rotation in world space. If `None`, the rotation of the body isn't affected.
+    pub rotation: Option<Rotation>,
+    /// The frequency of the spring in hertz. Higher frequencies make the spring stiffer.
+    pub frequency: Scalar,
+    /// The damping ratio of the spring. A value of `1.0` is critical damping,
+    /// smaller values make the body oscillate around the target, and larger values make it move slower.
+    pub damping_ratio: Scalar,
+    /// The maximum force applied by the spring.
+    pub max_force: Scalar,
+    /// The maximum torque applied by the spring.
+    pub max_torque: Scalar,
+}
+
+impl SpringToTarget {
+    /// Creates a new critically damped [`SpringToTarget`] pulling the body towards the given position.
+    pub fn new(position: Vector) -> Self {
+        Self {
+            position,
+            rotation: None,
+            frequency: 5.0,
+            damping_ratio: 1.0,
+            max_force: Scalar::MAX,
+            max_torque: Scalar::MAX,
+        }
+    }
+
+    /// Sets the target rotation in world space.
+    pub fn with_rotation(self, rotation: Rotation) -> Self {
+        Self {
+            rotation: Some(rotation),
+            ..self
+        }
+    }
+
+    /// Sets the frequency of the spring in hertz.
+    pub fn with_frequency(self, frequency: Scalar) -> Self {
+        Self { frequency, ..self }
+    }
+
+    /// Sets the damping ratio of the spring. A value of `1.0` is critical damping.
+    pub fn with_damping_ratio(self, damping_ratio: Scalar) -> Self {
+        Self {
+            damping_ratio,
+            ..self
+        }
+    }
+
+    /// Sets the maximum force applied by the spring.
+    pub fn with_max_force(self, max_force: Scalar) -> Self {
+        Self { max_force, ..self }
+    }
+
+    /// Sets the maximum torque applied by the spring.
+    pub fn with_max_torque(self, max_torque: Scalar) -> Self {
+        Self { max_torque, ..self }
+    }
+
+    /// Returns the change in velocity that moves a value with the given `error` to the target
+    /// during a timestep of length `dt`, using implicit integration for stability.
+    ///
+    /// The result is an acceleration independent of mass, so it is scaled by the mass or inertia to get a force or torque.
+    pub(crate) fn delta_velocity<T>(&self, error: T, velocity: T, dt: Scalar) -> T
+    where
+        T: std::ops::Mul<Scalar, Output = T> + std::ops::Sub<Output = T>,
+    {
+        let omega = 2.0 * PI * self.frequency;
+        let stiffness = omega * omega;
+        let damping = 2.0 * self.damping_ratio * omega;
+
+        (error * stiffness - velocity * damping) * (dt / (1.0 + dt * damping + dt * dt * stiffness))
+    }
+}
+
+impl Default for SpringToTarget {
+    /// Creates a spring pulling the body towards the origin.
+    fn default() -> Self {
+        Self::new(Vector::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::prelude::*;
+
+    #[test]
+    fn spring_to_target_pulls_body_to_target() {
+        let mut app = create_app();
+
+        let target = Vector::X * 3.0 + Vector::Y * 2.0;
+        let held = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                SpringToTarget::new(target).with_frequency(3.0),
+            ))
+            .id();
+        let weak = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * -3.0),
+                Collider::ball(0.5),
+                SpringToTarget::new(Vector::X * -3.0 + Vector::Y * 2.0).with_max_force(0.1),
+            ))
+            .id();
+
+        app.step_physics(120);
+
+        // The spring holds the body up against gravity, only sagging slightly
+        let position = app.world.get::<Position>(held).unwrap().0;
+        assert!(position.distance(target) < 0.1);
+
+        // The force of the weak spring is too small to lift the body
+        assert!(app.world.get::<Position>(weak).unwrap().y < 0.0);
+    }
+}
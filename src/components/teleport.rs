@@ -0,0 +1,123 @@
+use crate::prelude::*;
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+/// Moves a [rigid body](RigidBody) to a new [`Position`] and [`Rotation`] at the start of the next physics frame.
+///
+/// Unlike setting the [`Position`] directly, teleporting also moves the previous position and rotation
+/// used by the solver and removes the body's existing contacts, so the solver doesn't generate huge velocities
+/// to pull the body back or push it out of colliders that it was touching at its old location.
+///
+/// The component is removed once the teleport has been applied. The [`TeleportCommandsExt`] trait
+/// provides a convenient way to add it using `Commands`.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// # #[cfg(all(feature = "3d", feature = "f32"))]
+/// fn respawn(mut commands: Commands, query: Query<Entity, With<Player>>) {
+///     for entity in &query {
+///         commands
+///             .entity(entity)
+///             .teleport_to(Vec3::Y * 2.0, Rotation::default());
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct Teleport {
+    /// The position that the body is moved to.
+    pub position: Vector,
+    /// The rotation that the body is rotated to.
+    pub rotation: Rotation,
+    /// If true, the body's [`LinearVelocity`] and [`AngularVelocity`] are set to zero.
+    pub reset_velocity: bool,
+}
+
+impl Teleport {
+    /// Creates a [`Teleport`] to the given position and rotation that keeps the body's velocity.
+    pub fn new(position: Vector, rotation: Rotation) -> Self {
+        Self {
+            position,
+            rotation,
+            reset_velocity: false,
+        }
+    }
+
+    /// Sets the body's [`LinearVelocity`] and [`AngularVelocity`] to zero when it's teleported.
+    pub fn with_velocity_reset(mut self) -> Self {
+        self.reset_velocity = true;
+        self
+    }
+}
+
+/// An extension trait for teleporting [rigid bodies](RigidBody) using `EntityCommands`.
+///
+/// See [`Teleport`] for more information.
+pub trait TeleportCommandsExt {
+    /// Teleports the body to the given position and rotation, keeping its velocity.
+    fn teleport_to(&mut self, position: Vector, rotation: Rotation) -> &mut Self;
+}
+
+impl TeleportCommandsExt for EntityCommands<'_, '_, '_> {
+    fn teleport_to(&mut self, position: Vector, rotation: Rotation) -> &mut Self {
+        self.insert(Teleport::new(position, rotation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn teleport_moves_body_and_clears_contacts() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LinearVelocity(Vector::X),
+            ))
+            .id();
+        let obstacle = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * 0.9),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(1);
+        assert!(app.world.resource::<Collisions>().contains(body, obstacle));
+
+        app.world
+            .entity_mut(body)
+            .insert(Teleport::new(Vector::Y * 10.0, Rotation::default()).with_velocity_reset());
+
+        app.step_physics(1);
+
+        assert!(!app.world.entity(body).contains::<Teleport>());
+        assert!(!app.world.resource::<Collisions>().contains(body, obstacle));
+        assert_relative_eq!(app.world.get::<Position>(body).unwrap().y, 10.0);
+        assert_eq!(
+            app.world.get::<LinearVelocity>(body).unwrap().0,
+            Vector::ZERO
+        );
+    }
+}
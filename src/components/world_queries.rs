@@ -72,6 +72,18 @@ impl<'w> RigidBodyQueryItem<'w> {
         self.position.0 + self.accumulated_translation.0
     }
 
+    /// Returns the world-space center of mass of the body.
+    pub fn world_center_of_mass(&self) -> Vector {
+        self.current_position() + self.rotation.rotate(self.center_of_mass.0)
+    }
+
+    /// Computes the world-space velocity of the given world-space `point` on the body,
+    /// taking into account both the linear and angular velocity.
+    pub fn velocity_at_point(&self, point: Vector) -> Vector {
+        self.linear_velocity
+            .at_point(*self.angular_velocity, point, self.world_center_of_mass())
+    }
+
     /// Returns the [dominance](Dominance) of the body.
     ///
     /// If it isn't specified, the default of `0` is returned for dynamic bodies.
@@ -160,7 +172,7 @@ impl<'w> SubAssign<ColliderMassProperties> for MassPropertiesQueryItem<'w> {
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::*;
+    use crate::{prelude::*, test_utils::*, tests::create_app};
     use approx::assert_relative_eq;
     use bevy::prelude::*;
 
@@ -293,4 +305,45 @@ mod tests {
             epsilon = 0.000_001
         );
     }
+
+    #[test]
+    fn velocity_at_point_takes_center_of_mass_into_account() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        // Initialize the components of the body
+        app.step_physics(1);
+
+        #[cfg(feature = "2d")]
+        let angular_velocity = AngularVelocity(2.0);
+        #[cfg(feature = "3d")]
+        let angular_velocity = AngularVelocity(Vector::Z * 2.0);
+        app.world.entity_mut(body).insert((
+            LinearVelocity(Vector::Y),
+            angular_velocity,
+            CenterOfMass(Vector::X * 0.5),
+        ));
+
+        let mut query = app.world.query::<RigidBodyQuery>();
+        let rb = query.single_mut(&mut app.world);
+        let center_of_mass = rb.world_center_of_mass();
+        assert_relative_eq!(center_of_mass, Vector::X * 1.5);
+
+        // Rotating counterclockwise, a point above the center of mass moves in the -X direction
+        assert_relative_eq!(
+            rb.velocity_at_point(center_of_mass + Vector::Y),
+            Vector::Y - Vector::X * 2.0
+        );
+        assert_eq!(rb.velocity_at_point(center_of_mass), Vector::Y);
+    }
 }
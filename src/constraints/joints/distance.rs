@@ -13,7 +13,7 @@ use bevy::{
 ///
 /// Distance joints can be useful for things like springs, muscles, and mass-spring networks.
 #[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
-#[reflect(MapEntities)]
+#[reflect(Component, MapEntities)]
 pub struct DistanceJoint {
     /// First entity constrained by the joint.
     pub entity1: Entity,
@@ -35,8 +35,13 @@ pub struct DistanceJoint {
     pub lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring-damper that makes the alignment of the joint soft, if any.
+    /// It replaces the `compliance` of the alignment. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
     /// The force exerted by the joint.
     pub force: Vector,
+    /// The length limit that was reached during the last substep.
+    pub limit_state: JointLimitState,
 }
 
 impl XpbdConstraint<2> for DistanceJoint {
@@ -46,6 +51,9 @@ impl XpbdConstraint<2> for DistanceJoint {
 
     fn clear_lagrange_multipliers(&mut self) {
         self.lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+        self.limit_state = JointLimitState::Free;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -66,7 +74,9 @@ impl Joint for DistanceJoint {
             damping_angular: 0.0,
             lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
             force: Vector::ZERO,
+            limit_state: JointLimitState::Free,
         }
     }
 
@@ -74,6 +84,13 @@ impl Joint for DistanceJoint {
         Self { compliance, ..self }
     }
 
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     fn with_local_anchor_1(self, anchor: Vector) -> Self {
         Self {
             local_anchor1: anchor,
@@ -117,6 +134,26 @@ impl Joint for DistanceJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn compliance(&self) -> Scalar {
+        self.compliance
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force
+    }
+
+    fn torque(&self) -> Torque {
+        Torque::ZERO
+    }
+
+    fn limit_state(&self) -> JointLimitState {
+        self.limit_state
+    }
 }
 
 impl DistanceJoint {
@@ -139,6 +176,7 @@ impl DistanceJoint {
             if length < Scalar::EPSILON {
                 return Vector::ZERO;
             }
+            self.limit_state = limits.state(length);
             delta_x += limits.compute_correction(
                 body1.current_position() + world_r1,
                 body2.current_position() + world_r2,
@@ -169,8 +207,14 @@ impl DistanceJoint {
         let gradients = [n, -n];
 
         // Compute Lagrange multiplier update, essentially the signed magnitude of the correction
-        let delta_lagrange =
-            self.compute_lagrange_update(self.lagrange, c, &gradients, &w, self.compliance, dt);
+        let delta_lagrange = if let Some(spring) = self.spring {
+            // The movement of the attachment points along the joint during this substep, used for damping
+            let displacement = anchor_displacement(body1, self.local_anchor1)
+                - anchor_displacement(body2, self.local_anchor2);
+            spring.compute_delta_lagrange(self.lagrange, c, n.dot(displacement), w1 + w2, dt)
+        } else {
+            self.compute_lagrange_update(self.lagrange, c, &gradients, &w, self.compliance, dt)
+        };
         self.lagrange += delta_lagrange;
 
         // Apply positional correction (method from PositionConstraint)
@@ -201,6 +245,13 @@ impl PositionConstraint for DistanceJoint {}
 
 impl AngularConstraint for DistanceJoint {}
 
+impl Default for DistanceJoint {
+    /// Creates a joint with placeholder entities, anchors at the body origins, a rest length of zero and no compliance.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
 impl MapEntities for DistanceJoint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
@@ -2,7 +2,10 @@
 
 use crate::prelude::*;
 use bevy::{
-    ecs::entity::{EntityMapper, MapEntities},
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
     prelude::*,
 };
 
@@ -10,7 +13,8 @@ use bevy::{
 ///
 /// You should generally prefer using a single body instead of multiple bodies fixed together,
 /// but fixed joints can be useful for things like rigid structures where a force can dynamically break the joints connecting individual bodies.
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct FixedJoint {
     /// First entity constrained by the joint.
     pub entity1: Entity,
@@ -30,6 +34,9 @@ pub struct FixedJoint {
     pub align_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring-damper that makes the alignment of the joint soft, if any.
+    /// It replaces the `compliance` of the alignment. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
@@ -44,6 +51,9 @@ impl XpbdConstraint<2> for FixedJoint {
     fn clear_lagrange_multipliers(&mut self) {
         self.position_lagrange = 0.0;
         self.align_lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+        self.align_torque = Torque::ZERO;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -53,20 +63,45 @@ impl XpbdConstraint<2> for FixedJoint {
         // Align orientation
         let dq = self.get_delta_q(&body1.rotation, &body2.rotation);
         let mut lagrange = self.align_lagrange;
-        self.align_torque = self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt);
+        self.align_torque = if let Some(spring) = self.spring {
+            let previous_dq = self.get_delta_q(&body1.previous_rotation, &body2.previous_rotation);
+            self.align_orientation_with_spring(
+                body1,
+                body2,
+                dq,
+                previous_dq,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt)
+        };
         self.align_lagrange = lagrange;
 
         // Align position of local attachment points
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = if let Some(spring) = self.spring {
+            self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            )
+        };
         self.position_lagrange = lagrange;
     }
 }
@@ -83,6 +118,7 @@ impl Joint for FixedJoint {
             position_lagrange: 0.0,
             align_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
@@ -95,6 +131,13 @@ impl Joint for FixedJoint {
         Self { compliance, ..self }
     }
 
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     fn with_local_anchor_1(self, anchor: Vector) -> Self {
         Self {
             local_anchor1: anchor,
@@ -138,6 +181,22 @@ impl Joint for FixedJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn compliance(&self) -> Scalar {
+        self.compliance
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force
+    }
+
+    fn torque(&self) -> Torque {
+        self.align_torque
+    }
 }
 
 impl FixedJoint {
@@ -156,6 +215,13 @@ impl PositionConstraint for FixedJoint {}
 
 impl AngularConstraint for FixedJoint {}
 
+impl Default for FixedJoint {
+    /// Creates a joint with placeholder entities, anchors at the body origins and no compliance.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
 impl MapEntities for FixedJoint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
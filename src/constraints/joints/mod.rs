@@ -21,6 +21,9 @@
 //! | [`RevoluteJoint`]  | 1 Rotation                | 1 Rotation                  |
 //! | [`SphericalJoint`] | 1 Rotation                | 3 Rotations                 |
 //!
+//! There is also a [`SpringJoint`] that doesn't remove any DOF. Instead, it pulls the bodies
+//! towards a rest length like a damped spring.
+//!
 //! ## Using joints
 //!
 //! In Bevy XPBD, joints are modeled as components. You can create a joint by simply spawning
@@ -49,6 +52,38 @@
 //! *Compliance* refers to the inverse of stiffness, so using a compliance of 0 corresponds to
 //! infinite stiffness.
 //!
+//! Every joint type supports compliance, so joints can be made intentionally soft, for example for rubbery
+//! connections or squishy ragdolls. The compliance applies to all parts of a joint, including its alignment
+//! and its [limits](DistanceLimit). It has the unit meters / Newton for positional corrections and
+//! radians / Newton-meter for angular corrections. A joint with a compliance of `c` that supports a weight
+//! of `F` Newtons stretches by roughly `c * F` meters.
+//!
+//! Soft joints can also be used for stability. Long chains of rigid joints between bodies with very different
+//! masses can jitter, and a small compliance like `0.00001` often makes them behave better at the cost of
+//! a bit of stretching.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # #[cfg(feature = "2d")]
+//! # use bevy_xpbd_2d::prelude::*;
+//! # #[cfg(feature = "3d")]
+//! # use bevy_xpbd_3d::prelude::*;
+//! #
+//! fn setup(mut commands: Commands) {
+//!     let torso = commands.spawn(RigidBody::Dynamic).id();
+//!     let arm = commands.spawn(RigidBody::Dynamic).id();
+//!
+//!     // A squishy shoulder that stretches slightly under load
+//!     commands.spawn(SphericalJoint::new(torso, arm).with_compliance(0.001));
+//! }
+//! ```
+//!
+//! ### Springs
+//!
+//! Instead of a compliance, the alignment of any joint can be made soft with a [`JointSpring`] using
+//! the `with_spring` method. Springs are configured using a frequency in Hertz and a damping ratio, like
+//! "2 Hz, 0.7 damping", and they behave the same regardless of the masses of the attached bodies.
+//!
 //! ### Attachment positions
 //!
 //! By default, joints are connected to the centers of entities, but attachment positions can be used to change this.
@@ -56,18 +91,79 @@
 //! You can use `with_local_anchor_1` and `with_local_anchor_2` to set the attachment positions on the first
 //! and second entity respectively.
 //!
+//! Computing local anchors by hand can be error-prone, so you can also attach a joint at a point in world space
+//! using `new_world_anchor`. The local anchors are then computed from the current positions and rotations
+//! of the bodies at the start of the next physics frame. See [`JointWorldAnchor`] for more information.
+//!
 //! ### Damping
 //!
 //! You can configure the linear and angular damping caused by joints using the `with_linear_velocity_damping` and
 //! `with_angular_velocity_damping` methods. Increasing the damping values will cause the velocities
 //! of the connected entities to decrease faster.
 //!
+//! ### Motors
+//!
+//! [`RevoluteJoint`]s and [`PrismaticJoint`]s can be driven by a [`JointMotor`], either at a target velocity
+//! or towards a target position using a servo with a given stiffness and damping. The strength of a motor
+//! can be limited with a maximum force or torque, which is useful for things like robotic arms that hold
+//! their pose under load.
+//!
+//! ### Disabling joints
+//!
+//! Joints can be temporarily disabled by adding the [`JointDisabled`] component to the joint entity.
+//! Removing the component enables the joint again with its previous configuration.
+//!
+//! ### Articulations
+//!
+//! Long chains of joints, like ropes, tank treads or ragdolls with many links, can stretch when the joints are
+//! solved in an arbitrary order. Joints marked with the [`Articulated`] component are instead solved in order
+//! along the chains that they form, which makes long chains much stiffer at normal substep counts.
+//!
+//! ### Limit events
+//!
+//! A [`JointLimitReached`] event is sent when a joint reaches its lower or upper limit.
+//! The current state of the limits can also be read using the [`Joint`] trait's `limit_state` method.
+//!
+//! ### Forces
+//!
+//! After each substep, joints store the force and torque that they applied to the bodies. These can be read
+//! using the `force` and `torque` methods of the [`Joint`] trait, or from the joint's fields for a more granular
+//! breakdown. This can be useful for breaking joints under too much stress or for triggering sounds.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # #[cfg(feature = "2d")]
+//! # use bevy_xpbd_2d::prelude::*;
+//! # #[cfg(feature = "3d")]
+//! # use bevy_xpbd_3d::prelude::*;
+//! #
+//! fn break_joints(mut commands: Commands, query: Query<(Entity, &FixedJoint)>) {
+//!     for (entity, joint) in &query {
+//!         if joint.force().length() > 500.0 {
+//!             commands.entity(entity).despawn();
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! For structures like buildings, the [`StructuralStressPlugin`] can break [`FixedJoint`]s automatically.
+//! It also propagates the weight of the bodies through the structure, so that joints near the supports
+//! break under the load of everything above them.
+//!
 //! ### Other configuration
 //!
 //! Different joints may have different configuration options. Many joints allow you to change the axis of allowed
 //! translation or rotation, and they may have distance or angle limits along these axes.
 //!
 //! Take a look at the documentation and methods of each joint to see all of the configuration options.
+//! Some joints also have methods for computing their current state from the attached bodies, like
+//! [`RevoluteJoint::angle`] and [`PrismaticJoint::translation`].
+//!
+//! ### Scenes
+//!
+//! Joints are registered for reflection and implement `MapEntities`, so jointed assemblies can be stored
+//! in a `DynamicScene`. When the scene is spawned, the joints' `entity1` and `entity2` are remapped
+//! to the newly spawned bodies.
 //!
 //! ## Custom joints
 //!
@@ -88,24 +184,170 @@ mod fixed;
 mod prismatic;
 mod revolute;
 mod spherical;
+mod spring;
 
 pub use distance::*;
 pub use fixed::*;
 pub use prismatic::*;
 pub use revolute::*;
 pub use spherical::*;
+pub use spring::*;
 
 use crate::prelude::*;
 use bevy::prelude::*;
 
+/// A point in world space where a [joint](joints) should be attached to both of its bodies.
+///
+/// At the start of the next physics frame, the joint's local anchors are computed from the current
+/// [`Position`] and [`Rotation`] of the bodies so that both anchors are located at the given point,
+/// after which this component is removed. Any anchors set before that are overwritten.
+///
+/// Joints with a world anchor can be created using `new_world_anchor`, or by adding
+/// this component to the joint entity manually.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(all(feature = "3d", feature = "f32"))]
+/// fn setup(mut commands: Commands) {
+///     let door_frame = commands
+///         .spawn((RigidBody::Static, Position(Vec3::new(-1.0, 1.0, 0.0))))
+///         .id();
+///     let door = commands
+///         .spawn((RigidBody::Dynamic, Position(Vec3::new(0.0, 1.0, 0.0))))
+///         .id();
+///
+///     // Attach the door to the frame at the hinge located in between the bodies
+///     commands.spawn(RevoluteJoint::new_world_anchor(
+///         door_frame,
+///         door,
+///         Vec3::new(-0.5, 1.0, 0.0),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct JointWorldAnchor(pub Vector);
+
+/// A marker component that temporarily disables a [joint](joints) or another constraint
+/// that is solved using [`solve_constraint`].
+///
+/// Disabled joints aren't solved and don't apply damping, but they keep all of their configuration,
+/// so they can be enabled again by simply removing this component. This can be useful for things
+/// like releasing and reattaching a grappling hook.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::{ecs::query::Has, prelude::*};
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Grapple;
+///
+/// fn toggle_grapple(
+///     mut commands: Commands,
+///     keyboard_input: Res<Input<KeyCode>>,
+///     query: Query<(Entity, Has<JointDisabled>), With<Grapple>>,
+/// ) {
+///     if keyboard_input.just_pressed(KeyCode::Space) {
+///         for (entity, disabled) in &query {
+///             if disabled {
+///                 commands.entity(entity).remove::<JointDisabled>();
+///             } else {
+///                 commands.entity(entity).insert(JointDisabled);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct JointDisabled;
+
+/// Marks a joint as a part of an articulation, a chain of bodies connected by joints.
+///
+/// Articulated joints are solved using ordered Gauss-Seidel sweeps along the chains that they form,
+/// starting from the root of each chain, which is a [static](RigidBody::Static) or [kinematic](RigidBody::Kinematic)
+/// body or the end of the chain. Each iteration solves the joints from the root to the tips and back, so that corrections
+/// propagate through the whole chain within a single substep. This prevents long chains from stretching
+/// and exploding, at the cost of more solver work.
+///
+/// The number of iterations can be configured using [`SolverConfig::articulation_iterations`].
+///
+/// Only joints of the same type are ordered together, so an articulation should use a single joint type.
+/// Custom joints are only solved as articulations if [`solve_articulated_joints`] has been added for them.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(all(feature = "3d", feature = "f32"))]
+/// fn spawn_chain(mut commands: Commands) {
+///     let mut previous = commands.spawn(RigidBody::Static).id();
+///
+///     for i in 1..=30 {
+///         let link = commands
+///             .spawn((
+///                 RigidBody::Dynamic,
+///                 Collider::ball(0.1),
+///                 Position(Vec3::NEG_Y * i as f32 * 0.25),
+///             ))
+///             .id();
+///
+///         commands.spawn((
+///             SphericalJoint::new(previous, link).with_local_anchor_2(Vec3::Y * 0.25),
+///             Articulated,
+///         ));
+///
+///         previous = link;
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Articulated;
+
 /// A trait for [joints].
 pub trait Joint: Component + PositionConstraint + AngularConstraint {
     /// Creates a new joint between two entities.
     fn new(entity1: Entity, entity2: Entity) -> Self;
 
+    /// Creates a new joint between two entities, attached to both bodies at the given point in world space.
+    ///
+    /// The local anchors are computed from the current positions and rotations of the bodies
+    /// at the start of the next physics frame. See [`JointWorldAnchor`] for more information.
+    fn new_world_anchor(
+        entity1: Entity,
+        entity2: Entity,
+        anchor: Vector,
+    ) -> (Self, JointWorldAnchor)
+    where
+        Self: Sized,
+    {
+        (Self::new(entity1, entity2), JointWorldAnchor(anchor))
+    }
+
     /// Sets the joint's compliance (inverse of stiffness, meters / Newton).
     fn with_compliance(self, compliance: Scalar) -> Self;
 
+    /// Sets the spring-damper that makes the alignment of the joint soft. See [`JointSpring`].
+    fn with_spring(self, spring: JointSpring) -> Self;
+
     /// Sets the attachment point on the first body.
     fn with_local_anchor_1(self, anchor: Vector) -> Self;
 
@@ -130,6 +372,32 @@ pub trait Joint: Component + PositionConstraint + AngularConstraint {
     /// Returns the angular velocity damping of the joint.
     fn damping_angular(&self) -> Scalar;
 
+    /// Returns the joint's compliance (inverse of stiffness). A compliance of 0 corresponds to a rigid joint.
+    fn compliance(&self) -> Scalar;
+
+    /// Returns the spring-damper that makes the alignment of the joint soft, if any.
+    fn spring(&self) -> Option<JointSpring>;
+
+    /// Returns the force that the joint applied to the bodies during the last substep.
+    ///
+    /// The force is computed from the joint's Lagrange multipliers and the substep's delta time,
+    /// and it is zero if the joint wasn't solved, for example because its bodies are sleeping.
+    fn force(&self) -> Vector;
+
+    /// Returns the total torque that the joint applied to the bodies during the last substep,
+    /// including the torque caused by alignment and joint limits.
+    ///
+    /// The torque is computed from the joint's Lagrange multipliers and the substep's delta time,
+    /// and it is zero if the joint wasn't solved, for example because its bodies are sleeping.
+    fn torque(&self) -> Torque;
+
+    /// Returns which of the joint's limits was reached during the last substep, if any.
+    ///
+    /// Joints without limits always return [`JointLimitState::Free`].
+    fn limit_state(&self) -> JointLimitState {
+        JointLimitState::Free
+    }
+
     /// Applies a positional correction that aligns the positions of the local attachment points `r1` and `r2`.
     ///
     /// Returns the force exerted by the alignment.
@@ -230,6 +498,101 @@ pub trait Joint: Component + PositionConstraint + AngularConstraint {
         // Return constraint torque
         self.compute_torque(*lagrange, axis, dt)
     }
+
+    /// Applies a soft positional correction that pulls the local attachment points `r1` and `r2` together
+    /// like a spring-damper. See [`JointSpring`].
+    ///
+    /// Returns the force exerted by the spring.
+    #[allow(clippy::too_many_arguments)]
+    fn align_position_with_spring(
+        &self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        r1: Vector,
+        r2: Vector,
+        lagrange: &mut Scalar,
+        spring: JointSpring,
+        dt: Scalar,
+    ) -> Vector {
+        let world_r1 = body1.rotation.rotate(r1);
+        let world_r2 = body2.rotation.rotate(r2);
+
+        let delta_x = DistanceLimit::new(0.0, 0.0).compute_correction(
+            body1.current_position() + world_r1,
+            body2.current_position() + world_r2,
+        );
+        let magnitude = delta_x.length();
+
+        if magnitude <= Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        let dir = delta_x / magnitude;
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir);
+
+        // The movement of the attachment points along the correction during this substep, used for damping
+        let displacement = anchor_displacement(body1, r1) - anchor_displacement(body2, r2);
+
+        // Compute Lagrange multiplier update
+        let delta_lagrange =
+            spring.compute_delta_lagrange(*lagrange, magnitude, dir.dot(displacement), w1 + w2, dt);
+        *lagrange += delta_lagrange;
+
+        // Apply positional correction to align the positions of the bodies
+        self.apply_positional_correction(body1, body2, delta_lagrange, dir, world_r1, world_r2);
+
+        // Return constraint force
+        self.compute_force(*lagrange, dir, dt)
+    }
+
+    /// Applies a soft angular correction that aligns the orientation of the bodies like a spring-damper.
+    /// See [`JointSpring`].
+    ///
+    /// `previous_delta_q` is the difference in orientation at the start of the substep, which is used for damping.
+    ///
+    /// Returns the torque exerted by the spring.
+    #[allow(clippy::too_many_arguments)]
+    fn align_orientation_with_spring(
+        &self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        delta_q: Vector3,
+        previous_delta_q: Vector3,
+        lagrange: &mut Scalar,
+        spring: JointSpring,
+        dt: Scalar,
+    ) -> Torque {
+        let angle = delta_q.length();
+
+        if angle <= Scalar::EPSILON {
+            return Torque::ZERO;
+        }
+
+        let axis = delta_q / angle;
+
+        // Compute generalized inverse masses
+        let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis);
+        let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis);
+
+        // Compute Lagrange multiplier update, using the change in the angle during this substep for damping
+        let delta_lagrange = spring.compute_delta_lagrange(
+            *lagrange,
+            angle,
+            axis.dot(delta_q - previous_delta_q),
+            w1 + w2,
+            dt,
+        );
+        *lagrange += delta_lagrange;
+
+        // Apply angular correction to align the bodies
+        self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+
+        // Return constraint torque
+        self.compute_torque(*lagrange, axis, dt)
+    }
 }
 
 /// A limit that indicates that the distance between two points should be between `min` and `max`.
@@ -272,6 +635,17 @@ impl DistanceLimit {
         }
     }
 
+    /// Returns which of the limits the given `distance` is outside of.
+    pub fn state(&self, distance: Scalar) -> JointLimitState {
+        if distance < self.min {
+            JointLimitState::Lower
+        } else if distance > self.max {
+            JointLimitState::Upper
+        } else {
+            JointLimitState::Free
+        }
+    }
+
     /// Returns the positional correction required to limit the distance between `p1` and `p2`
     /// to be inside the distance limit along a given `axis`.
     fn compute_correction_along_axis(&self, p1: Vector, p2: Vector, axis: Vector) -> Vector {
@@ -292,7 +666,7 @@ impl DistanceLimit {
 }
 
 /// A limit that indicates that angles should be between `alpha` and `beta`.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
 pub struct AngleLimit {
     /// The minimum angle.
     pub alpha: Scalar,
@@ -313,14 +687,14 @@ impl AngleLimit {
     }
 
     /// Returns the angular correction required to limit the angle between the axes `n1` and `n2`
-    /// to be inside the angle limits.
+    /// to be inside the angle limits, and the limit that was exceeded.
     fn compute_correction(
         &self,
         n: Vector3,
         n1: Vector3,
         n2: Vector3,
         max_correction: Scalar,
-    ) -> Option<Vector3> {
+    ) -> Option<(Vector3, JointLimitState)> {
         let mut phi = n1.cross(n2).dot(n).asin();
 
         if n1.dot(n2) < 0.0 {
@@ -336,6 +710,7 @@ impl AngleLimit {
         }
 
         if phi < self.alpha || phi > self.beta {
+            let state = self.state(phi);
             phi = phi.clamp(self.alpha, self.beta);
 
             let rot = Quaternion::from_axis_angle(n, phi);
@@ -347,9 +722,675 @@ impl AngleLimit {
                 omega *= max_correction / phi;
             }
 
-            return Some(omega);
+            return Some((omega, state));
         }
 
         None
     }
+
+    /// Returns which of the limits the given `angle` is outside of.
+    pub fn state(&self, angle: Scalar) -> JointLimitState {
+        if angle < self.alpha {
+            JointLimitState::Lower
+        } else if angle > self.beta {
+            JointLimitState::Upper
+        } else {
+            JointLimitState::Free
+        }
+    }
+}
+
+/// A motor that drives the relative motion of the bodies attached to a [joint](joints) along its free axis.
+///
+/// Motors are supported by [`RevoluteJoint`], where they drive the relative rotation around the `aligned_axis`,
+/// and [`PrismaticJoint`], where they drive the relative translation along the `free_axis`.
+///
+/// A motor can either drive the joint at a target velocity, or act as a servo that drives the joint towards
+/// a target position like a damped spring. The force or torque applied by the motor is limited by `max_force`,
+/// so a motor with bounded strength can't hold a pose under a load that is too heavy for it.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let base = commands.spawn(RigidBody::Static).id();
+///     let arm = commands
+///         .spawn((RigidBody::Dynamic, Collider::ball(0.5)))
+///         .id();
+///
+///     // Hold the arm at an angle of 45 degrees, using a torque of at most 100 Newton meters
+///     commands.spawn(RevoluteJoint::new(base, arm).with_motor(
+///         JointMotor::servo(std::f32::consts::FRAC_PI_4 as _, 500.0, 50.0).with_max_force(100.0),
+///     ));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct JointMotor {
+    /// The target that the motor drives the joint towards.
+    pub mode: MotorMode,
+    /// The maximum force or torque that the motor can apply.
+    ///
+    /// Defaults to infinity.
+    pub max_force: Scalar,
+}
+
+/// The target of a [`JointMotor`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum MotorMode {
+    /// The motor drives the joint at a target velocity.
+    ///
+    /// For a [`RevoluteJoint`], this is the angular velocity in radians per second.
+    Velocity {
+        /// The target velocity of the joint.
+        target_velocity: Scalar,
+    },
+    /// The motor acts as a servo that drives the joint towards a target position like a damped spring.
+    ///
+    /// For a [`RevoluteJoint`], this is the angle in radians.
+    Position {
+        /// The target position of the joint.
+        target_position: Scalar,
+        /// The stiffness of the servo. For a [`PrismaticJoint`], this has the unit Newtons / meter.
+        stiffness: Scalar,
+        /// The damping coefficient of the servo. For a [`PrismaticJoint`], this has the unit Newton seconds / meter.
+        damping: Scalar,
+    },
+}
+
+impl JointMotor {
+    /// Creates a motor that drives the joint at the given target velocity.
+    pub fn velocity(target_velocity: Scalar) -> Self {
+        Self {
+            mode: MotorMode::Velocity { target_velocity },
+            max_force: Scalar::INFINITY,
+        }
+    }
+
+    /// Creates a servo motor that drives the joint towards the given target position
+    /// with the given stiffness and damping.
+    pub fn servo(target_position: Scalar, stiffness: Scalar, damping: Scalar) -> Self {
+        Self {
+            mode: MotorMode::Position {
+                target_position,
+                stiffness,
+                damping,
+            },
+            max_force: Scalar::INFINITY,
+        }
+    }
+
+    /// Sets the maximum force or torque that the motor can apply.
+    pub fn with_max_force(self, max_force: Scalar) -> Self {
+        Self { max_force, ..self }
+    }
+
+    /// Computes the update to the motor's Lagrange multiplier.
+    ///
+    /// `position` is the current position of the joint, and `displacement` is the change in the position
+    /// during the current substep. `wrap` is used for wrapping the position error, for example for angles.
+    /// `w_sum` is the sum of the generalized inverse masses of the bodies along the motor's axis.
+    ///
+    /// The accumulated Lagrange multiplier is clamped so that the force doesn't exceed `max_force`.
+    pub(crate) fn compute_delta_lagrange(
+        &self,
+        lagrange: Scalar,
+        position: Scalar,
+        displacement: Scalar,
+        wrap: impl Fn(Scalar) -> Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        if w_sum <= Scalar::EPSILON {
+            return 0.0;
+        }
+
+        let delta_lagrange = match self.mode {
+            MotorMode::Velocity { target_velocity } => {
+                // Drive the displacement during the substep towards the target velocity
+                let c = displacement - target_velocity * dt;
+                -c / w_sum
+            }
+            MotorMode::Position {
+                target_position,
+                stiffness,
+                damping,
+            } => {
+                if stiffness <= 0.0 {
+                    return 0.0;
+                }
+
+                // A damped spring, see equation 26 of the XPBD paper
+                let c = wrap(position - target_position);
+                let compliance = stiffness.recip();
+                let tilde_compliance = compliance / dt.powi(2);
+                let gamma = compliance * damping / dt;
+                (-c - tilde_compliance * lagrange - gamma * displacement)
+                    / ((1.0 + gamma) * w_sum + tilde_compliance)
+            }
+        };
+
+        // The force is lambda / h^2, so the Lagrange multiplier is limited by max_force * h^2
+        let max_lagrange = self.max_force * dt.powi(2);
+        (lagrange + delta_lagrange).clamp(-max_lagrange, max_lagrange) - lagrange
+    }
+}
+
+/// A spring-damper that makes the alignment of a [joint](joints) soft instead of rigid.
+///
+/// The spring is configured using a natural `frequency` in Hertz and a `damping_ratio` instead of
+/// a stiffness and damping coefficient. The stiffness and damping are scaled by the effective mass of the bodies,
+/// so a joint with a given spring behaves the same regardless of how heavy the attached bodies are. For example,
+/// a body hanging from a joint with a frequency of `f` sags by `g / (2π * f)²` under the gravitational
+/// acceleration `g`, no matter what its mass is.
+///
+/// Springs are supported by all joint types. They replace the [compliance](joints#stiffness) of the constraints
+/// that align the bodies, like the attachment points of a [`SphericalJoint`] or the orientations of a [`FixedJoint`].
+/// The angle limits of [`RevoluteJoint`]s and [`SphericalJoint`]s and [motors](JointMotor) are not affected.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let car = commands.spawn(RigidBody::Dynamic).id();
+///     let trailer = commands.spawn(RigidBody::Dynamic).id();
+///
+///     // A soft trailer hitch that oscillates at 2 Hz and quickly settles
+///     commands.spawn(FixedJoint::new(car, trailer).with_spring(JointSpring::new(2.0, 0.7)));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct JointSpring {
+    /// The natural frequency of the spring in Hertz. Higher frequencies make the joint stiffer.
+    ///
+    /// A frequency of zero disables the alignment of the joint entirely.
+    pub frequency: Scalar,
+    /// The damping ratio of the spring.
+    ///
+    /// A damping ratio of 0 makes the joint oscillate without damping, 1 is critically damped
+    /// and values above 1 make the joint slowly creep towards its rest position.
+    pub damping_ratio: Scalar,
+}
+
+impl JointSpring {
+    /// Creates a spring-damper with the given natural frequency in Hertz and damping ratio.
+    pub fn new(frequency: Scalar, damping_ratio: Scalar) -> Self {
+        Self {
+            frequency,
+            damping_ratio,
+        }
+    }
+
+    /// Computes the update to the Lagrange multiplier of a constraint that is softened by the spring.
+    ///
+    /// `c` is the current value of the constraint function, and `gradient_dot_displacement` is the change in `c`
+    /// during the current substep. `w_sum` is the sum of the generalized inverse masses of the bodies.
+    pub(crate) fn compute_delta_lagrange(
+        &self,
+        lagrange: Scalar,
+        c: Scalar,
+        gradient_dot_displacement: Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        if w_sum <= Scalar::EPSILON || self.frequency <= 0.0 {
+            return 0.0;
+        }
+
+        // The stiffness is the effective mass (1 / w_sum) times the squared angular frequency,
+        // and the damping coefficient is 2 * effective mass * damping ratio * angular frequency.
+        // Substituting them into equation 26 of the XPBD paper cancels out the mass in the damping term.
+        let omega = 2.0 * PI * self.frequency;
+        let tilde_compliance = w_sum / (omega * dt).powi(2);
+        let gamma = 2.0 * self.damping_ratio / (omega * dt);
+        (-c - tilde_compliance * lagrange - gamma * gradient_dot_displacement)
+            / ((1.0 + gamma) * w_sum + tilde_compliance)
+    }
+}
+
+/// Returns the movement of the given local attachment point of a body in world space during the current substep.
+pub(crate) fn anchor_displacement(body: &RigidBodyQueryItem, local_anchor: Vector) -> Vector {
+    body.current_position() - body.previous_position.0 + body.rotation.rotate(local_anchor)
+        - body.previous_rotation.rotate(local_anchor)
+}
+
+/// Describes which limit of a [joint](joints) is reached, if any.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JointLimitState {
+    /// The joint is within its limits, or it has no limits.
+    #[default]
+    Free,
+    /// The lower limit of the joint is reached.
+    Lower,
+    /// The upper limit of the joint is reached.
+    Upper,
+}
+
+/// An event that is sent when a [joint](joints) reaches its lower or upper limit.
+///
+/// The event is sent once when a limit is reached, and it is sent again only after the joint has left the limit
+/// or reached its other limit.
+/// This can be used for things like playing sounds when a door hits its end stop.
+///
+/// The current state of a joint's limits can also be read at any time using [`Joint::limit_state`].
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JointLimitReached {
+    /// The joint entity.
+    pub joint: Entity,
+    /// The limit that was reached, either [`JointLimitState::Lower`] or [`JointLimitState::Upper`].
+    pub limit: JointLimitState,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    /// Spawns a static body without a collider and a dynamic ball at the given positions,
+    /// and returns the static body and the ball.
+    pub(super) fn spawn_anchor_and_ball(
+        app: &mut App,
+        anchor_position: Vector,
+        ball_position: Vector,
+    ) -> (Entity, Entity) {
+        let anchor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(anchor_position),
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(ball_position),
+                Collider::ball(0.5),
+            ))
+            .id();
+        (anchor, ball)
+    }
+
+    #[test]
+    fn joints_are_remapped_when_copied_with_reflection() {
+        use bevy::ecs::{
+            entity::EntityMap,
+            reflect::{ReflectComponent, ReflectMapEntities},
+        };
+
+        let app = create_app();
+
+        let mut source = World::new();
+        let body1 = source.spawn_empty().id();
+        let body2 = source.spawn_empty().id();
+        let joint = source
+            .spawn(RevoluteJoint::new(body1, body2).with_local_anchor_1(Vector::X))
+            .id();
+
+        // Copy the joint into another world the same way scenes do
+        let mut destination = World::new();
+        let mut entity_map = EntityMap::default();
+        for entity in [body1, body2, joint] {
+            // Offset the entities so that the IDs differ between the worlds
+            destination.spawn_empty();
+            entity_map.insert(entity, destination.spawn_empty().id());
+        }
+        let new_joint = entity_map.get(joint).unwrap();
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get(std::any::TypeId::of::<RevoluteJoint>())
+            .expect("joints should be registered");
+        let reflect_component = registration.data::<ReflectComponent>().unwrap();
+        let component = reflect_component
+            .reflect(source.entity(joint))
+            .unwrap()
+            .clone_value();
+        reflect_component.insert(&mut destination.entity_mut(new_joint), &*component);
+        registration
+            .data::<ReflectMapEntities>()
+            .unwrap()
+            .map_entities(&mut destination, &mut entity_map, &[new_joint]);
+
+        let new_joint = destination.get::<RevoluteJoint>(new_joint).unwrap();
+        assert_eq!(new_joint.entity1, entity_map.get(body1).unwrap());
+        assert_eq!(new_joint.entity2, entity_map.get(body2).unwrap());
+        assert_eq!(new_joint.local_anchor1, Vector::X);
+    }
+
+    #[test]
+    fn joint_world_anchor_computes_local_anchors() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        #[cfg(feature = "2d")]
+        let rotation = Rotation::from_degrees(90.0);
+        #[cfg(feature = "3d")]
+        let rotation = Rotation(Quaternion::from_rotation_z(
+            std::f64::consts::FRAC_PI_2 as Scalar,
+        ));
+
+        let (body1, body2) = spawn_anchor_and_ball(&mut app, Vector::X * -2.0, Vector::X * 2.0);
+        app.world.entity_mut(body2).insert(rotation);
+        let joint = app
+            .world
+            .spawn(RevoluteJoint::new_world_anchor(body1, body2, Vector::Y))
+            .id();
+
+        app.step_physics(1);
+
+        assert!(!app.world.entity(joint).contains::<JointWorldAnchor>());
+        let joint = app.world.get::<RevoluteJoint>(joint).unwrap();
+        assert_relative_eq!(joint.local_anchor1.x, 2.0, epsilon = 0.0001);
+        assert_relative_eq!(joint.local_anchor1.y, 1.0, epsilon = 0.0001);
+        // The anchor is rotated into the local space of the second body
+        let anchor2 = rotation.rotate(joint.local_anchor2);
+        assert_relative_eq!(anchor2.x, -2.0, epsilon = 0.0001);
+        assert_relative_eq!(anchor2.y, 1.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn joint_force_matches_supported_weight() {
+        let mut app = create_app();
+
+        let (anchor, body) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::NEG_Y);
+        let joint = app
+            .world
+            .spawn(DistanceJoint::new(anchor, body).with_rest_length(1.0))
+            .id();
+
+        app.step_physics(60);
+
+        let mass = app.world.get::<Mass>(body).unwrap().0;
+        let gravity = app.world.resource::<Gravity>().0;
+        let joint = app.world.get::<DistanceJoint>(joint).unwrap();
+        assert_relative_eq!(
+            joint.force().length(),
+            mass * gravity.length(),
+            max_relative = 0.05
+        );
+    }
+
+    #[test]
+    fn disabled_joints_are_not_solved() {
+        let mut app = create_app();
+
+        let (anchor, body) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::NEG_Y);
+        let joint = app
+            .world
+            .spawn((
+                DistanceJoint::new(anchor, body).with_rest_length(1.0),
+                JointDisabled,
+            ))
+            .id();
+
+        app.step_physics(30);
+
+        // The body falls freely while the joint is disabled
+        assert!(app.world.get::<Position>(body).unwrap().y < -2.0);
+        assert_eq!(
+            app.world.get::<DistanceJoint>(joint).unwrap().force(),
+            Vector::ZERO
+        );
+
+        app.world.entity_mut(joint).remove::<JointDisabled>();
+
+        app.step_physics(30);
+
+        // The joint pulls the body back to the rest length
+        let distance = app.world.get::<Position>(body).unwrap().length();
+        assert_relative_eq!(distance, 1.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn joint_limit_reached_is_reported_once() {
+        let mut app = create_app();
+
+        let (anchor, body) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::ZERO);
+        let joint = app
+            .world
+            .spawn(
+                PrismaticJoint::new(anchor, body)
+                    .with_free_axis(Vector::Y)
+                    .with_limits(-1.0, 1.0),
+            )
+            .id();
+
+        let mut reader = app
+            .world
+            .resource::<Events<JointLimitReached>>()
+            .get_reader();
+        let mut events = vec![];
+
+        // Let the body fall against the lower limit and rest there
+        for _ in 0..60 {
+            app.step_physics(1);
+            let limit_events = app.world.resource::<Events<JointLimitReached>>();
+            events.extend(reader.iter(limit_events).copied());
+        }
+
+        assert_eq!(
+            events,
+            vec![JointLimitReached {
+                joint,
+                limit: JointLimitState::Lower
+            }]
+        );
+        assert_eq!(
+            app.world
+                .get::<PrismaticJoint>(joint)
+                .unwrap()
+                .limit_state(),
+            JointLimitState::Lower
+        );
+    }
+
+    #[test]
+    fn joint_state_is_computed_from_bodies() {
+        let joint = RevoluteJoint::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+
+        #[cfg(feature = "2d")]
+        let (rotation1, rotation2, angular_velocity1, angular_velocity2) = (
+            Rotation::from_radians(0.5),
+            Rotation::from_radians(1.5),
+            AngularVelocity(1.0),
+            AngularVelocity(3.0),
+        );
+        #[cfg(feature = "3d")]
+        let (rotation1, rotation2, angular_velocity1, angular_velocity2) = (
+            Rotation(Quaternion::from_rotation_z(0.5)),
+            Rotation(Quaternion::from_rotation_z(1.5)),
+            AngularVelocity(Vector::Z),
+            AngularVelocity(Vector::new(5.0, 0.0, 3.0)),
+        );
+
+        assert_relative_eq!(joint.angle(&rotation1, &rotation2), 1.0, epsilon = 0.0001);
+        #[cfg(feature = "2d")]
+        let angular_velocity = joint.angular_velocity(&angular_velocity1, &angular_velocity2);
+        #[cfg(feature = "3d")]
+        let angular_velocity =
+            joint.angular_velocity(&rotation1, &angular_velocity1, &angular_velocity2);
+        assert_relative_eq!(angular_velocity, 2.0, epsilon = 0.0001);
+
+        let joint = PrismaticJoint::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+            .with_free_axis(Vector::Y)
+            .with_local_anchor_1(Vector::Y);
+        let translation = joint.translation(
+            &Position(Vector::ZERO),
+            &Rotation::default(),
+            &Position(Vector::Y * 3.0 + Vector::X),
+            &Rotation::default(),
+        );
+        assert_relative_eq!(translation, 2.0, epsilon = 0.0001);
+        let speed = joint.speed(
+            &Rotation::default(),
+            &LinearVelocity(Vector::Y),
+            &LinearVelocity(Vector::NEG_Y + Vector::X),
+        );
+        assert_relative_eq!(speed, -2.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn articulated_chain_stretches_less() {
+        fn chain_stretch(articulated: bool) -> Scalar {
+            let mut app = create_app();
+            app.insert_resource(SubstepCount(4));
+
+            let mut previous = app
+                .world
+                .spawn((TransformBundle::default(), RigidBody::Static))
+                .id();
+
+            // A 30-link chain hanging from a static body, with a heavy weight at the end
+            for i in 1..=30 {
+                let link = app
+                    .world
+                    .spawn((
+                        TransformBundle::default(),
+                        RigidBody::Dynamic,
+                        Position(Vector::NEG_Y * i as Scalar * 0.25),
+                        Collider::ball(0.1),
+                        ColliderDensity(if i == 30 { 1000.0 } else { 1.0 }),
+                    ))
+                    .id();
+
+                let mut joint = app.world.spawn(
+                    SphericalJoint::new(previous, link).with_local_anchor_2(Vector::Y * 0.25),
+                );
+                if articulated {
+                    joint.insert(Articulated);
+                }
+
+                previous = link;
+            }
+
+            app.step_physics(60);
+
+            app.world.get::<Position>(previous).unwrap().length() - 30.0 * 0.25
+        }
+
+        let stretch = chain_stretch(false);
+        let articulated_stretch = chain_stretch(true);
+
+        assert!(articulated_stretch < stretch * 0.5);
+    }
+
+    #[test]
+    fn joint_motors_drive_joints() {
+        fn servo_angle(max_torque: Scalar) -> Scalar {
+            let mut app = create_app();
+
+            let (base, arm) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::X);
+            let joint = RevoluteJoint::new(base, arm)
+                .with_local_anchor_2(Vector::NEG_X)
+                .with_motor(JointMotor::servo(0.5, 1000.0, 50.0).with_max_force(max_torque));
+            app.world.spawn(joint);
+
+            app.step_physics(120);
+
+            joint.angle(
+                app.world.get::<Rotation>(base).unwrap(),
+                app.world.get::<Rotation>(arm).unwrap(),
+            )
+        }
+
+        // A strong servo holds the arm at the target angle against gravity
+        assert_relative_eq!(servo_angle(Scalar::INFINITY), 0.5, epsilon = 0.05);
+        // A weak servo can't hold the weight of the arm
+        assert!(servo_angle(1.0) < 0.0);
+
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let (base, slider) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::ZERO);
+        app.world
+            .spawn(PrismaticJoint::new(base, slider).with_motor(JointMotor::velocity(2.0)));
+
+        app.step_physics(60);
+
+        // The slider moves along the free axis at the target velocity
+        let velocity = app.world.get::<LinearVelocity>(slider).unwrap();
+        assert_relative_eq!(velocity.x, 2.0, epsilon = 0.01);
+        let position = app.world.get::<Position>(slider).unwrap();
+        assert_relative_eq!(position.x, 2.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn compliant_joint_stretches_under_load() {
+        let compliance = 0.01;
+
+        let mut app = create_app();
+
+        let (anchor, body) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::NEG_Y);
+        app.world.entity_mut(body).insert(LinearDamping(5.0));
+        let joint = app
+            .world
+            .spawn(
+                DistanceJoint::new(anchor, body)
+                    .with_rest_length(1.0)
+                    .with_compliance(compliance),
+            )
+            .id();
+
+        app.step_physics(180);
+
+        assert_eq!(
+            app.world.get::<DistanceJoint>(joint).unwrap().compliance(),
+            compliance
+        );
+
+        // The joint stretches until the stretch times the stiffness matches the weight of the body
+        let mass = app.world.get::<Mass>(body).unwrap().0;
+        let gravity = app.world.resource::<Gravity>().0;
+        let stretch = app.world.get::<Position>(body).unwrap().length() - 1.0;
+        assert_relative_eq!(
+            stretch,
+            compliance * mass * gravity.length(),
+            max_relative = 0.1
+        );
+    }
+
+    #[test]
+    fn joint_spring_softness_is_independent_of_mass() {
+        let spring = JointSpring::new(2.0, 1.0);
+
+        let mut app = create_app();
+
+        // Hang a light and a heavy body next to each other
+        let bodies = [(1.0, Vector::ZERO), (10.0, Vector::X * 5.0)].map(|(density, offset)| {
+            let (anchor, body) = spawn_anchor_and_ball(&mut app, offset, offset + Vector::NEG_Y);
+            app.world.entity_mut(body).insert(ColliderDensity(density));
+            app.world.spawn(
+                DistanceJoint::new(anchor, body)
+                    .with_rest_length(1.0)
+                    .with_spring(spring),
+            );
+            (body, offset)
+        });
+
+        app.step_physics(180);
+
+        // Both bodies sag by g / ω² regardless of their mass
+        let gravity = app.world.resource::<Gravity>().0.length();
+        let omega = 2.0 * PI * spring.frequency;
+        for (body, offset) in bodies {
+            let position = app.world.get::<Position>(body).unwrap();
+            let stretch = position.distance(offset) - 1.0;
+            assert_relative_eq!(stretch, gravity / omega.powi(2), max_relative = 0.05);
+        }
+    }
 }
@@ -2,14 +2,18 @@
 
 use crate::prelude::*;
 use bevy::{
-    ecs::entity::{EntityMapper, MapEntities},
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
     prelude::*,
 };
 
 /// A prismatic joint prevents relative movement of the attached bodies, except for translation along one `free_axis`.
 ///
 /// Prismatic joints can be useful for things like elevators, pistons, sliding doors and moving platforms.
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct PrismaticJoint {
     /// First entity constrained by the joint.
     pub entity1: Entity,
@@ -23,6 +27,8 @@ pub struct PrismaticJoint {
     pub free_axis: Vector,
     /// The extents of the allowed relative translation along the free axis.
     pub free_axis_limits: Option<DistanceLimit>,
+    /// A motor that drives the relative translation of the bodies along the free axis.
+    pub motor: Option<JointMotor>,
     /// Linear damping applied by the joint.
     pub damping_linear: Scalar,
     /// Angular damping applied by the joint.
@@ -31,12 +37,21 @@ pub struct PrismaticJoint {
     pub position_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the alignment of the bodies.
     pub align_lagrange: Scalar,
+    /// Lagrange multiplier for the positional correction caused by the motor.
+    pub motor_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring-damper that makes the alignment of the joint soft, if any.
+    /// It replaces the `compliance` of the alignment. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
     pub align_torque: Torque,
+    /// The force exerted by the motor.
+    pub motor_force: Vector,
+    /// The limit of the relative translation along the free axis that was reached during the last substep.
+    pub limit_state: JointLimitState,
 }
 
 impl XpbdConstraint<2> for PrismaticJoint {
@@ -47,6 +62,12 @@ impl XpbdConstraint<2> for PrismaticJoint {
     fn clear_lagrange_multipliers(&mut self) {
         self.position_lagrange = 0.0;
         self.align_lagrange = 0.0;
+        self.motor_lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+        self.align_torque = Torque::ZERO;
+        self.motor_force = Vector::ZERO;
+        self.limit_state = JointLimitState::Free;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -56,9 +77,25 @@ impl XpbdConstraint<2> for PrismaticJoint {
         // Align orientations
         let dq = self.get_delta_q(&body1.rotation, &body2.rotation);
         let mut lagrange = self.align_lagrange;
-        self.align_torque = self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt);
+        self.align_torque = if let Some(spring) = self.spring {
+            let previous_dq = self.get_delta_q(&body1.previous_rotation, &body2.previous_rotation);
+            self.align_orientation_with_spring(
+                body1,
+                body2,
+                dq,
+                previous_dq,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt)
+        };
         self.align_lagrange = lagrange;
 
+        // Drive the translation along the free axis with the motor
+        self.motor_force = self.apply_motor(body1, body2, dt);
+
         // Constrain the relative positions of the bodies, only allowing translation along one free axis
         self.force = self.constrain_positions(body1, body2, dt);
     }
@@ -73,16 +110,21 @@ impl Joint for PrismaticJoint {
             local_anchor2: Vector::ZERO,
             free_axis: Vector::X,
             free_axis_limits: None,
+            motor: None,
             damping_linear: 1.0,
             damping_angular: 1.0,
             position_lagrange: 0.0,
             align_lagrange: 0.0,
+            motor_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
             #[cfg(feature = "3d")]
             align_torque: Vector::ZERO,
+            motor_force: Vector::ZERO,
+            limit_state: JointLimitState::Free,
         }
     }
 
@@ -90,6 +132,13 @@ impl Joint for PrismaticJoint {
         Self { compliance, ..self }
     }
 
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     fn with_local_anchor_1(self, anchor: Vector) -> Self {
         Self {
             local_anchor1: anchor,
@@ -133,6 +182,26 @@ impl Joint for PrismaticJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn compliance(&self) -> Scalar {
+        self.compliance
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force + self.motor_force
+    }
+
+    fn torque(&self) -> Torque {
+        self.align_torque
+    }
+
+    fn limit_state(&self) -> JointLimitState {
+        self.limit_state
+    }
 }
 
 impl PrismaticJoint {
@@ -152,11 +221,10 @@ impl PrismaticJoint {
 
         let axis1 = body1.rotation.rotate(self.free_axis);
         if let Some(limits) = self.free_axis_limits {
-            delta_x += limits.compute_correction_along_axis(
-                body1.current_position() + world_r1,
-                body2.current_position() + world_r2,
-                axis1,
-            );
+            let p1 = body1.current_position() + world_r1;
+            let p2 = body2.current_position() + world_r2;
+            self.limit_state = limits.state((p2 - p1).dot(axis1));
+            delta_x += limits.compute_correction_along_axis(p1, p2, axis1);
         }
 
         let zero_distance_limit = DistanceLimit::ZERO;
@@ -204,14 +272,27 @@ impl PrismaticJoint {
         let w = [w1, w2];
 
         // Compute Lagrange multiplier update
-        let delta_lagrange = self.compute_lagrange_update(
-            self.position_lagrange,
-            magnitude,
-            &gradients,
-            &w,
-            self.compliance,
-            dt,
-        );
+        let delta_lagrange = if let Some(spring) = self.spring {
+            // The movement of the attachment points along the correction during this substep, used for damping
+            let displacement = anchor_displacement(body1, self.local_anchor1)
+                - anchor_displacement(body2, self.local_anchor2);
+            spring.compute_delta_lagrange(
+                self.position_lagrange,
+                magnitude,
+                dir.dot(displacement),
+                w1 + w2,
+                dt,
+            )
+        } else {
+            self.compute_lagrange_update(
+                self.position_lagrange,
+                magnitude,
+                &gradients,
+                &w,
+                self.compliance,
+                dt,
+            )
+        };
         self.position_lagrange += delta_lagrange;
 
         // Apply positional correction to align the positions of the bodies
@@ -221,6 +302,55 @@ impl PrismaticJoint {
         self.compute_force(self.position_lagrange, dir, dt)
     }
 
+    /// Applies the motor's positional correction along the free axis.
+    ///
+    /// Returns the force exerted by the motor.
+    fn apply_motor(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Vector {
+        let Some(motor) = self.motor else {
+            return Vector::ZERO;
+        };
+
+        let world_r1 = body1.rotation.rotate(self.local_anchor1);
+        let world_r2 = body2.rotation.rotate(self.local_anchor2);
+        let axis = body1.rotation.rotate(self.free_axis);
+
+        let translation = ((body2.current_position() + world_r2)
+            - (body1.current_position() + world_r1))
+            .dot(axis);
+        let previous_translation = ((body2.previous_position.0
+            + body2.previous_rotation.rotate(self.local_anchor2))
+            - (body1.previous_position.0 + body1.previous_rotation.rotate(self.local_anchor1)))
+        .dot(body1.previous_rotation.rotate(self.free_axis));
+
+        // A positive Lagrange multiplier pushes the bodies apart along the free axis
+        let dir = -axis;
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, dir);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, dir);
+
+        let delta_lagrange = motor.compute_delta_lagrange(
+            self.motor_lagrange,
+            translation,
+            translation - previous_translation,
+            |error| error,
+            w1 + w2,
+            dt,
+        );
+        self.motor_lagrange += delta_lagrange;
+
+        // Apply positional correction to drive the relative translation
+        self.apply_positional_correction(body1, body2, delta_lagrange, dir, world_r1, world_r2);
+
+        // Return motor force
+        self.compute_force(self.motor_lagrange, dir, dt)
+    }
+
     /// Sets the joint's free axis. Relative translations are allowed along this free axis.
     pub fn with_free_axis(self, axis: Vector) -> Self {
         Self {
@@ -237,6 +367,39 @@ impl PrismaticJoint {
         }
     }
 
+    /// Sets the motor that drives the relative translation of the bodies along the free axis.
+    pub fn with_motor(self, motor: JointMotor) -> Self {
+        Self {
+            motor: Some(motor),
+            ..self
+        }
+    }
+
+    /// Returns the current translation of the joint, which is the offset of the second attachment point
+    /// relative to the first attachment point along the `free_axis`.
+    pub fn translation(
+        &self,
+        position1: &Position,
+        rotation1: &Rotation,
+        position2: &Position,
+        rotation2: &Rotation,
+    ) -> Scalar {
+        let p1 = position1.0 + rotation1.rotate(self.local_anchor1);
+        let p2 = position2.0 + rotation2.rotate(self.local_anchor2);
+        (p2 - p1).dot(rotation1.rotate(self.free_axis))
+    }
+
+    /// Returns the current speed of the joint, which is the linear velocity of the second body
+    /// relative to the first body along the `free_axis`.
+    pub fn speed(
+        &self,
+        rotation1: &Rotation,
+        linear_velocity1: &LinearVelocity,
+        linear_velocity2: &LinearVelocity,
+    ) -> Scalar {
+        (linear_velocity2.0 - linear_velocity1.0).dot(rotation1.rotate(self.free_axis))
+    }
+
     #[cfg(feature = "2d")]
     fn get_delta_q(&self, rot1: &Rotation, rot2: &Rotation) -> Vector3 {
         (*rot2 - *rot1).as_radians() * Vector3::Z
@@ -252,6 +415,13 @@ impl PositionConstraint for PrismaticJoint {}
 
 impl AngularConstraint for PrismaticJoint {}
 
+impl Default for PrismaticJoint {
+    /// Creates a joint with placeholder entities, anchors at the body origins, a free X axis and no compliance.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
 impl MapEntities for PrismaticJoint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
@@ -2,14 +2,18 @@
 
 use crate::prelude::*;
 use bevy::{
-    ecs::entity::{EntityMapper, MapEntities},
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
     prelude::*,
 };
 
 /// A revolute joint prevents relative movement of the attached bodies, except for rotation around one `aligned_axis`.
 ///
 /// Revolute joints can be useful for things like wheels, fans, revolving doors etc.
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct RevoluteJoint {
     /// First entity constrained by the joint.
     pub entity1: Entity,
@@ -29,6 +33,8 @@ pub struct RevoluteJoint {
     pub aligned_axis: Vector,
     /// The extents of the allowed relative rotation of the bodies around the `aligned_axis`.
     pub angle_limit: Option<AngleLimit>,
+    /// A motor that drives the relative rotation of the bodies around the `aligned_axis`.
+    pub motor: Option<JointMotor>,
     /// Linear damping applied by the joint.
     pub damping_linear: Scalar,
     /// Angular damping applied by the joint.
@@ -39,14 +45,23 @@ pub struct RevoluteJoint {
     pub align_lagrange: Scalar,
     /// Lagrange multiplier for the angular correction caused by the angle limits.
     pub angle_limit_lagrange: Scalar,
+    /// Lagrange multiplier for the angular correction caused by the motor.
+    pub motor_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring-damper that makes the alignment of the joint soft, if any.
+    /// It replaces the `compliance` of the alignment. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when aligning the bodies.
     pub align_torque: Torque,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `aligned_axis`.
     pub angle_limit_torque: Torque,
+    /// The torque exerted by the motor.
+    pub motor_torque: Torque,
+    /// The limit of the relative rotation around the `aligned_axis` that was reached during the last substep.
+    pub limit_state: JointLimitState,
 }
 
 impl XpbdConstraint<2> for RevoluteJoint {
@@ -58,6 +73,13 @@ impl XpbdConstraint<2> for RevoluteJoint {
         self.position_lagrange = 0.0;
         self.align_lagrange = 0.0;
         self.angle_limit_lagrange = 0.0;
+        self.motor_lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+        self.align_torque = Torque::ZERO;
+        self.angle_limit_torque = Torque::ZERO;
+        self.motor_torque = Torque::ZERO;
+        self.limit_state = JointLimitState::Free;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -67,22 +89,50 @@ impl XpbdConstraint<2> for RevoluteJoint {
         // Constrain the relative rotation of the bodies, only allowing rotation around one free axis
         let dq = self.get_delta_q(&body1.rotation, &body2.rotation);
         let mut lagrange = self.align_lagrange;
-        self.align_torque = self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt);
+        self.align_torque = if let Some(spring) = self.spring {
+            let previous_dq = self.get_delta_q(&body1.previous_rotation, &body2.previous_rotation);
+            self.align_orientation_with_spring(
+                body1,
+                body2,
+                dq,
+                previous_dq,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_orientation(body1, body2, dq, &mut lagrange, compliance, dt)
+        };
         self.align_lagrange = lagrange;
 
         // Align positions
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = if let Some(spring) = self.spring {
+            self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            )
+        };
         self.position_lagrange = lagrange;
 
+        // Drive the rotation around the free axis with the motor
+        self.motor_torque = self.apply_motor(body1, body2, dt);
+
         // Apply angle limits when rotating around the free axis
         self.angle_limit_torque = self.apply_angle_limits(body1, body2, dt);
     }
@@ -97,12 +147,15 @@ impl Joint for RevoluteJoint {
             local_anchor2: Vector::ZERO,
             aligned_axis: Vector3::Z,
             angle_limit: None,
+            motor: None,
             damping_linear: 1.0,
             damping_angular: 1.0,
             position_lagrange: 0.0,
             align_lagrange: 0.0,
             angle_limit_lagrange: 0.0,
+            motor_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             align_torque: 0.0,
@@ -112,6 +165,11 @@ impl Joint for RevoluteJoint {
             angle_limit_torque: 0.0,
             #[cfg(feature = "3d")]
             angle_limit_torque: Vector::ZERO,
+            #[cfg(feature = "2d")]
+            motor_torque: 0.0,
+            #[cfg(feature = "3d")]
+            motor_torque: Vector::ZERO,
+            limit_state: JointLimitState::Free,
         }
     }
 
@@ -119,6 +177,13 @@ impl Joint for RevoluteJoint {
         Self { compliance, ..self }
     }
 
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     fn with_local_anchor_1(self, anchor: Vector) -> Self {
         Self {
             local_anchor1: anchor,
@@ -162,6 +227,26 @@ impl Joint for RevoluteJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn compliance(&self) -> Scalar {
+        self.compliance
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force
+    }
+
+    fn torque(&self) -> Torque {
+        self.align_torque + self.angle_limit_torque + self.motor_torque
+    }
+
+    fn limit_state(&self) -> JointLimitState {
+        self.limit_state
+    }
 }
 
 impl RevoluteJoint {
@@ -182,12 +267,110 @@ impl RevoluteJoint {
         }
     }
 
+    /// Sets the motor that drives the relative rotation of the bodies around the `aligned_axis`.
+    pub fn with_motor(self, motor: JointMotor) -> Self {
+        Self {
+            motor: Some(motor),
+            ..self
+        }
+    }
+
+    /// Returns the current angle of the joint in radians, which is the rotation of the second body
+    /// relative to the first body around the `aligned_axis`. The angle is between `-PI` and `PI`.
+    ///
+    /// In 2D, counterclockwise rotation is positive.
+    pub fn angle(&self, rotation1: &Rotation, rotation2: &Rotation) -> Scalar {
+        #[cfg(feature = "2d")]
+        {
+            (*rotation2 - *rotation1).as_radians()
+        }
+        #[cfg(feature = "3d")]
+        {
+            let axis = rotation1.rotate(self.aligned_axis);
+            // Any direction perpendicular to the aligned axis works as a reference for measuring the angle
+            let reference = self.aligned_axis.any_orthonormal_vector();
+            let a1 = rotation1.rotate(reference);
+            let a2 = rotation2.rotate(reference);
+            a1.cross(a2).dot(axis).atan2(a1.dot(a2))
+        }
+    }
+
+    /// Returns the current angular velocity of the joint, which is the angular velocity of the second body
+    /// relative to the first body.
+    ///
+    /// Counterclockwise rotation is positive.
+    #[cfg(feature = "2d")]
+    pub fn angular_velocity(
+        &self,
+        angular_velocity1: &AngularVelocity,
+        angular_velocity2: &AngularVelocity,
+    ) -> Scalar {
+        angular_velocity2.0 - angular_velocity1.0
+    }
+
+    /// Returns the current angular velocity of the joint, which is the angular velocity of the second body
+    /// relative to the first body around the `aligned_axis`.
+    #[cfg(feature = "3d")]
+    pub fn angular_velocity(
+        &self,
+        rotation1: &Rotation,
+        angular_velocity1: &AngularVelocity,
+        angular_velocity2: &AngularVelocity,
+    ) -> Scalar {
+        let axis = rotation1.rotate(self.aligned_axis);
+        (angular_velocity2.0 - angular_velocity1.0).dot(axis)
+    }
+
     fn get_delta_q(&self, rot1: &Rotation, rot2: &Rotation) -> Vector3 {
         let a1 = rot1.rotate_vec3(self.aligned_axis);
         let a2 = rot2.rotate_vec3(self.aligned_axis);
         a1.cross(a2)
     }
 
+    /// Applies the motor's angular correction around the `aligned_axis`.
+    ///
+    /// Returns the torque exerted by the motor.
+    fn apply_motor(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) -> Torque {
+        let Some(motor) = self.motor else {
+            return Torque::ZERO;
+        };
+
+        #[cfg(feature = "2d")]
+        let axis = Vector3::Z;
+        #[cfg(feature = "3d")]
+        let axis = body1.rotation.rotate(self.aligned_axis);
+
+        let wrap_angle = |angle: Scalar| (angle + PI).rem_euclid(2.0 * PI) - PI;
+
+        let angle = self.angle(&body1.rotation, &body2.rotation);
+        let previous_angle = self.angle(&body1.previous_rotation.0, &body2.previous_rotation.0);
+
+        // Compute generalized inverse masses
+        let w1 = AngularConstraint::compute_generalized_inverse_mass(self, body1, axis);
+        let w2 = AngularConstraint::compute_generalized_inverse_mass(self, body2, axis);
+
+        let delta_lagrange = motor.compute_delta_lagrange(
+            self.motor_lagrange,
+            angle,
+            wrap_angle(angle - previous_angle),
+            wrap_angle,
+            w1 + w2,
+            dt,
+        );
+        self.motor_lagrange += delta_lagrange;
+
+        // Apply angular correction to drive the relative rotation
+        self.apply_angular_correction(body1, body2, delta_lagrange, axis);
+
+        // Return motor torque
+        self.compute_torque(self.motor_lagrange, axis, dt)
+    }
+
     /// Applies angle limits to limit the relative rotation of the bodies around the `aligned_axis`.
     #[allow(clippy::too_many_arguments)]
     fn apply_angle_limits(
@@ -206,7 +389,8 @@ impl RevoluteJoint {
             let a2 = body2.rotation.rotate_vec3(limit_axis);
             let n = a1.cross(a2).normalize();
 
-            if let Some(dq) = angle_limit.compute_correction(n, a1, a2, PI) {
+            if let Some((dq, state)) = angle_limit.compute_correction(n, a1, a2, PI) {
+                self.limit_state = state;
                 let mut lagrange = self.angle_limit_lagrange;
                 let torque =
                     self.align_orientation(body1, body2, dq, &mut lagrange, self.compliance, dt);
@@ -222,6 +406,13 @@ impl PositionConstraint for RevoluteJoint {}
 
 impl AngularConstraint for RevoluteJoint {}
 
+impl Default for RevoluteJoint {
+    /// Creates a joint with placeholder entities, anchors at the body origins, a Z hinge axis and no compliance.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
 impl MapEntities for RevoluteJoint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
@@ -2,14 +2,18 @@
 
 use crate::prelude::*;
 use bevy::{
-    ecs::entity::{EntityMapper, MapEntities},
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
     prelude::*,
 };
 
 /// A spherical joint prevents relative translation of the attached bodies while allowing rotation around all axes.
 ///
 /// Spherical joints can be useful for things like pendula, chains, ragdolls etc.
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, MapEntities)]
 pub struct SphericalJoint {
     /// First entity constrained by the joint.
     pub entity1: Entity,
@@ -39,12 +43,19 @@ pub struct SphericalJoint {
     pub twist_lagrange: Scalar,
     /// The joint's compliance, the inverse of stiffness, has the unit meters / Newton.
     pub compliance: Scalar,
+    /// The spring-damper that makes the alignment of the joint soft, if any.
+    /// It replaces the `compliance` of the alignment. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
     /// The force exerted by the joint.
     pub force: Vector,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `swing_axis`.
     pub swing_torque: Torque,
     /// The torque exerted by the joint when limiting the relative rotation of the bodies around the `twist_axis`.
     pub twist_torque: Torque,
+    /// The limit of the relative rotation around the `swing_axis` that was reached during the last substep.
+    pub swing_limit_state: JointLimitState,
+    /// The limit of the relative rotation around the `twist_axis` that was reached during the last substep.
+    pub twist_limit_state: JointLimitState,
 }
 
 impl XpbdConstraint<2> for SphericalJoint {
@@ -56,6 +67,12 @@ impl XpbdConstraint<2> for SphericalJoint {
         self.position_lagrange = 0.0;
         self.swing_lagrange = 0.0;
         self.twist_lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+        self.swing_torque = Torque::ZERO;
+        self.twist_torque = Torque::ZERO;
+        self.swing_limit_state = JointLimitState::Free;
+        self.twist_limit_state = JointLimitState::Free;
     }
 
     fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
@@ -64,15 +81,27 @@ impl XpbdConstraint<2> for SphericalJoint {
 
         // Align positions
         let mut lagrange = self.position_lagrange;
-        self.force = self.align_position(
-            body1,
-            body2,
-            self.local_anchor1,
-            self.local_anchor2,
-            &mut lagrange,
-            compliance,
-            dt,
-        );
+        self.force = if let Some(spring) = self.spring {
+            self.align_position_with_spring(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                spring,
+                dt,
+            )
+        } else {
+            self.align_position(
+                body1,
+                body2,
+                self.local_anchor1,
+                self.local_anchor2,
+                &mut lagrange,
+                compliance,
+                dt,
+            )
+        };
         self.position_lagrange = lagrange;
 
         // Apply swing limits
@@ -100,6 +129,7 @@ impl Joint for SphericalJoint {
             swing_lagrange: 0.0,
             twist_lagrange: 0.0,
             compliance: 0.0,
+            spring: None,
             force: Vector::ZERO,
             #[cfg(feature = "2d")]
             swing_torque: 0.0,
@@ -109,6 +139,8 @@ impl Joint for SphericalJoint {
             twist_torque: 0.0,
             #[cfg(feature = "3d")]
             twist_torque: Vector::ZERO,
+            swing_limit_state: JointLimitState::Free,
+            twist_limit_state: JointLimitState::Free,
         }
     }
 
@@ -116,6 +148,13 @@ impl Joint for SphericalJoint {
         Self { compliance, ..self }
     }
 
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
     fn with_local_anchor_1(self, anchor: Vector) -> Self {
         Self {
             local_anchor1: anchor,
@@ -159,6 +198,32 @@ impl Joint for SphericalJoint {
     fn damping_angular(&self) -> Scalar {
         self.damping_angular
     }
+
+    fn compliance(&self) -> Scalar {
+        self.compliance
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force
+    }
+
+    fn torque(&self) -> Torque {
+        self.swing_torque + self.twist_torque
+    }
+
+    /// Returns the state of the swing limit, or the state of the twist limit
+    /// if the swing limit isn't reached.
+    fn limit_state(&self) -> JointLimitState {
+        if self.swing_limit_state != JointLimitState::Free {
+            self.swing_limit_state
+        } else {
+            self.twist_limit_state
+        }
+    }
 }
 
 impl SphericalJoint {
@@ -199,7 +264,8 @@ impl SphericalJoint {
 
             let n = n / n_magnitude;
 
-            if let Some(dq) = joint_limit.compute_correction(n, a1, a2, PI) {
+            if let Some((dq, state)) = joint_limit.compute_correction(n, a1, a2, PI) {
+                self.swing_limit_state = state;
                 let mut lagrange = self.swing_lagrange;
                 let torque =
                     self.align_orientation(body1, body2, dq, &mut lagrange, self.compliance, dt);
@@ -247,7 +313,8 @@ impl SphericalJoint {
 
             let max_correction = if a1.dot(a2) > -0.5 { 2.0 * PI } else { dt };
 
-            if let Some(dq) = joint_limit.compute_correction(n, n1, n2, max_correction) {
+            if let Some((dq, state)) = joint_limit.compute_correction(n, n1, n2, max_correction) {
+                self.twist_limit_state = state;
                 let mut lagrange = self.twist_lagrange;
                 let torque =
                     self.align_orientation(body1, body2, dq, &mut lagrange, self.compliance, dt);
@@ -263,6 +330,13 @@ impl PositionConstraint for SphericalJoint {}
 
 impl AngularConstraint for SphericalJoint {}
 
+impl Default for SphericalJoint {
+    /// Creates a joint with placeholder entities, anchors at the body origins and no compliance.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
 impl MapEntities for SphericalJoint {
     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
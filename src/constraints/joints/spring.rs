@@ -0,0 +1,317 @@
+//! [`SpringJoint`] component.
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// A spring joint pulls the attached bodies towards a given `rest_length` from each other like a damped spring,
+/// while allowing rotation around all axes.
+///
+/// Unlike a [`DistanceJoint`], the spring is configured using a `stiffness` and a `damping` coefficient,
+/// so the bodies can stretch and oscillate around the rest length. This can be useful for things like
+/// soft tethers, suspension bridges and camera rigs that shouldn't be rigid.
+///
+/// The spring is implemented as a compliant constraint, so it stays stable even with very high stiffness values.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let anchor = commands.spawn(RigidBody::Static).id();
+///     let weight = commands
+///         .spawn((RigidBody::Dynamic, Collider::ball(0.5)))
+///         .id();
+///
+///     commands.spawn(
+///         SpringJoint::new(anchor, weight)
+///             .with_rest_length(2.0)
+///             .with_stiffness(100.0)
+///             .with_damping(5.0),
+///     );
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct SpringJoint {
+    /// First entity constrained by the joint.
+    pub entity1: Entity,
+    /// Second entity constrained by the joint.
+    pub entity2: Entity,
+    /// Attachment point on the first body.
+    pub local_anchor1: Vector,
+    /// Attachment point on the second body.
+    pub local_anchor2: Vector,
+    /// The distance that the spring pulls the attached bodies towards.
+    pub rest_length: Scalar,
+    /// The stiffness of the spring, has the unit Newtons / meter.
+    ///
+    /// An infinite stiffness makes the spring rigid, like a [`DistanceJoint`].
+    pub stiffness: Scalar,
+    /// The damping coefficient of the spring, has the unit Newton seconds / meter.
+    /// It damps the relative velocity of the attachment points along the spring.
+    pub damping: Scalar,
+    /// A spring-damper configured by a frequency and damping ratio that replaces the `stiffness` and `damping`
+    /// of the spring, if any. See [`JointSpring`].
+    pub spring: Option<JointSpring>,
+    /// Linear damping applied by the joint.
+    pub damping_linear: Scalar,
+    /// Angular damping applied by the joint.
+    pub damping_angular: Scalar,
+    /// Lagrange multiplier for the positional correction.
+    pub lagrange: Scalar,
+    /// The force exerted by the joint.
+    pub force: Vector,
+}
+
+impl XpbdConstraint<2> for SpringJoint {
+    fn entities(&self) -> [Entity; 2] {
+        [self.entity1, self.entity2]
+    }
+
+    fn clear_lagrange_multipliers(&mut self) {
+        self.lagrange = 0.0;
+        // The forces are derived from the Lagrange multipliers, so they are reset as well
+        self.force = Vector::ZERO;
+    }
+
+    fn solve(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) {
+        self.force = self.constrain_length(bodies, dt);
+    }
+}
+
+impl Joint for SpringJoint {
+    fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            rest_length: 0.0,
+            stiffness: 1.0,
+            damping: 0.0,
+            spring: None,
+            damping_linear: 0.0,
+            damping_angular: 0.0,
+            lagrange: 0.0,
+            force: Vector::ZERO,
+        }
+    }
+
+    /// Sets the joint's compliance (inverse of stiffness, meters / Newton).
+    ///
+    /// This is equivalent to setting the `stiffness` to the inverse of the compliance.
+    fn with_compliance(self, compliance: Scalar) -> Self {
+        Self {
+            stiffness: compliance.recip(),
+            ..self
+        }
+    }
+
+    fn with_spring(self, spring: JointSpring) -> Self {
+        Self {
+            spring: Some(spring),
+            ..self
+        }
+    }
+
+    fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    fn with_linear_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_linear: damping,
+            ..self
+        }
+    }
+
+    fn with_angular_velocity_damping(self, damping: Scalar) -> Self {
+        Self {
+            damping_angular: damping,
+            ..self
+        }
+    }
+
+    fn local_anchor_1(&self) -> Vector {
+        self.local_anchor1
+    }
+
+    fn local_anchor_2(&self) -> Vector {
+        self.local_anchor2
+    }
+
+    fn damping_linear(&self) -> Scalar {
+        self.damping_linear
+    }
+
+    fn damping_angular(&self) -> Scalar {
+        self.damping_angular
+    }
+
+    fn compliance(&self) -> Scalar {
+        self.stiffness.recip()
+    }
+
+    fn spring(&self) -> Option<JointSpring> {
+        self.spring
+    }
+
+    fn force(&self) -> Vector {
+        self.force
+    }
+
+    fn torque(&self) -> Torque {
+        Torque::ZERO
+    }
+}
+
+impl SpringJoint {
+    /// Pulls the attachment points towards the rest length, damping their relative velocity along the spring.
+    ///
+    /// Returns the force exerted by this constraint.
+    fn constrain_length(&mut self, bodies: [&mut RigidBodyQueryItem; 2], dt: Scalar) -> Vector {
+        let [body1, body2] = bodies;
+
+        if self.spring.is_none() && self.stiffness <= 0.0 {
+            return Vector::ZERO;
+        }
+
+        let world_r1 = body1.rotation.rotate(self.local_anchor1);
+        let world_r2 = body2.rotation.rotate(self.local_anchor2);
+
+        let delta_x = (body1.current_position() + world_r1) - (body2.current_position() + world_r2);
+        let length = delta_x.length();
+
+        if length <= Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        // The value of the constraint function. When this is zero, the spring is at its rest length.
+        let c = length - self.rest_length;
+        let n = delta_x / length;
+
+        // Compute generalized inverse masses
+        let w1 = PositionConstraint::compute_generalized_inverse_mass(self, body1, world_r1, n);
+        let w2 = PositionConstraint::compute_generalized_inverse_mass(self, body2, world_r2, n);
+        let w_sum = w1 + w2;
+
+        if w_sum <= Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        // The movement of the attachment points along the spring during this substep
+        let displacement1 = body1.current_position() - body1.previous_position.0 + world_r1
+            - body1.previous_rotation.rotate(self.local_anchor1);
+        let displacement2 = body2.current_position() - body2.previous_position.0 + world_r2
+            - body2.previous_rotation.rotate(self.local_anchor2);
+        let gradient_dot_displacement = n.dot(displacement1 - displacement2);
+
+        // Compute Lagrange multiplier update with damping, see equation 26 of the XPBD paper
+        let delta_lagrange = if let Some(spring) = self.spring {
+            spring.compute_delta_lagrange(self.lagrange, c, gradient_dot_displacement, w_sum, dt)
+        } else {
+            let compliance = self.stiffness.recip();
+            let tilde_compliance = compliance / dt.powi(2);
+            let gamma = compliance * self.damping / dt;
+            (-c - tilde_compliance * self.lagrange - gamma * gradient_dot_displacement)
+                / ((1.0 + gamma) * w_sum + tilde_compliance)
+        };
+        self.lagrange += delta_lagrange;
+
+        // Apply positional correction
+        self.apply_positional_correction(body1, body2, delta_lagrange, n, world_r1, world_r2);
+
+        // Return constraint force
+        self.compute_force(self.lagrange, n, dt)
+    }
+
+    /// Sets the spring's rest length, or the distance that the bodies are pulled towards.
+    pub fn with_rest_length(self, rest_length: Scalar) -> Self {
+        Self {
+            rest_length,
+            ..self
+        }
+    }
+
+    /// Sets the stiffness of the spring (Newtons / meter).
+    pub fn with_stiffness(self, stiffness: Scalar) -> Self {
+        Self { stiffness, ..self }
+    }
+
+    /// Sets the damping coefficient of the spring (Newton seconds / meter).
+    pub fn with_damping(self, damping: Scalar) -> Self {
+        Self { damping, ..self }
+    }
+}
+
+impl PositionConstraint for SpringJoint {}
+
+impl AngularConstraint for SpringJoint {}
+
+impl Default for SpringJoint {
+    /// Creates a spring with placeholder entities, anchors at the body origins, a rest length of zero,
+    /// a stiffness of `1.0` and no damping.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
+impl MapEntities for SpringJoint {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constraints::joints::tests::spawn_anchor_and_ball, prelude::*, test_utils::*,
+        tests::create_app,
+    };
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn spring_joint_settles_at_hookes_law_extension() {
+        let mut app = create_app();
+
+        let (anchor, body) = spawn_anchor_and_ball(&mut app, Vector::ZERO, Vector::NEG_Y);
+        app.world.spawn(
+            SpringJoint::new(anchor, body)
+                .with_rest_length(1.0)
+                .with_stiffness(50.0)
+                .with_damping(10.0),
+        );
+
+        app.step_physics(300);
+
+        let mass = app.world.get::<Mass>(body).unwrap().0;
+        let gravity = app.world.resource::<Gravity>().0.length();
+        let length = app.world.get::<Position>(body).unwrap().length();
+
+        // The spring stretches until its force balances the weight of the body
+        assert_relative_eq!(length, 1.0 + mass * gravity / 50.0, max_relative = 0.02);
+        assert!(app.world.get::<LinearVelocity>(body).unwrap().length() < 0.05);
+    }
+}
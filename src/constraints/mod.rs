@@ -21,6 +21,7 @@
 //!     - [`SphericalJoint`]
 //!     - [`RevoluteJoint`]
 //!     - [`PrismaticJoint`]
+//!     - [`SpringJoint`]
 //!
 //! More constraint types will be added in future releases. If you need more constraints now, consider
 //! [creating your own constraints](custom-constraints).
@@ -29,16 +30,21 @@
 //!
 //! In Bevy XPBD, you can easily create your own constraints using the same APIs that the engine uses for its own constraints.
 //!
-//! First, create a struct and implement the [`XpbdConstraint`] trait, giving the number of participating entities using generics.
+//! First, create a component and implement the [`XpbdConstraint`] trait, giving the number of participating entities using generics.
+//! Constraints must also implement `MapEntities` so that the entities they refer to can be mapped, for example when spawning scenes.
 //! It should look similar to this:
 //!
 //! ```
-//! use bevy::prelude::*;
+//! use bevy::{
+//!     ecs::entity::{EntityMapper, MapEntities},
+//!     prelude::*,
+//! };
 //! # #[cfg(feature = "2d")]
 //! # use bevy_xpbd_2d::prelude::*;
 //! # #[cfg(feature = "3d")]
 //! use bevy_xpbd_3d::prelude::*;
 //!
+//! #[derive(Component)]
 //! struct CustomConstraint {
 //!     entity1: Entity,
 //!     entity2: Entity,
@@ -57,29 +63,35 @@
 //!         // Constraint solving logic goes here
 //!     }
 //! }
+//!
+//! impl MapEntities for CustomConstraint {
+//!     fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+//!         self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+//!         self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+//!     }
+//! }
 //! ```
 //!
 //! Take a look at [`XpbdConstraint::solve`] and the constraint [theory](#theory) to learn more about what to put in `solve`.
+//! The participating bodies are given as [`RigidBodyQueryItem`]s, which provide access to the state of the bodies,
+//! like their positions, rotations, velocities and mass properties.
 //!
-//! Next, we need to add a system that solves the constraint during each run of the [solver]. If your constraint is
-//! a component like most of Bevy XPBD's constraints, you can use the generic [`solve_constraint`] system that handles
-//! some of the background work for you.
-//!
-//! Add the `solve_constraint::<YourConstraint, ENTITY_COUNT>` system to the
-//! [substepping schedule's](SubstepSchedule) [`SubstepSet::SolveUserConstraints`] set. It should look like this:
+//! Next, the constraint needs to be registered so that it is solved during each substep of the [solver].
+//! This is done using [`add_constraint`](ConstraintAppExt::add_constraint), which adds the generic [`solve_constraint`]
+//! system to the [substepping schedule's](SubstepSchedule) [`SubstepSet::SolveUserConstraints`] set:
 //!
 //! ```ignore
-//! // Get substep schedule
-//! let substeps = app
-//!     .get_schedule_mut(SubstepSchedule)
-//!     .expect("add SubstepSchedule first");
-//!
-//! // Add custom constraint
-//! substeps.add_systems(
-//!     solve_constraint::<CustomConstraint, 2>.in_set(SubstepSet::SolveUserConstraints),
-//! );
+//! app.add_plugins(PhysicsPlugins::default())
+//!     .add_constraint::<CustomConstraint, 2>();
 //! ```
 //!
+//! Custom constraints are solved after the built-in constraints, in the order that they were registered.
+//! If the constraint should be solved before or between the built-in constraints, for example to solve
+//! vehicle suspension before contacts, you can register it with a [`ConstraintPriority`] using
+//! [`add_constraint_with_priority`](ConstraintAppExt::add_constraint_with_priority) instead.
+//! If you store constraints somewhere other than components, you can instead add your own solver system
+//! to [`SubstepSet::SolveUserConstraints`].
+//!
 //! Now just spawn an instance of the constraint, give it the participating entities, and the constraint should be getting
 //! solved automatically according to the `solve` method!
 //!
@@ -33,12 +33,85 @@ pub struct PenetrationConstraint {
     pub static_friction_coefficient: Scalar,
     /// The coefficient of [restitution](Restitution) in this contact.
     pub restitution_coefficient: Scalar,
+    /// The maximum [adhesion](Adhesion) force that holds the bodies together when they are separating.
+    pub adhesion: Scalar,
+    /// The soft constraint coefficients used when contacts are solved using [`SolverMode::TgsSoft`].
+    /// If `None`, penetration is resolved using plain XPBD.
+    pub softness: Option<ContactSoftness>,
     /// Normal force acting along the constraint.
     pub normal_force: Vector,
     /// Static friction force acting along this constraint.
     pub static_friction_force: Vector,
 }
 
+/// Soft constraint coefficients for contacts solved using [`SolverMode::TgsSoft`].
+///
+/// Instead of resolving all of the penetration at once, only a part of it is resolved during each substep,
+/// like a damped spring with the given frequency and damping ratio.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContactSoftness {
+    /// The rate at which penetration is resolved, in 1 / seconds.
+    pub bias_rate: Scalar,
+    /// The scale applied to the correction computed from the penetration.
+    pub mass_scale: Scalar,
+    /// The scale applied to the correction that has already been applied during the substep, which softens the contact.
+    pub impulse_scale: Scalar,
+    /// The maximum speed in meters per second at which overlapping bodies are pushed apart.
+    pub max_pushout_velocity: Scalar,
+}
+
+impl ContactSoftness {
+    /// Computes the soft constraint coefficients for the given frequency in Hertz, damping ratio,
+    /// maximum pushout velocity and substep duration.
+    pub fn new(
+        hertz: Scalar,
+        damping_ratio: Scalar,
+        max_pushout_velocity: Scalar,
+        dt: Scalar,
+    ) -> Self {
+        if hertz <= 0.0 {
+            return Self {
+                bias_rate: 0.0,
+                mass_scale: 1.0,
+                impulse_scale: 0.0,
+                max_pushout_velocity,
+            };
+        }
+
+        let omega = 2.0 * PI * hertz;
+        let a1 = 2.0 * damping_ratio + dt * omega;
+        let a2 = dt * omega * a1;
+        let a3 = 1.0 / (1.0 + a2);
+
+        Self {
+            bias_rate: omega / a1,
+            mass_scale: a2 * a3,
+            impulse_scale: a3,
+            max_pushout_velocity,
+        }
+    }
+
+    /// Computes the Lagrange multiplier update for resolving the given penetration depth
+    /// with the sum of the generalized inverse masses `w_sum`.
+    ///
+    /// The total Lagrange multiplier is kept compressive, so the contact never pulls the bodies together.
+    pub fn compute_lagrange_update(
+        &self,
+        lagrange: Scalar,
+        penetration: Scalar,
+        w_sum: Scalar,
+        dt: Scalar,
+    ) -> Scalar {
+        if w_sum <= Scalar::EPSILON {
+            return 0.0;
+        }
+
+        let correction = (self.bias_rate * penetration).min(self.max_pushout_velocity) * dt;
+        let delta_lagrange = -self.mass_scale * correction / w_sum - self.impulse_scale * lagrange;
+        (lagrange + delta_lagrange).min(0.0) - lagrange
+    }
+}
+
 impl XpbdConstraint<2> for PenetrationConstraint {
     fn entities(&self) -> [Entity; 2] {
         [self.entity1, self.entity2]
@@ -57,8 +130,11 @@ impl XpbdConstraint<2> for PenetrationConstraint {
         let p2 = body2.current_position() + body2.rotation.rotate(self.contact.point2);
         self.contact.penetration = (p1 - p2).dot(self.contact.global_normal1(&body1.rotation));
 
-        // If penetration depth is under 0, skip the collision
+        // If penetration depth is under 0, skip the collision unless the contact is sticky
         if self.contact.penetration <= Scalar::EPSILON {
+            if self.adhesion > 0.0 {
+                self.solve_adhesion(body1, body2, dt);
+            }
             return;
         }
 
@@ -89,6 +165,8 @@ impl PenetrationConstraint {
             dynamic_friction_coefficient: 0.0,
             static_friction_coefficient: 0.0,
             restitution_coefficient: 0.0,
+            adhesion: 0.0,
+            softness: None,
             normal_force: Vector::ZERO,
             static_friction_force: Vector::ZERO,
         }
@@ -118,8 +196,12 @@ impl PenetrationConstraint {
         let w = [w1, w2];
 
         // Compute Lagrange multiplier update
-        let delta_lagrange =
-            self.compute_lagrange_update(lagrange, penetration, &gradients, &w, compliance, dt);
+        let delta_lagrange = match self.softness {
+            Some(softness) => softness.compute_lagrange_update(lagrange, penetration, w1 + w2, dt),
+            None => {
+                self.compute_lagrange_update(lagrange, penetration, &gradients, &w, compliance, dt)
+            }
+        };
         self.normal_lagrange += delta_lagrange;
 
         // Apply positional correction to solve overlap
@@ -129,6 +211,51 @@ impl PenetrationConstraint {
         self.normal_force = self.normal_lagrange * normal / dt.powi(2);
     }
 
+    /// Pulls separating bodies back together, limiting the force to the adhesion force.
+    fn solve_adhesion(
+        &mut self,
+        body1: &mut RigidBodyQueryItem,
+        body2: &mut RigidBodyQueryItem,
+        dt: Scalar,
+    ) {
+        // Shorter aliases
+        let compliance = self.compliance;
+        let lagrange = self.normal_lagrange;
+        let separation = self.contact.penetration;
+        let normal = self.contact.global_normal1(&body1.rotation);
+        let r1 = body1.rotation.rotate(self.r1);
+        let r2 = body2.rotation.rotate(self.r2);
+
+        // Compute generalized inverse masses
+        let w1 = self.compute_generalized_inverse_mass(body1, r1, normal);
+        let w2 = self.compute_generalized_inverse_mass(body2, r2, normal);
+
+        // Constraint gradients and inverse masses
+        let gradients = [normal, -normal];
+        let w = [w1, w2];
+
+        // Compute Lagrange multiplier update and clamp the total force to the adhesion force
+        let max_lagrange = self.adhesion * dt.powi(2);
+        let delta_lagrange =
+            self.compute_lagrange_update(lagrange, separation, &gradients, &w, compliance, dt);
+        let delta_lagrange = (lagrange + delta_lagrange).min(max_lagrange) - lagrange;
+        if delta_lagrange <= 0.0 {
+            return;
+        }
+        self.normal_lagrange += delta_lagrange;
+
+        // Apply positional correction to pull the bodies together
+        self.apply_positional_correction(body1, body2, delta_lagrange, normal, r1, r2);
+
+        // Update normal force using the equation f = lambda * n / h^2
+        self.normal_force = self.normal_lagrange * normal / dt.powi(2);
+    }
+
+    /// Returns true if the constraint is holding the bodies together using [adhesion](Adhesion).
+    pub fn is_adhering(&self) -> bool {
+        self.normal_lagrange > 0.0
+    }
+
     fn solve_friction(
         &mut self,
         body1: &mut RigidBodyQueryItem,
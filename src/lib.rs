@@ -23,10 +23,11 @@
 //! - [Collision detection](collision) and [`Collider`]s powered by [parry](parry)
 //!     - Collision events: [`Collision`], [`CollisionStarted`], [`CollisionEnded`]
 //!     - Access to [colliding entities](CollidingEntities)
-//!     - [Sensor colliders](Sensor)
+//!     - [Sensor colliders](Sensor) and [query-only colliders](QueryOnly)
 //!     - [Collision layers](CollisionLayers)
 //!     - [Contact and time of impact queries](collision::contact_query)
-//! - Material properties like [restitution](Restitution) and [friction](Friction)
+//! - Material properties like [restitution](Restitution), [friction](Friction), [soft contacts](ContactCompliance)
+//! and [adhesion](Adhesion)
 //! - [Linear damping](LinearDamping) and [angular damping](AngularDamping) for simulating drag
 //! - External [forces](ExternalForce), [torque](ExternalTorque), [impulses](ExternalImpulse) and
 //! [angular impulses](ExternalAngularImpulse)
@@ -44,6 +45,7 @@
 //! (with `debug-plugin` feature)
 //! - Automatically deactivating bodies with [sleeping](Sleeping)
 //! - Configurable [timesteps](PhysicsTimestep), [time scale](PhysicsTimescale) and [substepping](SubstepCount)
+//! - Configurable [integrators](Integrator), like [semi-implicit Euler](SemiImplicitEuler) and [velocity Verlet](VelocityVerlet)
 //! - `f32`/`f64` precision (`f32` by default)
 //!
 //! ## Getting started
@@ -80,7 +82,7 @@
 //!
 //! ### Feature flags
 //!
-//! Default features: `2d`/`3d`, `f32` and `collider-from-mesh`
+//! Default features: `2d`/`3d`, `f32` and `parallel`, and `async-collider` for `bevy_xpbd_3d`
 //!
 //! - `2d` enables simulation on the `x` and `y` axes. Enabled by default for `bevy_xpbd_2d`. Incompatible with `3d`.
 //! - `3d` enables simulation on the `x`, `y` and `z` axes. Enabled by default for `bevy_xpbd_3d`. Incompatible with `2d`.
@@ -89,13 +91,75 @@
 //! small timesteps. Incompatible with `f32`.
 //! - `debug-plugin` enables the `PhysicsDebugPlugin` used for rendering physics objects and properties, like
 //! [colliders](Collider), [AABBs](ColliderAabb) and [contacts](Contact).
-//! - `collider-from-mesh` allows you to create [colliders](Collider) from Bevy meshes. Enables `bevy_render`.
+//! - `egui` enables the `PhysicsTuningPlugin`, an [egui](https://github.com/emilk/egui) panel for tuning
+//! the simulation at runtime. Requires `bevy_egui`.
+//! - `rapier-compat` enables the `RapierCompatPlugin` and components that mirror common
+//! [bevy_rapier](https://github.com/dimforge/bevy_rapier) components, for easier migration.
+//! - `picking` enables the `PhysicsPickingPlugin`, a [bevy_mod_picking](https://github.com/aevyrie/bevy_mod_picking)
+//! backend for picking [colliders](Collider). Requires `bevy_picking_core` and enables `bevy_render`.
+//! - `mouse-drag` enables the `MouseDragPlugin` for dragging dynamic bodies with the mouse. Enables `bevy_render`.
+//! - `camera-raycast` enables `SpatialQuery::cast_ray_from_camera` for casting rays from the cursor. Enables `bevy_render`.
+//! - `collider-from-mesh` allows you to create [colliders](Collider) from Bevy meshes and mesh shapes. Enables `bevy_render`.
+//! - `async-collider` allows you to generate [colliders](Collider) from mesh handles and scenes. Enables
+//! `collider-from-mesh` and `bevy_scene`.
+//! - `collider-from-image` allows you to generate 2D [colliders](Collider) from the alpha channel of sprite images.
+//! Enables `bevy_render`.
 //! - `simd` enables [SIMD](https://en.wikipedia.org/wiki/Single_instruction,_multiple_data) optimizations.
 //! - `parallel` enables multithreading. This improves performance for larger simulations but can add unnecessary
-//! overhead for smaller ones.
+//! overhead for smaller ones. Without it, the physics schedules run on a single thread.
+//! - `single-threaded` disables all parallel iteration and use of the `ComputeTaskPool`, even if `parallel`
+//! is enabled by another crate in the dependency tree.
 //! - `enhanced-determinism` enables increased determinism. (Note: cross-platform determinism doesn't work yet, even
 //! with this feature enabled)
 //!
+//! ### Headless usage
+//!
+//! Bevy XPBD doesn't depend on `bevy_render` or `bevy_asset` unless the `collider-from-mesh`, `collider-from-image`,
+//! `async-collider` or `debug-plugin` features are enabled. This means that it can be run headless with `MinimalPlugins`,
+//! for example on dedicated game servers.
+//!
+//! `bevy_xpbd_3d` enables `async-collider` by default. Its systems only run when the mesh assets and the scene spawner
+//! exist, so the physics plugins also work with `MinimalPlugins` when the default features are enabled. To avoid compiling
+//! `bevy_render` and `bevy_scene` at all, disable the default features:
+//!
+//! ```toml
+//! [dependencies]
+//! bevy_xpbd_3d = { version = "0.2", default-features = false, features = ["3d", "f32", "parallel"] }
+//! ```
+//!
+//! The only other plugin that the physics plugins need is Bevy's `TransformPlugin`, which is required
+//! for keeping physics positions and `Transform` in sync:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! # #[cfg(feature = "2d")]
+//! # use bevy_xpbd_2d::prelude::*;
+//! # #[cfg(feature = "3d")]
+//! use bevy_xpbd_3d::prelude::*;
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((MinimalPlugins, TransformPlugin, PhysicsPlugins::default()))
+//!         .run();
+//! }
+//! ```
+//!
+//! Instead of `SpatialBundle`, which requires `bevy_render`, rigid bodies can use a `TransformBundle`.
+//!
+//! ### Single-threaded and WASM usage
+//!
+//! The physics schedules always use Bevy's single-threaded executor. When the `parallel` feature is disabled
+//! or the `single-threaded` feature is enabled, Bevy XPBD also doesn't use parallel iterators or the `ComputeTaskPool`,
+//! so the whole simulation runs on the thread that runs the `PhysicsSchedule`. This avoids multithreading overhead
+//! for small simulations.
+//!
+//! For `wasm32` builds without atomics, disable the default features so that Parry is also built without `rayon`:
+//!
+//! ```toml
+//! [dependencies]
+//! bevy_xpbd_2d = { version = "0.2", default-features = false, features = ["2d", "f32", "single-threaded"] }
+//! ```
+//!
 //! ### Install the plugin
 //!
 //! Bevy XPBD is designed to be very modular. It is built from many different [plugins] that
@@ -171,6 +235,7 @@
 //! - [Define collision layers](CollisionLayers#creation)
 //! - [Configure restitution (bounciness)](Restitution)
 //! - [Configure friction](Friction)
+//! - [Make contacts soft](ContactCompliance)
 //! - [Configure gravity](Gravity)
 //! - [Apply external forces](ExternalForce)
 //! - [Apply external torque](ExternalTorque)
@@ -424,6 +489,7 @@ pub mod constraints;
 pub mod math;
 pub mod plugins;
 pub mod resources;
+pub mod test_utils;
 
 /// Re-exports common components, bundles, resources, plugins and types.
 pub mod prelude {
@@ -432,7 +498,8 @@ pub mod prelude {
         constraints::{joints::*, *},
         plugins::*,
         resources::*,
-        PhysicsSet,
+        PhysicsSchedule, PhysicsSet, PhysicsStepSet, PostProcessCollisions, PrepareSet,
+        SubstepSchedule, SubstepSet, SyncSet,
     };
     pub(crate) use crate::{math::*, *};
     pub use bevy_xpbd_derive::*;
@@ -512,15 +579,22 @@ pub enum PhysicsSet {
 /// System sets for the main steps in the physics simulation loop. These are typically run in the [`PhysicsSchedule`].
 ///
 /// 1. Broad phase
-/// 2. Substeps
+/// 2. Filter broad phase pairs
+/// 3. Substeps
 ///     1. Integrate
 ///     2. Narrow phase
-///     3. Solve positional and angular constraints
-///     4. Update velocities
-///     5. Solve velocity constraints (dynamic friction and restitution)
-/// 3. Report contacts (send collision events)
-/// 4. Sleeping
-/// 5. Spatial queries
+///     3. Post-process collisions
+///     4. Solve positional and angular constraints
+///     5. Solve user constraints
+///     6. Update velocities
+///     7. Solve velocity constraints (dynamic friction and restitution)
+///     8. Apply translation
+/// 4. Report contacts (send collision events)
+/// 5. Sleeping
+/// 6. Spatial queries
+///
+/// The order of the sets is stable, so you can order your own systems relative to them,
+/// for example to run a system right after the broad phase.
 #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PhysicsStepSet {
     /// Responsible for collecting pairs of potentially colliding entities into [`BroadCollisionPairs`] using
@@ -528,6 +602,13 @@ pub enum PhysicsStepSet {
     ///
     /// See [`BroadPhasePlugin`].
     BroadPhase,
+    /// Empty by default. Runs after the broad phase and before the narrow phase,
+    /// allowing user-defined systems to filter [`BroadCollisionPairs`].
+    ///
+    /// Removing a pair here is a cheap way to prevent two entities from colliding
+    /// during the current physics step, as no contacts will be computed for it.
+    /// See [`BroadCollisionPairs`] for an example.
+    FilterPairs,
     /// Responsible for substepping, which is an inner loop inside a physics step.
     ///
     /// See [`SubstepSet`] and [`SubstepSchedule`].
@@ -552,8 +633,47 @@ pub enum PhysicsStepSet {
 /// 2. Narrow phase
 /// 3. Post-process collisions
 /// 4. Solve positional and angular constraints
-/// 5. Update velocities
-/// 6. Solve velocity constraints (dynamic friction and restitution)
+/// 5. Solve user constraints
+/// 6. Update velocities
+/// 7. Solve velocity constraints (dynamic friction and restitution)
+/// 8. Apply translation
+///
+/// The order of the sets is stable, so you can order your own systems relative to them.
+///
+/// ## Example
+///
+/// A system that runs after the narrow phase has computed the contacts of the substep,
+/// but before the solver resolves them:
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((DefaultPlugins, PhysicsPlugins::default()));
+///
+///     app.get_schedule_mut(SubstepSchedule)
+///         .expect("add SubstepSchedule first")
+///         .add_systems(
+///             inspect_contacts
+///                 .after(SubstepSet::NarrowPhase)
+///                 .before(SubstepSet::SolveConstraints),
+///         );
+///
+///     // Run the app...
+/// }
+///
+/// fn inspect_contacts(collisions: Res<Collisions>) {
+///     for contacts in collisions.iter() {
+///         println!("{:?} and {:?} are touching", contacts.entity1, contacts.entity2);
+///     }
+/// }
+/// ```
 #[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SubstepSet {
     /// Responsible for integrating Newton's 2nd law of motion,
@@ -575,16 +695,19 @@ pub enum SubstepSet {
     PostProcessCollisions,
     /// The [solver] iterates through [constraints] and solves them.
     ///
+    /// The constraints are solved in groups ordered by their [`ConstraintPriority`].
+    ///
     /// **Note**: If you want to [create your own constraints](constraints#custom-constraints),
-    /// you should add them in [`SubstepSet::SolveUserConstraints`]
-    /// to avoid system order ambiguities.
+    /// you should add them in [`SubstepSet::SolveUserConstraints`] or register them with
+    /// a [`ConstraintPriority`] to avoid system order ambiguities.
     ///
     /// See [`SolverPlugin`].
     SolveConstraints,
     /// The [solver] iterates through custom [constraints] created by the user and solves them.
     ///
     /// You can [create new constraints](constraints#custom-constraints) by implementing [`XpbdConstraint`]
-    /// for a component and adding the [constraint system](solve_constraint) to this set.
+    /// for a component and registering it using [`ConstraintAppExt::add_constraint`], which adds
+    /// the [constraint system](solve_constraint) to this set.
     ///
     /// See [`SolverPlugin`].
     SolveUserConstraints,
@@ -602,3 +725,70 @@ pub enum SubstepSet {
     /// See [`SolverPlugin`].
     ApplyTranslation,
 }
+
+/// System sets for the stages of [`PhysicsSet::Prepare`], in which new [rigid bodies](RigidBody) and
+/// [colliders](Collider) are initialized before the physics step.
+///
+/// 1. Pre-initialization
+/// 2. Propagate transforms
+/// 3. Initialize rigid bodies
+/// 4. Initialize mass properties
+/// 5. Initialize colliders
+/// 6. Initialize transforms
+/// 7. Finalize
+///
+/// The sets are run in the schedule given to the [`PreparePlugin`], `PostUpdate` by default.
+/// Their order is stable, so you can order your own systems relative to them, for example
+/// to modify the components of new colliders before their mass properties are added to their bodies.
+///
+/// Note that components inserted with commands in one stage only become visible once the commands
+/// have been applied. This happens at the start of [`PrepareSet::PreInit`], at the end of [`PrepareSet::InitColliders`]
+/// and before the [joint anchors](JointWorldAnchor) are resolved in [`PrepareSet::Finalize`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrepareSet {
+    /// Applies commands queued before the physics frame and sends the [`RigidBodyAdded`],
+    /// [`RigidBodyRemoved`], [`ColliderAdded`] and [`ColliderRemoved`] events.
+    PreInit,
+    /// Propagates `Transform` to `GlobalTransform` if new bodies or colliders have been added,
+    /// so that they are initialized at the correct position.
+    PropagateTransforms,
+    /// Adds missing rigid body components for entities with a [`RigidBody`].
+    InitRigidBodies,
+    /// Adds missing mass properties for entities with a [`RigidBody`].
+    InitMassProperties,
+    /// Adds missing collider components for entities with a [`Collider`] and updates their [`ColliderParent`].
+    InitColliders,
+    /// Initializes the [`Position`] and [`Rotation`] of new bodies and colliders from their `Transform`
+    /// and computes the transforms of colliders relative to their bodies.
+    InitTransforms,
+    /// Updates mass properties, clamps material coefficients and resolves the local anchors
+    /// of [joints] with a [`JointWorldAnchor`].
+    Finalize,
+}
+
+/// System sets for the stages of synchronizing physics components with `Transform`.
+///
+/// 1. Pre-step: runs after [`PhysicsSet::Prepare`] and before [`PhysicsSet::StepSimulation`]
+/// 2. `Transform` to [`Position`] and [`Rotation`]: runs in [`PhysicsSet::Sync`]
+/// 3. [`Position`] and [`Rotation`] to `Transform`: runs in [`PhysicsSet::Sync`]
+/// 4. Finalize: runs in [`PhysicsSet::Sync`]
+///
+/// The sets are run in the schedule given to the [`SyncPlugin`], `PostUpdate` by default.
+/// Their order is stable, so you can order your own systems relative to them, for example to read
+/// the interpolated `Transform` of bodies before it is propagated to their children.
+///
+/// See [`SyncConfig`] for configuring which of the stages are run.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SyncSet {
+    /// Applies `Transform` changes and [teleports](Teleport) that happened between the end of
+    /// the previous physics frame and the start of the physics step.
+    PreStep,
+    /// Applies `Transform` changes that happened during the physics frame to [`Position`] and [`Rotation`].
+    TransformToPosition,
+    /// Applies [`Position`] and [`Rotation`] changes to `Transform`, including
+    /// [interpolation](InterpolateTransform) and [extrapolation](ExtrapolateTransform).
+    PositionToTransform,
+    /// Updates the data used for detecting `Transform` changes on the next frame
+    /// and the scale of colliders.
+    Finalize,
+}
@@ -0,0 +1,223 @@
+//! Deactivates bodies that are outside of all activity regions.
+//!
+//! See [`ActivityRegionPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Deactivates [dynamic](RigidBody::Dynamic) bodies that are outside of all [`ActivityRegion`]s
+/// and activates them again when they enter a region.
+///
+/// This is useful for open worlds with a large number of props, where only the bodies around the
+/// camera or the players need to be simulated.
+///
+/// Bodies are deactivated by marking them as [`Sleeping`] and adding the [`OutsideActivityRegions`] component.
+/// Their velocities are kept, so they continue moving where they left off once they are activated again.
+/// Bodies with the [`AlwaysActive`] component are never deactivated.
+///
+/// If there are no activity regions, no bodies are deactivated.
+///
+/// The regions are checked after [`PhysicsStepSet::Sleeping`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // Only simulate bodies within 100 units of the player
+///     commands.spawn((
+///         TransformBundle::default(),
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         ActivityRegion::new(100.0),
+///         AlwaysActive,
+///     ));
+/// }
+/// ```
+pub struct ActivityRegionPlugin;
+
+impl Plugin for ActivityRegionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ActivityRegion>()
+            .register_type::<OutsideActivityRegions>()
+            .register_type::<AlwaysActive>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                update_activity_regions
+                    .after(PhysicsStepSet::Sleeping)
+                    .before(PhysicsStepSet::SpatialQuery),
+            );
+    }
+}
+
+/// A spherical region around an entity where bodies are simulated. Requires the [`ActivityRegionPlugin`].
+///
+/// The region is centered at the [`GlobalTransform`] of the entity, so it can be added to cameras and players.
+/// Bodies are activated when their [`Position`] is inside the [radius](ActivityRegion::radius), and they are
+/// only deactivated again once they are further than the radius plus the [margin](ActivityRegion::margin) away,
+/// so that bodies at the edge of the region don't switch between the states all the time.
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct ActivityRegion {
+    /// The radius of the region.
+    pub radius: Scalar,
+    /// How far outside the radius active bodies need to be before they are deactivated.
+    pub margin: Scalar,
+}
+
+impl ActivityRegion {
+    /// Creates a new [`ActivityRegion`] with the given radius and a margin of 10% of the radius.
+    pub fn new(radius: Scalar) -> Self {
+        Self {
+            radius,
+            margin: radius * 0.1,
+        }
+    }
+
+    /// Sets the margin that active bodies need to be outside the radius before they are deactivated.
+    pub fn with_margin(self, margin: Scalar) -> Self {
+        Self { margin, ..self }
+    }
+}
+
+impl Default for ActivityRegion {
+    /// Creates a region with a radius of `100.0`.
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+/// Marks a body that has been deactivated by the [`ActivityRegionPlugin`] because it is outside of all
+/// [`ActivityRegion`]s. The component is removed when the body enters a region.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct OutsideActivityRegions;
+
+/// Prevents a body from being deactivated by the [`ActivityRegionPlugin`] when it is outside of all [`ActivityRegion`]s.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct AlwaysActive;
+
+#[allow(clippy::type_complexity)]
+fn update_activity_regions(
+    mut commands: Commands,
+    regions: Query<(&ActivityRegion, &GlobalTransform)>,
+    mut bodies: Query<
+        (
+            Entity,
+            &RigidBody,
+            &Position,
+            &mut TimeSleeping,
+            Has<Sleeping>,
+            Has<OutsideActivityRegions>,
+        ),
+        Without<AlwaysActive>,
+    >,
+) {
+    if regions.is_empty() {
+        return;
+    }
+
+    let regions: Vec<(Vector, ActivityRegion)> = regions
+        .iter()
+        .map(|(region, transform)| {
+            #[cfg(feature = "2d")]
+            let center = transform.translation().truncate().adjust_precision();
+            #[cfg(feature = "3d")]
+            let center = transform.translation().adjust_precision();
+            (center, *region)
+        })
+        .collect();
+
+    for (entity, rb, position, mut time_sleeping, is_sleeping, is_outside) in &mut bodies {
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        // Inactive bodies need to enter the radius, active bodies need to leave the radius and the margin
+        let is_in_region = regions.iter().any(|(center, region)| {
+            let radius = if is_outside {
+                region.radius
+            } else {
+                region.radius + region.margin
+            };
+            position.distance_squared(*center) <= radius * radius
+        });
+
+        if is_in_region && is_outside {
+            commands
+                .entity(entity)
+                .remove::<(Sleeping, OutsideActivityRegions)>();
+            time_sleeping.0 = 0.0;
+        } else if !is_in_region && !is_outside {
+            commands
+                .entity(entity)
+                .insert((Sleeping, OutsideActivityRegions));
+        } else if !is_in_region && !is_sleeping {
+            // The body can be woken up by other bodies or by changes to its properties, so keep it asleep
+            commands.entity(entity).insert(Sleeping);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn bodies_outside_activity_regions_are_deactivated() {
+        let mut app = create_app();
+        app.add_plugins(ActivityRegionPlugin);
+
+        let region = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                ActivityRegion::new(10.0).with_margin(1.0),
+            ))
+            .id();
+        // Leaves the region and its margin after 3 seconds
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 5.0),
+                LinearVelocity(Vector::X * 2.0),
+                GravityScale(0.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(240);
+
+        // The body stopped at the edge of the margin, but its velocity was kept
+        assert!(app.world.get::<OutsideActivityRegions>(body).is_some());
+        assert!(app.world.get::<Sleeping>(body).is_some());
+        let position = app.world.get::<Position>(body).unwrap().x;
+        assert!(position > 11.0 && position < 11.1);
+        assert_relative_eq!(app.world.get::<LinearVelocity>(body).unwrap().x, 2.0);
+
+        // Move the region to the body
+        let transform = Transform::from_translation(Vec3::X * 12.0);
+        app.world
+            .entity_mut(region)
+            .insert((transform, GlobalTransform::from(transform)));
+
+        app.step_physics(60);
+
+        // The body continues moving where it left off
+        assert!(app.world.get::<OutsideActivityRegions>(body).is_none());
+        assert!(app.world.get::<Sleeping>(body).is_none());
+        assert!(app.world.get::<Position>(body).unwrap().x > position + 1.5);
+    }
+}
@@ -0,0 +1,585 @@
+//! Helpers for character controllers.
+//!
+//! See [`CharacterPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Adds helpers for building character controllers on top of [rigid bodies](RigidBody).
+///
+/// - [`CharacterCapsule`] smoothly resizes the capsule [`Collider`] of a character to a target height,
+/// for example for crouching, and only lets it grow when there is headroom above the character.
+/// - [`WallDetector`] finds the walls next to a character and the ledges at the top of them,
+/// for example for wall sliding, wall jumping and grabbing ledges.
+///
+/// The plugin doesn't move characters by itself. Pushing dynamic bodies with a kinematic character
+/// and resolving collisions against moving colliders like doors and rotating platforms are only
+/// implemented in the `basic_kinematic_character` example, and can be copied from there.
+///
+/// The plugin requires the [`SpatialQueryPlugin`]. The capsules are resized after [`PhysicsStepSet::BroadPhase`]
+/// and before [`PhysicsStepSet::Substeps`], and the walls are detected after [`PhysicsStepSet::SpatialQuery`].
+pub struct CharacterPlugin;
+
+impl Plugin for CharacterPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CharacterCapsule>()
+            .register_type::<WallDetector>()
+            .register_type::<WallContact>()
+            .register_type::<Ledge>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                resize_character_capsules
+                    .after(PhysicsStepSet::BroadPhase)
+                    .before(PhysicsStepSet::Substeps),
+            )
+            .add_systems(detect_walls.after(PhysicsStepSet::SpatialQuery));
+    }
+}
+
+/// The fraction of the radius that the headroom check shrinks the capsule by
+/// so that walls next to the character don't block it from growing.
+const HEADROOM_SKIN: Scalar = 0.05;
+
+/// An upright capsule [`Collider`] for a character that can be resized at runtime, for example for
+/// crouching or going prone. Requires the [`CharacterPlugin`].
+///
+/// The height is the total height of the capsule, including its rounded ends. Each physics step, the
+/// height moves towards the [target height](CharacterCapsule::target_height) at the
+/// [resize speed](CharacterCapsule::resize_speed), and the collider is replaced with a capsule of the new height.
+/// The bottom of the capsule stays in place, so the feet of the character don't leave the ground.
+///
+/// Before the capsule grows, a shape cast checks if there is headroom above the character.
+/// If the capsule would hit a collider, it only grows until it touches the collider, and
+/// [`CharacterCapsule::has_headroom`] returns false until it can reach the target height.
+/// This means that a crouching character under a low ceiling stays crouched and stands up
+/// automatically once the ceiling is gone.
+///
+/// The character should be spawned with the [collider](CharacterCapsule::collider) of the capsule,
+/// and its rotation should usually be [locked](LockedAxes) so that the capsule stays upright.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let capsule = CharacterCapsule::new(0.4, 1.8);
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         capsule.collider(),
+///         capsule,
+///         LockedAxes::ROTATION_LOCKED,
+///     ));
+/// }
+///
+/// fn crouch(mut capsules: Query<&mut CharacterCapsule>) {
+///     for mut capsule in &mut capsules {
+///         // The character stands back up when the target height is set to 1.8 again
+///         capsule.target_height = 1.0;
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct CharacterCapsule {
+    /// The radius of the capsule.
+    pub radius: Scalar,
+    /// The height that the capsule is resized to over time.
+    pub target_height: Scalar,
+    /// How fast the height of the capsule changes in units per second.
+    pub resize_speed: Scalar,
+    /// The current total height of the capsule.
+    height: Scalar,
+    /// True if the capsule couldn't grow towards its target height during the latest physics step.
+    blocked: bool,
+}
+
+impl CharacterCapsule {
+    /// Creates a new [`CharacterCapsule`] with the given radius and total height.
+    /// The height can't be smaller than the diameter of the capsule.
+    pub fn new(radius: Scalar, height: Scalar) -> Self {
+        let height = height.max(2.0 * radius);
+        Self {
+            radius,
+            target_height: height,
+            resize_speed: 4.0,
+            height,
+            blocked: false,
+        }
+    }
+
+    /// Sets the speed at which the height of the capsule changes in units per second.
+    pub fn with_resize_speed(self, resize_speed: Scalar) -> Self {
+        Self {
+            resize_speed,
+            ..self
+        }
+    }
+
+    /// Returns the current total height of the capsule.
+    pub fn height(&self) -> Scalar {
+        self.height
+    }
+
+    /// Returns true if the capsule has reached its target height.
+    pub fn is_at_target_height(&self) -> bool {
+        self.height == self.target_height.max(2.0 * self.radius)
+    }
+
+    /// Returns false if a collider above the character stopped the capsule from growing
+    /// towards its target height during the latest physics step.
+    pub fn has_headroom(&self) -> bool {
+        !self.blocked
+    }
+
+    /// Returns a capsule [`Collider`] with the current height and radius.
+    pub fn collider(&self) -> Collider {
+        Collider::capsule(self.height - 2.0 * self.radius, self.radius)
+    }
+}
+
+impl Default for CharacterCapsule {
+    /// Creates a capsule with a radius of `0.5` and a height of `2.0`.
+    fn default() -> Self {
+        Self::new(0.5, 2.0)
+    }
+}
+
+fn resize_character_capsules(
+    mut characters: Query<(
+        Entity,
+        &mut CharacterCapsule,
+        &mut Collider,
+        &mut Position,
+        &Rotation,
+    )>,
+    spatial_query: Res<SpatialQueryPipeline>,
+    dt: Res<DeltaTime>,
+) {
+    for (entity, mut capsule, mut collider, mut position, rotation) in &mut characters {
+        let target_height = capsule.target_height.max(2.0 * capsule.radius);
+        let max_change = capsule.resize_speed * dt.0;
+        let mut change = (target_height - capsule.height).clamp(-max_change, max_change);
+        let mut blocked = false;
+        let up = rotation.rotate(Vector::Y);
+
+        if change > 0.0 {
+            // Cast the top of the capsule upwards to find out how much headroom there is
+            let skin = capsule.radius * HEADROOM_SKIN;
+            let head_center = position.0 + up * (0.5 * capsule.height - capsule.radius);
+            if let Some(hit) = spatial_query.cast_shape(
+                &Collider::ball(capsule.radius - skin),
+                head_center,
+                Default::default(),
+                up,
+                change + skin,
+                true,
+                SpatialQueryFilter::new().without_entities([entity]),
+            ) {
+                let free_distance = (hit.time_of_impact - skin).max(0.0);
+                if free_distance < change {
+                    change = free_distance;
+                    blocked = true;
+                }
+            }
+        }
+
+        if capsule.blocked != blocked {
+            capsule.blocked = blocked;
+        }
+
+        if change != 0.0 {
+            capsule.height += change;
+            *collider = capsule.collider();
+            // Keep the bottom of the capsule in place
+            position.0 += up * change * 0.5;
+        }
+    }
+}
+
+/// Finds the walls next to a character and the ledges at the top of them. Requires the [`CharacterPlugin`].
+///
+/// After each physics step, the [`Collider`] of the character is cast horizontally in several directions
+/// around its local `Y` axis. Surfaces that are hit within the [maximum distance](WallDetector::max_distance)
+/// and whose normals are at most [`WallDetector::max_wall_tilt`] radians away from the horizontal plane
+/// are reported as [walls](WallDetector::walls), which can be used for wall sliding and wall jumping.
+///
+/// If [ledge detection](WallDetector::with_ledges) is enabled, a ray is cast down onto the top of each wall,
+/// starting at the [ledge reach](WallDetector::ledge_reach) above the position of the character. If the ray hits
+/// a walkable surface, the top of the wall is reported as a [ledge](WallDetector::ledge) that can be grabbed.
+/// Walls that are taller than the reach don't have ledges.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::capsule(1.0, 0.4),
+///         LockedAxes::ROTATION_LOCKED,
+///         WallDetector::new(0.1).with_ledges(1.5, 0.2),
+///     ));
+/// }
+///
+/// fn grab_ledges(mut characters: Query<(&WallDetector, &mut LinearVelocity)>) {
+///     for (detector, mut velocity) in &mut characters {
+///         if let Some(ledge) = detector.ledge() {
+///             println!("Grabbed ledge at {}", ledge.point);
+///             velocity.0 = Vector::ZERO;
+///         } else if let Some(wall) = detector.closest_wall() {
+///             // Slide down the wall slowly
+///             println!("Sliding down wall {:?}", wall.entity);
+///             velocity.y = velocity.y.max(-2.0);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct WallDetector {
+    /// The maximum distance between the collider of the character and a wall.
+    pub max_distance: Scalar,
+    /// The maximum angle in radians between the normal of a wall and the horizontal plane.
+    pub max_wall_tilt: Scalar,
+    /// The maximum height of a ledge above the position of the character. Ledges aren't detected if this is zero.
+    pub ledge_reach: Scalar,
+    /// How deep the top of a wall needs to be for it to count as a ledge.
+    pub ledge_depth: Scalar,
+    walls: Vec<WallContact>,
+    ledge: Option<Ledge>,
+}
+
+impl WallDetector {
+    /// Creates a new [`WallDetector`] that finds walls within the given distance of the collider of the character.
+    /// Ledge detection is disabled.
+    pub fn new(max_distance: Scalar) -> Self {
+        Self {
+            max_distance,
+            max_wall_tilt: PI / 6.0,
+            ledge_reach: 0.0,
+            ledge_depth: 0.0,
+            walls: vec![],
+            ledge: None,
+        }
+    }
+
+    /// Sets the maximum angle in radians between the normal of a wall and the horizontal plane.
+    pub fn with_max_wall_tilt(self, max_wall_tilt: Scalar) -> Self {
+        Self {
+            max_wall_tilt,
+            ..self
+        }
+    }
+
+    /// Enables ledge detection for ledges up to `reach` above the position of the character
+    /// whose top is at least `depth` deep.
+    pub fn with_ledges(self, reach: Scalar, depth: Scalar) -> Self {
+        Self {
+            ledge_reach: reach,
+            ledge_depth: depth,
+            ..self
+        }
+    }
+
+    /// Returns the walls found during the latest physics step, sorted by distance.
+    /// Each collider is only reported once.
+    pub fn walls(&self) -> &[WallContact] {
+        &self.walls
+    }
+
+    /// Returns the closest wall found during the latest physics step.
+    pub fn closest_wall(&self) -> Option<&WallContact> {
+        self.walls.first()
+    }
+
+    /// Returns true if a wall was found during the latest physics step.
+    pub fn is_touching_wall(&self) -> bool {
+        !self.walls.is_empty()
+    }
+
+    /// Returns the closest ledge found during the latest physics step.
+    pub fn ledge(&self) -> Option<&Ledge> {
+        self.ledge.as_ref()
+    }
+}
+
+impl Default for WallDetector {
+    /// Creates a detector that finds walls within `0.1` units without ledge detection.
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+/// A wall found by a [`WallDetector`].
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct WallContact {
+    /// The entity of the wall collider.
+    pub entity: Entity,
+    /// The closest point on the wall in world space.
+    pub point: Vector,
+    /// The outward normal of the wall in world space, pointing towards the character.
+    pub normal: Vector,
+    /// The distance between the collider of the character and the wall.
+    pub distance: Scalar,
+}
+
+/// A ledge at the top of a wall found by a [`WallDetector`].
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct Ledge {
+    /// The entity of the collider that the ledge belongs to.
+    pub entity: Entity,
+    /// The point on top of the ledge in world space, [`WallDetector::ledge_depth`] away from the edge.
+    pub point: Vector,
+    /// The normal of the top surface of the ledge in world space.
+    pub normal: Vector,
+    /// The normal of the wall below the ledge in world space.
+    pub wall_normal: Vector,
+    /// The height of the ledge above the position of the character.
+    pub height: Scalar,
+}
+
+/// The number of directions that walls are searched in.
+#[cfg(feature = "2d")]
+const WALL_DIRECTIONS: usize = 2;
+/// The number of directions that walls are searched in.
+#[cfg(feature = "3d")]
+const WALL_DIRECTIONS: usize = 8;
+/// The maximum number of colliders hit in each direction when searching for walls.
+const MAX_HITS_PER_DIRECTION: u32 = 4;
+
+fn detect_walls(
+    mut characters: Query<(Entity, &mut WallDetector, &Collider, &Position, &Rotation)>,
+    spatial_query: Res<SpatialQueryPipeline>,
+) {
+    for (entity, mut detector, collider, position, rotation) in &mut characters {
+        let up = rotation.rotate(Vector::Y);
+        let right = rotation.rotate(Vector::X);
+        let filter = SpatialQueryFilter::new().without_entities([entity]);
+        let min_wall_dot = detector.max_wall_tilt.cos();
+
+        let mut walls: Vec<WallContact> = vec![];
+        for i in 0..WALL_DIRECTIONS {
+            let angle = i as Scalar * 2.0 * PI / WALL_DIRECTIONS as Scalar;
+            #[cfg(feature = "2d")]
+            let direction = Rotation::from_radians(angle).rotate(right);
+            #[cfg(feature = "3d")]
+            let direction = Quaternion::from_axis_angle(up, angle) * right;
+
+            // The character can be touching the ground, so the closest hit isn't necessarily a wall
+            let hits = spatial_query.shape_hits(
+                collider,
+                position.0,
+                (*rotation).into(),
+                direction,
+                detector.max_distance,
+                MAX_HITS_PER_DIRECTION,
+                false,
+                filter.clone(),
+            );
+
+            // The pipeline shape is in world space, so the witness points and normals of the hit colliders are too
+            let Some(wall) = hits
+                .iter()
+                .map(|hit| WallContact {
+                    entity: hit.entity,
+                    point: hit.point1,
+                    normal: hit.normal1.normalize_or_zero(),
+                    distance: hit.time_of_impact,
+                })
+                .find(|wall| wall.normal.reject_from(up).length() >= min_wall_dot)
+            else {
+                continue;
+            };
+
+            if let Some(existing) = walls
+                .iter_mut()
+                .find(|existing| existing.entity == wall.entity)
+            {
+                if wall.distance < existing.distance {
+                    *existing = wall;
+                }
+            } else {
+                walls.push(wall);
+            }
+        }
+        walls.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        let mut ledge = None;
+        if detector.ledge_reach > 0.0 {
+            ledge = walls.iter().find_map(|wall| {
+                // Cast a ray down onto the top of the wall from the height of the reach
+                let inward = -wall.normal.reject_from(up).normalize_or_zero();
+                let wall_height = (wall.point - position.0).dot(up);
+                let origin = wall.point
+                    + inward * detector.ledge_depth
+                    + up * (detector.ledge_reach - wall_height);
+                let hit = spatial_query.cast_ray(
+                    origin,
+                    -up,
+                    detector.ledge_reach,
+                    true,
+                    filter.clone(),
+                )?;
+
+                // If the ray starts inside the wall, the wall is taller than the reach
+                if hit.time_of_impact <= 0.0 || hit.normal.dot(up) <= detector.max_wall_tilt.sin() {
+                    return None;
+                }
+
+                Some(Ledge {
+                    entity: hit.entity,
+                    point: origin - up * hit.time_of_impact,
+                    normal: hit.normal,
+                    wall_normal: wall.normal,
+                    height: detector.ledge_reach - hit.time_of_impact,
+                })
+            });
+        }
+
+        if detector.walls != walls || detector.ledge != ledge {
+            detector.walls = walls;
+            detector.ledge = ledge;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, floor_collider},
+    };
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn character_capsules_only_grow_when_there_is_headroom() {
+        let mut app = create_app();
+        app.add_plugins(CharacterPlugin);
+
+        // A ceiling whose bottom is at a height of 1.5
+        let ceiling = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                floor_collider(4.0),
+                Position(Vector::Y * 2.0),
+            ))
+            .id();
+
+        // A crouching character whose feet are at the origin
+        let mut capsule = CharacterCapsule::new(0.5, 1.0);
+        capsule.target_height = 2.0;
+        let character = app
+            .world
+            .spawn((
+                RigidBody::Kinematic,
+                capsule.collider(),
+                capsule,
+                Position(Vector::Y * 0.5),
+            ))
+            .id();
+
+        app.step_physics(60);
+
+        // The character only stands up until it touches the ceiling
+        let capsule = *app.world.get::<CharacterCapsule>(character).unwrap();
+        let position = app.world.get::<Position>(character).unwrap().0;
+        assert!(!capsule.has_headroom());
+        assert!(capsule.height() > 1.4 && capsule.height() <= 1.5);
+        assert_relative_eq!(position.y - 0.5 * capsule.height(), 0.0, epsilon = 0.001);
+
+        app.world.despawn(ceiling);
+
+        app.step_physics(60);
+
+        // Without the ceiling, the character stands up fully with its feet in place
+        let capsule = *app.world.get::<CharacterCapsule>(character).unwrap();
+        let position = app.world.get::<Position>(character).unwrap().0;
+        assert!(capsule.has_headroom());
+        assert!(capsule.is_at_target_height());
+        assert_relative_eq!(position.y, 1.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn wall_detectors_find_walls_and_ledges() {
+        let mut app = create_app();
+        app.add_plugins(CharacterPlugin);
+
+        #[cfg(feature = "2d")]
+        let (low_wall_collider, high_wall_collider) =
+            (Collider::cuboid(1.0, 3.0), Collider::cuboid(1.0, 5.0));
+        #[cfg(feature = "3d")]
+        let (low_wall_collider, high_wall_collider) = (
+            Collider::cuboid(1.0, 3.0, 4.0),
+            Collider::cuboid(1.0, 5.0, 4.0),
+        );
+
+        // A wall whose top is at a height of 1.0 right next to the first character
+        let low_wall = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                low_wall_collider,
+                Position(Vector::X * 1.05 - Vector::Y * 0.5),
+            ))
+            .id();
+        let low_wall_character = app
+            .world
+            .spawn((
+                RigidBody::Kinematic,
+                Collider::capsule(1.0, 0.5),
+                Position(Vector::ZERO),
+                WallDetector::new(0.1).with_ledges(1.5, 0.2),
+            ))
+            .id();
+
+        // A wall whose top is at a height of 2.0, which is out of reach for the second character
+        let high_wall = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                high_wall_collider,
+                Position(Vector::X * 11.05 - Vector::Y * 0.5),
+            ))
+            .id();
+        let high_wall_character = app
+            .world
+            .spawn((
+                RigidBody::Kinematic,
+                Collider::capsule(1.0, 0.5),
+                Position(Vector::X * 10.0),
+                WallDetector::new(0.1).with_ledges(1.5, 0.2),
+            ))
+            .id();
+
+        app.step_physics(2);
+
+        let detector = app.world.get::<WallDetector>(low_wall_character).unwrap();
+        let wall = detector.closest_wall().expect("no wall found");
+        assert_eq!(wall.entity, low_wall);
+        assert_relative_eq!(wall.normal.x, -1.0, epsilon = 0.001);
+        assert_relative_eq!(wall.distance, 0.05, epsilon = 0.001);
+        let ledge = detector.ledge().expect("no ledge found");
+        assert_eq!(ledge.entity, low_wall);
+        assert_relative_eq!(ledge.height, 1.0, epsilon = 0.001);
+        assert_relative_eq!(ledge.normal.y, 1.0, epsilon = 0.001);
+
+        let detector = app.world.get::<WallDetector>(high_wall_character).unwrap();
+        assert_eq!(detector.walls().len(), 1);
+        assert_eq!(detector.walls()[0].entity, high_wall);
+        assert!(detector.ledge().is_none());
+    }
+}
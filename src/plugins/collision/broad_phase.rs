@@ -5,19 +5,28 @@
 
 use crate::prelude::*;
 use bevy::prelude::*;
+use parry::{bounding_volume::BoundingVolume, partitioning::Qbvh};
 
 /// Collects pairs of potentially colliding entities into [`BroadCollisionPairs`] using
 /// [AABB](ColliderAabb) intersection checks. This speeds up narrow phase collision detection,
 /// as the number of precise collision checks required is greatly reduced.
 ///
 /// Currently, the broad phase uses the [sweep and prune](https://en.wikipedia.org/wiki/Sweep_and_prune) algorithm.
+/// Colliders attached to [static](RigidBody::Static) bodies are stored separately in a bounding volume hierarchy
+/// that is only rebuilt when static colliders change, and the other colliders are tested against it.
+/// This way, large levels made of static colliders don't need to be sorted every step.
+///
+/// Pairs of entities that ignore each other using [`IgnoreCollisions`] are removed right after they are collected.
 ///
 /// The broad phase systems run in [`PhysicsStepSet::BroadPhase`].
 pub struct BroadPhasePlugin;
 
 impl Plugin for BroadPhasePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AabbIntervals>();
+        app.init_resource::<AabbIntervals>()
+            .init_resource::<StaticColliderTree>()
+            .init_resource::<BroadPhaseConfig>()
+            .register_type::<BroadPhaseConfig>();
 
         let physics_schedule = app
             .get_schedule_mut(PhysicsSchedule)
@@ -29,6 +38,7 @@ impl Plugin for BroadPhasePlugin {
                 update_aabb_intervals,
                 add_new_aabb_intervals,
                 collect_collision_pairs,
+                filter_ignored_collisions,
             )
                 .chain()
                 .in_set(PhysicsStepSet::BroadPhase),
@@ -36,11 +46,94 @@ impl Plugin for BroadPhasePlugin {
     }
 }
 
+/// A resource for configuring the [broad phase](BroadPhasePlugin).
+///
+/// The [`ColliderAabb`]s used by the broad phase are enlarged by a safety margin, so that pairs are found
+/// before the colliders actually touch. Larger margins find more pairs ahead of time, but also create
+/// more pairs that the narrow phase needs to check.
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct BroadPhaseConfig {
+    /// A constant padding added to each side of the [`ColliderAabb`]s.
+    pub aabb_padding: Scalar,
+    /// How far ahead in time the motion of colliders is predicted when computing their [`ColliderAabb`]s,
+    /// as a multiple of the [`DeltaTime`]. The AABBs cover the space that the colliders sweep through
+    /// during this time, which accounts for fast and accelerating bodies.
+    pub prediction_factor: Scalar,
+}
+
+impl Default for BroadPhaseConfig {
+    fn default() -> Self {
+        Self {
+            aabb_padding: 0.0,
+            prediction_factor: 2.0,
+        }
+    }
+}
+
 /// A list of entity pairs for potential collisions collected during the broad phase.
+///
+/// ## Filtering pairs
+///
+/// The pairs are collected in [`PhysicsStepSet::BroadPhase`] and used by the narrow phase to compute contacts.
+/// Systems added to [`PhysicsStepSet::FilterPairs`] can remove pairs using [`retain`](#method.retain)
+/// before any contacts are computed for them, which is much cheaper than removing the collisions
+/// afterwards in [`PostProcessCollisions`].
+///
+/// Below is an example of projectiles ignoring their shooter for a short amount of time after being fired.
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::{prelude::*, PhysicsSchedule, PhysicsStepSet};
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::{prelude::*, PhysicsSchedule, PhysicsStepSet};
+///
+/// #[derive(Component)]
+/// struct Projectile {
+///     shooter: Entity,
+///     age: f32,
+/// }
+///
+/// fn main() {
+///     let mut app = App::new();
+///
+///     app.add_plugins((DefaultPlugins, PhysicsPlugins::default()));
+///
+///     app.get_schedule_mut(PhysicsSchedule)
+///         .expect("add PhysicsSchedule first")
+///         .add_systems(ignore_shooter.in_set(PhysicsStepSet::FilterPairs));
+///
+///     // Run the app...
+/// }
+///
+/// fn ignore_shooter(mut pairs: ResMut<BroadCollisionPairs>, projectiles: Query<&Projectile>) {
+///     let ignores_other = |entity: Entity, other: Entity| {
+///         projectiles
+///             .get(entity)
+///             .map_or(false, |p| p.shooter == other && p.age < 0.2)
+///     };
+///     pairs.retain(|entity1, entity2| {
+///         !ignores_other(entity1, entity2) && !ignores_other(entity2, entity1)
+///     });
+/// }
+/// ```
 #[derive(Reflect, Resource, Default, Debug)]
 #[reflect(Resource)]
 pub struct BroadCollisionPairs(pub Vec<(Entity, Entity)>);
 
+impl BroadCollisionPairs {
+    /// Retains only the entity pairs for which the specified predicate returns `true`.
+    /// Pairs for which the predicate returns `false` are removed, and no contacts will be
+    /// computed for them during the current physics step.
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(Entity, Entity) -> bool,
+    {
+        self.0.retain(|(entity1, entity2)| keep(*entity1, *entity2));
+    }
+}
+
 type AABBChanged = Or<(
     Changed<Position>,
     Changed<Rotation>,
@@ -49,7 +142,8 @@ type AABBChanged = Or<(
     Changed<Collider>,
 )>;
 
-/// Updates the Axis-Aligned Bounding Boxes of all colliders. A safety margin will be added to account for sudden accelerations.
+/// Updates the Axis-Aligned Bounding Boxes of all colliders. A safety margin configured by [`BroadPhaseConfig`]
+/// will be added to account for sudden accelerations.
 #[allow(clippy::type_complexity)]
 fn update_aabb(
     mut colliders: Query<
@@ -69,9 +163,10 @@ fn update_aabb(
         With<Children>,
     >,
     dt: Res<DeltaTime>,
+    config: Res<BroadPhaseConfig>,
 ) {
     // Safety margin multiplier bigger than DELTA_TIME to account for sudden accelerations
-    let safety_margin_factor = 2.0 * dt.0;
+    let safety_margin_factor = config.prediction_factor * dt.0;
 
     for (collider, mut aabb, pos, rot, collider_parent, lin_vel, ang_vel) in &mut colliders {
         let (lin_vel, ang_vel) = if let (Some(lin_vel), Some(ang_vel)) = (lin_vel, ang_vel) {
@@ -85,12 +180,7 @@ fn update_aabb(
             // TODO: This assumes that the colliders would continue moving in the same direction,
             //       but because they are orbiting, the direction will change. We should take
             //       into account the uniform circular motion.
-            let offset = pos.0 - parent_pos.0;
-            #[cfg(feature = "2d")]
-            let vel_at_offset =
-                lin_vel.0 + Vector::new(-ang_vel.0 * offset.y, ang_vel.0 * offset.x) * 1.0;
-            #[cfg(feature = "3d")]
-            let vel_at_offset = lin_vel.0 + ang_vel.cross(offset);
+            let vel_at_offset = lin_vel.at_point(*ang_vel, pos.0, parent_pos.0);
             (LinearVelocity(vel_at_offset), *ang_vel)
         } else {
             (LinearVelocity::ZERO, AngularVelocity::ZERO)
@@ -125,7 +215,8 @@ fn update_aabb(
         // Compute swept AABB, the space that the body would occupy if it was integrated for one frame
         aabb.0 = collider
             .shape_scaled()
-            .compute_swept_aabb(&start_iso, &end_iso);
+            .compute_swept_aabb(&start_iso, &end_iso)
+            .loosened(config.aabb_padding);
     }
 }
 
@@ -133,64 +224,195 @@ fn update_aabb(
 type IsBodyInactive = bool;
 
 /// Entities with [`ColliderAabb`]s sorted along an axis by their extents.
+///
+/// Only contains colliders that aren't attached to [static](RigidBody::Static) bodies.
+/// Static colliders are stored in [`StaticColliderTree`] instead.
 #[derive(Resource, Default)]
 struct AabbIntervals(Vec<(Entity, ColliderAabb, CollisionLayers, IsBodyInactive)>);
 
-/// Updates [`AabbIntervals`] to keep them in sync with the [`ColliderAabb`]s.
+/// True if the static collider was added or moved during the current physics step.
+type IsStaticMoved = bool;
+
+/// The colliders of [static](RigidBody::Static) bodies, stored in a bounding volume hierarchy.
+///
+/// Static colliders rarely change, so instead of sorting them along with the other colliders every step,
+/// the hierarchy is only rebuilt when static colliders are added, removed or moved. The AABBs of the other colliders
+/// are then tested against the hierarchy, which avoids most of the work for large levels with few moving bodies.
+#[derive(Resource, Default)]
+struct StaticColliderTree {
+    colliders: Vec<(Entity, ColliderAabb, CollisionLayers, IsStaticMoved)>,
+    qbvh: Qbvh<u32>,
+    needs_rebuild: bool,
+}
+
+/// Returns true if the collider is attached to a [static](RigidBody::Static) body.
+fn is_static_collider(
+    entity: Entity,
+    collider_parent: Option<&ColliderParent>,
+    bodies: &Query<&RigidBody>,
+) -> bool {
+    let body = collider_parent.map_or(entity, |parent| parent.get());
+    bodies.get(body).map_or(false, |rb| rb.is_static())
+}
+
+/// Updates [`AabbIntervals`] and [`StaticColliderTree`] to keep them in sync with the [`ColliderAabb`]s.
+/// [Query-only](QueryOnly) colliders are removed, as they don't take part in the simulation.
 fn update_aabb_intervals(
-    aabbs: Query<(&ColliderAabb, Ref<Position>, Ref<Rotation>)>,
+    aabbs: Query<
+        (
+            &ColliderAabb,
+            Option<&ColliderParent>,
+            Ref<Position>,
+            Ref<Rotation>,
+        ),
+        Without<QueryOnly>,
+    >,
+    bodies: Query<&RigidBody>,
     mut intervals: ResMut<AabbIntervals>,
+    mut static_tree: ResMut<StaticColliderTree>,
 ) {
-    intervals.0.retain_mut(|(entity, aabb, _, is_inactive)| {
-        if let Ok((new_aabb, position, rotation)) = aabbs.get(*entity) {
+    let mut new_static_colliders = vec![];
+
+    intervals
+        .0
+        .retain_mut(|(entity, aabb, layers, is_inactive)| {
+            let Ok((new_aabb, collider_parent, position, rotation)) = aabbs.get(*entity) else {
+                return false;
+            };
+
+            if is_static_collider(*entity, collider_parent, &bodies) {
+                new_static_colliders.push((*entity, *new_aabb, *layers, true));
+                return false;
+            }
+
             *aabb = *new_aabb;
             *is_inactive = !position.is_changed() && !rotation.is_changed();
             true
-        } else {
-            false
-        }
-    });
+        });
+
+    let static_tree = &mut *static_tree;
+    let mut new_dynamic_colliders = vec![];
+
+    static_tree
+        .colliders
+        .retain_mut(|(entity, aabb, layers, is_moved)| {
+            let Ok((new_aabb, collider_parent, ..)) = aabbs.get(*entity) else {
+                static_tree.needs_rebuild = true;
+                return false;
+            };
+
+            if !is_static_collider(*entity, collider_parent, &bodies) {
+                new_dynamic_colliders.push((*entity, *new_aabb, *layers, false));
+                static_tree.needs_rebuild = true;
+                return false;
+            }
+
+            *is_moved = *aabb != *new_aabb;
+            if *is_moved {
+                *aabb = *new_aabb;
+                static_tree.needs_rebuild = true;
+            }
+            true
+        });
+
+    if !new_static_colliders.is_empty() {
+        static_tree.colliders.extend(new_static_colliders);
+        static_tree.needs_rebuild = true;
+    }
+
+    // Insertion sort will move these into place
+    intervals.0.extend(new_dynamic_colliders);
 }
 
-/// Adds new [`ColliderAabb`]s to [`AabbIntervals`].
+/// Adds new [`ColliderAabb`]s to [`AabbIntervals`] or [`StaticColliderTree`].
 #[allow(clippy::type_complexity)]
 fn add_new_aabb_intervals(
     aabbs: Query<
         (
             Entity,
             &ColliderAabb,
-            Option<&RigidBody>,
+            Option<&ColliderParent>,
             Option<&CollisionLayers>,
         ),
-        Added<ColliderAabb>,
+        (Added<ColliderAabb>, Without<QueryOnly>),
     >,
+    // Colliders that stop being query-only need to be added back
+    colliders: Query<
+        (
+            Entity,
+            &ColliderAabb,
+            Option<&ColliderParent>,
+            Option<&CollisionLayers>,
+        ),
+        Without<QueryOnly>,
+    >,
+    mut removed_query_only: RemovedComponents<QueryOnly>,
+    bodies: Query<&RigidBody>,
     mut intervals: ResMut<AabbIntervals>,
+    mut static_tree: ResMut<StaticColliderTree>,
 ) {
-    let aabbs = aabbs.iter().map(|(ent, aabb, rb, layers)| {
-        (
-            ent,
-            *aabb,
-            // Default to treating collider as immovable/static for filtering unnecessary collision checks
-            layers.map_or(CollisionLayers::default(), |layers| *layers),
-            rb.map_or(false, |rb| rb.is_static()),
-        )
-    });
-    intervals.0.extend(aabbs);
+    let mut new_intervals = vec![];
+    let no_longer_query_only = removed_query_only
+        .iter()
+        .filter_map(|entity| colliders.get(entity).ok());
+
+    for (entity, aabb, collider_parent, layers) in aabbs.iter().chain(no_longer_query_only) {
+        let layers = layers.map_or(CollisionLayers::default(), |layers| *layers);
+
+        if is_static_collider(entity, collider_parent, &bodies) {
+            static_tree.colliders.push((entity, *aabb, layers, true));
+            static_tree.needs_rebuild = true;
+        } else {
+            new_intervals.push((entity, *aabb, layers, false));
+        }
+    }
+
+    if new_intervals.is_empty() {
+        return;
+    }
+
+    // Merge the new intervals into the existing ones in a single pass instead of appending them
+    // and letting insertion sort move them into place one by one. This keeps adding large batches
+    // of colliders, like chunks of a streamed level, roughly linear in the number of intervals.
+    new_intervals.sort_unstable_by(|a, b| a.1.mins.x.total_cmp(&b.1.mins.x));
+
+    let old_intervals = std::mem::take(&mut intervals.0);
+    let mut merged = Vec::with_capacity(old_intervals.len() + new_intervals.len());
+    let mut old_intervals = old_intervals.into_iter().peekable();
+    let mut new_intervals = new_intervals.into_iter().peekable();
+
+    loop {
+        let take_new = match (old_intervals.peek(), new_intervals.peek()) {
+            (Some(old), Some(new)) => new.1.mins.x < old.1.mins.x,
+            (None, Some(_)) => true,
+            (Some(_), None) => false,
+            (None, None) => break,
+        };
+        if take_new {
+            merged.extend(new_intervals.next());
+        } else {
+            merged.extend(old_intervals.next());
+        }
+    }
+
+    intervals.0 = merged;
 }
 
 /// Collects bodies that are potentially colliding.
 fn collect_collision_pairs(
-    intervals: ResMut<AabbIntervals>,
+    mut intervals: ResMut<AabbIntervals>,
+    mut static_tree: ResMut<StaticColliderTree>,
     mut broad_collision_pairs: ResMut<BroadCollisionPairs>,
 ) {
-    sweep_and_prune(intervals, &mut broad_collision_pairs.0);
+    sweep_and_prune(&mut intervals, &mut broad_collision_pairs.0);
+    collect_static_pairs(&intervals, &mut static_tree, &mut broad_collision_pairs.0);
 }
 
 /// Sorts the entities by their minimum extents along an axis and collects the entity pairs that have intersecting AABBs.
 ///
 /// Sweep and prune exploits temporal coherence, as bodies are unlikely to move significantly between two simulation steps. Insertion sort is used, as it is good at sorting nearly sorted lists efficiently.
 fn sweep_and_prune(
-    mut intervals: ResMut<AabbIntervals>,
+    intervals: &mut AabbIntervals,
     broad_collision_pairs: &mut Vec<(Entity, Entity)>,
 ) {
     // Sort bodies along the x-axis using insertion sort, a sorting algorithm great for sorting nearly sorted lists.
@@ -228,6 +450,76 @@ fn sweep_and_prune(
     }
 }
 
+/// Tests the [`AabbIntervals`] against the [`StaticColliderTree`] and collects the entity pairs that have
+/// intersecting AABBs. The tree is rebuilt first if static colliders have changed.
+fn collect_static_pairs(
+    intervals: &AabbIntervals,
+    static_tree: &mut StaticColliderTree,
+    broad_collision_pairs: &mut Vec<(Entity, Entity)>,
+) {
+    if static_tree.needs_rebuild {
+        let aabbs = static_tree
+            .colliders
+            .iter()
+            .enumerate()
+            .map(|(i, (_, aabb, ..))| (i as u32, aabb.0));
+        static_tree.qbvh.clear_and_rebuild(aabbs, 0.0);
+        static_tree.needs_rebuild = false;
+    }
+
+    if static_tree.colliders.is_empty() {
+        return;
+    }
+
+    let mut hits = vec![];
+
+    for (ent1, aabb1, layers1, inactive1) in intervals.0.iter() {
+        hits.clear();
+        static_tree.qbvh.intersect_aabb(aabb1, &mut hits);
+
+        for &index in hits.iter() {
+            let (ent2, aabb2, layers2, moved2) = &static_tree.colliders[index as usize];
+
+            // No collisions between bodies that haven't moved or colliders with incompatible layers
+            if (*inactive1 && !*moved2) || !layers1.interacts_with(*layers2) {
+                continue;
+            }
+
+            // Keep the same order as sweep and prune, where the entity with the smaller extent comes first
+            if aabb2.mins.x < aabb1.mins.x {
+                broad_collision_pairs.push((*ent2, *ent1));
+            } else {
+                broad_collision_pairs.push((*ent1, *ent2));
+            }
+        }
+    }
+}
+
+/// Removes the pairs of entities that ignore each other using [`IgnoreCollisions`] from [`BroadCollisionPairs`].
+fn filter_ignored_collisions(
+    mut pairs: ResMut<BroadCollisionPairs>,
+    colliders: Query<&ColliderParent>,
+    ignored: Query<&IgnoreCollisions>,
+) {
+    if ignored.is_empty() {
+        return;
+    }
+
+    let body = |entity: Entity| colliders.get(entity).map_or(entity, |parent| parent.get());
+
+    // Returns true if the collider or its body ignores the other collider or its body
+    let ignores = |entity: Entity, other: Entity| {
+        let other_body = body(other);
+        [entity, body(entity)].into_iter().any(|entity| {
+            ignored.get(entity).map_or(false, |ignored| {
+                ignored.contains(&other) || ignored.contains(&other_body)
+            })
+        })
+    };
+
+    pairs.retain(|entity1, entity2| !ignores(entity1, entity2) && !ignores(entity2, entity1));
+}
+
 /// Sorts a list iteratively using comparisons. In an ascending sort order, when a smaller value is encountered, it is moved lower in the list until it is larger than the item before it.
 ///
 /// This is relatively slow for large lists, but very efficient in cases where the list is already mostly sorted.
@@ -240,3 +532,214 @@ fn insertion_sort<T>(items: &mut Vec<T>, comparison: fn(&T, &T) -> bool) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider, floor_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn filtered_pairs_do_not_collide() {
+        let mut app = create_app();
+
+        app.insert_resource(Gravity::ZERO);
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                (|mut pairs: ResMut<BroadCollisionPairs>| pairs.retain(|_, _| false))
+                    .in_set(PhysicsStepSet::FilterPairs),
+            );
+
+        app.add_systems(Startup, |mut commands: Commands| {
+            // Two overlapping balls that would normally push each other apart
+            commands.spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::ZERO),
+                Collider::ball(0.5),
+            ));
+            commands.spawn((
+                SpatialBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 0.5),
+                Collider::ball(0.5),
+            ));
+        });
+
+        app.step_physics(10);
+
+        assert_eq!(app.world.resource::<Collisions>().iter().count(), 0);
+
+        // The bodies should not have been pushed apart
+        let mut query = app.world.query::<&LinearVelocity>();
+        for lin_vel in query.iter(&app.world) {
+            assert_eq!(*lin_vel, LinearVelocity::ZERO);
+        }
+    }
+
+    #[test]
+    fn bodies_collide_with_many_static_colliders() {
+        let mut app = create_app();
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        // A floor made of many static tiles
+        for i in -50..50 {
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * i as Scalar),
+                cube_collider(1.0),
+            ));
+        }
+
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 2.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(120);
+
+        // The ball rests on the floor
+        let position = app.world.get::<Position>(ball).unwrap().0;
+        assert_relative_eq!(position.y, 1.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn query_only_colliders_are_ignored_by_the_simulation() {
+        let mut app = create_app();
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        let volume = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(10.0),
+                QueryOnly,
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 2.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(60);
+
+        // The ball falls through the volume without generating contacts
+        assert!(app.world.get::<Position>(ball).unwrap().0.y < -1.0);
+        assert!(app
+            .world
+            .resource::<Collisions>()
+            .get(ball, volume)
+            .is_none());
+        assert!(app
+            .world
+            .get::<CollidingEntities>(ball)
+            .unwrap()
+            .0
+            .is_empty());
+
+        // Spatial queries still hit the volume
+        let spatial_query = app.world.resource::<SpatialQueryPipeline>();
+        let hit = spatial_query
+            .cast_ray(
+                Vector::Y * 5.0,
+                Vector::NEG_Y,
+                10.0,
+                true,
+                SpatialQueryFilter::default().without_entities([ball]),
+            )
+            .expect("ray should hit the query-only collider");
+        assert_eq!(hit.entity, volume);
+    }
+
+    #[test]
+    fn ignored_entities_dont_collide() {
+        let mut app = create_app();
+
+        let vehicle = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Static))
+            .id();
+        let vehicle_collider = app
+            .world
+            .spawn((TransformBundle::default(), Collider::ball(1.0)))
+            .set_parent(vehicle)
+            .id();
+        let player = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 0.5),
+                Collider::ball(0.5),
+                IgnoreCollisions::new([vehicle]),
+            ))
+            .id();
+        let other = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * -0.5),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        // Ignoring the vehicle body also ignores its child collider
+        let collisions = app.world.resource::<Collisions>();
+        assert!(collisions.get(player, vehicle_collider).is_none());
+        assert!(collisions.get(other, vehicle_collider).is_some());
+    }
+
+    #[test]
+    fn aabb_padding_finds_pairs_ahead_of_contact() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(BroadPhaseConfig {
+            aabb_padding: 0.2,
+            ..default()
+        });
+
+        app.add_systems(Startup, |mut commands: Commands| {
+            for x in [-0.6, 0.6] {
+                commands.spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::X * x),
+                    Collider::ball(0.5),
+                ));
+            }
+        });
+
+        app.step_physics(1);
+
+        // The colliders are 0.2 units apart, so the padded AABBs overlap but there are no contacts
+        let stats = *app.world.resource::<PhysicsStats>();
+        assert_eq!(stats.broad_phase_pairs, 1);
+        assert_eq!(stats.contact_pairs, 0);
+
+        let mut aabbs = app.world.query::<&ColliderAabb>();
+        for aabb in aabbs.iter(&app.world) {
+            assert_relative_eq!(aabb.size().y, 1.4, epsilon = 0.001);
+        }
+    }
+}
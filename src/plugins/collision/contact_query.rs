@@ -15,7 +15,10 @@
 //! and point projection, see [spatial queries](spatial_query).
 
 use crate::prelude::*;
-use parry::query::{PersistentQueryDispatcher, Unsupported};
+use parry::{
+    query::{PersistentQueryDispatcher, Unsupported},
+    shape::SharedShape,
+};
 
 /// An error indicating that a [contact query](contact_query) is not supported for one of the [`Collider`] shapes.
 pub type UnsupportedShape = Unsupported;
@@ -121,6 +124,17 @@ pub fn contact(
 /// Returns an empty vector if the colliders are separated by a distance greater than `prediction_distance`
 /// or if the given shapes are invalid.
 ///
+/// ## Internal edges
+///
+/// Shapes sliding or rolling across a flat surface made of several triangles (or segments in 2D) can hit
+/// the edges between the triangles, which produces contact normals that point along the surface and makes
+/// the shapes catch on invisible seams. These are often called *ghost collisions*.
+///
+/// To prevent this, contacts against [triangle meshes](Collider::trimesh) (and [polylines](Collider::polyline) in 2D)
+/// that lie on an internal edge or vertex use the normal of the triangle instead. An edge is internal
+/// if the adjacent triangles are nearly coplanar or form a concave corner. Contacts on convex edges that are
+/// sharper than [`INTERNAL_EDGE_ANGLE`] and on the boundary of the mesh are left untouched.
+///
 /// ## Example
 ///
 /// ```
@@ -161,58 +175,280 @@ pub fn contact_manifolds(
     rotation2: impl Into<Rotation>,
     prediction_distance: Scalar,
 ) -> Vec<ContactManifold> {
+    let mut manifolds = vec![];
+    contact_manifolds_into(
+        collider1,
+        position1,
+        rotation1,
+        collider2,
+        position2,
+        rotation2,
+        prediction_distance,
+        &mut manifolds,
+    );
+    manifolds
+}
+
+/// Computes the contact manifolds like [`contact_manifolds`], but writes them into `manifolds`.
+/// The allocations of the manifolds that are already in the vector are reused.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn contact_manifolds_into(
+    collider1: &Collider,
+    position1: impl Into<Position>,
+    rotation1: impl Into<Rotation>,
+    collider2: &Collider,
+    position2: impl Into<Position>,
+    rotation2: impl Into<Rotation>,
+    prediction_distance: Scalar,
+    manifolds: &mut Vec<ContactManifold>,
+) {
     let isometry1 = utils::make_isometry(position1.into(), rotation1.into());
     let isometry2 = utils::make_isometry(position2.into(), rotation2.into());
     let isometry12 = isometry1.inv_mul(&isometry2);
 
-    // TODO: Reuse manifolds from previous frame to improve performance
-    let mut manifolds: Vec<parry::query::ContactManifold<(), ()>> = vec![];
+    let mut parry_manifolds: Vec<parry::query::ContactManifold<(), ()>> = vec![];
     let _ = parry::query::DefaultQueryDispatcher.contact_manifolds(
         &isometry12,
         collider1.shape_scaled().0.as_ref(),
         collider2.shape_scaled().0.as_ref(),
         prediction_distance,
-        &mut manifolds,
+        &mut parry_manifolds,
         &mut None,
     );
-    manifolds
-        .iter()
-        .filter_map(|manifold| {
-            let subpos1 = manifold.subshape_pos1.unwrap_or_default();
-            let subpos2 = manifold.subshape_pos2.unwrap_or_default();
-            let normal1: Vector = subpos1
-                .rotation
-                .transform_vector(&manifold.local_n1)
-                .normalize()
-                .into();
-            let normal2: Vector = subpos2
-                .rotation
-                .transform_vector(&manifold.local_n2)
-                .normalize()
-                .into();
 
-            // Make sure normals are valid
-            if !normal1.is_normalized() || !normal2.is_normalized() {
-                return None;
+    let mut count = 0;
+    for manifold in parry_manifolds.iter() {
+        let subpos1 = manifold.subshape_pos1.unwrap_or_default();
+        let subpos2 = manifold.subshape_pos2.unwrap_or_default();
+        let mut normal1: Vector = subpos1
+            .rotation
+            .transform_vector(&manifold.local_n1)
+            .normalize()
+            .into();
+        let mut normal2: Vector = subpos2
+            .rotation
+            .transform_vector(&manifold.local_n2)
+            .normalize()
+            .into();
+
+        // Fix normals of contacts on internal edges of triangle meshes and polylines
+        if let Some(deepest) = manifold.find_deepest_contact() {
+            let corrected1 = correct_internal_edge_normal(
+                collider1.shape_scaled(),
+                manifold.subshape1,
+                deepest.local_p1.into(),
+                normal1,
+            );
+            let corrected2 = correct_internal_edge_normal(
+                collider2.shape_scaled(),
+                manifold.subshape2,
+                deepest.local_p2.into(),
+                normal2,
+            );
+
+            if corrected1 != normal1 {
+                normal1 = corrected1;
+                normal2 = -Vector::from(
+                    isometry12
+                        .rotation
+                        .inverse_transform_vector(&normal1.into()),
+                );
+            } else if corrected2 != normal2 {
+                normal2 = corrected2;
+                normal1 = -Vector::from(isometry12.rotation.transform_vector(&normal2.into()));
             }
+        }
 
-            Some(ContactManifold {
+        // Make sure normals are valid
+        if !normal1.is_normalized() || !normal2.is_normalized() {
+            continue;
+        }
+
+        let contacts = manifold.contacts().iter().map(|contact| ContactData {
+            point1: subpos1.transform_point(&contact.local_p1).into(),
+            point2: subpos2.transform_point(&contact.local_p2).into(),
+            normal1,
+            normal2,
+            penetration: -contact.dist,
+        });
+
+        // Reuse the existing manifolds and their contact buffers
+        if let Some(reused) = manifolds.get_mut(count) {
+            reused.normal1 = normal1;
+            reused.normal2 = normal2;
+            reused.contacts.clear();
+            reused.contacts.extend(contacts);
+        } else {
+            manifolds.push(ContactManifold {
                 normal1,
                 normal2,
-                contacts: manifold
-                    .contacts()
-                    .iter()
-                    .map(|contact| ContactData {
-                        point1: subpos1.transform_point(&contact.local_p1).into(),
-                        point2: subpos2.transform_point(&contact.local_p2).into(),
-                        normal1,
-                        normal2,
-                        penetration: -contact.dist,
-                    })
-                    .collect(),
-            })
-        })
-        .collect()
+                contacts: contacts.collect(),
+            });
+        }
+        count += 1;
+    }
+    manifolds.truncate(count);
+}
+
+/// The minimum angle between the faces adjacent to a convex edge of a triangle mesh (or the segments adjacent to
+/// a convex vertex of a polyline in 2D) for the edge to produce its own contact normals.
+///
+/// Contacts on edges with smaller angles use the normal of the face instead. See [`contact_manifolds`].
+pub const INTERNAL_EDGE_ANGLE: Scalar = 0.1;
+
+/// Returns the normal of the triangle (or segment in 2D) of the given shape if the contact at `point` lies
+/// on an internal edge or vertex. Otherwise, the original `normal` is returned.
+///
+/// The point and normals are in the local space of the shape.
+fn correct_internal_edge_normal(
+    shape: &SharedShape,
+    subshape: u32,
+    point: Vector,
+    normal: Vector,
+) -> Vector {
+    #[cfg(feature = "2d")]
+    let (features, face_normal, adjacent) = {
+        let Some(polyline) = shape.as_polyline() else {
+            return normal;
+        };
+        let segment = polyline.segment(subshape);
+        let Some(face_normal) = segment.normal() else {
+            return normal;
+        };
+        let (a, b): (Vector, Vector) = (segment.a.into(), segment.b.into());
+
+        // The features of a segment are its endpoints
+        let features = [(a, a), (b, b)];
+        let adjacent = move |vertex: Vector, _: Vector| {
+            polyline
+                .segments()
+                .enumerate()
+                .filter(move |(i, _)| *i as u32 != subshape)
+                .filter_map(move |(_, other)| {
+                    let (c, d): (Vector, Vector) = (other.a.into(), other.b.into());
+                    let normal: Vector = other.normal()?.into_inner().into();
+                    if c.distance_squared(vertex) < INTERNAL_EDGE_TOLERANCE.powi(2) {
+                        Some((d, normal))
+                    } else if d.distance_squared(vertex) < INTERNAL_EDGE_TOLERANCE.powi(2) {
+                        Some((c, normal))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        (features, Vector::from(face_normal.into_inner()), adjacent)
+    };
+
+    #[cfg(feature = "3d")]
+    let (features, face_normal, adjacent) = {
+        let Some(trimesh) = shape.as_trimesh() else {
+            return normal;
+        };
+        let triangle = trimesh.triangle(subshape);
+        let Some(face_normal) = triangle.normal() else {
+            return normal;
+        };
+        let (a, b, c): (Vector, Vector, Vector) =
+            (triangle.a.into(), triangle.b.into(), triangle.c.into());
+
+        // The features of a triangle are its edges
+        let features = [(a, b), (b, c), (c, a)];
+        let adjacent = move |a: Vector, b: Vector| {
+            use parry::bounding_volume::{Aabb, BoundingVolume};
+
+            let aabb = Aabb::from_points(&[a.into(), b.into()]).loosened(INTERNAL_EDGE_TOLERANCE);
+            let mut candidates = vec![];
+            trimesh.qbvh().intersect_aabb(&aabb, &mut candidates);
+
+            candidates
+                .into_iter()
+                .filter(|i| *i != subshape)
+                .filter_map(|i| {
+                    let other = trimesh.triangle(i);
+                    let normal: Vector = other.normal()?.into_inner().into();
+                    let vertices: [Vector; 3] = [other.a.into(), other.b.into(), other.c.into()];
+                    let is_shared = |vertex: Vector| {
+                        vertices
+                            .iter()
+                            .any(|v| v.distance_squared(vertex) < INTERNAL_EDGE_TOLERANCE.powi(2))
+                    };
+                    if !is_shared(a) || !is_shared(b) {
+                        return None;
+                    }
+                    // The vertex of the adjacent triangle that isn't on the shared edge
+                    vertices
+                        .into_iter()
+                        .find(|v| {
+                            v.distance_squared(a) >= INTERNAL_EDGE_TOLERANCE.powi(2)
+                                && v.distance_squared(b) >= INTERNAL_EDGE_TOLERANCE.powi(2)
+                        })
+                        .map(|opposite| (opposite, normal))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        (features, Vector::from(face_normal.into_inner()), adjacent)
+    };
+
+    // Make the face normal point towards the other shape
+    let face_normal = if face_normal.dot(normal) < 0.0 {
+        -face_normal
+    } else {
+        face_normal
+    };
+
+    if face_normal.dot(normal) >= 1.0 - Scalar::EPSILON.sqrt() {
+        return normal;
+    }
+
+    let mut on_feature = false;
+
+    for (a, b) in features {
+        if distance_to_segment(point, a, b) > INTERNAL_EDGE_TOLERANCE {
+            continue;
+        }
+        on_feature = true;
+
+        let neighbors = adjacent(a, b);
+
+        // Edges on the boundary of the mesh are always active
+        if neighbors.is_empty() {
+            return normal;
+        }
+
+        let is_active = neighbors.iter().all(|(opposite, neighbor_normal)| {
+            let is_convex = (*opposite - a).dot(face_normal) < -INTERNAL_EDGE_TOLERANCE;
+            let is_flat = face_normal.dot(*neighbor_normal).abs() > INTERNAL_EDGE_ANGLE.cos();
+            is_convex && !is_flat
+        });
+
+        if is_active {
+            return normal;
+        }
+    }
+
+    if on_feature {
+        face_normal
+    } else {
+        normal
+    }
+}
+
+/// The distance within which points are considered to be on the same edge or vertex
+/// when correcting contacts on internal edges.
+const INTERNAL_EDGE_TOLERANCE: Scalar = 1.0e-4;
+
+/// Returns the distance from `point` to the line segment between `a` and `b`.
+fn distance_to_segment(point: Vector, a: Vector, b: Vector) -> Scalar {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((point - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance(a + ab * t)
 }
 
 /// Information about the closest points between two [`Collider`]s.
@@ -564,3 +800,53 @@ pub fn time_of_impact(
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn contacts_on_internal_edges_use_face_normals() {
+        // A flat floor made of several pieces, and a ball right next to the seam between them
+        #[cfg(feature = "2d")]
+        let floor = Collider::polyline(
+            vec![Vector::new(-1.0, 0.0), Vector::ZERO, Vector::new(1.0, 0.0)],
+            None,
+        );
+        #[cfg(feature = "2d")]
+        let ball_position = Vector::new(0.1, 0.49);
+
+        #[cfg(feature = "3d")]
+        let floor = Collider::trimesh(
+            vec![
+                Vector::new(-1.0, 0.0, -1.0),
+                Vector::new(1.0, 0.0, -1.0),
+                Vector::new(1.0, 0.0, 1.0),
+                Vector::new(-1.0, 0.0, 1.0),
+            ],
+            vec![[0, 1, 2], [0, 2, 3]],
+        );
+        #[cfg(feature = "3d")]
+        let ball_position = Vector::new(0.1, 0.49, -0.1);
+
+        let manifolds = contact_manifolds(
+            &floor,
+            Vector::ZERO,
+            Rotation::default(),
+            &Collider::ball(0.5),
+            ball_position,
+            Rotation::default(),
+            0.1,
+        );
+
+        assert!(!manifolds.is_empty());
+
+        // None of the contacts push the ball sideways
+        for manifold in manifolds {
+            assert_relative_eq!(manifold.normal1, Vector::Y, epsilon = 0.0001);
+            assert_relative_eq!(manifold.normal2, -Vector::Y, epsilon = 0.0001);
+        }
+    }
+}
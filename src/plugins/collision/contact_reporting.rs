@@ -11,6 +11,8 @@ use crate::prelude::*;
 /// - [`Collision`]
 /// - [`CollisionStarted`]
 /// - [`CollisionEnded`]
+///
+/// Which colliders send events can be configured using [`CollisionEventLayers`].
 pub struct ContactReportingPlugin;
 
 impl Plugin for ContactReportingPlugin {
@@ -32,6 +34,9 @@ impl Plugin for ContactReportingPlugin {
 pub struct Collision(pub Contacts);
 
 /// A [collision event](Collider#collision-events) that is sent when two entities start colliding.
+///
+/// The speed of the impact can be read from [`Contacts::impact_speed`] using [`Collisions::get`]
+/// or the corresponding [`Collision`] event.
 #[derive(Event, Clone, Debug, PartialEq)]
 pub struct CollisionStarted(pub Entity, pub Entity);
 
@@ -40,20 +45,35 @@ pub struct CollisionStarted(pub Entity, pub Entity);
 pub struct CollisionEnded(pub Entity, pub Entity);
 
 /// Sends collision events and updates [`CollidingEntities`].
+///
+/// Events are only sent for colliders whose [`CollisionEventLayers`] interact.
 pub fn report_contacts(
     mut colliders: Query<&mut CollidingEntities>,
+    event_layers: Query<&CollisionEventLayers>,
     collisions: Res<Collisions>,
     mut collision_ev_writer: EventWriter<Collision>,
     mut collision_started_ev_writer: EventWriter<CollisionStarted>,
     mut collision_ended_ev_writer: EventWriter<CollisionEnded>,
 ) {
     for ((entity1, entity2), contacts) in collisions.get_internal().iter() {
+        let sends_events = match (event_layers.get(*entity1), event_layers.get(*entity2)) {
+            (Ok(layers1), Ok(layers2)) => layers1.0.interacts_with(layers2.0),
+            (Ok(layers), Err(_)) | (Err(_), Ok(layers)) => {
+                layers.0.interacts_with(CollisionLayers::default())
+            }
+            (Err(_), Err(_)) => true,
+        };
+
         if contacts.during_current_frame {
-            collision_ev_writer.send(Collision(contacts.clone()));
+            if sends_events {
+                collision_ev_writer.send(Collision(contacts.clone()));
+            }
 
             // Collision started
             if contacts.during_current_frame && !contacts.during_previous_frame {
-                collision_started_ev_writer.send(CollisionStarted(*entity1, *entity2));
+                if sends_events {
+                    collision_started_ev_writer.send(CollisionStarted(*entity1, *entity2));
+                }
 
                 if let Ok(mut colliding_entities1) = colliders.get_mut(*entity1) {
                     colliding_entities1.insert(*entity2);
@@ -66,7 +86,9 @@ pub fn report_contacts(
 
         // Collision ended
         if !contacts.during_current_frame {
-            collision_ended_ev_writer.send(CollisionEnded(*entity1, *entity2));
+            if sends_events {
+                collision_ended_ev_writer.send(CollisionEnded(*entity1, *entity2));
+            }
 
             if let Ok(mut colliding_entities1) = colliders.get_mut(*entity1) {
                 colliding_entities1.remove(entity2);
@@ -77,3 +99,145 @@ pub fn report_contacts(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, floor_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn collisions_report_impact_speed() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let wall = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * 3.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LinearVelocity(Vector::X * 10.0),
+            ))
+            .id();
+
+        let mut impact_speed = None;
+        for _ in 0..30 {
+            app.step_physics(1);
+
+            let events = app.world.resource::<Events<CollisionStarted>>();
+            if events.iter_current_update_events().next().is_some() {
+                let contacts = app.world.resource::<Collisions>().get(wall, ball).unwrap();
+                impact_speed = Some(contacts.impact_speed);
+            }
+        }
+
+        assert_relative_eq!(impact_speed.unwrap(), 10.0, max_relative = 0.05);
+    }
+
+    #[test]
+    fn collision_event_layers_filter_events() {
+        let mut app = create_app();
+
+        let ground_layers = CollisionEventLayers(CollisionLayers::from_bits(0b01, 0b10));
+        let debris_layers = CollisionEventLayers(CollisionLayers::from_bits(0b01, 0b10));
+        let player_layers = CollisionEventLayers(CollisionLayers::from_bits(0b10, 0b11));
+
+        let ground = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(20.0),
+                ground_layers,
+            ))
+            .id();
+        let debris = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * -5.0 + Vector::Y * 0.9),
+                Collider::ball(0.5),
+                debris_layers,
+            ))
+            .id();
+        let player = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 5.0 + Vector::Y * 0.9),
+                Collider::ball(0.5),
+                player_layers,
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        // Both bodies collide with the ground physically
+        let collisions = app.world.resource::<Collisions>();
+        assert!(collisions.get(debris, ground).is_some());
+        assert!(collisions.get(player, ground).is_some());
+
+        // Only the player's collision sends events
+        let events = app.world.resource::<Events<CollisionStarted>>();
+        let mut reader = events.get_reader();
+        let started = reader.iter(events).collect::<Vec<_>>();
+        assert_eq!(started.len(), 1);
+        assert!(started[0].0 == player || started[0].1 == player);
+    }
+
+    #[test]
+    fn colliding_entities_track_sensor_overlaps() {
+        let mut app = create_app();
+
+        let zone = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(4.0),
+                Sensor,
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 2.0),
+                Collider::ball(0.25),
+            ))
+            .id();
+
+        let mut was_inside = false;
+        for _ in 0..120 {
+            app.step_physics(1);
+
+            // The sets of both entities are kept in sync with the overlap
+            let zone_entities = app.world.get::<CollidingEntities>(zone).unwrap();
+            let ball_entities = app.world.get::<CollidingEntities>(ball).unwrap();
+            assert_eq!(zone_entities.contains(&ball), ball_entities.contains(&zone));
+            was_inside |= zone_entities.contains(&ball);
+        }
+
+        // The ball fell through the zone and left it
+        assert!(was_inside);
+        assert!(app.world.get::<Position>(ball).unwrap().y < -1.0);
+        assert!(app.world.get::<CollidingEntities>(zone).unwrap().is_empty());
+    }
+}
@@ -243,12 +243,54 @@ pub(super) struct PreviousCollisions(Collisions);
 /// The contacts are stored in contact manifolds.
 /// Each manifold contains one or more contact points, and each contact
 /// in a given manifold shares the same contact normal.
+///
+/// ## Material overrides
+///
+/// The [friction](Friction) and [restitution](Restitution) used for the contact pair can be overridden
+/// without changing the materials of either collider by setting `friction` and `restitution` in
+/// the [`PostProcessCollisions`] schedule. The overrides are kept for as long as the colliders stay in contact.
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Wheel;
+///
+/// #[derive(Component)]
+/// struct Mud;
+///
+/// // Add this to the `PostProcessCollisions` schedule
+/// fn wheels_slip_in_mud(
+///     mut collisions: ResMut<Collisions>,
+///     wheels: Query<(), With<Wheel>>,
+///     mud: Query<(), With<Mud>>,
+/// ) {
+///     for contacts in collisions.iter_mut() {
+///         let [e1, e2] = [contacts.entity1, contacts.entity2];
+///         if (wheels.contains(e1) && mud.contains(e2)) || (wheels.contains(e2) && mud.contains(e1)) {
+///             contacts.friction = Some(Friction::new(0.1));
+///         }
+///     }
+/// }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct Contacts {
     /// First entity in the contact.
     pub entity1: Entity,
     /// Second entity in the contact.
     pub entity2: Entity,
+    /// The entity of the [rigid body](RigidBody) that the first collider is attached to.
+    ///
+    /// For [child colliders](Collider#multiple-colliders), this is the closest ancestor with a [`RigidBody`].
+    pub body_entity1: Option<Entity>,
+    /// The entity of the [rigid body](RigidBody) that the second collider is attached to.
+    ///
+    /// For [child colliders](Collider#multiple-colliders), this is the closest ancestor with a [`RigidBody`].
+    pub body_entity2: Option<Entity>,
     /// A list of contact manifolds between two colliders.
     /// Each manifold contains one or more contact points, but each contact
     /// in a given manifold shares the same contact normal.
@@ -259,6 +301,17 @@ pub struct Contacts {
     pub during_current_substep: bool,
     /// True if the bodies were in contact during the previous frame.
     pub during_previous_frame: bool,
+    /// Overrides the combined [friction](Friction) of the colliders for this contact pair.
+    pub friction: Option<Friction>,
+    /// Overrides the combined [restitution](Restitution) of the colliders for this contact pair.
+    pub restitution: Option<Restitution>,
+    /// The speed at which the bodies were approaching each other along the contact normal
+    /// when the collision started.
+    ///
+    /// Unlike the impulse applied by the contact, this doesn't depend on the masses of the bodies,
+    /// which makes it useful for things like choosing the volume of impact sounds, as light but fast
+    /// objects can hit hard while only applying a small impulse. It is zero for sensors.
+    pub impact_speed: Scalar,
 }
 
 /// A contact manifold between two colliders, containing a set of contact points.
@@ -3,9 +3,9 @@
 //! See [`NarrowPhasePlugin`].
 
 use crate::prelude::*;
-use bevy::ecs::query::Has;
-#[cfg(feature = "parallel")]
-use bevy::tasks::{ComputeTaskPool, ParallelSlice};
+#[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+use bevy::tasks::{ComputeTaskPool, ParallelSliceMut};
+use bevy::{ecs::query::Has, utils::HashSet};
 
 /// Computes contacts between entities.
 ///
@@ -29,7 +29,7 @@ impl Plugin for NarrowPhasePlugin {
                 (
                     // Reset collision states before the substepping loop
                     reset_collision_states
-                        .after(PhysicsStepSet::BroadPhase)
+                        .after(PhysicsStepSet::FilterPairs)
                         .before(PhysicsStepSet::Substeps),
                     // Remove ended collisions after contact reporting
                     ((|mut collisions: ResMut<Collisions>| {
@@ -77,6 +77,15 @@ impl Default for NarrowPhaseConfig {
 }
 
 /// Computes contacts based on [`BroadCollisionPairs`] and adds them to [`Collisions`].
+///
+/// Pairs where neither collider has moved since the previous substep are skipped, and their contacts
+/// from the previous substep are reused. This is detected using change detection on [`Position`], [`Rotation`]
+/// and [`Collider`], so it mostly benefits static bodies and bodies at rest. Pairs that weren't in
+/// [`BroadCollisionPairs`] in the previous substep are always computed, because they could have been
+/// filtered out before, for example with [`CollisionLayers`] or [`IgnoreCollisions`].
+///
+/// The contact manifold buffers of replaced collisions are reused for new collisions,
+/// so that the contacts don't need to be reallocated every substep.
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 pub fn collect_collisions(
@@ -85,98 +94,207 @@ pub fn collect_collisions(
         Option<&AccumulatedTranslation>,
         &Rotation,
         &Collider,
+        Option<&ColliderParent>,
     )>,
     broad_collision_pairs: Res<BroadCollisionPairs>,
     mut collisions: ResMut<Collisions>,
     narrow_phase_config: Res<NarrowPhaseConfig>,
+    moved_colliders: Query<
+        (),
+        Or<(
+            Changed<Position>,
+            Changed<AccumulatedTranslation>,
+            Changed<Rotation>,
+            Changed<Collider>,
+        )>,
+    >,
+    mut manifold_pool: Local<Vec<Vec<ContactManifold>>>,
+    mut previous_pairs: Local<HashSet<(Entity, Entity)>>,
 ) {
-    #[cfg(feature = "parallel")]
+    // Contacts can only be reused for pairs that were already checked in the previous substep
+    let can_reuse_contacts = |entity1: Entity, entity2: Entity| {
+        !narrow_phase_config.is_changed()
+            && !moved_colliders.contains(entity1)
+            && !moved_colliders.contains(entity2)
+            && previous_pairs.contains(&ordered_pair(entity1, entity2))
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
     {
+        // Each pair gets a manifold buffer from the pool so that the contacts can be computed in parallel
+        let mut jobs: Vec<(Entity, Entity, Vec<ContactManifold>)> = vec![];
+        for (entity1, entity2) in broad_collision_pairs.0.iter() {
+            if can_reuse_contacts(*entity1, *entity2) {
+                reuse_contacts(&mut collisions, *entity1, *entity2);
+                continue;
+            }
+            jobs.push((*entity1, *entity2, manifold_pool.pop().unwrap_or_default()));
+        }
+
         let pool = ComputeTaskPool::get();
-        // TODO: Verify if `par_splat_map` is deterministic. If not, sort the collisions.
-        let new_collisions = broad_collision_pairs
-            .0
-            .par_splat_map(pool, None, |chunks| {
-                let mut new_collisions: Vec<Contacts> = vec![];
-                for (entity1, entity2) in chunks {
-                    if let Ok([bundle1, bundle2]) = bodies.get_many([*entity1, *entity2]) {
-                        let (position1, accumulated_translation1, rotation1, collider1) = bundle1;
-                        let (position2, accumulated_translation2, rotation2, collider2) = bundle2;
-
-                        let position1 =
-                            position1.0 + accumulated_translation1.copied().unwrap_or_default().0;
-                        let position2 =
-                            position2.0 + accumulated_translation2.copied().unwrap_or_default().0;
-
-                        let previous_contact = collisions.get_internal().get(&(*entity1, *entity2));
-
-                        let contacts = Contacts {
-                            entity1: *entity1,
-                            entity2: *entity2,
-                            during_current_frame: true,
-                            during_current_substep: true,
-                            during_previous_frame: previous_contact
-                                .map_or(false, |c| c.during_previous_frame),
-                            manifolds: contact_query::contact_manifolds(
-                                collider1,
-                                position1,
-                                *rotation1,
-                                collider2,
-                                position2,
-                                *rotation2,
-                                narrow_phase_config.prediction_distance,
-                            ),
-                        };
-
-                        if !contacts.manifolds.is_empty() {
-                            new_collisions.push(contacts);
-                        }
+        // TODO: Verify if `par_splat_map_mut` is deterministic. If not, sort the collisions.
+        let new_collisions = jobs.par_splat_map_mut(pool, None, |chunks| {
+            let mut new_collisions: Vec<Contacts> = vec![];
+            for (entity1, entity2, manifolds) in chunks.iter_mut() {
+                if let Ok([bundle1, bundle2]) = bodies.get_many([*entity1, *entity2]) {
+                    let (position1, accumulated_translation1, rotation1, collider1, parent1) =
+                        bundle1;
+                    let (position2, accumulated_translation2, rotation2, collider2, parent2) =
+                        bundle2;
+
+                    let position1 =
+                        position1.0 + accumulated_translation1.copied().unwrap_or_default().0;
+                    let position2 =
+                        position2.0 + accumulated_translation2.copied().unwrap_or_default().0;
+
+                    contact_query::contact_manifolds_into(
+                        collider1,
+                        position1,
+                        *rotation1,
+                        collider2,
+                        position2,
+                        *rotation2,
+                        narrow_phase_config.prediction_distance,
+                        manifolds,
+                    );
+
+                    if manifolds.is_empty() {
+                        continue;
                     }
+
+                    let previous_contact = collisions.get_internal().get(&(*entity1, *entity2));
+
+                    new_collisions.push(Contacts {
+                        entity1: *entity1,
+                        entity2: *entity2,
+                        body_entity1: parent1.map(|p| p.get()),
+                        body_entity2: parent2.map(|p| p.get()),
+                        during_current_frame: true,
+                        during_current_substep: true,
+                        during_previous_frame: previous_contact
+                            .map_or(false, |c| c.during_previous_frame),
+                        friction: previous_contact.and_then(|c| c.friction),
+                        restitution: previous_contact.and_then(|c| c.restitution),
+                        impact_speed: previous_contact.map_or(0.0, |c| c.impact_speed),
+                        manifolds: std::mem::take(manifolds),
+                    });
                 }
-                new_collisions
-            })
-            .into_iter()
-            .flatten();
-        collisions.extend(new_collisions);
+            }
+            new_collisions
+        });
+
+        for contacts in new_collisions.into_iter().flatten() {
+            // The manifolds of the replaced collision can be reused in the next substep
+            if let Some(old_contacts) = collisions.insert_collision_pair(contacts) {
+                recycle_manifolds(&mut manifold_pool, old_contacts.manifolds);
+            }
+        }
+        for (_, _, manifolds) in jobs {
+            recycle_manifolds(&mut manifold_pool, manifolds);
+        }
     }
-    #[cfg(not(feature = "parallel"))]
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
     {
         for (entity1, entity2) in broad_collision_pairs.0.iter() {
+            if can_reuse_contacts(*entity1, *entity2) {
+                reuse_contacts(&mut collisions, *entity1, *entity2);
+                continue;
+            }
+
             if let Ok([bundle1, bundle2]) = bodies.get_many([*entity1, *entity2]) {
-                let (position1, accumulated_translation1, rotation1, collider1) = bundle1;
-                let (position2, accumulated_translation2, rotation2, collider2) = bundle2;
+                let (position1, accumulated_translation1, rotation1, collider1, parent1) = bundle1;
+                let (position2, accumulated_translation2, rotation2, collider2, parent2) = bundle2;
 
                 let position1 =
                     position1.0 + accumulated_translation1.copied().unwrap_or_default().0;
                 let position2 =
                     position2.0 + accumulated_translation2.copied().unwrap_or_default().0;
 
+                let mut manifolds = manifold_pool.pop().unwrap_or_default();
+                contact_query::contact_manifolds_into(
+                    collider1,
+                    position1,
+                    *rotation1,
+                    collider2,
+                    position2,
+                    *rotation2,
+                    narrow_phase_config.prediction_distance,
+                    &mut manifolds,
+                );
+
+                if manifolds.is_empty() {
+                    recycle_manifolds(&mut manifold_pool, manifolds);
+                    continue;
+                }
+
                 let previous_contact = collisions.get_internal().get(&(*entity1, *entity2));
 
                 let contacts = Contacts {
                     entity1: *entity1,
                     entity2: *entity2,
+                    body_entity1: parent1.map(|p| p.get()),
+                    body_entity2: parent2.map(|p| p.get()),
                     during_current_frame: true,
                     during_current_substep: true,
                     during_previous_frame: previous_contact
                         .map_or(false, |c| c.during_previous_frame),
-                    manifolds: contact_query::contact_manifolds(
-                        collider1,
-                        position1,
-                        *rotation1,
-                        collider2,
-                        position2,
-                        *rotation2,
-                        narrow_phase_config.prediction_distance,
-                    ),
+                    friction: previous_contact.and_then(|c| c.friction),
+                    restitution: previous_contact.and_then(|c| c.restitution),
+                    impact_speed: previous_contact.map_or(0.0, |c| c.impact_speed),
+                    manifolds,
                 };
 
-                if !contacts.manifolds.is_empty() {
-                    collisions.insert_collision_pair(contacts);
+                // The manifolds of the replaced collision can be reused in the next substep
+                if let Some(old_contacts) = collisions.insert_collision_pair(contacts) {
+                    recycle_manifolds(&mut manifold_pool, old_contacts.manifolds);
                 }
             }
         }
     }
+
+    previous_pairs.clear();
+    previous_pairs.extend(
+        broad_collision_pairs
+            .0
+            .iter()
+            .map(|(entity1, entity2)| ordered_pair(*entity1, *entity2)),
+    );
+}
+
+/// Returns the entities of a pair in a consistent order, since the broad phase
+/// doesn't guarantee that the entities are always in the same order.
+fn ordered_pair(entity1: Entity, entity2: Entity) -> (Entity, Entity) {
+    if entity1 < entity2 {
+        (entity1, entity2)
+    } else {
+        (entity2, entity1)
+    }
+}
+
+/// Marks the cached contacts between two colliders that haven't moved as still being in contact.
+///
+/// The pair must have been checked in the previous substep. Then, if there are no cached contacts,
+/// the colliders weren't touching in the previous substep, so they can't be touching now either.
+fn reuse_contacts(collisions: &mut Collisions, entity1: Entity, entity2: Entity) {
+    let collisions = collisions.get_internal_mut();
+    // The broad phase doesn't guarantee that the entities are always in the same order
+    let contacts = match collisions.get_mut(&(entity1, entity2)) {
+        Some(contacts) => Some(contacts),
+        None => collisions.get_mut(&(entity2, entity1)),
+    };
+    if let Some(contacts) = contacts {
+        contacts.during_current_frame = true;
+        contacts.during_current_substep = true;
+    }
+}
+
+/// Returns a buffer of contact manifolds to the pool used by [`collect_collisions`].
+///
+/// The buffer isn't cleared, so that the allocations of the contacts in the manifolds are reused as well.
+fn recycle_manifolds(pool: &mut Vec<Vec<ContactManifold>>, manifolds: Vec<ContactManifold>) {
+    if manifolds.capacity() > 0 {
+        pool.push(manifolds);
+    }
 }
 
 // TODO: The collision state handling feels a bit confusing and error-prone.
@@ -215,3 +333,115 @@ pub fn reset_substep_collision_states(mut collisions: ResMut<Collisions>) {
         contacts.during_current_substep = false;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::collect_collisions;
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    /// Creates an app that only runs the narrow phase so that nothing else moves the colliders.
+    fn create_narrow_phase_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<Collisions>()
+            .init_resource::<BroadCollisionPairs>()
+            .init_resource::<NarrowPhaseConfig>()
+            .add_systems(Update, collect_collisions);
+        app
+    }
+
+    /// Spawns two balls that overlap by 0.2.
+    fn spawn_overlapping_balls(app: &mut App) -> (Entity, Entity) {
+        let a = app
+            .world
+            .spawn((
+                Position(Vector::ZERO),
+                Rotation::default(),
+                Collider::ball(0.5),
+            ))
+            .id();
+        let b = app
+            .world
+            .spawn((
+                Position(Vector::X * 0.8),
+                Rotation::default(),
+                Collider::ball(0.5),
+            ))
+            .id();
+        (a, b)
+    }
+
+    #[test]
+    fn narrow_phase_reuses_contacts_of_colliders_that_have_not_moved() {
+        let mut app = create_narrow_phase_app();
+        let (a, b) = spawn_overlapping_balls(&mut app);
+        app.world
+            .resource_mut::<BroadCollisionPairs>()
+            .0
+            .push((a, b));
+
+        let penetration = |app: &App| {
+            let collisions = app.world.resource::<Collisions>();
+            let contacts = collisions.get_internal().get(&(a, b)).unwrap();
+            assert!(contacts.during_current_frame);
+            contacts.manifolds[0].contacts[0].penetration
+        };
+        // Marks the cached contacts as outdated so that we can tell whether they were recomputed
+        let mark_contacts = |app: &mut App| {
+            let mut collisions = app.world.resource_mut::<Collisions>();
+            let contacts = collisions.get_internal_mut().get_mut(&(a, b)).unwrap();
+            contacts.during_current_frame = false;
+            contacts.manifolds[0].contacts[0].penetration = -1.0;
+        };
+
+        app.update();
+        assert_relative_eq!(penetration(&app), 0.2, epsilon = 0.001);
+
+        // The colliders haven't moved, so the resting pair keeps its cached contacts
+        mark_contacts(&mut app);
+        app.update();
+        assert_eq!(penetration(&app), -1.0);
+
+        // Moving one of the colliders recomputes the contacts
+        mark_contacts(&mut app);
+        app.world.get_mut::<Position>(b).unwrap().0 = Vector::X * 0.9;
+        app.update();
+        assert_relative_eq!(penetration(&app), 0.1, epsilon = 0.001);
+
+        // Changing the shape of one of the colliders also recomputes the contacts
+        mark_contacts(&mut app);
+        *app.world.get_mut::<Collider>(a).unwrap() = Collider::ball(0.6);
+        app.update();
+        assert_relative_eq!(penetration(&app), 0.2, epsilon = 0.001);
+    }
+
+    #[test]
+    fn narrow_phase_computes_contacts_of_pairs_that_stop_being_filtered() {
+        let mut app = create_narrow_phase_app();
+        let (a, b) = spawn_overlapping_balls(&mut app);
+
+        // The overlapping pair is filtered out of the broad phase pairs, so it has no contacts
+        app.update();
+        app.update();
+        assert!(app.world.resource::<Collisions>().get(a, b).is_none());
+
+        // The filter is removed while neither collider moves, so the contacts can't be reused
+        app.world
+            .resource_mut::<BroadCollisionPairs>()
+            .0
+            .push((a, b));
+        app.update();
+        let collisions = app.world.resource::<Collisions>();
+        let contacts = collisions
+            .get(a, b)
+            .expect("the colliders should be in contact");
+        assert!(contacts.during_current_frame);
+        assert_relative_eq!(
+            contacts.manifolds[0].contacts[0].penetration,
+            0.2,
+            epsilon = 0.001
+        );
+    }
+}
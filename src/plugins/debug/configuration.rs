@@ -3,27 +3,83 @@ use bevy::prelude::*;
 
 /// Controls the global physics debug configuration.
 ///
+/// The configuration can be modified at runtime, and the changes are applied on the next frame.
 /// To configure the debug rendering of specific entities, use the [`DebugRender`] component.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn toggle_gizmos(keys: Res<Input<KeyCode>>, mut gizmos: ResMut<PhysicsGizmos>) {
+///     if keys.just_pressed(KeyCode::F1) {
+///         gizmos.enabled = !gizmos.enabled;
+///     }
+///     // Only render the colliders of selected entities
+///     if keys.just_pressed(KeyCode::F2) {
+///         gizmos.collider_mode = ColliderGizmoMode::Selected;
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn select_player(mut commands: Commands, player: Query<Entity, Added<Player>>) {
+///     for entity in &player {
+///         commands.entity(entity).insert(GizmoSelected);
+///     }
+/// }
+/// ```
 #[derive(Reflect, Resource)]
 #[reflect(Resource)]
-pub struct PhysicsDebugConfig {
+pub struct PhysicsGizmos {
     /// Determines if debug rendering is enabled.
     pub enabled: bool,
     /// The lengths of the axes drawn for an entity at the center of mass.
     pub axis_lengths: Option<Vector>,
     /// The color of the [AABBs](ColliderAabb). If `None`, the AABBs will not be rendered.
     pub aabb_color: Option<Color>,
+    /// The color of the lines drawn between the [AABB](ColliderAabb) centers of entity pairs
+    /// in [`BroadCollisionPairs`]. If `None`, the broad phase pairs will not be rendered.
+    ///
+    /// This can be useful for finding out if AABBs are too large or if the broad phase
+    /// is producing an unexpectedly large number of pairs.
+    pub broad_phase_pair_color: Option<Color>,
     /// The color of the [collider](Collider) wireframes. If `None`, the colliders will not be rendered.
     pub collider_color: Option<Color>,
+    /// Determines which [colliders](Collider) are rendered.
+    pub collider_mode: ColliderGizmoMode,
     /// The colors (in HSLA) for [sleeping](Sleeping) bodies will be multiplied by this array.
     /// If `None`, sleeping will have no effect on the colors.
     pub sleeping_color_multiplier: Option<[f32; 4]>,
+    /// The color of the [collider](Collider) wireframes of [sleeping](Sleeping) bodies.
+    /// If `None`, the `collider_color` multiplied by the `sleeping_color_multiplier` will be used instead.
+    ///
+    /// This can be useful for tuning the [`SleepingThreshold`] and [`DeactivationTime`].
+    pub sleeping_collider_color: Option<Color>,
     /// The color of the contact points. If `None`, the contact points will not be rendered.
+    ///
+    /// A line is also drawn between the contact points of the two entities, which visualizes
+    /// the penetration depth.
     pub contact_color: Option<Color>,
+    /// The color of the contact normals. If `None`, the contact normals will not be rendered.
+    pub contact_normal_color: Option<Color>,
+    /// Determines how the lengths of the contact normals are computed.
+    pub contact_normal_scale: ContactGizmoScale,
     /// The color of the lines drawn from the centers of bodies to their joint anchors.
     pub joint_anchor_color: Option<Color>,
     /// The color of the lines drawn between joint anchors, indicating the separation.
     pub joint_separation_color: Option<Color>,
+    /// The color of the lines drawn along the free or aligned axes of joints,
+    /// such as the rotation axis of a [`RevoluteJoint`] or the free axis of a [`PrismaticJoint`].
+    pub joint_axis_color: Option<Color>,
+    /// The color of joint limits. Angle limits are drawn as arcs and distance limits
+    /// along the free axis of a [`PrismaticJoint`] are drawn as lines.
+    pub joint_limit_color: Option<Color>,
     /// The color used for the rays in [raycasts](spatial_query#ray-casting).
     pub raycast_color: Option<Color>,
     /// The color used for the hit points in [raycasts](spatial_query#ray-casting).
@@ -43,7 +99,7 @@ pub struct PhysicsDebugConfig {
     pub hide_meshes: bool,
 }
 
-impl Default for PhysicsDebugConfig {
+impl Default for PhysicsGizmos {
     fn default() -> Self {
         Self {
             enabled: true,
@@ -52,11 +108,18 @@ impl Default for PhysicsDebugConfig {
             #[cfg(feature = "3d")]
             axis_lengths: Some(Vector::new(0.5, 0.5, 0.5)),
             aabb_color: None,
+            broad_phase_pair_color: None,
             collider_color: Some(Color::ORANGE),
+            collider_mode: ColliderGizmoMode::All,
             sleeping_color_multiplier: Some([1.0, 1.0, 0.4, 1.0]),
+            sleeping_collider_color: None,
             contact_color: None,
+            contact_normal_color: None,
+            contact_normal_scale: ContactGizmoScale::default(),
             joint_anchor_color: Some(Color::PINK),
             joint_separation_color: Some(Color::RED),
+            joint_axis_color: None,
+            joint_limit_color: None,
             raycast_color: Some(Color::RED),
             raycast_point_color: Some(Color::YELLOW),
             raycast_normal_color: Some(Color::PINK),
@@ -69,8 +132,8 @@ impl Default for PhysicsDebugConfig {
     }
 }
 
-impl PhysicsDebugConfig {
-    /// Creates a [`PhysicsDebugConfig`] configuration with all rendering options enabled.
+impl PhysicsGizmos {
+    /// Creates a [`PhysicsGizmos`] configuration with all rendering options enabled.
     pub fn all() -> Self {
         Self {
             enabled: true,
@@ -79,11 +142,18 @@ impl PhysicsDebugConfig {
             #[cfg(feature = "3d")]
             axis_lengths: Some(Vector::new(0.5, 0.5, 0.5)),
             aabb_color: Some(Color::rgb(0.8, 0.8, 0.8)),
+            broad_phase_pair_color: Some(Color::YELLOW),
             collider_color: Some(Color::ORANGE),
+            collider_mode: ColliderGizmoMode::All,
             sleeping_color_multiplier: Some([1.0, 1.0, 0.4, 1.0]),
+            sleeping_collider_color: None,
             contact_color: Some(Color::CYAN),
+            contact_normal_color: Some(Color::RED),
+            contact_normal_scale: ContactGizmoScale::default(),
             joint_anchor_color: Some(Color::PINK),
             joint_separation_color: Some(Color::RED),
+            joint_axis_color: Some(Color::BLUE),
+            joint_limit_color: Some(Color::LIME_GREEN),
             raycast_color: Some(Color::RED),
             raycast_point_color: Some(Color::YELLOW),
             raycast_normal_color: Some(Color::PINK),
@@ -95,7 +165,7 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with debug rendering enabled but all options turned off.
+    /// Creates a [`PhysicsGizmos`] configuration with debug rendering enabled but all options turned off.
     ///
     /// Note: this doesn't affect entities with [`DebugRender`] component; their debug gizmos will be visible.
     pub fn none() -> Self {
@@ -103,11 +173,18 @@ impl PhysicsDebugConfig {
             enabled: true,
             axis_lengths: None,
             aabb_color: None,
+            broad_phase_pair_color: None,
             collider_color: None,
+            collider_mode: ColliderGizmoMode::All,
             sleeping_color_multiplier: None,
+            sleeping_collider_color: None,
             contact_color: None,
+            contact_normal_color: None,
+            contact_normal_scale: ContactGizmoScale::default(),
             joint_anchor_color: None,
             joint_separation_color: None,
+            joint_axis_color: None,
+            joint_limit_color: None,
             raycast_color: None,
             raycast_point_color: None,
             raycast_normal_color: None,
@@ -119,7 +196,7 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with the given lengths for the axes
+    /// Creates a [`PhysicsGizmos`] configuration with the given lengths for the axes
     /// that are drawn for the entity at the center of mass. Other debug rendering options will be disabled.
     pub fn axes(axis_lengths: Vector) -> Self {
         Self {
@@ -128,7 +205,7 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with a given AABB color.
+    /// Creates a [`PhysicsGizmos`] configuration with a given AABB color.
     /// Other debug rendering options will be disabled.
     pub fn aabbs(color: Color) -> Self {
         Self {
@@ -137,7 +214,16 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with a given collider color.
+    /// Creates a [`PhysicsGizmos`] configuration with a given color for
+    /// [broad phase pairs](BroadCollisionPairs). Other debug rendering options will be disabled.
+    pub fn broad_phase_pairs(color: Color) -> Self {
+        Self {
+            broad_phase_pair_color: Some(color),
+            ..Self::none()
+        }
+    }
+
+    /// Creates a [`PhysicsGizmos`] configuration with a given collider color.
     /// Other debug rendering options will be disabled.
     pub fn colliders(color: Color) -> Self {
         Self {
@@ -146,7 +232,7 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with a given contact color.
+    /// Creates a [`PhysicsGizmos`] configuration with a given contact color.
     /// Other debug rendering options will be disabled.
     pub fn contacts(color: Color) -> Self {
         Self {
@@ -155,7 +241,7 @@ impl PhysicsDebugConfig {
         }
     }
 
-    /// Creates a [`PhysicsDebugConfig`] configuration with given colors for
+    /// Creates a [`PhysicsGizmos`] configuration with given colors for
     /// joint anchors and separation distances. Other debug rendering options will be disabled.
     pub fn joints(anchor_color: Option<Color>, separation_color: Option<Color>) -> Self {
         Self {
@@ -177,24 +263,54 @@ impl PhysicsDebugConfig {
         self
     }
 
+    /// Sets the color of the lines drawn between [broad phase pairs](BroadCollisionPairs).
+    pub fn with_broad_phase_pair_color(mut self, color: Color) -> Self {
+        self.broad_phase_pair_color = Some(color);
+        self
+    }
+
     /// Sets the collider color.
     pub fn with_collider_color(mut self, color: Color) -> Self {
         self.collider_color = Some(color);
         self
     }
 
+    /// Sets which [colliders](Collider) are rendered.
+    pub fn with_collider_mode(mut self, mode: ColliderGizmoMode) -> Self {
+        self.collider_mode = mode;
+        self
+    }
+
     /// Sets the multiplier used for the colors (in HSLA) of [sleeping](Sleeping) bodies.
     pub fn with_sleeping_color_multiplier(mut self, color_multiplier: [f32; 4]) -> Self {
         self.sleeping_color_multiplier = Some(color_multiplier);
         self
     }
 
+    /// Sets the collider color used for [sleeping](Sleeping) bodies.
+    pub fn with_sleeping_collider_color(mut self, color: Color) -> Self {
+        self.sleeping_collider_color = Some(color);
+        self
+    }
+
     /// Sets the contact color.
     pub fn with_contact_color(mut self, color: Color) -> Self {
         self.contact_color = Some(color);
         self
     }
 
+    /// Sets the contact normal color.
+    pub fn with_contact_normal_color(mut self, color: Color) -> Self {
+        self.contact_normal_color = Some(color);
+        self
+    }
+
+    /// Sets how the lengths of the contact normals are computed.
+    pub fn with_contact_normal_scale(mut self, scale: ContactGizmoScale) -> Self {
+        self.contact_normal_scale = scale;
+        self
+    }
+
     /// Sets the colors used for debug rendering joints.
     pub fn with_joint_colors(anchor_color: Option<Color>, separation_color: Option<Color>) -> Self {
         Self {
@@ -204,6 +320,18 @@ impl PhysicsDebugConfig {
         }
     }
 
+    /// Sets the color of the lines drawn along the free or aligned axes of joints.
+    pub fn with_joint_axis_color(mut self, color: Color) -> Self {
+        self.joint_axis_color = Some(color);
+        self
+    }
+
+    /// Sets the color of joint limits.
+    pub fn with_joint_limit_color(mut self, color: Color) -> Self {
+        self.joint_limit_color = Some(color);
+        self
+    }
+
     /// Sets the colors used for debug rendering raycasts.
     pub fn with_raycast_colors(
         mut self,
@@ -250,6 +378,12 @@ impl PhysicsDebugConfig {
         self
     }
 
+    /// Disables debug rendering of [broad phase pairs](BroadCollisionPairs).
+    pub fn without_broad_phase_pairs(mut self) -> Self {
+        self.broad_phase_pair_color = None;
+        self
+    }
+
     /// Disables collider debug rendering.
     pub fn without_colliders(mut self) -> Self {
         self.collider_color = None;
@@ -259,6 +393,7 @@ impl PhysicsDebugConfig {
     /// Disables contact debug rendering.
     pub fn without_contacts(mut self) -> Self {
         self.contact_color = None;
+        self.contact_normal_color = None;
         self
     }
 
@@ -266,6 +401,8 @@ impl PhysicsDebugConfig {
     pub fn without_joints(mut self) -> Self {
         self.joint_anchor_color = None;
         self.joint_separation_color = None;
+        self.joint_axis_color = None;
+        self.joint_limit_color = None;
         self
     }
 
@@ -287,9 +424,58 @@ impl PhysicsDebugConfig {
     }
 }
 
+/// The previous name of [`PhysicsGizmos`].
+#[deprecated(since = "0.3.0", note = "renamed to `PhysicsGizmos`")]
+pub type PhysicsDebugConfig = PhysicsGizmos;
+
+/// Determines which [colliders](Collider) are rendered by the [`PhysicsDebugPlugin`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColliderGizmoMode {
+    /// The colliders of all entities are rendered.
+    #[default]
+    All,
+    /// Only the colliders of entities with the [`GizmoSelected`] component are rendered,
+    /// along with the colliders attached to bodies with the component.
+    ///
+    /// This can be useful in large scenes where rendering every collider would be too cluttered or slow.
+    Selected,
+}
+
+/// A marker component for entities whose colliders are rendered when the [`ColliderGizmoMode`]
+/// of [`PhysicsGizmos`] is [`Selected`](ColliderGizmoMode::Selected).
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct GizmoSelected;
+
+/// Determines how the lengths of contact normals are computed in debug rendering.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum ContactGizmoScale {
+    /// Every contact normal has the same given length.
+    Constant(Scalar),
+    /// The length of each contact normal is the magnitude of the normal force
+    /// applied at the contact multiplied by the given scaling factor.
+    ///
+    /// The forces are read from the penetration constraints of the last substep,
+    /// so this requires the [`SolverPlugin`].
+    Scaled(Scalar),
+}
+
+impl Default for ContactGizmoScale {
+    fn default() -> Self {
+        #[cfg(feature = "2d")]
+        {
+            Self::Constant(20.0)
+        }
+        #[cfg(feature = "3d")]
+        {
+            Self::Constant(0.5)
+        }
+    }
+}
+
 /// A component for the debug render configuration of an entity.
 ///
-/// This overwrites the global [`PhysicsDebugConfig`] for this specific entity.
+/// This overwrites the global [`PhysicsGizmos`] for this specific entity.
 #[derive(Component, Reflect, Clone, Copy, PartialEq)]
 #[reflect(Component)]
 pub struct DebugRender {
@@ -302,6 +488,12 @@ pub struct DebugRender {
     /// If the entity is [sleeping](Sleeping), its colors (in HSLA) will be multiplied by this array.
     /// If `None`, sleeping will have no effect on the colors.
     pub sleeping_color_multiplier: Option<[f32; 4]>,
+    /// The color of the [collider](Collider) wireframe when the entity is [sleeping](Sleeping).
+    /// If `None`, the `collider_color` multiplied by the `sleeping_color_multiplier` will be used instead.
+    pub sleeping_collider_color: Option<Color>,
+    /// Determines if the entity's [`RayCaster`] and [`ShapeCaster`] should be rendered.
+    /// The colors are determined by the global [`PhysicsGizmos`].
+    pub spatial_queries: bool,
     /// Determines if the entity's visibility should be set to `Visibility::Hidden`, which will only show the debug render.
     pub hide_mesh: bool,
 }
@@ -316,6 +508,8 @@ impl Default for DebugRender {
             aabb_color: None,
             collider_color: Some(Color::ORANGE),
             sleeping_color_multiplier: Some([1.0, 1.0, 0.4, 1.0]),
+            sleeping_collider_color: None,
+            spatial_queries: true,
             hide_mesh: false,
         }
     }
@@ -332,17 +526,24 @@ impl DebugRender {
             aabb_color: Some(Color::rgb(0.8, 0.8, 0.8)),
             collider_color: Some(Color::ORANGE),
             sleeping_color_multiplier: Some([1.0, 1.0, 0.4, 1.0]),
+            sleeping_collider_color: None,
+            spatial_queries: true,
             hide_mesh: true,
         }
     }
 
     /// Disables all debug rendering for this entity.
+    ///
+    /// The entity's [`RayCaster`] and [`ShapeCaster`] are still rendered.
+    /// They can be hidden using [`DebugRender::with_spatial_queries`].
     pub fn none() -> Self {
         Self {
             axis_lengths: None,
             aabb_color: None,
             collider_color: None,
             sleeping_color_multiplier: None,
+            sleeping_collider_color: None,
+            spatial_queries: true,
             hide_mesh: false,
         }
     }
@@ -398,12 +599,24 @@ impl DebugRender {
         self
     }
 
+    /// Sets the collider color used for [sleeping](Sleeping) bodies.
+    pub fn with_sleeping_collider_color(mut self, color: Color) -> Self {
+        self.sleeping_collider_color = Some(color);
+        self
+    }
+
     /// Sets the visibility of the entity's visual mesh.
     pub fn with_mesh_visibility(mut self, is_visible: bool) -> Self {
         self.hide_mesh = !is_visible;
         self
     }
 
+    /// Sets whether the entity's [`RayCaster`] and [`ShapeCaster`] should be rendered.
+    pub fn with_spatial_queries(mut self, render: bool) -> Self {
+        self.spatial_queries = render;
+        self
+    }
+
     /// Disables axis debug rendering.
     pub fn without_axes(mut self) -> Self {
         self.axis_lengths = None;
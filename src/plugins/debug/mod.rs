@@ -17,15 +17,16 @@ use bevy::{ecs::query::Has, prelude::*};
 ///
 /// - Entity axes
 /// - [AABBs](ColliderAabb)
+/// - [Broad phase pairs](BroadCollisionPairs)
 /// - [Collider] wireframes
 /// - Use different colors for [sleeping](Sleeping) bodies
-/// - [Contacts]
-/// - [Joints](joints)
+/// - [Contacts]: contact points, normals and penetration depths
+/// - [Joints](joints): anchors, axes and limits
 /// - [`RayCaster`]
 /// - [`ShapeCaster`]
 /// - Changing the visibility of entities to only show debug rendering
 ///
-/// By default, only axes, colliders and joints are debug rendered. You can use the [`PhysicsDebugConfig`]
+/// By default, only axes, colliders and joints are debug rendered. You can use the [`PhysicsGizmos`]
 /// resource for the global configuration and the [`DebugRender`] component
 /// for entity-level configuration.
 pub struct PhysicsDebugPlugin {
@@ -51,7 +52,7 @@ impl Default for PhysicsDebugPlugin {
 
 impl Plugin for PhysicsDebugPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PhysicsDebugConfig>()
+        app.init_resource::<PhysicsGizmos>()
             .insert_resource(GizmoConfig {
                 #[cfg(feature = "2d")]
                 line_width: 2.0,
@@ -59,13 +60,15 @@ impl Plugin for PhysicsDebugPlugin {
                 line_width: 1.5,
                 ..default()
             })
-            .register_type::<PhysicsDebugConfig>()
+            .register_type::<PhysicsGizmos>()
             .register_type::<DebugRender>()
+            .register_type::<GizmoSelected>()
             .add_systems(
                 self.schedule.dyn_clone(),
                 (
                     debug_render_axes,
                     debug_render_aabbs,
+                    debug_render_broad_phase_pairs,
                     debug_render_colliders,
                     debug_render_contacts,
                     // TODO: Refactor joints to allow iterating over all of them without generics
@@ -74,12 +77,17 @@ impl Plugin for PhysicsDebugPlugin {
                     debug_render_joints::<DistanceJoint>,
                     debug_render_joints::<RevoluteJoint>,
                     debug_render_joints::<SphericalJoint>,
+                    debug_render_joints::<SpringJoint>,
+                    debug_render_revolute_joint_limits,
+                    debug_render_prismatic_joint_limits,
+                    #[cfg(feature = "3d")]
+                    debug_render_spherical_joint_limits,
                     debug_render_raycasts,
                     debug_render_shapecasts,
                     change_mesh_visibility,
                 )
                     .after(PhysicsSet::StepSimulation)
-                    .run_if(|config: Res<PhysicsDebugConfig>| config.enabled),
+                    .run_if(|config: Res<PhysicsGizmos>| config.enabled),
             );
     }
 }
@@ -94,7 +102,7 @@ fn debug_render_axes(
         Option<&DebugRender>,
     )>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
     for (pos, rot, local_com, sleeping, render_config) in &bodies {
         // If the body is sleeping, the colors will be multiplied by the sleeping color multiplier
@@ -144,7 +152,7 @@ fn debug_render_axes(
 fn debug_render_aabbs(
     aabbs: Query<(&ColliderAabb, Option<&DebugRender>, Has<Sleeping>)>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
     #[cfg(feature = "2d")]
     for (aabb, render_config, sleeping) in &aabbs {
@@ -189,43 +197,105 @@ fn debug_render_aabbs(
     }
 }
 
+fn debug_render_broad_phase_pairs(
+    aabbs: Query<&ColliderAabb>,
+    broad_collision_pairs: Res<BroadCollisionPairs>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsGizmos>,
+) {
+    let Some(color) = config.broad_phase_pair_color else {
+        return;
+    };
+    for (entity1, entity2) in broad_collision_pairs.0.iter() {
+        if let Ok([aabb1, aabb2]) = aabbs.get_many([*entity1, *entity2]) {
+            debug_renderer.draw_line(
+                Vector::from(aabb1.center()),
+                Vector::from(aabb2.center()),
+                color,
+            );
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn debug_render_colliders(
     mut colliders: Query<(
         &Collider,
         &Position,
         &Rotation,
+        Option<&ColliderParent>,
         Option<&DebugRender>,
         Has<Sleeping>,
+        Has<GizmoSelected>,
     )>,
+    sleeping_bodies: Query<(), With<Sleeping>>,
+    selected_bodies: Query<(), With<GizmoSelected>>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
-    for (collider, position, rotation, render_config, sleeping) in &mut colliders {
-        if let Some(mut color) = render_config.map_or(config.collider_color, |c| c.collider_color) {
-            // If the body is sleeping, multiply the color by the sleeping color multiplier
-            if sleeping {
-                let [h, s, l, a] = color.as_hsla_f32();
-                if let Some(mul) = render_config.map_or(config.sleeping_color_multiplier, |c| {
-                    c.sleeping_color_multiplier
-                }) {
-                    color = Color::hsla(h * mul[0], s * mul[1], l * mul[2], a * mul[3]);
-                }
-            }
+    for (collider, position, rotation, parent, render_config, sleeping, selected) in &mut colliders
+    {
+        // Child colliders don't have the `Sleeping` or `GizmoSelected` components,
+        // so we need to check the body instead
+        let selected = selected || parent.is_some_and(|p| selected_bodies.contains(p.get()));
+        let sleeping = sleeping || parent.is_some_and(|p| sleeping_bodies.contains(p.get()));
+
+        if let Some(color) = collider_gizmo_color(&config, render_config, selected, sleeping) {
             debug_renderer.draw_collider(collider, position, rotation, color);
         }
     }
 }
 
+/// Returns the color that a collider should be rendered with, or `None` if it shouldn't be rendered.
+///
+/// `selected` and `sleeping` should also be true if the body that the collider is attached to
+/// is [selected](GizmoSelected) or [sleeping](Sleeping).
+fn collider_gizmo_color(
+    config: &PhysicsGizmos,
+    render_config: Option<&DebugRender>,
+    selected: bool,
+    sleeping: bool,
+) -> Option<Color> {
+    if config.collider_mode == ColliderGizmoMode::Selected && !selected {
+        return None;
+    }
+
+    let mut color = render_config.map_or(config.collider_color, |c| c.collider_color)?;
+
+    if sleeping {
+        if let Some(sleeping_color) = render_config.map_or(config.sleeping_collider_color, |c| {
+            c.sleeping_collider_color
+        }) {
+            color = sleeping_color;
+        } else if let Some(mul) = render_config.map_or(config.sleeping_color_multiplier, |c| {
+            c.sleeping_color_multiplier
+        }) {
+            // Multiply the color by the sleeping color multiplier
+            let [h, s, l, a] = color.as_hsla_f32();
+            color = Color::hsla(h * mul[0], s * mul[1], l * mul[2], a * mul[3]);
+        }
+    }
+
+    Some(color)
+}
+
 fn debug_render_contacts(
     colliders: Query<(&Position, &Rotation), With<Collider>>,
+    bodies: Query<(&Position, &Rotation), With<RigidBody>>,
     mut collisions: EventReader<Collision>,
+    penetration_constraints: Option<Res<solver::PenetrationConstraints>>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
-    let Some(color) = config.contact_color else {
+    if config.contact_color.is_none() && config.contact_normal_color.is_none() {
         return;
-    };
+    }
+
+    #[cfg(feature = "2d")]
+    let (len, head_length, head_width) = (5.0, 8.0, 6.0);
+    #[cfg(feature = "3d")]
+    let (len, head_length, head_width) = (0.3, 0.1, 0.08);
+
     for Collision(contacts) in collisions.iter() {
         let Ok((position1, rotation1)) = colliders.get(contacts.entity1) else {
             continue;
@@ -238,20 +308,56 @@ fn debug_render_contacts(
             for contact in manifold.contacts.iter() {
                 let p1 = contact.global_point1(position1, rotation1);
                 let p2 = contact.global_point2(position2, rotation2);
-                #[cfg(feature = "2d")]
-                let len = 5.0;
-                #[cfg(feature = "3d")]
-                let len = 0.3;
 
-                debug_renderer.draw_line(p1 - Vector::X * len, p1 + Vector::X * len, color);
-                debug_renderer.draw_line(p1 - Vector::Y * len, p1 + Vector::Y * len, color);
-                #[cfg(feature = "3d")]
-                debug_renderer.draw_line(p1 - Vector::Z * len, p1 + Vector::Z * len, color);
+                if let Some(color) = config.contact_color {
+                    debug_renderer.draw_line(p1 - Vector::X * len, p1 + Vector::X * len, color);
+                    debug_renderer.draw_line(p1 - Vector::Y * len, p1 + Vector::Y * len, color);
+                    #[cfg(feature = "3d")]
+                    debug_renderer.draw_line(p1 - Vector::Z * len, p1 + Vector::Z * len, color);
 
-                debug_renderer.draw_line(p2 - Vector::X * len, p2 + Vector::X * len, color);
-                debug_renderer.draw_line(p2 - Vector::Y * len, p2 + Vector::Y * len, color);
-                #[cfg(feature = "3d")]
-                debug_renderer.draw_line(p2 - Vector::Z * len, p2 + Vector::Z * len, color);
+                    debug_renderer.draw_line(p2 - Vector::X * len, p2 + Vector::X * len, color);
+                    debug_renderer.draw_line(p2 - Vector::Y * len, p2 + Vector::Y * len, color);
+                    #[cfg(feature = "3d")]
+                    debug_renderer.draw_line(p2 - Vector::Z * len, p2 + Vector::Z * len, color);
+
+                    // The distance between the points is the penetration depth
+                    debug_renderer.draw_line(p1, p2, color);
+                }
+
+                if let (Some(color), ContactGizmoScale::Constant(length)) =
+                    (config.contact_normal_color, config.contact_normal_scale)
+                {
+                    let normal = contact.global_normal1(rotation1);
+                    debug_renderer.draw_arrow(
+                        p1,
+                        p1 + normal * length,
+                        head_length,
+                        head_width,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+
+    // Normals scaled by the normal force are drawn using the penetration constraints,
+    // as the collisions themselves don't store any forces.
+    if let (Some(color), ContactGizmoScale::Scaled(scale), Some(constraints)) = (
+        config.contact_normal_color,
+        config.contact_normal_scale,
+        penetration_constraints,
+    ) {
+        for constraint in constraints.0.iter() {
+            let Ok([(position1, rotation1), _]) = bodies.get_many(constraint.entities()) else {
+                continue;
+            };
+
+            let p1 = constraint.contact.global_point1(position1, rotation1);
+            let normal = constraint.contact.global_normal1(rotation1);
+            let length = constraint.normal_force.length() * scale;
+
+            if length > Scalar::EPSILON {
+                debug_renderer.draw_arrow(p1, p1 + normal * length, head_length, head_width, color);
             }
         }
     }
@@ -261,7 +367,7 @@ fn debug_render_joints<T: Joint>(
     bodies: Query<(&Position, &Rotation, Has<Sleeping>)>,
     joints: Query<(&T, Option<&DebugRender>)>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
     for (joint, render_config) in &joints {
         if let Ok([(pos1, rot1, sleeping1), (pos2, rot2, sleeping2)]) =
@@ -311,12 +417,199 @@ fn debug_render_joints<T: Joint>(
     }
 }
 
+/// The length of the lines and the radius of the arcs used for rendering joint axes and angle limits.
+#[cfg(feature = "2d")]
+const JOINT_GIZMO_LENGTH: Scalar = 20.0;
+/// The length of the lines and the radius of the arcs used for rendering joint axes and angle limits.
+#[cfg(feature = "3d")]
+const JOINT_GIZMO_LENGTH: Scalar = 0.5;
+
+fn debug_render_revolute_joint_limits(
+    bodies: Query<(&Position, &Rotation)>,
+    joints: Query<&RevoluteJoint>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsGizmos>,
+) {
+    if config.joint_axis_color.is_none() && config.joint_limit_color.is_none() {
+        return;
+    }
+
+    for joint in &joints {
+        let Ok([(pos1, rot1), (pos2, rot2)]) = bodies.get_many(joint.entities()) else {
+            continue;
+        };
+        let anchor1 = pos1.0 + rot1.rotate(joint.local_anchor1);
+
+        // The angle limits are measured between these axes of the bodies
+        #[cfg(feature = "2d")]
+        let (limit_axis1, limit_axis2) = (rot1.rotate(Vector::X), rot2.rotate(Vector::X));
+        #[cfg(feature = "3d")]
+        let (aligned_axis, limit_axis1, limit_axis2) = {
+            let axis = joint.aligned_axis;
+            let limit_axis = Vector::new(axis.z, axis.x, axis.y);
+            (
+                rot1.rotate(axis),
+                rot1.rotate(limit_axis),
+                rot2.rotate(limit_axis),
+            )
+        };
+
+        if let Some(color) = config.joint_axis_color {
+            #[cfg(feature = "3d")]
+            debug_renderer.draw_line(
+                anchor1 - aligned_axis * JOINT_GIZMO_LENGTH,
+                anchor1 + aligned_axis * JOINT_GIZMO_LENGTH,
+                color,
+            );
+            // Draw the current relative rotation of the second body
+            debug_renderer.draw_line(anchor1, anchor1 + limit_axis2 * JOINT_GIZMO_LENGTH, color);
+        }
+
+        if let (Some(color), Some(angle_limit)) = (config.joint_limit_color, joint.angle_limit) {
+            let from = limit_axis1;
+            #[cfg(feature = "2d")]
+            let (min, max) = (
+                Rotation::from_radians(angle_limit.alpha).rotate(from),
+                Rotation::from_radians(angle_limit.beta).rotate(from),
+            );
+            #[cfg(feature = "3d")]
+            let (min, max) = (
+                Quaternion::from_axis_angle(aligned_axis, angle_limit.alpha) * from,
+                Quaternion::from_axis_angle(aligned_axis, angle_limit.beta) * from,
+            );
+
+            debug_renderer.draw_line(anchor1, anchor1 + min * JOINT_GIZMO_LENGTH, color);
+            debug_renderer.draw_line(anchor1, anchor1 + max * JOINT_GIZMO_LENGTH, color);
+            debug_renderer.draw_arc(
+                anchor1,
+                #[cfg(feature = "3d")]
+                aligned_axis,
+                from,
+                angle_limit.alpha,
+                angle_limit.beta,
+                JOINT_GIZMO_LENGTH,
+                color,
+            );
+        }
+    }
+}
+
+fn debug_render_prismatic_joint_limits(
+    bodies: Query<(&Position, &Rotation)>,
+    joints: Query<&PrismaticJoint>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsGizmos>,
+) {
+    if config.joint_axis_color.is_none() && config.joint_limit_color.is_none() {
+        return;
+    }
+
+    for joint in &joints {
+        let Ok((pos1, rot1)) = bodies.get(joint.entity1) else {
+            continue;
+        };
+        let anchor1 = pos1.0 + rot1.rotate(joint.local_anchor1);
+        let free_axis = rot1.rotate(joint.free_axis);
+
+        if let Some(color) = config.joint_axis_color {
+            debug_renderer.draw_line(
+                anchor1 - free_axis * JOINT_GIZMO_LENGTH,
+                anchor1 + free_axis * JOINT_GIZMO_LENGTH,
+                color,
+            );
+        }
+
+        if let (Some(color), Some(limits)) = (config.joint_limit_color, joint.free_axis_limits) {
+            let min = anchor1 + free_axis * limits.min;
+            let max = anchor1 + free_axis * limits.max;
+            debug_renderer.draw_line(min, max, color);
+
+            // Draw short perpendicular lines at the ends of the allowed range
+            #[cfg(feature = "2d")]
+            let perpendicular = Vector::new(-free_axis.y, free_axis.x);
+            #[cfg(feature = "3d")]
+            let perpendicular = free_axis.any_orthonormal_vector();
+            let tick = perpendicular * JOINT_GIZMO_LENGTH * 0.25;
+            debug_renderer.draw_line(min - tick, min + tick, color);
+            debug_renderer.draw_line(max - tick, max + tick, color);
+        }
+    }
+}
+
+#[cfg(feature = "3d")]
+fn debug_render_spherical_joint_limits(
+    bodies: Query<(&Position, &Rotation)>,
+    joints: Query<&SphericalJoint>,
+    mut debug_renderer: PhysicsDebugRenderer,
+    config: Res<PhysicsGizmos>,
+) {
+    if config.joint_axis_color.is_none() && config.joint_limit_color.is_none() {
+        return;
+    }
+
+    for joint in &joints {
+        let Ok((pos1, rot1)) = bodies.get(joint.entity1) else {
+            continue;
+        };
+        let anchor1 = pos1.0 + rot1.rotate(joint.local_anchor1);
+        let swing_axis = rot1.rotate(joint.swing_axis);
+        let twist_axis = rot1.rotate(joint.twist_axis);
+
+        if let Some(color) = config.joint_axis_color {
+            debug_renderer.draw_line(anchor1, anchor1 + swing_axis * JOINT_GIZMO_LENGTH, color);
+            debug_renderer.draw_line(anchor1, anchor1 + twist_axis * JOINT_GIZMO_LENGTH, color);
+        }
+
+        let Some(color) = config.joint_limit_color else {
+            continue;
+        };
+
+        // The swing limit is drawn as a cone around the swing axis
+        if let Some(swing_limit) = joint.swing_limit {
+            let tilt_axis = swing_axis.any_orthonormal_vector();
+            let rim_direction =
+                Quaternion::from_axis_angle(tilt_axis, swing_limit.beta) * swing_axis;
+            debug_renderer.draw_arc(
+                anchor1,
+                swing_axis,
+                rim_direction,
+                0.0,
+                2.0 * PI,
+                JOINT_GIZMO_LENGTH,
+                color,
+            );
+            for i in 0..4 {
+                let angle = i as Scalar * PI * 0.5;
+                let direction = Quaternion::from_axis_angle(swing_axis, angle) * rim_direction;
+                debug_renderer.draw_line(anchor1, anchor1 + direction * JOINT_GIZMO_LENGTH, color);
+            }
+        }
+
+        // The twist limit is drawn as an arc around the swing axis
+        if let Some(twist_limit) = joint.twist_limit {
+            debug_renderer.draw_arc(
+                anchor1,
+                swing_axis,
+                twist_axis,
+                twist_limit.alpha,
+                twist_limit.beta,
+                JOINT_GIZMO_LENGTH,
+                color,
+            );
+        }
+    }
+}
+
 fn debug_render_raycasts(
-    query: Query<(&RayCaster, &RayHits)>,
+    query: Query<(&RayCaster, &RayHits, Option<&DebugRender>)>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
-    for (ray, hits) in &query {
+    for (ray, hits, render_config) in &query {
+        if !ray.enabled || render_config.is_some_and(|c| !c.spatial_queries) {
+            continue;
+        }
+
         let ray_color = config
             .raycast_color
             .unwrap_or(Color::rgba(0.0, 0.0, 0.0, 0.0));
@@ -341,11 +634,15 @@ fn debug_render_raycasts(
 }
 
 fn debug_render_shapecasts(
-    query: Query<(&ShapeCaster, &ShapeHits)>,
+    query: Query<(&ShapeCaster, &ShapeHits, Option<&DebugRender>)>,
     mut debug_renderer: PhysicsDebugRenderer,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
-    for (shape_caster, hits) in &query {
+    for (shape_caster, hits, render_config) in &query {
+        if !shape_caster.enabled || render_config.is_some_and(|c| !c.spatial_queries) {
+            continue;
+        }
+
         let ray_color = config
             .shapecast_color
             .unwrap_or(Color::rgba(0.0, 0.0, 0.0, 0.0));
@@ -382,7 +679,7 @@ type MeshVisibilityQueryFilter = (
 
 fn change_mesh_visibility(
     mut meshes: Query<(&mut Visibility, Option<&DebugRender>), MeshVisibilityQueryFilter>,
-    config: Res<PhysicsDebugConfig>,
+    config: Res<PhysicsGizmos>,
 ) {
     if config.is_changed() {
         for (mut visibility, render_config) in &mut meshes {
@@ -395,3 +692,374 @@ fn change_mesh_visibility(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RecordedDebugLines;
+    use crate::{
+        prelude::*,
+        tests::{create_app, tick_60_fps},
+    };
+    use approx::assert_relative_eq;
+    use bevy::{
+        asset::AssetPlugin, gizmos::GizmoPlugin, prelude::*, render::render_resource::Shader,
+    };
+
+    /// Creates an app with the [`PhysicsDebugPlugin`] that uses the given configuration.
+    ///
+    /// The lines drawn by the debug renderer are recorded, see [`take_debug_lines`].
+    fn create_debug_app(config: PhysicsGizmos) -> App {
+        let mut app = create_app();
+        app.add_plugins(AssetPlugin::default());
+        // The gizmo plugin loads its shader
+        app.add_asset::<Shader>();
+        app.add_plugins((GizmoPlugin, PhysicsDebugPlugin::default()));
+        app.insert_resource(config)
+            .init_resource::<RecordedDebugLines>();
+        app
+    }
+
+    /// Returns the start and end points and the colors of the lines
+    /// drawn by the debug renderer since the last call.
+    fn take_debug_lines(app: &mut App) -> Vec<(Vector, Vector, Color)> {
+        std::mem::take(&mut app.world.resource_mut::<RecordedDebugLines>().0)
+    }
+
+    #[test]
+    fn sleeping_colliders_use_sleeping_collider_color() {
+        let mut app = create_debug_app(
+            PhysicsGizmos::none()
+                .with_collider_color(Color::RED)
+                .with_sleeping_collider_color(Color::GRAY),
+        );
+        app.insert_resource(Gravity::ZERO);
+
+        let mut spawn_body = |x: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from_transform(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::capsule(1.0, 0.5),
+                ))
+                .id()
+        };
+        let awake_body = spawn_body(0.0);
+        let sleeping_body = spawn_body(10.0);
+        // `DebugRender` overrides the global configuration
+        let configured_body = spawn_body(20.0);
+        app.world.entity_mut(awake_body).insert(SleepingDisabled);
+        app.world.entity_mut(configured_body).insert(
+            DebugRender::default()
+                .with_collider_color(Color::BLUE)
+                .with_sleeping_collider_color(Color::BLACK),
+        );
+
+        for _ in 0..600 {
+            tick_60_fps(&mut app);
+            if app.world.entity(sleeping_body).contains::<Sleeping>() {
+                break;
+            }
+        }
+        assert!(app.world.entity(sleeping_body).contains::<Sleeping>());
+        assert!(app.world.entity(configured_body).contains::<Sleeping>());
+
+        // Returns the colors of the lines drawn for the collider at the given x coordinate, without duplicates
+        let collider_colors = |lines: &[(Vector, Vector, Color)], x: Scalar| {
+            let center = Vector::X * x;
+            let mut colors: Vec<Color> = lines
+                .iter()
+                .filter(|(a, b, _)| a.distance(center) < 2.0 && b.distance(center) < 2.0)
+                .map(|(.., color)| *color)
+                .collect();
+            colors.dedup();
+            colors
+        };
+
+        take_debug_lines(&mut app);
+        tick_60_fps(&mut app);
+        let lines = take_debug_lines(&mut app);
+        assert_eq!(collider_colors(&lines, 0.0), vec![Color::RED]);
+        assert_eq!(collider_colors(&lines, 10.0), vec![Color::GRAY]);
+        assert_eq!(collider_colors(&lines, 20.0), vec![Color::BLACK]);
+
+        // Without a sleeping collider color, the color is multiplied by the sleeping color multiplier
+        *app.world.resource_mut::<PhysicsGizmos>() = PhysicsGizmos::none()
+            .with_collider_color(Color::RED)
+            .with_sleeping_color_multiplier([1.0, 1.0, 0.5, 1.0]);
+        let [h, s, l, a] = Color::RED.as_hsla_f32();
+        let dimmed_red = Color::hsla(h, s, l * 0.5, a);
+
+        tick_60_fps(&mut app);
+        let lines = take_debug_lines(&mut app);
+        assert_eq!(collider_colors(&lines, 0.0), vec![Color::RED]);
+        assert_eq!(collider_colors(&lines, 10.0), vec![dimmed_red]);
+        assert_eq!(collider_colors(&lines, 20.0), vec![Color::BLACK]);
+    }
+
+    #[test]
+    fn spatial_query_debug_rendering_can_be_disabled_per_entity() {
+        let ray_color = Color::RED;
+        let mut app = create_debug_app(PhysicsGizmos::none().with_raycast_colors(
+            Some(ray_color),
+            None,
+            None,
+        ));
+
+        let mut spawn_ray = |x: Scalar| {
+            let origin = Vector::X * x;
+            let entity = app.world.spawn(RayCaster::new(origin, Vector::Y)).id();
+            (entity, origin)
+        };
+        let default_ray = spawn_ray(0.0);
+        let configured_ray = spawn_ray(10.0);
+        // `DebugRender::none` doesn't hide spatial queries
+        let ray_with_debug_render_none = spawn_ray(20.0);
+        let hidden_ray = spawn_ray(30.0);
+        let disabled_ray = spawn_ray(40.0);
+        app.world
+            .entity_mut(configured_ray.0)
+            .insert(DebugRender::default());
+        app.world
+            .entity_mut(ray_with_debug_render_none.0)
+            .insert(DebugRender::none());
+        app.world
+            .entity_mut(hidden_ray.0)
+            .insert(DebugRender::default().with_spatial_queries(false));
+        app.world
+            .entity_mut(disabled_ray.0)
+            .get_mut::<RayCaster>()
+            .unwrap()
+            .disable();
+
+        tick_60_fps(&mut app);
+
+        // Each rendered ray is drawn as an arrow that starts at its origin
+        let lines = take_debug_lines(&mut app);
+        let is_rendered = |(_, origin): (Entity, Vector)| {
+            lines
+                .iter()
+                .any(|(a, _, color)| *a == origin && *color == ray_color)
+        };
+        assert!(is_rendered(default_ray));
+        assert!(is_rendered(configured_ray));
+        assert!(is_rendered(ray_with_debug_render_none));
+        assert!(!is_rendered(hidden_ray));
+
+        // Disabled spatial queries are never rendered
+        assert!(!is_rendered(disabled_ray));
+    }
+
+    #[test]
+    fn selected_collider_gizmo_mode_only_renders_selected_colliders() {
+        let mut app = create_debug_app(PhysicsGizmos::none().with_collider_color(Color::RED));
+        app.insert_resource(Gravity::ZERO);
+
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Dynamic,
+            Collider::capsule(1.0, 0.5),
+            GizmoSelected,
+        ));
+        app.world.spawn((
+            TransformBundle::from_transform(Transform::from_xyz(10.0, 0.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::capsule(1.0, 0.5),
+        ));
+        // The child collider of a selected body is rendered even though it doesn't have `GizmoSelected`
+        app.world
+            .spawn((
+                TransformBundle::from_transform(Transform::from_xyz(20.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                GizmoSelected,
+            ))
+            .with_children(|children| {
+                children.spawn((TransformBundle::default(), Collider::capsule(1.0, 0.5)));
+            });
+
+        // Returns `true` if lines were drawn for the collider at the given x coordinate
+        let is_rendered = |lines: &[(Vector, Vector, Color)], x: Scalar| {
+            let center = Vector::X * x;
+            lines
+                .iter()
+                .any(|(a, b, _)| a.distance(center) < 2.0 && b.distance(center) < 2.0)
+        };
+
+        tick_60_fps(&mut app);
+
+        let lines = take_debug_lines(&mut app);
+        assert!(is_rendered(&lines, 0.0));
+        assert!(is_rendered(&lines, 10.0));
+        assert!(is_rendered(&lines, 20.0));
+
+        app.world.resource_mut::<PhysicsGizmos>().collider_mode = ColliderGizmoMode::Selected;
+
+        tick_60_fps(&mut app);
+
+        let lines = take_debug_lines(&mut app);
+        assert!(is_rendered(&lines, 0.0));
+        assert!(!is_rendered(&lines, 10.0));
+        assert!(is_rendered(&lines, 20.0));
+    }
+
+    #[test]
+    fn joint_axes_and_limits_are_only_rendered_when_enabled() {
+        let mut app = create_debug_app(PhysicsGizmos::none());
+
+        let body1 = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Static))
+            .id();
+        let body2 = app
+            .world
+            .spawn((
+                TransformBundle::from_transform(Transform::from_xyz(1.0, 0.0, 0.0)),
+                RigidBody::Static,
+            ))
+            .id();
+        let (alpha, beta) = (-PI / 4.0, PI / 2.0);
+        app.world
+            .spawn(RevoluteJoint::new(body1, body2).with_angle_limits(alpha, beta));
+
+        tick_60_fps(&mut app);
+
+        assert!(take_debug_lines(&mut app).is_empty());
+
+        let (axis_color, limit_color) = (Color::GREEN, Color::RED);
+        *app.world.resource_mut::<PhysicsGizmos>() = PhysicsGizmos::none()
+            .with_joint_axis_color(axis_color)
+            .with_joint_limit_color(limit_color);
+
+        tick_60_fps(&mut app);
+
+        let lines = take_debug_lines(&mut app);
+        let limit_lines: Vec<_> = lines
+            .iter()
+            .filter(|(.., color)| *color == limit_color)
+            .collect();
+        let axis_lines: Vec<_> = lines
+            .iter()
+            .filter(|(.., color)| *color == axis_color)
+            .collect();
+
+        // The angle limits are measured from the X axis of the first body around the Z axis
+        #[cfg(feature = "2d")]
+        let rotate = |angle: Scalar| Rotation::from_radians(angle).rotate(Vector::X);
+        #[cfg(feature = "3d")]
+        let rotate = |angle: Scalar| Quaternion::from_axis_angle(Vector::Z, angle) * Vector::X;
+
+        // The limits are drawn as lines from the anchor, followed by an arc between them
+        assert!(limit_lines.len() > 3);
+        let (min_line, max_line, arc) = (limit_lines[0], limit_lines[1], &limit_lines[2..]);
+        let radius = min_line.1.length();
+        assert_eq!(min_line.0, Vector::ZERO);
+        assert_eq!(max_line.0, Vector::ZERO);
+        assert_relative_eq!(min_line.1, rotate(alpha) * radius, epsilon = 1e-5);
+        assert_relative_eq!(max_line.1, rotate(beta) * radius, epsilon = 1e-5);
+
+        // The arc should start and end at the limits, and every point should be on the circle
+        assert_relative_eq!(arc[0].0, rotate(alpha) * radius, epsilon = 1e-5);
+        assert_relative_eq!(arc[arc.len() - 1].1, rotate(beta) * radius, epsilon = 1e-5);
+        for (a, b, _) in arc {
+            assert_relative_eq!(a.length(), radius, epsilon = 1e-5);
+            assert_relative_eq!(b.length(), radius, epsilon = 1e-5);
+        }
+
+        // The current rotation of the second body is drawn as a line from the anchor.
+        // In 3D, the aligned axis is also drawn.
+        #[cfg(feature = "2d")]
+        assert_eq!(axis_lines.len(), 1);
+        #[cfg(feature = "3d")]
+        assert_eq!(axis_lines.len(), 2);
+        let rotation_line = axis_lines[axis_lines.len() - 1];
+        assert_eq!(rotation_line.0, Vector::ZERO);
+        assert_relative_eq!(rotation_line.1, Vector::X * radius, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn contact_points_and_normals_are_rendered() {
+        let (contact_color, normal_color) = (Color::YELLOW, Color::RED);
+        let mut app = create_debug_app(
+            PhysicsGizmos::none()
+                .with_contact_color(contact_color)
+                .with_contact_normal_color(normal_color)
+                .with_contact_normal_scale(ContactGizmoScale::Constant(1.0)),
+        );
+        app.insert_resource(Gravity::ZERO);
+
+        // The sensor isn't pushed apart from the ball, so the balls keep overlapping by 0.2
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Static,
+            Collider::ball(0.5),
+            Sensor,
+        ));
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 0.8),
+            Collider::ball(0.5),
+        ));
+
+        tick_60_fps(&mut app);
+        take_debug_lines(&mut app);
+        tick_60_fps(&mut app);
+
+        let lines = take_debug_lines(&mut app);
+        let lines_with_color = |color: Color| {
+            lines
+                .iter()
+                .filter(move |(.., line_color)| *line_color == color)
+                .map(|(a, b, _)| (*a, *b))
+        };
+
+        // The line between the contact points has the length of the penetration depth
+        let penetration_lines: Vec<_> = lines_with_color(contact_color)
+            .filter(|(a, b)| a.distance(*b) < 0.5)
+            .collect();
+        assert!(!penetration_lines.is_empty());
+        let (p1, p2) = penetration_lines[0];
+        assert_relative_eq!(p1.distance(p2), 0.2, epsilon = 1e-5);
+
+        // The normal starts at the first contact point and points along the X axis
+        let normal = lines_with_color(normal_color)
+            .find(|(a, _)| *a == p1)
+            .expect("no contact normal was rendered");
+        assert_relative_eq!((normal.1 - normal.0).x.abs(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn broad_phase_pairs_are_rendered_between_aabb_centers() {
+        let pair_color = Color::YELLOW;
+        let mut app =
+            create_debug_app(PhysicsGizmos::none().with_broad_phase_pair_color(pair_color));
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(BroadPhaseConfig {
+            aabb_padding: 0.2,
+            ..default()
+        });
+
+        // The padded AABBs of the first two balls overlap, but the third ball is far away
+        for x in [-0.6, 0.6, 10.0] {
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * x),
+                Collider::ball(0.5),
+            ));
+        }
+
+        tick_60_fps(&mut app);
+        take_debug_lines(&mut app);
+        tick_60_fps(&mut app);
+
+        let lines = take_debug_lines(&mut app);
+        let pair_lines: Vec<_> = lines
+            .iter()
+            .filter(|(.., color)| *color == pair_color)
+            .collect();
+        assert_eq!(pair_lines.len(), 1);
+        let (a, b, _) = pair_lines[0];
+        assert_relative_eq!(a.x.min(b.x), -0.6, epsilon = 1e-5);
+        assert_relative_eq!(a.x.max(b.x), 0.6, epsilon = 1e-5);
+    }
+}
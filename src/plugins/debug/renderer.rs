@@ -1,5 +1,3 @@
-use std::marker::PhantomData;
-
 use crate::prelude::*;
 use bevy::{ecs::system::SystemParam, prelude::*};
 use parry::shape::{SharedShape, TypedShape};
@@ -10,12 +8,28 @@ use parry::shape::{SharedShape, TypedShape};
 pub struct PhysicsDebugRenderer<'w, 's> {
     /// A `SystemParam` for drawing lines and shapes using bevy_gizmos.
     pub gizmos: Gizmos<'s>,
-    phantom_data: PhantomData<&'w ()>,
+    recorded_lines: LineRecorder<'w>,
 }
 
+#[cfg(not(test))]
+type LineRecorder<'w> = std::marker::PhantomData<&'w ()>;
+#[cfg(test)]
+type LineRecorder<'w> = Option<ResMut<'w, RecordedDebugLines>>;
+
+/// Stores the lines drawn by [`PhysicsDebugRenderer`] so that tests can inspect them,
+/// since the lines stored by bevy_gizmos can't be accessed.
+#[cfg(test)]
+#[derive(Resource, Default)]
+pub(crate) struct RecordedDebugLines(pub Vec<(Vector, Vector, Color)>);
+
 impl<'w, 's> PhysicsDebugRenderer<'w, 's> {
     /// Draws a line from `a` to `b`.
     pub fn draw_line(&mut self, a: Vector, b: Vector, color: Color) {
+        #[cfg(test)]
+        if let Some(recorded_lines) = &mut self.recorded_lines {
+            recorded_lines.0.push((a, b, color));
+        }
+
         #[cfg(feature = "2d")]
         self.gizmos.line_2d(a.as_f32(), b.as_f32(), color);
         #[cfg(feature = "3d")]
@@ -31,6 +45,17 @@ impl<'w, 's> PhysicsDebugRenderer<'w, 's> {
         closed: bool,
         color: Color,
     ) {
+        #[cfg(test)]
+        if let Some(recorded_lines) = &mut self.recorded_lines {
+            recorded_lines.0.extend(points.windows(2).map(|segment| {
+                (
+                    position.0 + rotation.rotate(segment[0]),
+                    position.0 + rotation.rotate(segment[1]),
+                    color,
+                )
+            }));
+        }
+
         let pos = position.as_f32();
         #[cfg(feature = "2d")]
         self.gizmos.linestrip_2d(
@@ -107,6 +132,39 @@ impl<'w, 's> PhysicsDebugRenderer<'w, 's> {
         }
     }
 
+    /// Draws an arc with the given `center` and `radius`, starting at the angle `min_angle`
+    /// and ending at the angle `max_angle`. The angles are measured from the unit vector `from`.
+    ///
+    /// In 3D, the arc is drawn around the given unit `axis`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_arc(
+        &mut self,
+        center: Vector,
+        #[cfg(feature = "3d")] axis: Vector,
+        from: Vector,
+        min_angle: Scalar,
+        max_angle: Scalar,
+        radius: Scalar,
+        color: Color,
+    ) {
+        let mut points = arc_points(
+            center,
+            #[cfg(feature = "3d")]
+            axis,
+            from,
+            min_angle,
+            max_angle,
+            radius,
+        );
+        let Some(mut previous) = points.next() else {
+            return;
+        };
+        for next in points {
+            self.draw_line(previous, next, color);
+            previous = next;
+        }
+    }
+
     /// Draws a collider shape with a given position and rotation.
     #[allow(clippy::unnecessary_cast)]
     pub fn draw_collider(
@@ -497,3 +555,30 @@ impl<'w, 's> PhysicsDebugRenderer<'w, 's> {
         }
     }
 }
+
+/// Returns the points of an arc with the given `center` and `radius`, starting at the angle `min_angle`
+/// and ending at the angle `max_angle`. The angles are measured from the unit vector `from`.
+///
+/// In 3D, the arc is around the given unit `axis`.
+fn arc_points(
+    center: Vector,
+    #[cfg(feature = "3d")] axis: Vector,
+    from: Vector,
+    min_angle: Scalar,
+    max_angle: Scalar,
+    radius: Scalar,
+) -> impl Iterator<Item = Vector> {
+    let segments = ((max_angle - min_angle).abs() / (PI / 16.0))
+        .ceil()
+        .max(1.0) as usize;
+    let step = (max_angle - min_angle) / segments as Scalar;
+
+    (0..=segments).map(move |i| {
+        let angle = min_angle + step * i as Scalar;
+        #[cfg(feature = "2d")]
+        let direction = Rotation::from_radians(angle).rotate(from);
+        #[cfg(feature = "3d")]
+        let direction = Quaternion::from_axis_angle(axis, angle) * from;
+        center + direction * radius
+    })
+}
@@ -0,0 +1,269 @@
+//! Moves kinematic bodies along paths.
+//!
+//! See [`FollowPathPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Moves [kinematic](RigidBody::Kinematic) bodies with the [`FollowPath`] component along their paths.
+///
+/// Instead of teleporting the bodies, the plugin sets their [`LinearVelocity`] at the start of each
+/// physics step so that they reach the next point on the path by the end of the step. This way,
+/// bodies riding on moving platforms and contacts with the moving bodies behave correctly.
+///
+/// The velocities are computed before [`PhysicsStepSet::BroadPhase`].
+pub struct FollowPathPlugin;
+
+impl Plugin for FollowPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FollowPath>()
+            .register_type::<PathMode>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(follow_paths.before(PhysicsStepSet::BroadPhase));
+    }
+}
+
+/// Moves a [kinematic](RigidBody::Kinematic) body along a sequence of waypoints at a given speed.
+/// Requires the [`FollowPathPlugin`].
+///
+/// Curves can be followed by sampling them into waypoints using [`FollowPath::from_curve`].
+/// What happens at the end of the path is determined by the [`PathMode`].
+///
+/// If the body isn't on the path, it moves onto it during the first physics step.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // An elevator moving up and down
+///     commands.spawn((
+///         RigidBody::Kinematic,
+///         Collider::ball(1.0),
+///         FollowPath::new(vec![Vector::ZERO, Vector::Y * 10.0], 2.0).with_mode(PathMode::PingPong),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct FollowPath {
+    /// The points that the body moves through in world space.
+    pub waypoints: Vec<Vector>,
+    /// The speed at which the body moves along the path.
+    pub speed: Scalar,
+    /// What happens when the body reaches the end of the path.
+    pub mode: PathMode,
+    /// The distance that the body has travelled along the path from the first waypoint.
+    pub distance: Scalar,
+    /// True if the body is moving from the last waypoint towards the first one in [`PathMode::PingPong`].
+    pub reversed: bool,
+    /// True if the body is paused and not moving along the path.
+    pub paused: bool,
+}
+
+impl FollowPath {
+    /// Creates a new [`FollowPath`] moving through the given waypoints at the given speed.
+    pub fn new(waypoints: Vec<Vector>, speed: Scalar) -> Self {
+        Self {
+            waypoints,
+            speed,
+            mode: PathMode::default(),
+            distance: 0.0,
+            reversed: false,
+            paused: false,
+        }
+    }
+
+    /// Creates a new [`FollowPath`] by sampling the given curve at `samples` evenly spaced parameter values
+    /// between `0.0` and `1.0`.
+    pub fn from_curve(curve: impl Fn(Scalar) -> Vector, samples: usize, speed: Scalar) -> Self {
+        let samples = samples.max(2);
+        let waypoints = (0..samples)
+            .map(|i| curve(i as Scalar / (samples - 1) as Scalar))
+            .collect();
+        Self::new(waypoints, speed)
+    }
+
+    /// Sets the [`PathMode`].
+    pub fn with_mode(self, mode: PathMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Returns the total length of the path. In [`PathMode::Loop`], this includes the segment
+    /// from the last waypoint back to the first one.
+    pub fn length(&self) -> Scalar {
+        self.segments()
+            .map(|(start, end)| start.distance(end))
+            .sum()
+    }
+
+    /// Returns the point at the given distance along the path.
+    pub fn point_at(&self, distance: Scalar) -> Vector {
+        let mut remaining = distance.max(0.0);
+
+        for (start, end) in self.segments() {
+            let segment_length = start.distance(end);
+            if remaining <= segment_length && segment_length > 0.0 {
+                return start.lerp(end, remaining / segment_length);
+            }
+            remaining -= segment_length;
+        }
+
+        match self.mode {
+            PathMode::Loop => self.waypoints.first(),
+            _ => self.waypoints.last(),
+        }
+        .copied()
+        .unwrap_or_default()
+    }
+
+    /// Returns true if the body has reached the end of the path in [`PathMode::Once`].
+    pub fn is_finished(&self) -> bool {
+        self.mode == PathMode::Once && self.distance >= self.length()
+    }
+
+    /// Advances the distance along the path by `delta`, handling the end of the path according to the [`PathMode`].
+    pub fn advance(&mut self, delta: Scalar) {
+        let length = self.length();
+        if length <= 0.0 {
+            return;
+        }
+
+        match self.mode {
+            PathMode::Once => self.distance = (self.distance + delta).min(length),
+            PathMode::Loop => self.distance = (self.distance + delta).rem_euclid(length),
+            PathMode::PingPong => {
+                let mut distance = if self.reversed {
+                    self.distance - delta
+                } else {
+                    self.distance + delta
+                };
+
+                // Bounce back from the ends of the path
+                while distance < 0.0 || distance > length {
+                    if distance > length {
+                        distance = 2.0 * length - distance;
+                        self.reversed = true;
+                    } else {
+                        distance = -distance;
+                        self.reversed = false;
+                    }
+                }
+                self.distance = distance;
+            }
+        }
+    }
+
+    fn segments(&self) -> impl Iterator<Item = (Vector, Vector)> + '_ {
+        let closing_segment = match (self.mode, self.waypoints.first(), self.waypoints.last()) {
+            (PathMode::Loop, Some(first), Some(last)) if self.waypoints.len() > 1 => {
+                Some((*last, *first))
+            }
+            _ => None,
+        };
+
+        self.waypoints
+            .windows(2)
+            .map(|points| (points[0], points[1]))
+            .chain(closing_segment)
+    }
+}
+
+/// Determines what happens when a body following a [`FollowPath`] reaches the end of the path.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PathMode {
+    /// The body stops at the last waypoint.
+    #[default]
+    Once,
+    /// The body moves from the last waypoint back to the first one and starts over.
+    Loop,
+    /// The body moves back and forth between the first and last waypoints.
+    PingPong,
+}
+
+/// Advances bodies along their paths and sets their velocities so that they reach their new positions by the end of the step.
+fn follow_paths(
+    mut bodies: Query<(&RigidBody, &mut FollowPath, &Position, &mut LinearVelocity)>,
+    dt: Res<DeltaTime>,
+) {
+    if dt.0 <= 0.0 {
+        return;
+    }
+
+    for (rb, mut path, position, mut lin_vel) in &mut bodies {
+        if !rb.is_kinematic() {
+            continue;
+        }
+
+        let velocity = if path.paused || path.waypoints.is_empty() {
+            Vector::ZERO
+        } else {
+            let delta = path.speed * dt.0;
+            path.advance(delta);
+            (path.point_at(path.distance) - position.0) / dt.0
+        };
+
+        // Avoid triggering change detection unnecessarily
+        if lin_vel.0 != velocity {
+            lin_vel.0 = velocity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn kinematic_bodies_follow_paths() {
+        let mut app = create_app();
+        app.add_plugins(FollowPathPlugin);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Kinematic,
+                Collider::ball(0.5),
+                FollowPath::new(vec![Vector::ZERO, Vector::X * 5.0, Vector::X * 10.0], 5.0),
+            ))
+            .id();
+
+        // Let the first step start the movement
+        app.step_physics(1);
+        let start = app.world.get::<Position>(body).unwrap().x;
+
+        app.step_physics(60);
+
+        let position = app.world.get::<Position>(body).unwrap().0;
+        assert_relative_eq!(position.x - start, 5.0, epsilon = 0.01);
+        assert_relative_eq!(
+            app.world.get::<LinearVelocity>(body).unwrap().x,
+            5.0,
+            epsilon = 0.01
+        );
+
+        app.step_physics(120);
+
+        // The body stops at the end of the path
+        assert!(app.world.get::<FollowPath>(body).unwrap().is_finished());
+        assert_relative_eq!(
+            app.world.get::<Position>(body).unwrap().x,
+            10.0,
+            epsilon = 0.01
+        );
+        assert_eq!(
+            app.world.get::<LinearVelocity>(body).unwrap().0,
+            Vector::ZERO
+        );
+    }
+}
@@ -10,16 +10,33 @@ use bevy::prelude::*;
 /// This acts as a prediction for the next positions and orientations of the bodies. The [solver] corrects these predicted
 /// positions to follow the rules set by the [constraints].
 ///
-/// The integration scheme used is very closely related to implicit Euler integration.
+/// By default, the integration scheme used is [semi-implicit Euler](SemiImplicitEuler) integration, which is very closely related to implicit Euler integration.
+/// The scheme used for linear motion can be changed by inserting the [`PhysicsIntegrator`] resource with a different [`Integrator`],
+/// like [`VelocityVerlet`]. Rotations are always integrated using semi-implicit Euler integration.
+///
+/// The [springs](SpringToTarget) that pull bodies towards target poses and custom [force fields](ForceField) registered with [`ForceFieldAppExt::add_force_field`] are also
+/// applied to each body during integration.
 ///
 /// The integration systems run in [`SubstepSet::Integrate`].
 pub struct IntegratorPlugin;
 
 impl Plugin for IntegratorPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ForceFields>()
+            .init_resource::<PhysicsIntegrator>();
+
         app.get_schedule_mut(SubstepSchedule)
             .expect("add SubstepSchedule first")
-            .add_systems((integrate_pos, integrate_rot).in_set(SubstepSet::Integrate));
+            .add_systems(
+                (apply_springs_to_targets, (integrate_pos, integrate_rot))
+                    .chain()
+                    .in_set(SubstepSet::Integrate),
+            )
+            .add_systems(
+                apply_integrator_velocity_corrections
+                    .after(SubstepSet::UpdateVelocities)
+                    .before(SubstepSet::SolveVelocities),
+            );
         app.get_schedule_mut(PhysicsSchedule)
             .expect("add PhysicsSchedule first")
             .add_systems(
@@ -31,14 +48,244 @@ impl Plugin for IntegratorPlugin {
     }
 }
 
+/// A global or regional force function, like a vortex, turbulence or a custom gravity model.
+///
+/// Force fields are evaluated by the [integrator](IntegratorPlugin) for each awake dynamic body during each substep,
+/// so they are applied consistently with gravity instead of fighting the substepping like forces applied
+/// from systems in other schedules. A force field can be a regional effect by returning zero force outside of its region.
+///
+/// Force fields are registered using [`ForceFieldAppExt::add_force_field`]. Closures taking a [`ForceFieldBody`]
+/// and returning a force also implement the trait.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// /// Pulls bodies towards the center with a force that is proportional to the distance.
+/// struct Well {
+///     center: Vector,
+///     strength: Scalar,
+///     radius: Scalar,
+/// }
+///
+/// impl ForceField for Well {
+///     fn force(&self, body: &ForceFieldBody) -> Vector {
+///         let offset = self.center - body.position;
+///         if offset.length() > self.radius {
+///             return Vector::ZERO;
+///         }
+///         offset * self.strength * body.mass
+///     }
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .add_force_field(Well {
+///             center: Vector::ZERO,
+///             strength: 5.0,
+///             radius: 10.0,
+///         })
+///         // Closures work too, here slowing bodies down like a thick fluid
+///         .add_force_field(|body: &ForceFieldBody| -body.linear_velocity * 0.1)
+///         .run();
+/// }
+/// ```
+pub trait ForceField: Send + Sync + 'static {
+    /// Returns the force applied to the given body.
+    fn force(&self, body: &ForceFieldBody) -> Vector;
+}
+
+impl<F: Fn(&ForceFieldBody) -> Vector + Send + Sync + 'static> ForceField for F {
+    fn force(&self, body: &ForceFieldBody) -> Vector {
+        self(body)
+    }
+}
+
+/// The state of a body that a [`ForceField`] is evaluated for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForceFieldBody {
+    /// The entity of the body.
+    pub entity: Entity,
+    /// The position of the body, including the translation accumulated during the current step.
+    pub position: Vector,
+    /// The linear velocity of the body.
+    pub linear_velocity: Vector,
+    /// The mass of the body.
+    pub mass: Scalar,
+}
+
+/// The [force fields](ForceField) evaluated by the [integrator](IntegratorPlugin).
+///
+/// Force fields are usually added using [`ForceFieldAppExt::add_force_field`], but they can also be added
+/// and removed at runtime by modifying this resource.
+#[derive(Resource, Default)]
+pub struct ForceFields(pub Vec<Box<dyn ForceField>>);
+
+impl ForceFields {
+    /// Adds a force field.
+    pub fn push(&mut self, field: impl ForceField) {
+        self.0.push(Box::new(field));
+    }
+
+    /// Returns the sum of the forces that the force fields apply to the given body.
+    pub fn force(&self, body: &ForceFieldBody) -> Vector {
+        self.0.iter().map(|field| field.force(body)).sum()
+    }
+}
+
+/// An extension trait for registering [force fields](ForceField).
+pub trait ForceFieldAppExt {
+    /// Registers a [`ForceField`] that is evaluated for each dynamic body during each substep.
+    fn add_force_field(&mut self, field: impl ForceField) -> &mut Self;
+}
+
+impl ForceFieldAppExt for App {
+    fn add_force_field(&mut self, field: impl ForceField) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(ForceFields::default)
+            .push(field);
+        self
+    }
+}
+
+/// A scheme for integrating the linear motion of dynamic bodies, used by the [`IntegratorPlugin`].
+///
+/// The default integrator is [`SemiImplicitEuler`]. Simulations that need better energy conservation,
+/// like orbital mechanics, can use [`VelocityVerlet`] instead by inserting the [`PhysicsIntegrator`] resource.
+///
+/// Note that after the constraints have been solved, the velocities of bodies are derived from their change in position.
+/// Integrators that compute velocities that differ from the predicted translation divided by the time step can preserve
+/// the difference using [`LinearIntegration::velocity_correction`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(PhysicsIntegrator::new(VelocityVerlet))
+///         .run();
+/// }
+/// ```
+pub trait Integrator: Send + Sync + 'static {
+    /// Integrates the linear motion of a dynamic body with the given `velocity` over a substep of length `delta_time`.
+    ///
+    /// `acceleration` returns the acceleration caused by gravity and external forces when the body
+    /// has been moved by the given offset from its position at the start of the substep.
+    fn integrate_linear(
+        &self,
+        velocity: Vector,
+        acceleration: &dyn Fn(Vector) -> Vector,
+        delta_time: Scalar,
+    ) -> LinearIntegration;
+}
+
+/// The result of integrating the linear motion of a body over a substep using an [`Integrator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinearIntegration {
+    /// The linear velocity of the body at the end of the substep.
+    pub velocity: Vector,
+    /// The predicted translation of the body during the substep.
+    pub translation: Vector,
+    /// The change in velocity that is applied after the velocity of the body has been derived
+    /// from its change in position at the end of the substep.
+    pub velocity_correction: Vector,
+}
+
+/// The [`Integrator`] used for integrating the linear motion of dynamic bodies. Defaults to [`SemiImplicitEuler`].
+#[derive(Resource)]
+pub struct PhysicsIntegrator(pub Box<dyn Integrator>);
+
+impl PhysicsIntegrator {
+    /// Creates a new [`PhysicsIntegrator`] that uses the given integrator.
+    pub fn new(integrator: impl Integrator) -> Self {
+        Self(Box::new(integrator))
+    }
+}
+
+impl Default for PhysicsIntegrator {
+    fn default() -> Self {
+        Self::new(SemiImplicitEuler)
+    }
+}
+
+/// Semi-implicit Euler integration, also known as symplectic Euler integration.
+///
+/// The velocity is updated using the acceleration at the start of the substep, and the position
+/// is then updated using the new velocity. This is the default [`Integrator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SemiImplicitEuler;
+
+impl Integrator for SemiImplicitEuler {
+    fn integrate_linear(
+        &self,
+        velocity: Vector,
+        acceleration: &dyn Fn(Vector) -> Vector,
+        delta_time: Scalar,
+    ) -> LinearIntegration {
+        let velocity = velocity + delta_time * acceleration(Vector::ZERO);
+        LinearIntegration {
+            velocity,
+            translation: delta_time * velocity,
+            velocity_correction: Vector::ZERO,
+        }
+    }
+}
+
+/// Velocity Verlet integration.
+///
+/// The position is updated using the velocity and acceleration at the start of the substep, and the velocity
+/// is updated using the average of the accelerations at the start and end of the substep. Compared to
+/// [`SemiImplicitEuler`], the velocities of bodies stay in sync with their positions, which conserves energy
+/// much better when forces depend on the position, like the gravity of a planet implemented as a [`ForceField`].
+///
+/// The acceleration is evaluated twice per substep, so force fields are also evaluated twice as often.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VelocityVerlet;
+
+impl Integrator for VelocityVerlet {
+    fn integrate_linear(
+        &self,
+        velocity: Vector,
+        acceleration: &dyn Fn(Vector) -> Vector,
+        delta_time: Scalar,
+    ) -> LinearIntegration {
+        let start_acceleration = acceleration(Vector::ZERO);
+        let translation =
+            delta_time * velocity + 0.5 * delta_time * delta_time * start_acceleration;
+        let end_acceleration = acceleration(translation);
+        LinearIntegration {
+            velocity: velocity + 0.5 * delta_time * (start_acceleration + end_acceleration),
+            translation,
+            // The velocity derived from the translation only includes half of the start acceleration
+            velocity_correction: 0.5 * delta_time * end_acceleration,
+        }
+    }
+}
+
 type PosIntegrationComponents = (
+    Entity,
     &'static RigidBody,
     &'static Position,
     &'static mut PreviousPosition,
     &'static mut AccumulatedTranslation,
     &'static mut LinearVelocity,
+    &'static mut IntegratorVelocityCorrection,
     Option<&'static LinearDamping>,
     Option<&'static GravityScale>,
+    Option<&'static CollisionLayers>,
     &'static ExternalForce,
     &'static Mass,
     &'static InverseMass,
@@ -50,16 +297,22 @@ type PosIntegrationComponents = (
 fn integrate_pos(
     mut bodies: Query<PosIntegrationComponents, Without<Sleeping>>,
     gravity: Res<Gravity>,
+    layer_gravity: Res<LayerGravity>,
+    force_fields: Res<ForceFields>,
+    integrator: Res<PhysicsIntegrator>,
     sub_dt: Res<SubDeltaTime>,
 ) {
     for (
+        entity,
         rb,
         pos,
         mut prev_pos,
         mut translation,
         mut lin_vel,
+        mut velocity_correction,
         lin_damping,
         gravity_scale,
+        layers,
         external_force,
         mass,
         inv_mass,
@@ -85,21 +338,66 @@ fn integrate_pos(
             let effective_inv_mass = locked_axes.apply_to_vec(Vector::splat(inv_mass.0));
 
             // Apply forces
-            let gravitation_force =
-                effective_mass * gravity.0 * gravity_scale.map_or(1.0, |scale| scale.0);
-            let external_forces = gravitation_force + external_force.force();
-            let delta_lin_vel = sub_dt.0 * external_forces * effective_inv_mass;
+            let gravity_scale = gravity_scale.map_or(1.0, |scale| scale.0)
+                * layers.map_or(1.0, |layers| layer_gravity.multiplier_for_layers(layers));
+            let gravitation_force = effective_mass * gravity.0 * gravity_scale;
+            let constant_forces = gravitation_force + external_force.force();
+
+            let position = pos.0 + translation.0;
+            let velocity = lin_vel.0;
+            let acceleration = |offset: Vector| {
+                let mut external_forces = constant_forces;
+                if !force_fields.0.is_empty() {
+                    external_forces += force_fields.force(&ForceFieldBody {
+                        entity,
+                        position: position + offset,
+                        linear_velocity: velocity,
+                        mass: mass.0,
+                    });
+                }
+                external_forces * effective_inv_mass
+            };
+
+            let integration = integrator
+                .0
+                .integrate_linear(velocity, &acceleration, sub_dt.0);
+
             // avoid triggering bevy's change detection unnecessarily
-            if delta_lin_vel != Vector::ZERO {
-                lin_vel.0 += delta_lin_vel;
+            if integration.velocity != lin_vel.0 {
+                lin_vel.0 = integration.velocity;
             }
-        }
-        if lin_vel.0 != Vector::ZERO {
+            let correction = locked_axes.apply_to_vec(integration.velocity_correction);
+            if correction != velocity_correction.0 {
+                velocity_correction.0 = correction;
+            }
+            if integration.translation != Vector::ZERO {
+                translation.0 += locked_axes.apply_to_vec(integration.translation);
+            }
+        } else if lin_vel.0 != Vector::ZERO {
             translation.0 += locked_axes.apply_to_vec(sub_dt.0 * lin_vel.0);
         }
     }
 }
 
+/// Applies the [velocity corrections](LinearIntegration::velocity_correction) of the [`Integrator`]
+/// after the velocities of dynamic bodies have been derived from their change in position.
+fn apply_integrator_velocity_corrections(
+    mut bodies: Query<
+        (
+            &RigidBody,
+            &IntegratorVelocityCorrection,
+            &mut LinearVelocity,
+        ),
+        Without<Sleeping>,
+    >,
+) {
+    for (rb, correction, mut lin_vel) in &mut bodies {
+        if rb.is_dynamic() && correction.0 != Vector::ZERO {
+            lin_vel.0 += correction.0;
+        }
+    }
+}
+
 type RotIntegrationComponents = (
     &'static RigidBody,
     &'static mut Rotation,
@@ -234,6 +532,96 @@ fn integrate_rot(
     }
 }
 
+type SpringToTargetComponents = (
+    &'static RigidBody,
+    &'static SpringToTarget,
+    &'static Position,
+    &'static AccumulatedTranslation,
+    &'static Rotation,
+    &'static mut LinearVelocity,
+    &'static mut AngularVelocity,
+    &'static Mass,
+    &'static Inertia,
+    Option<&'static LockedAxes>,
+);
+
+/// Changes the velocities of bodies with a [`SpringToTarget`] so that they are pulled towards their target poses.
+fn apply_springs_to_targets(
+    mut bodies: Query<SpringToTargetComponents, Without<Sleeping>>,
+    sub_dt: Res<SubDeltaTime>,
+) {
+    for (rb, spring, pos, translation, rot, mut lin_vel, mut ang_vel, mass, inertia, locked_axes) in
+        &mut bodies
+    {
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let locked_axes = locked_axes.map_or(LockedAxes::default(), |locked_axes| *locked_axes);
+
+        let error = spring.position - (pos.0 + translation.0);
+        let mut delta_lin_vel = spring.delta_velocity(error, lin_vel.0, sub_dt.0);
+
+        // Limit the force, which is the mass times the acceleration
+        let max_delta = spring.max_force * sub_dt.0 / mass.0;
+        if delta_lin_vel.length() > max_delta {
+            delta_lin_vel = delta_lin_vel.normalize_or_zero() * max_delta;
+        }
+
+        let delta_lin_vel = locked_axes.apply_to_vec(delta_lin_vel);
+        if delta_lin_vel != Vector::ZERO {
+            lin_vel.0 += delta_lin_vel;
+        }
+
+        let Some(target_rotation) = spring.rotation else {
+            continue;
+        };
+
+        let mut delta_ang_vel =
+            spring.delta_velocity(rotation_error(rot, &target_rotation), ang_vel.0, sub_dt.0);
+
+        // Limit the torque, which is the moment of inertia times the angular acceleration
+        let torque = inertia.rotated(rot).0 * delta_ang_vel / sub_dt.0;
+        let torque_magnitude = angular_magnitude(torque);
+        if torque_magnitude > spring.max_torque {
+            delta_ang_vel = delta_ang_vel * (spring.max_torque / torque_magnitude);
+        }
+
+        let delta_ang_vel = locked_axes.apply_to_angular_velocity(delta_ang_vel);
+        if delta_ang_vel != AngularVelocity::ZERO.0 {
+            ang_vel.0 += delta_ang_vel;
+        }
+    }
+}
+
+/// Returns the rotation from `rotation` to `target` as a rotation angle.
+#[cfg(feature = "2d")]
+fn rotation_error(rotation: &Rotation, target: &Rotation) -> Scalar {
+    target.mul(rotation.inverse()).as_radians()
+}
+
+/// Returns the rotation from `rotation` to `target` as a scaled axis.
+#[cfg(feature = "3d")]
+fn rotation_error(rotation: &Rotation, target: &Rotation) -> Vector {
+    let mut delta = target.0 * rotation.0.inverse();
+    // Take the shortest path
+    if delta.w < 0.0 {
+        delta = -delta;
+    }
+    let (axis, angle) = delta.to_axis_angle();
+    axis * angle
+}
+
+#[cfg(feature = "2d")]
+fn angular_magnitude(value: Scalar) -> Scalar {
+    value.abs()
+}
+
+#[cfg(feature = "3d")]
+fn angular_magnitude(value: Vector) -> Scalar {
+    value.length()
+}
+
 type ImpulseQueryComponents = (
     &'static RigidBody,
     &'static mut ExternalImpulse,
@@ -311,3 +699,112 @@ fn clear_forces_and_impulses(mut forces: Query<ForceComponents, ForceComponentsC
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    /// Spawns a dynamic ball at the given position.
+    fn spawn_ball(app: &mut App, position: Vector) -> Entity {
+        app.world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(position),
+                Collider::ball(0.5),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn layer_gravity_scales_gravity_of_bodies_on_layer() {
+        #[derive(PhysicsLayer)]
+        enum Layer {
+            Normal,
+            Spirit,
+        }
+
+        let mut app = create_app();
+        app.insert_resource(LayerGravity::default().with_multiplier(Layer::Spirit, 0.5));
+
+        let normal = spawn_ball(&mut app, Vector::ZERO);
+        let spirit = spawn_ball(&mut app, Vector::ZERO);
+        app.world
+            .entity_mut(normal)
+            .insert(CollisionLayers::new([Layer::Normal], [Layer::Normal]));
+        app.world
+            .entity_mut(spirit)
+            .insert(CollisionLayers::new([Layer::Spirit], [Layer::Spirit]));
+
+        app.step_physics(30);
+
+        let normal_vel = app.world.get::<LinearVelocity>(normal).unwrap().0;
+        let spirit_vel = app.world.get::<LinearVelocity>(spirit).unwrap().0;
+        assert_relative_eq!(spirit_vel.y, normal_vel.y * 0.5, max_relative = 0.001);
+    }
+
+    #[test]
+    fn force_fields_accelerate_bodies() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        // Pushes bodies on the positive X side away from the origin
+        app.add_force_field(|body: &ForceFieldBody| {
+            if body.position.x > 0.0 {
+                Vector::X * body.mass * 2.0
+            } else {
+                Vector::ZERO
+            }
+        });
+
+        let inside = spawn_ball(&mut app, Vector::X * 10.0);
+        let outside = spawn_ball(&mut app, Vector::X * -10.0);
+
+        app.step_physics(60);
+
+        let inside_vel = app.world.get::<LinearVelocity>(inside).unwrap().0;
+        let outside_vel = app.world.get::<LinearVelocity>(outside).unwrap().0;
+        assert_relative_eq!(inside_vel.x, 2.0, max_relative = 0.01);
+        assert_eq!(outside_vel, Vector::ZERO);
+    }
+
+    #[test]
+    fn velocity_verlet_conserves_orbital_energy_better_than_euler() {
+        fn max_energy_error(integrator: PhysicsIntegrator) -> Scalar {
+            const GM: Scalar = 100.0;
+
+            let mut app = create_app();
+            app.insert_resource(Gravity::ZERO);
+            app.insert_resource(integrator);
+            // Gravity of a planet at the origin
+            app.add_force_field(|body: &ForceFieldBody| {
+                -body.position.normalize_or_zero() * GM * body.mass / body.position.length_squared()
+            });
+
+            // Eccentric orbit so that the velocity isn't always perpendicular to the acceleration
+            let body = spawn_ball(&mut app, Vector::X * 10.0);
+            app.world
+                .entity_mut(body)
+                .insert(LinearVelocity(Vector::Y * 2.5));
+
+            let energy = |app: &App| {
+                let position = app.world.get::<Position>(body).unwrap().0;
+                let velocity = app.world.get::<LinearVelocity>(body).unwrap().0;
+                0.5 * velocity.length_squared() - GM / position.length()
+            };
+
+            let initial_energy = energy(&app);
+            let mut max_error: Scalar = 0.0;
+            for _ in 0..360 {
+                app.step_physics(1);
+                max_error = max_error.max((energy(&app) - initial_energy).abs());
+            }
+            max_error
+        }
+
+        let euler_error = max_energy_error(PhysicsIntegrator::new(SemiImplicitEuler));
+        let verlet_error = max_energy_error(PhysicsIntegrator::new(VelocityVerlet));
+        assert!(verlet_error < euler_error);
+    }
+}
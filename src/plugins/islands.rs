@@ -0,0 +1,403 @@
+//! Finds the groups of bodies that are connected by contacts and joints.
+//!
+//! See [`IslandPlugin`].
+
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// Finds the islands of the simulation after each physics step and stores them in the [`PhysicsIslands`] resource.
+///
+/// An island is a group of non-static [rigid bodies](RigidBody) that are connected to each other through contacts
+/// or [joints], directly or through other bodies in the island. Bodies in different islands can't affect each other
+/// during the step, so islands can be used for per-island budgeting, debugging overlays or custom activation policies,
+/// like waking up a whole pile of bodies at once.
+///
+/// [Static](RigidBody::Static) bodies don't connect islands, so two piles of bodies resting on the same ground
+/// are in separate islands.
+///
+/// The islands are computed after [`PhysicsStepSet::SpatialQuery`],
+/// and their number is reported in [`PhysicsStats::islands`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn log_large_islands(islands: Res<PhysicsIslands>) {
+///     for island in islands.iter().filter(|island| island.len() > 100) {
+///         println!(
+///             "Island {:?} has {} bodies and is sleeping: {}",
+///             island.id(),
+///             island.len(),
+///             island.is_sleeping()
+///         );
+///     }
+/// }
+/// ```
+pub struct IslandPlugin;
+
+impl Plugin for IslandPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsIslands>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                update_islands
+                    .after(PhysicsStepSet::SpatialQuery)
+                    .before(setup::update_physics_stats),
+            );
+    }
+}
+
+/// The identifier of an [`Island`] in [`PhysicsIslands`]. Identifiers are only valid until the islands are computed again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IslandId(usize);
+
+impl IslandId {
+    /// Returns the index of the island in [`PhysicsIslands`].
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A group of non-static bodies that are connected by contacts or joints. See [`IslandPlugin`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Island {
+    id: IslandId,
+    bodies: Vec<Entity>,
+    contact_count: usize,
+    joint_count: usize,
+    sleeping: bool,
+}
+
+impl Island {
+    /// Returns the identifier of the island.
+    pub fn id(&self) -> IslandId {
+        self.id
+    }
+
+    /// Returns the bodies in the island.
+    pub fn bodies(&self) -> &[Entity] {
+        &self.bodies
+    }
+
+    /// Returns the number of bodies in the island.
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    /// Returns true if the island has no bodies.
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    /// Returns the number of colliding entity pairs in the island.
+    pub fn contact_count(&self) -> usize {
+        self.contact_count
+    }
+
+    /// Returns the number of joints in the island.
+    pub fn joint_count(&self) -> usize {
+        self.joint_count
+    }
+
+    /// Returns true if all bodies in the island are [`Sleeping`].
+    pub fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+
+    /// Wakes up all bodies in the island by removing the [`Sleeping`] component.
+    pub fn wake_up(&self, commands: &mut Commands) {
+        for entity in self.bodies.iter() {
+            commands.entity(*entity).remove::<Sleeping>();
+        }
+    }
+}
+
+/// The islands found by the [`IslandPlugin`] during the latest physics step.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhysicsIslands {
+    islands: Vec<Island>,
+    body_islands: HashMap<Entity, IslandId>,
+}
+
+impl PhysicsIslands {
+    /// Returns the island with the given identifier, if it exists.
+    pub fn get(&self, id: IslandId) -> Option<&Island> {
+        self.islands.get(id.0)
+    }
+
+    /// Returns the island that the given body belongs to, if it exists.
+    /// Static bodies don't belong to any island.
+    pub fn island_of(&self, entity: Entity) -> Option<&Island> {
+        self.body_islands
+            .get(&entity)
+            .and_then(|id| self.islands.get(id.0))
+    }
+
+    /// Returns an iterator over the islands.
+    pub fn iter(&self) -> impl Iterator<Item = &Island> {
+        self.islands.iter()
+    }
+
+    /// Returns the number of islands.
+    pub fn len(&self) -> usize {
+        self.islands.len()
+    }
+
+    /// Returns true if there are no islands.
+    pub fn is_empty(&self) -> bool {
+        self.islands.is_empty()
+    }
+}
+
+/// A disjoint-set forest used for merging connected bodies into islands.
+pub(crate) struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    /// Resets the forest to `len` separate sets, reusing the allocation.
+    fn reset(&mut self, len: usize) {
+        self.parents.clear();
+        self.parents.extend(0..len);
+    }
+
+    pub(crate) fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            // Path halving
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    pub(crate) fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            // Keep the smaller index as the root so that islands are ordered deterministically
+            self.parents[root_a.max(root_b)] = root_a.min(root_b);
+        }
+    }
+}
+
+/// Adds the bodies connected by the joints of type `T` to `edges`.
+fn collect_joint_edges<T: Joint>(joints: &Query<&T>, edges: &mut Vec<[Entity; 2]>) {
+    edges.extend(joints.iter().map(|joint| joint.entities()));
+}
+
+/// Buffers used by [`update_islands`] that are reused between physics steps instead of being reallocated.
+#[derive(Default)]
+struct IslandBuffers {
+    indices: HashMap<Entity, usize>,
+    entities: Vec<Entity>,
+    sleeping: Vec<bool>,
+    contact_edges: Vec<[Entity; 2]>,
+    joint_edges: Vec<[Entity; 2]>,
+    root_islands: HashMap<usize, IslandId>,
+    union_find: UnionFind,
+}
+
+impl Default for UnionFind {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_islands(
+    bodies: Query<(Entity, &RigidBody, Has<Sleeping>)>,
+    collider_parents: Query<&ColliderParent>,
+    collisions: Res<Collisions>,
+    fixed_joints: Query<&FixedJoint>,
+    revolute_joints: Query<&RevoluteJoint>,
+    spherical_joints: Query<&SphericalJoint>,
+    prismatic_joints: Query<&PrismaticJoint>,
+    distance_joints: Query<&DistanceJoint>,
+    spring_joints: Query<&SpringJoint>,
+    mut islands: ResMut<PhysicsIslands>,
+    mut buffers: Local<IslandBuffers>,
+) {
+    let IslandBuffers {
+        indices,
+        entities,
+        sleeping,
+        contact_edges,
+        joint_edges,
+        root_islands,
+        union_find,
+    } = &mut *buffers;
+    indices.clear();
+    entities.clear();
+    sleeping.clear();
+    contact_edges.clear();
+    joint_edges.clear();
+    root_islands.clear();
+
+    for (entity, rb, is_sleeping) in &bodies {
+        if !rb.is_static() {
+            indices.insert(entity, entities.len());
+            entities.push(entity);
+            sleeping.push(is_sleeping);
+        }
+    }
+
+    let body_of = |collider: Entity| {
+        collider_parents
+            .get(collider)
+            .map_or(collider, |parent| parent.get())
+    };
+    contact_edges.extend(
+        collisions
+            .get_internal()
+            .values()
+            .filter(|contacts| contacts.during_current_frame)
+            .map(|contacts| [body_of(contacts.entity1), body_of(contacts.entity2)]),
+    );
+
+    collect_joint_edges(&fixed_joints, joint_edges);
+    collect_joint_edges(&revolute_joints, joint_edges);
+    collect_joint_edges(&spherical_joints, joint_edges);
+    collect_joint_edges(&prismatic_joints, joint_edges);
+    collect_joint_edges(&distance_joints, joint_edges);
+    collect_joint_edges(&spring_joints, joint_edges);
+
+    // Merge the bodies connected by contacts and joints. Static bodies aren't in `indices`, so they don't connect islands.
+    union_find.reset(entities.len());
+    for [entity1, entity2] in contact_edges.iter().chain(joint_edges.iter()) {
+        if let (Some(index1), Some(index2)) = (indices.get(entity1), indices.get(entity2)) {
+            union_find.union(*index1, *index2);
+        }
+    }
+
+    let islands = &mut *islands;
+    islands.body_islands.clear();
+
+    // The islands of the previous step are reused so that their body buffers don't need to be reallocated
+    let mut island_count = 0;
+    for (index, entity) in entities.iter().enumerate() {
+        let root = union_find.find(index);
+        let id = *root_islands.entry(root).or_insert_with(|| {
+            let id = IslandId(island_count);
+            if let Some(island) = islands.islands.get_mut(island_count) {
+                island.id = id;
+                island.bodies.clear();
+                island.contact_count = 0;
+                island.joint_count = 0;
+                island.sleeping = true;
+            } else {
+                islands.islands.push(Island {
+                    id,
+                    bodies: vec![],
+                    contact_count: 0,
+                    joint_count: 0,
+                    sleeping: true,
+                });
+            }
+            island_count += 1;
+            id
+        });
+        let island = &mut islands.islands[id.0];
+        island.bodies.push(*entity);
+        island.sleeping &= sleeping[index];
+        islands.body_islands.insert(*entity, id);
+    }
+    islands.islands.truncate(island_count);
+
+    for (edges, is_joint) in [(&*contact_edges, false), (&*joint_edges, true)] {
+        for [entity1, entity2] in edges.iter() {
+            let Some(id) = islands
+                .body_islands
+                .get(entity1)
+                .or_else(|| islands.body_islands.get(entity2))
+                .copied()
+            else {
+                continue;
+            };
+            let island = &mut islands.islands[id.0];
+            if is_joint {
+                island.joint_count += 1;
+            } else {
+                island.contact_count += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider, floor_collider},
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    fn islands_group_bodies_connected_by_contacts_and_joints() {
+        let mut app = create_app();
+        app.add_plugins(IslandPlugin);
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        let floor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::NEG_Y * 0.5),
+                floor_collider(40.0),
+            ))
+            .id();
+
+        // A stack of two boxes and a lone box resting on the same floor
+        let mut spawn_box = |position: Vector| {
+            app.world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(position),
+                    cube_collider(1.0),
+                ))
+                .id()
+        };
+        let bottom = spawn_box(Vector::Y * 0.5);
+        let top = spawn_box(Vector::Y * 1.5);
+        let lone = spawn_box(Vector::X * 10.0 + Vector::Y * 0.5);
+
+        // Two bodies connected by a joint high up in the air
+        let jointed1 = spawn_box(Vector::X * -10.0 + Vector::Y * 50.0);
+        let jointed2 = spawn_box(Vector::X * -13.0 + Vector::Y * 50.0);
+        app.world
+            .spawn(DistanceJoint::new(jointed1, jointed2).with_rest_length(3.0));
+
+        app.step_physics(30);
+
+        let islands = app.world.resource::<PhysicsIslands>();
+        let island_id = |entity: Entity| islands.island_of(entity).map(|island| island.id());
+
+        assert_eq!(islands.len(), 3);
+        assert!(islands.island_of(floor).is_none());
+        assert_eq!(island_id(bottom), island_id(top));
+        assert_eq!(island_id(jointed1), island_id(jointed2));
+        assert_ne!(island_id(bottom), island_id(lone));
+        assert_ne!(island_id(bottom), island_id(jointed1));
+
+        let stack = islands.island_of(top).unwrap();
+        assert_eq!(stack.len(), 2);
+        assert!(stack.contact_count() >= 1);
+        let jointed = islands.island_of(jointed1).unwrap();
+        assert_eq!(jointed.joint_count(), 1);
+        assert_eq!(islands.island_of(lone).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,784 @@
+//! Merges clusters of bodies connected by fixed joints into composite bodies.
+//!
+//! See [`JointMergingPlugin`].
+
+use crate::{plugins::islands::UnionFind, prelude::*};
+use bevy::{
+    ecs::system::Command,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+/// Merges clusters of [dynamic](RigidBody::Dynamic) bodies that are connected only by [`FixedJoint`]s
+/// into composite bodies that are simulated as a single rigid body.
+///
+/// Structures in building games often consist of a large number of parts fixed together. Every fixed joint
+/// is a constraint that the solver has to handle each substep, and large structures can sag and jitter
+/// before the solver converges. Merging the parts removes these constraints completely.
+///
+/// The colliders of a cluster are attached to one of its bodies, the root, and the mass properties of the other
+/// bodies are added to the root's mass properties. The other bodies become children of the root: their [`RigidBody`]
+/// is removed, and a [`MergedBody`] component is added. The merged joints are replaced by [`MergedJoint`] components.
+///
+/// The clusters are updated before [`PhysicsStepSet::BroadPhase`] whenever joints are added, when [`JointDisabled`],
+/// [`BreakableJoint`] or [`NoJointMerging`] are added to or removed from joints, and when merged bodies
+/// or joints are despawned. For example, adding a [`RevoluteJoint`] to a merged body splits the body off
+/// from its composite body, and despawning a merged body removes its mass from the composite body.
+///
+/// Bodies are only merged if all of their joints can be merged. Bodies that are attached to other types of joints,
+/// to [`BreakableJoint`]s or to joints with the [`NoJointMerging`] component, as well as bodies that have a parent,
+/// keep being simulated normally. Fixed joints with [`JointDisabled`] don't connect bodies.
+///
+/// A composite body can be split back into its parts using [`JointMergingCommandsExt::split_merged_bodies`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Destroyed;
+///
+/// fn setup(mut commands: Commands) {
+///     let wall = commands.spawn((RigidBody::Dynamic, Collider::ball(0.5))).id();
+///     let roof = commands.spawn((RigidBody::Dynamic, Collider::ball(0.5))).id();
+///
+///     // The wall and the roof are simulated as a single body
+///     commands.spawn(FixedJoint::new(wall, roof));
+/// }
+///
+/// fn collapse(mut commands: Commands, destroyed: Query<Entity, Added<Destroyed>>) {
+///     for root in &destroyed {
+///         // Restore the individual parts of the structure
+///         commands.split_merged_bodies(root);
+///     }
+/// }
+/// ```
+pub struct JointMergingPlugin;
+
+impl Plugin for JointMergingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MergedBodyStorage>()
+            .register_type::<NoJointMerging>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(detect_fixed_joint_clusters.before(PhysicsStepSet::BroadPhase));
+    }
+}
+
+/// A body that has been merged into a composite body by the [`JointMergingPlugin`].
+///
+/// The body is a child of the [root](MergedBody::root) of the composite body, and its colliders are attached to the root.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct MergedBody {
+    root: Entity,
+    rigid_body: RigidBody,
+    /// The mass properties that the body added to the root, in the local space of the root.
+    mass_properties: ColliderMassProperties,
+    /// The colliders attached to the body, their transforms relative to the body,
+    /// and the mass properties that they added to the root, in the local space of the root.
+    colliders: Vec<(Entity, ColliderTransform, ColliderMassProperties)>,
+}
+
+impl MergedBody {
+    /// Returns the root of the composite body that the body has been merged into.
+    pub fn root(&self) -> Entity {
+        self.root
+    }
+}
+
+/// Stores the [`MergedBody`] components so that the mass properties of despawned bodies
+/// can be removed from their composite bodies, since `RemovedComponents` only returns entities.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct MergedBodyStorage(HashMap<Entity, MergedBody>);
+
+/// A [`FixedJoint`] that has been removed by the [`JointMergingPlugin`] because its bodies have been merged.
+/// The joint is restored when the composite body is split.
+#[derive(Clone, Copy, Component, Debug, PartialEq)]
+pub struct MergedJoint {
+    /// The root of the composite body that the joint's bodies have been merged into.
+    pub root: Entity,
+    /// The removed joint.
+    pub joint: FixedJoint,
+}
+
+/// Prevents a [`FixedJoint`] from being merged by the [`JointMergingPlugin`]. The bodies attached to the joint
+/// are simulated as separate bodies.
+///
+/// This is added to the joints of composite bodies that are split using
+/// [`JointMergingCommandsExt::split_merged_bodies`], so that they aren't merged again right away.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct NoJointMerging;
+
+/// A [`Command`] that splits a composite body created by the [`JointMergingPlugin`] back into its parts.
+///
+/// The parts continue moving with the velocity of the composite body, and their joints are restored
+/// with the [`NoJointMerging`] component.
+///
+/// Usually, this is used through [`JointMergingCommandsExt::split_merged_bodies`].
+pub struct SplitMergedBodies {
+    /// The root of the composite body.
+    pub root: Entity,
+}
+
+impl Command for SplitMergedBodies {
+    fn apply(self, world: &mut World) {
+        for joint in split_merged_bodies(world, self.root) {
+            world.entity_mut(joint).insert(NoJointMerging);
+        }
+    }
+}
+
+/// An extension trait for splitting composite bodies using `Commands`. Requires the [`JointMergingPlugin`].
+pub trait JointMergingCommandsExt {
+    /// Splits the composite body with the given root back into its parts. See [`SplitMergedBodies`].
+    fn split_merged_bodies(&mut self, root: Entity);
+}
+
+impl JointMergingCommandsExt for Commands<'_, '_> {
+    fn split_merged_bodies(&mut self, root: Entity) {
+        self.add(SplitMergedBodies { root });
+    }
+}
+
+type JointMergingChangedFilter = Or<(
+    Added<FixedJoint>,
+    Added<RevoluteJoint>,
+    Added<SphericalJoint>,
+    Added<PrismaticJoint>,
+    Added<DistanceJoint>,
+    Added<SpringJoint>,
+    Added<JointDisabled>,
+    Added<BreakableJoint>,
+    Added<NoJointMerging>,
+)>;
+
+#[allow(clippy::too_many_arguments)]
+fn detect_fixed_joint_clusters(
+    mut commands: Commands,
+    changed_joints: Query<(), JointMergingChangedFilter>,
+    mut removed_markers: RemovedComponents<NoJointMerging>,
+    mut removed_disabled: RemovedComponents<JointDisabled>,
+    mut removed_breakable: RemovedComponents<BreakableJoint>,
+    mut removed_merged_joints: RemovedComponents<MergedJoint>,
+    mut removed_merged_bodies: RemovedComponents<MergedBody>,
+    mut removed_bodies: RemovedComponents<RigidBody>,
+    merged_bodies: Query<&MergedBody>,
+) {
+    let markers_removed = removed_markers.iter().count() > 0
+        || removed_disabled.iter().count() > 0
+        || removed_breakable.iter().count() > 0;
+    let merged_removed =
+        removed_merged_joints.iter().count() > 0 || removed_merged_bodies.iter().count() > 0;
+
+    // The members of a composite body whose root has been despawned have to be restored
+    let removed_bodies: HashSet<Entity> = removed_bodies.iter().collect();
+    let root_removed = !removed_bodies.is_empty()
+        && merged_bodies
+            .iter()
+            .any(|merged_body| removed_bodies.contains(&merged_body.root));
+
+    if !changed_joints.is_empty() || markers_removed || merged_removed || root_removed {
+        commands.add(MergeFixedJointClusters);
+    }
+}
+
+/// Adds the bodies attached to the joints of type `T` to `bodies`.
+fn collect_joint_bodies<T: Joint>(world: &mut World, bodies: &mut HashSet<Entity>) {
+    let mut joints = world.query::<&T>();
+    bodies.extend(joints.iter(world).flat_map(|joint| joint.entities()));
+}
+
+/// Updates the composite bodies so that each cluster of bodies connected by mergeable fixed joints is a single body.
+struct MergeFixedJointClusters;
+
+impl Command for MergeFixedJointClusters {
+    fn apply(self, world: &mut World) {
+        remove_despawned_merged_bodies(world);
+
+        // Bodies attached to joints that can't be merged have to stay separate bodies
+        let mut excluded = HashSet::default();
+        collect_joint_bodies::<RevoluteJoint>(world, &mut excluded);
+        collect_joint_bodies::<SphericalJoint>(world, &mut excluded);
+        collect_joint_bodies::<PrismaticJoint>(world, &mut excluded);
+        collect_joint_bodies::<DistanceJoint>(world, &mut excluded);
+        collect_joint_bodies::<SpringJoint>(world, &mut excluded);
+        let mut unmergeable_joints = world.query_filtered::<&FixedJoint, (
+            Without<JointDisabled>,
+            Or<(With<NoJointMerging>, With<BreakableJoint>)>,
+        )>();
+        excluded.extend(
+            unmergeable_joints
+                .iter(world)
+                .flat_map(|joint| joint.entities()),
+        );
+        let mut unmergeable_merged_joints = world.query_filtered::<&MergedJoint, (
+            Without<JointDisabled>,
+            Or<(With<NoJointMerging>, With<BreakableJoint>)>,
+        )>();
+        excluded.extend(
+            unmergeable_merged_joints
+                .iter(world)
+                .flat_map(|merged| merged.joint.entities()),
+        );
+
+        let mut bodies_query = world.query_filtered::<(
+            Entity,
+            Option<&RigidBody>,
+            Option<&MergedBody>,
+            Option<&Parent>,
+        ), Or<(With<RigidBody>, With<MergedBody>)>>();
+        let mut merged_roots = HashMap::default();
+        let mut mergeable = HashSet::default();
+        for (entity, rb, merged_body, parent) in bodies_query.iter(world) {
+            if let Some(merged_body) = merged_body {
+                merged_roots.insert(entity, merged_body.root);
+            }
+            let is_mergeable =
+                merged_body.is_some() || (rb.is_some_and(|rb| rb.is_dynamic()) && parent.is_none());
+            if is_mergeable && !excluded.contains(&entity) {
+                mergeable.insert(entity);
+            }
+        }
+
+        // Merged joints are included so that existing composite bodies are kept together
+        let mut fixed_joints = world.query_filtered::<(Entity, &FixedJoint), (
+            Without<JointDisabled>,
+            Without<NoJointMerging>,
+            Without<BreakableJoint>,
+        )>();
+        let mut merged_joints = world.query_filtered::<(Entity, &MergedJoint), (
+            Without<JointDisabled>,
+            Without<NoJointMerging>,
+            Without<BreakableJoint>,
+        )>();
+        let edges: Vec<(Entity, [Entity; 2])> = fixed_joints
+            .iter(world)
+            .map(|(entity, joint)| (entity, joint.entities()))
+            .chain(
+                merged_joints
+                    .iter(world)
+                    .map(|(entity, merged)| (entity, merged.joint.entities())),
+            )
+            .filter(|(_, bodies)| bodies.iter().all(|body| mergeable.contains(body)))
+            .collect();
+
+        let mut indices = HashMap::default();
+        let mut entities = vec![];
+        for (_, bodies) in edges.iter() {
+            for body in bodies {
+                indices.entry(*body).or_insert_with(|| {
+                    entities.push(*body);
+                    entities.len() - 1
+                });
+            }
+        }
+
+        let mut union_find = UnionFind::new(entities.len());
+        for (_, [entity1, entity2]) in edges.iter() {
+            union_find.union(indices[entity1], indices[entity2]);
+        }
+
+        let mut clusters: HashMap<usize, Vec<Entity>> = HashMap::default();
+        for (index, entity) in entities.iter().enumerate() {
+            clusters
+                .entry(union_find.find(index))
+                .or_default()
+                .push(*entity);
+        }
+
+        // Existing roots are kept as the roots of their clusters when possible
+        let existing_roots: HashSet<Entity> = merged_roots.values().copied().collect();
+        let mut desired_roots = HashMap::default();
+        for cluster in clusters.values() {
+            let root = cluster
+                .iter()
+                .filter(|entity| existing_roots.contains(*entity))
+                .min()
+                .or_else(|| cluster.iter().min())
+                .copied()
+                .unwrap();
+            desired_roots.extend(cluster.iter().map(|entity| (*entity, root)));
+        }
+
+        // Split the composite bodies whose clusters have changed
+        let mut split_roots = HashSet::default();
+        for (member, root) in merged_roots.iter() {
+            if desired_roots.get(member) != Some(root) || desired_roots.get(root) != Some(root) {
+                split_roots.insert(*root);
+            }
+        }
+        for root in split_roots.iter() {
+            split_merged_bodies(world, *root);
+        }
+
+        for (entity, root) in desired_roots.iter() {
+            let is_merged = merged_roots.get(entity) == Some(root) && !split_roots.contains(root);
+            if entity != root && !is_merged {
+                merge_body(world, *root, *entity);
+            }
+        }
+
+        for (entity, bodies) in edges {
+            let Some(joint) = world.get::<FixedJoint>(entity).copied() else {
+                continue;
+            };
+            world
+                .entity_mut(entity)
+                .remove::<FixedJoint>()
+                .insert(MergedJoint {
+                    root: desired_roots[&bodies[0]],
+                    joint,
+                });
+        }
+    }
+}
+
+/// Removes the mass properties of merged bodies that have been despawned from their composite bodies.
+fn remove_despawned_merged_bodies(world: &mut World) {
+    let despawned: Vec<Entity> = world
+        .resource::<MergedBodyStorage>()
+        .keys()
+        .filter(|entity| world.get_entity(**entity).is_none())
+        .copied()
+        .collect();
+
+    for entity in despawned {
+        let Some(merged_body) = world.resource_mut::<MergedBodyStorage>().remove(&entity) else {
+            continue;
+        };
+
+        // The mass properties of despawned colliders have already been removed from the root
+        let despawned_colliders: Vec<ColliderMassProperties> = merged_body
+            .colliders
+            .iter()
+            .filter(|(collider, ..)| world.get_entity(*collider).is_none())
+            .map(|(.., mass_properties)| *mass_properties)
+            .collect();
+
+        let mut mass_query = world.query::<MassPropertiesQuery>();
+        if let Ok(mut root_mass_properties) = mass_query.get_mut(world, merged_body.root) {
+            root_mass_properties -= merged_body.mass_properties;
+            for collider_mass_properties in despawned_colliders {
+                root_mass_properties += collider_mass_properties;
+            }
+        }
+    }
+}
+
+/// Attaches `body` and its colliders to `root` and adds the mass properties of the body to the root.
+fn merge_body(world: &mut World, root: Entity, body: Entity) {
+    let mut bodies_query = world.query::<(
+        &RigidBody,
+        &Position,
+        &Rotation,
+        &GlobalTransform,
+        &Mass,
+        &Inertia,
+        &CenterOfMass,
+    )>();
+    let Ok([root_item, body_item]) = bodies_query.get_many(world, [root, body]) else {
+        return;
+    };
+    let (_, root_pos, root_rot, root_transform, ..) = root_item;
+    let (rb, pos, rot, transform, mass, inertia, center_of_mass) = body_item;
+
+    let local_position = root_rot.inverse().rotate(pos.0 - root_pos.0);
+    #[cfg(feature = "2d")]
+    let local_rotation = root_rot.inverse().mul(*rot);
+    #[cfg(feature = "3d")]
+    let local_rotation = Rotation(root_rot.inverse().0 * rot.0);
+
+    let local_inertia = inertia.rotated(&local_rotation);
+    let mass_properties = ColliderMassProperties {
+        mass: *mass,
+        inverse_mass: InverseMass(1.0 / mass.0),
+        inertia: local_inertia,
+        inverse_inertia: local_inertia.inverse(),
+        center_of_mass: CenterOfMass(local_position + local_rotation.rotate(center_of_mass.0)),
+    };
+    let rigid_body = *rb;
+    let local_transform = transform.reparented_to(root_transform);
+
+    // The collider transforms are updated right away so that the mass properties of the colliders
+    // aren't subtracted from the root when the transforms are propagated
+    let mut colliders_query = world.query::<(
+        Entity,
+        &mut ColliderParent,
+        &mut ColliderTransform,
+        &mut PreviousColliderTransform,
+        &ColliderMassProperties,
+    )>();
+    let mut colliders = vec![];
+    for (
+        entity,
+        mut collider_parent,
+        mut collider_transform,
+        mut previous_transform,
+        collider_mass_properties,
+    ) in colliders_query.iter_mut(world)
+    {
+        if collider_parent.get() != body {
+            continue;
+        }
+        let body_transform = *collider_transform;
+
+        #[cfg(feature = "2d")]
+        let rotation = local_rotation.mul(collider_transform.rotation);
+        #[cfg(feature = "3d")]
+        let rotation = Rotation(local_rotation.0 * collider_transform.rotation.0);
+        *collider_parent = ColliderParent(root);
+        *collider_transform = ColliderTransform {
+            translation: local_position + local_rotation.rotate(collider_transform.translation),
+            rotation,
+            scale: collider_transform.scale,
+        };
+        **previous_transform = *collider_transform;
+
+        colliders.push((
+            entity,
+            body_transform,
+            ColliderMassProperties {
+                center_of_mass: CenterOfMass(
+                    collider_transform.transform_point(collider_mass_properties.center_of_mass.0),
+                ),
+                ..*collider_mass_properties
+            },
+        ));
+    }
+
+    let mut mass_query = world.query::<MassPropertiesQuery>();
+    if let Ok(mut root_mass_properties) = mass_query.get_mut(world, root) {
+        root_mass_properties += mass_properties;
+    }
+
+    let merged_body = MergedBody {
+        root,
+        rigid_body,
+        mass_properties,
+        colliders,
+    };
+    world
+        .resource_mut::<MergedBodyStorage>()
+        .insert(body, merged_body.clone());
+    world
+        .entity_mut(body)
+        .remove::<RigidBody>()
+        .insert((merged_body, local_transform))
+        .set_parent(root);
+}
+
+/// Restores the bodies merged into `root` as separate bodies and returns the joints that were restored.
+fn split_merged_bodies(world: &mut World, root: Entity) -> Vec<Entity> {
+    let mut members_query = world.query::<(Entity, &MergedBody)>();
+    let members: Vec<(Entity, MergedBody)> = members_query
+        .iter(world)
+        .filter(|(_, merged_body)| merged_body.root == root)
+        .map(|(entity, merged_body)| (entity, merged_body.clone()))
+        .collect();
+
+    let mut root_query = world.query::<(
+        &Position,
+        &Rotation,
+        &CenterOfMass,
+        &LinearVelocity,
+        &AngularVelocity,
+    )>();
+    let root_velocity =
+        root_query
+            .get(world, root)
+            .ok()
+            .map(|(pos, rot, center_of_mass, lin_vel, ang_vel)| {
+                (pos.0 + rot.rotate(center_of_mass.0), *lin_vel, *ang_vel)
+            });
+
+    let mut mass_query = world.query::<MassPropertiesQuery>();
+    for (entity, merged_body) in members {
+        world.resource_mut::<MergedBodyStorage>().remove(&entity);
+
+        if let Ok(mut root_mass_properties) = mass_query.get_mut(world, root) {
+            root_mass_properties -= merged_body.mass_properties;
+        }
+
+        for (collider, transform, _) in merged_body.colliders.iter() {
+            let Some(mut collider_mut) = world.get_entity_mut(*collider) else {
+                continue;
+            };
+            collider_mut.insert((ColliderParent(entity), *transform));
+            if let Some(mut previous_transform) =
+                collider_mut.get_mut::<PreviousColliderTransform>()
+            {
+                **previous_transform = *transform;
+            }
+        }
+
+        // The parts continue moving with the composite body
+        let (lin_vel, ang_vel) = match (root_velocity, world.get::<Position>(entity)) {
+            (Some((root_center_of_mass, lin_vel, ang_vel)), Some(pos)) => (
+                LinearVelocity(lin_vel.at_point(ang_vel, pos.0, root_center_of_mass)),
+                ang_vel,
+            ),
+            _ => (LinearVelocity::ZERO, AngularVelocity::ZERO),
+        };
+        let transform = world
+            .get::<GlobalTransform>(entity)
+            .map_or(Transform::default(), |transform| {
+                transform.compute_transform()
+            });
+
+        world
+            .entity_mut(entity)
+            .remove_parent()
+            .remove::<MergedBody>()
+            .insert((merged_body.rigid_body, lin_vel, ang_vel, transform));
+    }
+
+    let mut joints_query = world.query::<(Entity, &MergedJoint)>();
+    let joints: Vec<(Entity, FixedJoint)> = joints_query
+        .iter(world)
+        .filter(|(_, merged_joint)| merged_joint.root == root)
+        .map(|(entity, merged_joint)| (entity, merged_joint.joint))
+        .collect();
+
+    joints
+        .into_iter()
+        .map(|(entity, mut joint)| {
+            joint.clear_lagrange_multipliers();
+            world
+                .entity_mut(entity)
+                .remove::<MergedJoint>()
+                .insert(joint);
+            entity
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::{ecs::system::CommandQueue, prelude::*};
+
+    /// Spawns a row of three dynamic blocks along the X axis.
+    fn spawn_blocks(app: &mut App) -> Vec<Entity> {
+        (0..3)
+            .map(|i| {
+                app.world
+                    .spawn((
+                        TransformBundle::default(),
+                        RigidBody::Dynamic,
+                        Position(Vector::X * i as Scalar),
+                        Collider::ball(0.5),
+                    ))
+                    .id()
+            })
+            .collect()
+    }
+
+    /// Connects neighboring blocks with fixed joints and returns the joints.
+    fn connect_blocks(app: &mut App, blocks: &[Entity]) -> Vec<Entity> {
+        blocks
+            .windows(2)
+            .map(|pair| {
+                app.world
+                    .spawn(
+                        FixedJoint::new(pair[0], pair[1])
+                            .with_local_anchor_1(Vector::X * 0.5)
+                            .with_local_anchor_2(Vector::NEG_X * 0.5),
+                    )
+                    .id()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fixed_joint_clusters_are_merged_into_composite_bodies() {
+        let mut app = create_app();
+        app.add_plugins(JointMergingPlugin);
+        app.insert_resource(Gravity::ZERO);
+
+        let blocks = spawn_blocks(&mut app);
+
+        app.step_physics(1);
+
+        let block_mass = app.world.get::<Mass>(blocks[0]).unwrap().0;
+
+        connect_blocks(&mut app, &blocks);
+
+        app.step_physics(1);
+
+        // The first block is the root and has the combined mass properties
+        let root = blocks[0];
+        assert!(app.world.get::<RigidBody>(root).is_some());
+        for block in &blocks[1..] {
+            assert!(app.world.get::<RigidBody>(*block).is_none());
+            assert_eq!(app.world.get::<MergedBody>(*block).unwrap().root(), root);
+        }
+        assert_relative_eq!(
+            app.world.get::<Mass>(root).unwrap().0,
+            block_mass * 3.0,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            app.world.get::<CenterOfMass>(root).unwrap().0,
+            Vector::X,
+            epsilon = 0.001
+        );
+        assert_eq!(app.world.query::<&FixedJoint>().iter(&app.world).count(), 0);
+
+        // The merged blocks move with the root
+        app.world.get_mut::<LinearVelocity>(root).unwrap().0 = Vector::Y * 2.0;
+        app.step_physics(30);
+        let root_position = app.world.get::<Position>(root).unwrap().0;
+        assert!(root_position.y > 0.5);
+        assert_relative_eq!(
+            app.world.get::<Position>(blocks[2]).unwrap().0,
+            root_position + Vector::X * 2.0,
+            epsilon = 0.001
+        );
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &app.world).split_merged_bodies(root);
+        queue.apply(&mut app.world);
+
+        app.step_physics(1);
+
+        // The blocks are restored with the velocity of the composite body, and they aren't merged again
+        for block in &blocks {
+            assert!(app.world.get::<RigidBody>(*block).is_some());
+            assert!(app.world.get::<MergedBody>(*block).is_none());
+            assert_relative_eq!(
+                app.world.get::<LinearVelocity>(*block).unwrap().0,
+                Vector::Y * 2.0,
+                epsilon = 0.001
+            );
+        }
+        assert_relative_eq!(
+            app.world.get::<Mass>(root).unwrap().0,
+            block_mass,
+            epsilon = 0.001
+        );
+        assert_eq!(
+            app.world
+                .query_filtered::<&FixedJoint, With<NoJointMerging>>()
+                .iter(&app.world)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn composite_bodies_are_updated_when_joints_change() {
+        // Returns an app with three blocks merged into a composite body, the blocks,
+        // the merged joints and the mass of a single block
+        fn merged_blocks() -> (App, Vec<Entity>, Vec<Entity>, Scalar) {
+            let mut app = create_app();
+            app.add_plugins(JointMergingPlugin);
+            app.insert_resource(Gravity::ZERO);
+
+            let blocks = spawn_blocks(&mut app);
+            app.step_physics(1);
+
+            let block_mass = app.world.get::<Mass>(blocks[0]).unwrap().0;
+            let joints = connect_blocks(&mut app, &blocks);
+
+            app.step_physics(1);
+
+            assert!(app.world.get::<MergedBody>(blocks[2]).is_some());
+            (app, blocks, joints, block_mass)
+        }
+        let root_mass = |app: &App, blocks: &[Entity]| app.world.get::<Mass>(blocks[0]).unwrap().0;
+
+        // Attaching another type of joint to a merged block splits it off from the composite body
+        let (mut app, blocks, joints, block_mass) = merged_blocks();
+        let anchor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * 3.0),
+            ))
+            .id();
+        app.world.spawn(RevoluteJoint::new(blocks[2], anchor));
+        app.step_physics(1);
+        assert!(app.world.get::<RigidBody>(blocks[2]).is_some());
+        assert!(app.world.get::<MergedBody>(blocks[2]).is_none());
+        assert!(app.world.get::<FixedJoint>(joints[1]).is_some());
+        assert_eq!(
+            app.world.get::<MergedBody>(blocks[1]).unwrap().root(),
+            blocks[0]
+        );
+        assert_relative_eq!(root_mass(&app, &blocks), block_mass * 2.0, epsilon = 0.001);
+
+        // Disabling a merged joint splits the composite body
+        let (mut app, blocks, joints, block_mass) = merged_blocks();
+        app.world.entity_mut(joints[0]).insert(JointDisabled);
+        app.step_physics(1);
+        assert!(app.world.get::<RigidBody>(blocks[1]).is_some());
+        assert!(app.world.get::<FixedJoint>(joints[0]).is_some());
+        assert_eq!(
+            app.world.get::<MergedBody>(blocks[2]).unwrap().root(),
+            blocks[1]
+        );
+        assert_relative_eq!(root_mass(&app, &blocks), block_mass, epsilon = 0.001);
+
+        // Re-enabling the joint merges the blocks again
+        app.world.entity_mut(joints[0]).remove::<JointDisabled>();
+        app.step_physics(1);
+        let root = app.world.get::<MergedBody>(blocks[2]).unwrap().root();
+        assert_eq!(root, blocks[1]);
+        assert_eq!(app.world.get::<MergedBody>(blocks[0]).unwrap().root(), root);
+        assert_relative_eq!(
+            app.world.get::<Mass>(root).unwrap().0,
+            block_mass * 3.0,
+            epsilon = 0.001
+        );
+
+        // Merged joints that become breakable are restored, since they need to be solved to break
+        let (mut app, blocks, joints, block_mass) = merged_blocks();
+        app.world
+            .entity_mut(joints[1])
+            .insert(BreakableJoint::new(100.0));
+        app.step_physics(1);
+        assert!(app.world.get::<FixedJoint>(joints[1]).is_some());
+        assert!(app.world.get::<RigidBody>(blocks[2]).is_some());
+        assert_relative_eq!(root_mass(&app, &blocks), block_mass, epsilon = 0.001);
+
+        // Despawning a merged block removes its mass from the composite body
+        let (mut app, blocks, _, block_mass) = merged_blocks();
+        app.world.despawn(blocks[2]);
+        app.step_physics(1);
+        assert_eq!(
+            app.world.get::<MergedBody>(blocks[1]).unwrap().root(),
+            blocks[0]
+        );
+        assert_relative_eq!(root_mass(&app, &blocks), block_mass * 2.0, epsilon = 0.001);
+        assert_relative_eq!(
+            app.world.get::<CenterOfMass>(blocks[0]).unwrap().0,
+            Vector::X * 0.5,
+            epsilon = 0.001
+        );
+
+        // Despawning the root restores the other blocks as separate bodies
+        let (mut app, blocks, _, block_mass) = merged_blocks();
+        app.world.despawn(blocks[0]);
+        app.step_physics(1);
+        assert!(app.world.get::<RigidBody>(blocks[1]).is_some());
+        assert!(app.world.get::<MergedBody>(blocks[2]).is_some());
+        assert_eq!(
+            app.world.get::<MergedBody>(blocks[2]).unwrap().root(),
+            blocks[1]
+        );
+        assert_relative_eq!(
+            app.world.get::<Mass>(blocks[1]).unwrap().0,
+            block_mass * 2.0,
+            epsilon = 0.001
+        );
+    }
+}
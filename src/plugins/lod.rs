@@ -0,0 +1,288 @@
+//! Simplifies the simulation of bodies that are far away from the gameplay-relevant areas.
+//!
+//! See [`PhysicsLodPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Simplifies the simulation of bodies based on their [level of detail](PhysicsLod).
+///
+/// Contacts between bodies with [`PhysicsLod::Reduced`] are simplified to a single contact point,
+/// the deepest one, which reduces the number of contact constraints that the solver has to handle.
+/// Contacts with bodies that use [`PhysicsLod::Full`] are never simplified, so bodies close to the players
+/// always interact with the rest of the world accurately.
+///
+/// If there are any entities with the [`LodFocus`] component, the [`PhysicsLod`] of all
+/// [dynamic](RigidBody::Dynamic) bodies is updated automatically based on their distance to the closest focus.
+/// Bodies that are further away than [`PhysicsLodConfig::reduced_distance`] are demoted to [`PhysicsLod::Reduced`],
+/// and they are promoted back to [`PhysicsLod::Full`] when they approach a focus. Without any focuses,
+/// the [`PhysicsLod`] can be set manually per body.
+///
+/// The levels of detail are updated before [`PhysicsStepSet::BroadPhase`], and the contacts are simplified
+/// after [`SubstepSet::PostProcessCollisions`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default(), PhysicsLodPlugin))
+///         .insert_resource(PhysicsLodConfig {
+///             reduced_distance: 30.0,
+///             ..default()
+///         })
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     // Bodies further than 30 units from the player are simplified
+///     commands.spawn((TransformBundle::default(), LodFocus));
+/// }
+/// ```
+pub struct PhysicsLodPlugin;
+
+impl Plugin for PhysicsLodPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsLodConfig>()
+            .register_type::<PhysicsLodConfig>()
+            .register_type::<PhysicsLod>()
+            .register_type::<LodFocus>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(update_physics_lod.before(PhysicsStepSet::BroadPhase));
+
+        app.get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first")
+            .add_systems(
+                simplify_reduced_contacts
+                    .after(SubstepSet::PostProcessCollisions)
+                    .before(SubstepSet::SolveConstraints),
+            );
+    }
+}
+
+/// Configures how the [`PhysicsLodPlugin`] assigns levels of detail to bodies.
+#[derive(Resource, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct PhysicsLodConfig {
+    /// The distance from the closest [`LodFocus`] after which bodies use [`PhysicsLod::Reduced`].
+    pub reduced_distance: Scalar,
+    /// How much closer than the [`reduced_distance`](Self::reduced_distance) bodies need to get to a focus
+    /// before they are promoted back to [`PhysicsLod::Full`]. This prevents bodies at the boundary
+    /// from switching between the levels all the time.
+    pub hysteresis: Scalar,
+}
+
+impl Default for PhysicsLodConfig {
+    fn default() -> Self {
+        Self {
+            reduced_distance: 50.0,
+            hysteresis: 5.0,
+        }
+    }
+}
+
+/// The level of detail that a body is simulated with. See [`PhysicsLodPlugin`].
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum PhysicsLod {
+    /// The body is simulated normally.
+    #[default]
+    Full,
+    /// Contacts between the body and other bodies with reduced detail are simplified to a single contact point.
+    Reduced,
+}
+
+/// Marks a gameplay-relevant entity, like a camera or a player, around which bodies are simulated with
+/// [`PhysicsLod::Full`]. See [`PhysicsLodPlugin`].
+///
+/// The position of the focus is read from its [`GlobalTransform`].
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct LodFocus;
+
+fn update_physics_lod(
+    mut commands: Commands,
+    focuses: Query<&GlobalTransform, With<LodFocus>>,
+    mut bodies: Query<(Entity, &RigidBody, &Position, Option<&mut PhysicsLod>)>,
+    config: Res<PhysicsLodConfig>,
+) {
+    if focuses.is_empty() {
+        return;
+    }
+
+    let focuses: Vec<Vector> = focuses
+        .iter()
+        .map(|transform| {
+            #[cfg(feature = "2d")]
+            let focus = transform.translation().truncate().adjust_precision();
+            #[cfg(feature = "3d")]
+            let focus = transform.translation().adjust_precision();
+            focus
+        })
+        .collect();
+
+    for (entity, rb, position, lod) in &mut bodies {
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let current_lod = lod.as_deref().copied().unwrap_or_default();
+        let distance_sq = focuses
+            .iter()
+            .map(|focus| position.distance_squared(*focus))
+            .fold(Scalar::MAX, Scalar::min);
+
+        // Reduced bodies need to get closer than the distance to be promoted again
+        let threshold = match current_lod {
+            PhysicsLod::Full => config.reduced_distance,
+            PhysicsLod::Reduced => (config.reduced_distance - config.hysteresis).max(0.0),
+        };
+        let new_lod = if distance_sq > threshold * threshold {
+            PhysicsLod::Reduced
+        } else {
+            PhysicsLod::Full
+        };
+
+        match lod {
+            Some(mut lod) => {
+                if *lod != new_lod {
+                    *lod = new_lod;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(new_lod);
+            }
+        }
+    }
+}
+
+/// Reduces the contacts between bodies with [`PhysicsLod::Reduced`] to the deepest contact point.
+fn simplify_reduced_contacts(
+    bodies: Query<(&RigidBody, Option<&PhysicsLod>)>,
+    mut collisions: ResMut<Collisions>,
+) {
+    // Static bodies don't have a level of detail, so only the other bodies need to use reduced detail
+    let is_reduced = |body: Option<Entity>| {
+        body.and_then(|entity| bodies.get(entity).ok())
+            .map_or(true, |(rb, lod)| {
+                rb.is_static() || lod.copied().unwrap_or_default() == PhysicsLod::Reduced
+            })
+    };
+
+    for contacts in collisions.get_internal_mut().values_mut() {
+        if !contacts.during_current_substep
+            || !is_reduced(contacts.body_entity1)
+            || !is_reduced(contacts.body_entity2)
+        {
+            continue;
+        }
+
+        let Some((manifold_index, contact)) = contacts
+            .manifolds
+            .iter()
+            .enumerate()
+            .flat_map(|(index, manifold)| {
+                manifold
+                    .contacts
+                    .iter()
+                    .map(move |contact| (index, *contact))
+            })
+            .max_by(|(_, a), (_, b)| a.penetration.total_cmp(&b.penetration))
+        else {
+            continue;
+        };
+
+        let mut manifold = contacts.manifolds.swap_remove(manifold_index);
+        manifold.contacts = vec![contact];
+        contacts.manifolds = vec![manifold];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider, floor_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn reduced_lod_bodies_use_single_point_contacts() {
+        let mut app = create_app();
+        app.add_plugins(PhysicsLodPlugin);
+        app.insert_resource(PhysicsLodConfig {
+            reduced_distance: 5.0,
+            hysteresis: 1.0,
+        });
+
+        let ground = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                floor_collider(40.0),
+                Position(Vector::NEG_Y * 0.5),
+            ))
+            .id();
+        app.world.spawn((TransformBundle::default(), LodFocus));
+
+        let near_box = app
+            .world
+            .spawn((
+                RigidBody::Dynamic,
+                cube_collider(1.0),
+                Position(Vector::Y * 0.49),
+            ))
+            .id();
+        let far_box = app
+            .world
+            .spawn((
+                RigidBody::Dynamic,
+                cube_collider(1.0),
+                Position(Vector::X * 10.0 + Vector::Y * 0.49),
+            ))
+            .id();
+
+        app.step_physics(10);
+
+        assert_eq!(
+            app.world.get::<PhysicsLod>(near_box),
+            Some(&PhysicsLod::Full)
+        );
+        assert_eq!(
+            app.world.get::<PhysicsLod>(far_box),
+            Some(&PhysicsLod::Reduced)
+        );
+
+        let contact_count = |body: Entity| {
+            let collisions = app.world.resource::<Collisions>();
+            let contacts = collisions
+                .get(ground, body)
+                .expect("no contact with ground");
+            contacts
+                .manifolds
+                .iter()
+                .map(|manifold| manifold.contacts.len())
+                .sum::<usize>()
+        };
+        assert!(contact_count(near_box) > 1);
+        assert_eq!(contact_count(far_box), 1);
+
+        // The reduced body still rests on the ground
+        assert_relative_eq!(
+            app.world.get::<Position>(far_box).unwrap().y,
+            0.5,
+            epsilon = 0.05
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Attracts and repels bodies with [`Magnet`]s.
+//!
+//! See [`MagnetismPlugin`].
+
+use crate::prelude::*;
+use bevy::{ecs::query::Has, prelude::*};
+
+/// Applies forces between bodies with the [`Magnet`] component.
+///
+/// Magnets with opposite [polarities](MagnetPolarity) attract each other, and magnets with the same polarity
+/// repel each other. The force is proportional to the strengths of the magnets and falls off with the
+/// inverse square of the distance between them.
+///
+/// Nearby magnets are found by sweeping over the ranges of the magnets, similarly to the [broad phase](BroadPhasePlugin),
+/// so magnets that are far away from each other are cheap.
+///
+/// The forces are applied to the velocities of the bodies once per physics step,
+/// after [`PhysicsStepSet::BroadPhase`] and before [`PhysicsStepSet::Substeps`].
+pub struct MagnetismPlugin;
+
+impl Plugin for MagnetismPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Magnet>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                apply_magnet_forces
+                    .after(PhysicsStepSet::BroadPhase)
+                    .before(PhysicsStepSet::Substeps),
+            );
+    }
+}
+
+/// A magnet that attracts or repels other [rigid bodies](RigidBody) with magnets.
+/// Requires the [`MagnetismPlugin`].
+///
+/// Two magnets affect each other if the distance between them is less than the larger of their radii.
+/// The magnitude of the force is `strength1 * strength2 / distance²`, and it is attractive for opposite
+/// [polarities](MagnetPolarity) and repulsive for equal polarities.
+///
+/// Only [dynamic](RigidBody::Dynamic) bodies are moved by magnets, but magnets on static and kinematic bodies
+/// still attract and repel other magnets.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A fixed magnet that pulls in the other magnet
+///     commands.spawn((
+///         RigidBody::Static,
+///         Collider::ball(0.5),
+///         Magnet::new(50.0, 10.0, MagnetPolarity::North),
+///     ));
+///
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         Position(Vector::X * 5.0),
+///         Magnet::new(1.0, 10.0, MagnetPolarity::South),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct Magnet {
+    /// The strength of the magnet.
+    pub strength: Scalar,
+    /// The maximum distance at which the magnet affects other magnets.
+    pub radius: Scalar,
+    /// The polarity of the magnet.
+    pub polarity: MagnetPolarity,
+}
+
+impl Magnet {
+    /// Creates a new [`Magnet`] with the given strength, radius and polarity.
+    pub const fn new(strength: Scalar, radius: Scalar, polarity: MagnetPolarity) -> Self {
+        Self {
+            strength,
+            radius,
+            polarity,
+        }
+    }
+
+    /// Returns the force that the `other` magnet applies to this magnet, given the offset from this magnet to the other one.
+    ///
+    /// The force is zero if the magnets are out of range of each other.
+    pub fn force_from(&self, other: &Self, offset: Vector) -> Vector {
+        let distance_squared = offset.length_squared();
+        let radius = self.radius.max(other.radius);
+
+        if distance_squared >= radius * radius || distance_squared < Scalar::EPSILON {
+            return Vector::ZERO;
+        }
+
+        let magnitude = self.strength * other.strength / distance_squared;
+        let direction = offset / distance_squared.sqrt();
+
+        if self.polarity == other.polarity {
+            -direction * magnitude
+        } else {
+            direction * magnitude
+        }
+    }
+}
+
+impl Default for Magnet {
+    /// Creates a magnet with a strength and radius of `1.0` and the north polarity.
+    fn default() -> Self {
+        Self::new(1.0, 1.0, MagnetPolarity::North)
+    }
+}
+
+/// The polarity of a [`Magnet`]. Opposite polarities attract each other, and equal polarities repel each other.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MagnetPolarity {
+    /// The north pole.
+    #[default]
+    North,
+    /// The south pole.
+    South,
+}
+
+impl MagnetPolarity {
+    /// Returns the opposite polarity.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+        }
+    }
+}
+
+type MagnetQueryComponents = (
+    Entity,
+    &'static Magnet,
+    &'static RigidBody,
+    &'static Position,
+    &'static mut LinearVelocity,
+    &'static InverseMass,
+    Option<&'static LockedAxes>,
+    Has<Sleeping>,
+);
+
+/// Finds magnets that are in range of each other and applies the magnetic forces to their velocities.
+fn apply_magnet_forces(
+    mut commands: Commands,
+    mut magnets: Query<MagnetQueryComponents>,
+    mut intervals: Local<Vec<(Entity, Vector, Magnet)>>,
+    dt: Res<DeltaTime>,
+) {
+    intervals.clear();
+    intervals.extend(
+        magnets
+            .iter()
+            .map(|(entity, magnet, _, position, ..)| (entity, position.0, *magnet)),
+    );
+
+    if intervals.len() < 2 {
+        return;
+    }
+
+    // Sort the magnets along the x-axis by the start of their range
+    intervals.sort_unstable_by(|(_, pos1, magnet1), (_, pos2, magnet2)| {
+        (pos1.x - magnet1.radius).total_cmp(&(pos2.x - magnet2.radius))
+    });
+
+    for (i, (entity1, position1, magnet1)) in intervals.iter().enumerate() {
+        for (entity2, position2, magnet2) in intervals.iter().skip(i + 1) {
+            // The ranges don't overlap along the x-axis
+            if position2.x - magnet2.radius > position1.x + magnet1.radius {
+                break;
+            }
+
+            let force = magnet1.force_from(magnet2, *position2 - *position1);
+            if force == Vector::ZERO {
+                continue;
+            }
+
+            let Ok([body1, body2]) = magnets.get_many_mut([*entity1, *entity2]) else {
+                continue;
+            };
+
+            for ((entity, _, rb, _, mut lin_vel, inv_mass, locked_axes, sleeping), force) in
+                [(body1, force), (body2, -force)]
+            {
+                if !rb.is_dynamic() {
+                    continue;
+                }
+
+                if sleeping {
+                    commands.entity(entity).remove::<Sleeping>();
+                }
+
+                let locked_axes =
+                    locked_axes.map_or(LockedAxes::default(), |locked_axes| *locked_axes);
+                lin_vel.0 += locked_axes.apply_to_vec(force * inv_mass.0 * dt.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::prelude::*;
+
+    #[test]
+    fn magnets_attract_and_repel() {
+        let mut app = create_app();
+        app.add_plugins(MagnetismPlugin);
+        app.insert_resource(Gravity::ZERO);
+
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Static,
+            Collider::ball(0.5),
+            Magnet::new(10.0, 20.0, MagnetPolarity::North),
+        ));
+        let attracted = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 5.0),
+                Collider::ball(0.5),
+                Magnet::new(1.0, 1.0, MagnetPolarity::South),
+            ))
+            .id();
+        let repelled = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * -5.0),
+                Collider::ball(0.5),
+                Magnet::new(1.0, 1.0, MagnetPolarity::North),
+            ))
+            .id();
+        let out_of_range = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 30.0),
+                Collider::ball(0.5),
+                Magnet::new(1.0, 1.0, MagnetPolarity::South),
+            ))
+            .id();
+
+        app.step_physics(5);
+
+        assert!(app.world.get::<LinearVelocity>(attracted).unwrap().x < 0.0);
+        assert!(app.world.get::<LinearVelocity>(repelled).unwrap().x < 0.0);
+        assert_eq!(
+            app.world.get::<LinearVelocity>(out_of_range).unwrap().0,
+            Vector::ZERO
+        );
+    }
+}
@@ -20,32 +20,115 @@
 //! - [`PhysicsSchedule`] and [`PhysicsStepSet`]
 //! - [`SubstepSchedule`] and [`SubstepSet`]
 
+pub mod activity_regions;
+pub mod character;
 pub mod collision;
 #[cfg(feature = "debug-plugin")]
 pub mod debug;
+pub mod follow_path;
 pub mod integrator;
+pub mod islands;
+pub mod joint_merging;
+pub mod lod;
+pub mod magnetism;
+#[cfg(feature = "mouse-drag")]
+pub mod mouse_drag;
+#[cfg(feature = "picking")]
+pub mod picking;
+#[cfg(feature = "3d")]
+pub mod plane_lock;
 pub mod prepare;
+#[cfg(feature = "rapier-compat")]
+pub mod rapier_compat;
+pub mod region_streaming;
+pub mod relative_velocity;
+pub mod replay;
 pub mod setup;
 pub mod sleeping;
 pub mod solver;
 pub mod spatial_query;
+pub mod state_hash;
+pub mod static_chunks;
+pub mod static_merging;
+pub mod structural_stress;
 pub mod sync;
+#[cfg(feature = "2d")]
+pub mod tilemap;
+#[cfg(feature = "2d")]
+pub mod top_down;
+pub mod triggers;
+#[cfg(feature = "egui")]
+pub mod tuning;
 
+pub use activity_regions::{
+    ActivityRegion, ActivityRegionPlugin, AlwaysActive, OutsideActivityRegions,
+};
+pub use character::{CharacterCapsule, CharacterPlugin, Ledge, WallContact, WallDetector};
 pub use collision::{
-    broad_phase::{BroadCollisionPairs, BroadPhasePlugin},
+    broad_phase::{BroadCollisionPairs, BroadPhaseConfig, BroadPhasePlugin},
     contact_reporting::{Collision, CollisionEnded, CollisionStarted, ContactReportingPlugin},
     narrow_phase::{NarrowPhaseConfig, NarrowPhasePlugin},
     *,
 };
 #[cfg(feature = "debug-plugin")]
 pub use debug::*;
-pub use integrator::IntegratorPlugin;
+pub use follow_path::{FollowPath, FollowPathPlugin, PathMode};
+pub use integrator::{
+    ForceField, ForceFieldAppExt, ForceFieldBody, ForceFields, Integrator, IntegratorPlugin,
+    LinearIntegration, PhysicsIntegrator, SemiImplicitEuler, VelocityVerlet,
+};
+pub use islands::{Island, IslandId, IslandPlugin, PhysicsIslands};
+pub use joint_merging::{
+    JointMergingCommandsExt, JointMergingPlugin, MergedBody, MergedJoint, NoJointMerging,
+    SplitMergedBodies,
+};
+pub use lod::{LodFocus, PhysicsLod, PhysicsLodConfig, PhysicsLodPlugin};
+pub use magnetism::{Magnet, MagnetPolarity, MagnetismPlugin};
+#[cfg(feature = "mouse-drag")]
+pub use mouse_drag::{DragAnchor, MouseDragPlugin, MouseDragSettings};
+#[cfg(feature = "picking")]
+pub use picking::*;
+#[cfg(feature = "3d")]
+pub use plane_lock::{LockToPlane, PlaneLockPlugin};
 pub use prepare::*;
+#[cfg(feature = "rapier-compat")]
+pub use rapier_compat::RapierCompatPlugin;
+pub use region_streaming::{
+    FreezeRegion, FrozenBody, FrozenCollider, FrozenJoint, FrozenRegions, RegionFrozen,
+    RegionStreamingCommandsExt, RegionStreamingPlugin, RegionThawed, ThawRegion,
+};
+pub use relative_velocity::{RelativeVelocityPlugin, RelativeVelocitySensor};
+pub use replay::{
+    PhysicsInput, PhysicsRecorder, PhysicsRecording, PhysicsReplayer, RecordedBody, RecordedBodyId,
+    RecordedFrame, ReplayPlugin,
+};
 pub use setup::*;
 pub use sleeping::SleepingPlugin;
-pub use solver::{solve_constraint, SolverPlugin};
+pub use solver::{
+    solve_articulated_joints, solve_constraint, ConstraintAppExt, ConstraintPriority,
+    SolverBackend, SolverConfig, SolverMode, SolverPlugin, XpbdSolverBackend,
+};
 pub use spatial_query::*;
-pub use sync::SyncPlugin;
+pub use state_hash::{PhysicsStateHash, StateHashPlugin};
+pub use static_chunks::{
+    ChunkCoordinates, DespawnStaticChunk, SpawnStaticChunk, StaticChunk, StaticChunkCommandsExt,
+    StaticChunkMember, StaticChunkPlugin, StaticChunkRegistered, StaticChunks,
+};
+pub use static_merging::{
+    merge_static_colliders, MergeStaticColliders, MergedStaticCollider, StaticMergingCommandsExt,
+};
+pub use structural_stress::{BreakableJoint, JointBroken, StructuralStressPlugin};
+pub use sync::{
+    ExtrapolateTransform, InterpolateTransform, NoTransformInterpolation, PreviousStepPosition,
+    PreviousStepRotation, SyncConfig, SyncPlugin,
+};
+#[cfg(feature = "2d")]
+pub use tilemap::{TileColliderChunk, TileColliderMode, TileColliders, TilemapColliderPlugin};
+#[cfg(feature = "2d")]
+pub use top_down::{GroundFriction, TopDownConfig, TopDownPlugin};
+pub use triggers::{SensorOverlaps, TriggerEnter, TriggerExit, TriggerPlugin};
+#[cfg(feature = "egui")]
+pub use tuning::PhysicsTuningPlugin;
 
 #[allow(unused_imports)]
 use crate::prelude::*; // For doc comments
@@ -93,6 +176,11 @@ use bevy::prelude::*;
 /// }
 /// ```
 ///
+/// When the physics plugins are added to `FixedUpdate`, the [`PhysicsTimestep`] defaults to
+/// [`PhysicsTimestep::FixedUpdate`], which advances the simulation by the period of Bevy's `FixedTime`
+/// once per run of `FixedUpdate`. This way, physics and your own fixed-step gameplay systems share
+/// the same tick without drifting apart.
+///
 /// Note that using `FixedUpdate` with [`PhysicsTimestep::Fixed`] can produce unexpected results due to two separate
 /// fixed timesteps. However, using `FixedUpdate` can be useful for [networking usage](crate#can-the-engine-be-used-on-servers)
 /// when you need to keep the client and server in sync.
@@ -202,7 +290,7 @@ impl PluginGroup for PhysicsPlugins {
             .add(IntegratorPlugin)
             .add(NarrowPhasePlugin)
             .add(ContactReportingPlugin)
-            .add(SolverPlugin)
+            .add(SolverPlugin::default())
             .add(SleepingPlugin)
             .add(SpatialQueryPlugin::new(self.schedule.dyn_clone()))
             .add(SyncPlugin::new(self.schedule))
@@ -0,0 +1,329 @@
+//! Drags dynamic bodies with the mouse using a temporary spring.
+//!
+//! See [`MouseDragPlugin`].
+
+use crate::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
+
+/// Lets the user pick up and drag [dynamic](RigidBody::Dynamic) bodies with the mouse, which is useful
+/// for physics sandboxes, demos and debugging.
+///
+/// When the [drag button](MouseDragSettings::button) is pressed, the body under the cursor is picked using
+/// [spatial queries](spatial_query). In 3D, a ray is cast from the camera through the cursor, and in 2D,
+/// the collider containing the cursor position is picked.
+///
+/// The grabbed point on the body is then attached to a kinematic [`DragAnchor`] entity with a soft
+/// [`SpringJoint`]. The anchor follows the cursor until the button is released, at which point the anchor
+/// and the joint are despawned. In 3D, the anchor stays at the depth at which the body was grabbed.
+///
+/// The stiffness of the spring is scaled by the mass of the body, so light and heavy bodies are dragged
+/// the same way. It can be configured using [`MouseDragSettings`].
+///
+/// This plugin requires the `mouse-drag` feature.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default(), MouseDragPlugin))
+///         .run();
+/// }
+/// ```
+pub struct MouseDragPlugin;
+
+impl Plugin for MouseDragPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MouseDragSettings>()
+            .register_type::<MouseDragSettings>()
+            .register_type::<DragAnchor>()
+            .add_systems(Update, (start_drag, move_drag_anchors, end_drag).chain());
+    }
+}
+
+/// Configures the [`MouseDragPlugin`].
+#[derive(Reflect, Resource, Clone)]
+#[reflect(Resource)]
+pub struct MouseDragSettings {
+    /// The mouse button used for dragging bodies.
+    pub button: MouseButton,
+    /// The frequency of the spring pulling the body towards the cursor in hertz.
+    /// Higher frequencies make the body follow the cursor more tightly.
+    pub frequency: Scalar,
+    /// The damping ratio of the spring. A value of `1.0` is critical damping,
+    /// and smaller values make the body swing around the cursor.
+    pub damping_ratio: Scalar,
+    /// The [`SpatialQueryFilter`] used for picking bodies.
+    #[reflect(ignore)]
+    pub filter: SpatialQueryFilter,
+}
+
+impl Default for MouseDragSettings {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            frequency: 5.0,
+            damping_ratio: 0.7,
+            filter: SpatialQueryFilter::default(),
+        }
+    }
+}
+
+/// A kinematic body that follows the cursor while a body is being dragged by the [`MouseDragPlugin`].
+///
+/// The dragged body is attached to the anchor using the [`SpringJoint`] stored in `joint`.
+#[derive(Reflect, Component, Clone, Copy, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct DragAnchor {
+    /// The body being dragged.
+    pub body: Entity,
+    /// The [`SpringJoint`] attaching the body to the anchor.
+    pub joint: Entity,
+    /// The camera through which the body is being dragged.
+    pub camera: Entity,
+    /// The distance of the anchor from the camera along the ray through the cursor.
+    #[cfg(feature = "3d")]
+    pub depth: Scalar,
+}
+
+impl Default for DragAnchor {
+    /// Creates an anchor with placeholder entities.
+    fn default() -> Self {
+        Self {
+            body: Entity::PLACEHOLDER,
+            joint: Entity::PLACEHOLDER,
+            camera: Entity::PLACEHOLDER,
+            #[cfg(feature = "3d")]
+            depth: 0.0,
+        }
+    }
+}
+
+/// Picks the dynamic body under the cursor and attaches it to a new [`DragAnchor`] when the drag button is pressed.
+#[allow(clippy::too_many_arguments)]
+fn start_drag(
+    mut commands: Commands,
+    buttons: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    bodies: Query<(&RigidBody, &Position, &Rotation, &Mass)>,
+    colliders: Query<&ColliderParent>,
+    anchors: Query<(), With<DragAnchor>>,
+    spatial_query: SpatialQuery,
+    settings: Res<MouseDragSettings>,
+) {
+    if !buttons.just_pressed(settings.button) || !anchors.is_empty() {
+        return;
+    }
+
+    let Some(cursor) = windows.get_single().ok().and_then(|w| w.cursor_position()) else {
+        return;
+    };
+
+    let dynamic_body = |entity: Entity| {
+        let body = colliders.get(entity).map_or(entity, |parent| parent.get());
+        bodies
+            .get(body)
+            .map_or(false, |(rb, ..)| rb.is_dynamic())
+            .then_some(body)
+    };
+
+    for (camera_entity, camera, camera_transform) in cameras.iter().filter(|(_, c, _)| c.is_active)
+    {
+        #[cfg(feature = "2d")]
+        let Some((body, point)) = camera
+            .viewport_to_world_2d(camera_transform, cursor)
+            .and_then(|point| {
+                let point = point.adjust_precision();
+                spatial_query
+                    .point_intersections(point, settings.filter.clone())
+                    .into_iter()
+                    .find_map(dynamic_body)
+                    .map(|body| (body, point))
+            })
+        else {
+            continue;
+        };
+
+        #[cfg(feature = "3d")]
+        let Some((body, point, depth)) = camera
+            .viewport_to_world(camera_transform, cursor)
+            .and_then(|ray| {
+                let origin = ray.origin.adjust_precision();
+                let direction = ray.direction.adjust_precision();
+                let hit = spatial_query.cast_ray(
+                    origin,
+                    direction,
+                    Scalar::MAX,
+                    true,
+                    settings.filter.clone(),
+                )?;
+                let body = dynamic_body(hit.entity)?;
+                Some((
+                    body,
+                    origin + direction * hit.time_of_impact,
+                    hit.time_of_impact,
+                ))
+            })
+        else {
+            continue;
+        };
+
+        let Ok((_, position, rotation, mass)) = bodies.get(body) else {
+            continue;
+        };
+
+        // Scale the spring by the mass so that all bodies follow the cursor the same way
+        let omega = 2.0 * PI * settings.frequency;
+        let local_anchor = rotation.inverse().rotate(point - position.0);
+
+        let anchor = commands
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Kinematic,
+                Position(point),
+            ))
+            .id();
+        let joint = commands
+            .spawn(
+                SpringJoint::new(anchor, body)
+                    .with_local_anchor_2(local_anchor)
+                    .with_rest_length(0.0)
+                    .with_stiffness(mass.0 * omega * omega)
+                    .with_damping(mass.0 * 2.0 * settings.damping_ratio * omega),
+            )
+            .id();
+
+        commands.entity(anchor).insert(DragAnchor {
+            body,
+            joint,
+            camera: camera_entity,
+            #[cfg(feature = "3d")]
+            depth,
+        });
+
+        return;
+    }
+}
+
+/// Moves the [`DragAnchor`]s to the cursor.
+fn move_drag_anchors(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut anchors: Query<(&DragAnchor, &mut Position)>,
+) {
+    let Some(cursor) = windows.get_single().ok().and_then(|w| w.cursor_position()) else {
+        return;
+    };
+
+    for (anchor, mut position) in &mut anchors {
+        let Ok((camera, camera_transform)) = cameras.get(anchor.camera) else {
+            continue;
+        };
+
+        #[cfg(feature = "2d")]
+        let target = camera
+            .viewport_to_world_2d(camera_transform, cursor)
+            .map(|point| point.adjust_precision());
+
+        #[cfg(feature = "3d")]
+        let target = camera
+            .viewport_to_world(camera_transform, cursor)
+            .map(|ray| {
+                ray.origin.adjust_precision() + ray.direction.adjust_precision() * anchor.depth
+            });
+
+        if let Some(target) = target {
+            position.0 = target;
+        }
+    }
+}
+
+/// Despawns the [`DragAnchor`]s and their joints when the drag button is released or the dragged body is despawned.
+fn end_drag(
+    mut commands: Commands,
+    buttons: Res<Input<MouseButton>>,
+    anchors: Query<(Entity, &DragAnchor)>,
+    bodies: Query<(), With<RigidBody>>,
+    settings: Res<MouseDragSettings>,
+) {
+    for (entity, anchor) in &anchors {
+        if !buttons.pressed(settings.button) || !bodies.contains(anchor.body) {
+            commands.entity(entity).despawn_recursive();
+            if let Some(mut joint) = commands.get_entity(anchor.joint) {
+                joint.despawn();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, spawn_camera_with_window},
+    };
+    use bevy::{prelude::*, window::PrimaryWindow};
+
+    #[test]
+    fn bodies_are_dragged_to_the_cursor() {
+        let mut app = create_app();
+        app.add_plugins(MouseDragPlugin)
+            .init_resource::<Input<MouseButton>>()
+            .insert_resource(Gravity::ZERO);
+        spawn_camera_with_window(&mut app);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        // Add the collider to the spatial query pipeline
+        app.step_physics(1);
+
+        let set_cursor = |app: &mut App, position: Vec2| {
+            app.world
+                .query_filtered::<&mut Window, With<PrimaryWindow>>()
+                .single_mut(&mut app.world)
+                .set_cursor_position(Some(position));
+        };
+
+        // Press the button with the cursor at the center of the window, right above the body
+        set_cursor(&mut app, Vec2::new(640.0, 360.0));
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .press(MouseButton::Left);
+        app.step_physics(1);
+        app.world.resource_mut::<Input<MouseButton>>().clear();
+
+        let (anchor_entity, &anchor) = app
+            .world
+            .query::<(Entity, &DragAnchor)>()
+            .single(&app.world);
+        assert_eq!(anchor.body, body);
+        assert!(app.world.get::<SpringJoint>(anchor.joint).is_some());
+
+        // The body follows the anchor to the cursor
+        set_cursor(&mut app, Vec2::new(740.0, 360.0));
+        app.step_physics(60);
+        assert!(app.world.get::<Position>(anchor_entity).unwrap().x > 0.5);
+        assert!(app.world.get::<Position>(body).unwrap().x > 0.5);
+
+        // Releasing the button despawns the anchor and the joint
+        app.world
+            .resource_mut::<Input<MouseButton>>()
+            .release(MouseButton::Left);
+        app.step_physics(1);
+        assert!(app.world.get_entity(anchor_entity).is_none());
+        assert!(app.world.get_entity(anchor.joint).is_none());
+    }
+}
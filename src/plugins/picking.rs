@@ -0,0 +1,312 @@
+//! A [bevy_mod_picking](https://github.com/aevyrie/bevy_mod_picking) backend that picks [colliders](Collider)
+//! using [spatial queries](spatial_query).
+//!
+//! See [`PhysicsPickingPlugin`].
+
+use crate::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_picking_core::{
+    backend::{HitData, PointerHits},
+    pointer::{PointerId, PointerLocation},
+    PickSet,
+};
+
+/// A [bevy_mod_picking](https://github.com/aevyrie/bevy_mod_picking) backend that reports pointer hits
+/// on entities with a [`Collider`]. This makes entities that have colliders but no meshes or sprites,
+/// like sensors and hitboxes, pickable in editors and games.
+///
+/// In 3D, a ray is cast from each active camera through the pointer using [`SpatialQuery`], and every
+/// collider along the ray is reported as a hit. In 2D, the pointer position is converted to world space
+/// and every collider that contains the point is reported.
+///
+/// Like with other backends, entities still need bevy_mod_picking's `PickableBundle` to receive
+/// pointer events. Picking can be limited to specific entities with [`PhysicsPickingSettings`]
+/// and the [`PhysicsPickable`] component.
+///
+/// This plugin requires the `picking` feature.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_mod_picking::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             DefaultPickingPlugins,
+///             PhysicsPlugins::default(),
+///             PhysicsPickingPlugin,
+///         ))
+///         .run();
+/// }
+/// ```
+pub struct PhysicsPickingPlugin;
+
+impl Plugin for PhysicsPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsPickingSettings>()
+            .register_type::<PhysicsPickingSettings>()
+            .register_type::<PhysicsPickable>()
+            .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend));
+    }
+}
+
+/// Configures the [`PhysicsPickingPlugin`].
+#[derive(Reflect, Resource, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Resource)]
+pub struct PhysicsPickingSettings {
+    /// If true, only entities with the [`PhysicsPickable`] component are reported as hits.
+    /// Otherwise, all colliders can be picked.
+    pub require_markers: bool,
+}
+
+/// A marker component for colliders that can be picked when
+/// [`PhysicsPickingSettings::require_markers`] is enabled.
+#[derive(Reflect, Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct PhysicsPickable;
+
+#[allow(clippy::too_many_arguments)]
+fn update_hits(
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    pickables: Query<(), With<PhysicsPickable>>,
+    #[cfg(feature = "2d")] transforms: Query<&GlobalTransform>,
+    settings: Res<PhysicsPickingSettings>,
+    spatial_query: SpatialQuery,
+    mut output: EventWriter<PointerHits>,
+) {
+    let is_pickable = |entity: Entity| !settings.require_markers || pickables.contains(entity);
+
+    for (&pointer_id, location) in pointers.iter().filter_map(|(pointer_id, pointer)| {
+        pointer.location().map(|location| (pointer_id, location))
+    }) {
+        for (camera_entity, camera, camera_transform) in cameras.iter().filter(|(_, camera, _)| {
+            camera.is_active && location.is_in_viewport(camera, &primary_window)
+        }) {
+            #[cfg(feature = "2d")]
+            let picks = {
+                let Some(point) = camera.viewport_to_world_2d(camera_transform, location.position)
+                else {
+                    continue;
+                };
+                pick_colliders(
+                    &spatial_query,
+                    &transforms,
+                    camera_entity,
+                    camera_transform.translation().z,
+                    point.adjust_precision(),
+                    is_pickable,
+                )
+            };
+
+            #[cfg(feature = "3d")]
+            let picks = {
+                let Some(ray) = camera.viewport_to_world(camera_transform, location.position)
+                else {
+                    continue;
+                };
+                pick_colliders(
+                    &spatial_query,
+                    camera_entity,
+                    ray.origin.adjust_precision(),
+                    ray.direction.adjust_precision(),
+                    is_pickable,
+                )
+            };
+
+            if !picks.is_empty() {
+                output.send(PointerHits::new(pointer_id, picks, camera.order as f32));
+            }
+        }
+    }
+}
+
+/// Returns the pickable colliders that contain the given `point`, along with their hit data.
+///
+/// The depth of each hit is the distance of the entity from the camera along the view direction.
+#[cfg(feature = "2d")]
+fn pick_colliders(
+    spatial_query: &SpatialQuery,
+    transforms: &Query<&GlobalTransform>,
+    camera_entity: Entity,
+    camera_z: f32,
+    point: Vector,
+    is_pickable: impl Fn(Entity) -> bool,
+) -> Vec<(Entity, HitData)> {
+    spatial_query
+        .point_intersections(point, SpatialQueryFilter::default())
+        .into_iter()
+        .filter(|&entity| is_pickable(entity))
+        .map(|entity| {
+            let depth = transforms
+                .get(entity)
+                .map_or(0.0, |transform| camera_z - transform.translation().z);
+            (entity, HitData::new(camera_entity, depth, None, None))
+        })
+        .collect()
+}
+
+/// Returns the pickable colliders hit by a ray with the given `origin` and `direction`,
+/// along with their hit data.
+#[allow(clippy::unnecessary_cast)]
+#[cfg(feature = "3d")]
+fn pick_colliders(
+    spatial_query: &SpatialQuery,
+    camera_entity: Entity,
+    origin: Vector,
+    direction: Vector,
+    is_pickable: impl Fn(Entity) -> bool,
+) -> Vec<(Entity, HitData)> {
+    let mut picks = vec![];
+    spatial_query.ray_hits_callback(
+        origin,
+        direction,
+        Scalar::MAX,
+        true,
+        SpatialQueryFilter::default(),
+        |hit| {
+            if is_pickable(hit.entity) {
+                let position = origin + direction * hit.time_of_impact;
+                picks.push((
+                    hit.entity,
+                    HitData::new(
+                        camera_entity,
+                        hit.time_of_impact as f32,
+                        Some(position.as_f32()),
+                        Some(hit.normal.as_f32()),
+                    ),
+                ));
+            }
+            true
+        },
+    );
+    picks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::{create_app, spawn_camera_with_window, tick_60_fps},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn picking_backend_reports_pickable_colliders_under_pointer() {
+        use bevy::{
+            render::camera::RenderTarget,
+            window::{PrimaryWindow, WindowRef},
+        };
+        use bevy_picking_core::{
+            backend::PointerHits,
+            pointer::{Location, PointerId, PointerLocation},
+        };
+
+        let mut app = create_app();
+        app.add_plugins(PhysicsPickingPlugin)
+            .add_event::<PointerHits>()
+            .insert_resource(Gravity::ZERO);
+        let camera = spawn_camera_with_window(&mut app);
+
+        let pickable = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Collider::ball(0.5),
+            ))
+            .id();
+        #[cfg(feature = "2d")]
+        let other_transform = Transform::from_xyz(0.25, 0.0, 1.0);
+        #[cfg(feature = "3d")]
+        let other_transform = Transform::from_xyz(0.0, 0.0, -3.0);
+        let other = app
+            .world
+            .spawn((
+                TransformBundle::from_transform(other_transform),
+                RigidBody::Static,
+                Collider::ball(0.5),
+            ))
+            .id();
+        // Not under the pointer
+        app.world.spawn((
+            TransformBundle::from_transform(Transform::from_xyz(5.0, 0.0, 0.0)),
+            RigidBody::Static,
+            Collider::ball(0.5),
+        ));
+
+        // A mouse pointer at the center of the primary window
+        let window = app
+            .world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world);
+        app.world.spawn((
+            PointerId::Mouse,
+            PointerLocation::new(Location {
+                target: RenderTarget::Window(WindowRef::Primary)
+                    .normalize(Some(window))
+                    .unwrap(),
+                position: Vec2::new(640.0, 360.0),
+            }),
+        ));
+
+        // Returns the hits reported by the backend in the last frame, sorted by depth
+        let hits = |app: &mut App| {
+            tick_60_fps(app);
+            let events = app.world.resource::<Events<PointerHits>>();
+            let hits: Vec<&PointerHits> = events.iter_current_update_events().collect();
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].pointer, PointerId::Mouse);
+            let mut picks = hits[0].picks.clone();
+            picks.sort_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth));
+            picks
+        };
+
+        tick_60_fps(&mut app);
+
+        // Every collider under the pointer is reported
+        #[cfg(feature = "2d")]
+        let expected = vec![other, pickable];
+        #[cfg(feature = "3d")]
+        let expected = vec![pickable, other];
+        let picks = hits(&mut app);
+        assert_eq!(
+            picks.iter().map(|(entity, _)| *entity).collect::<Vec<_>>(),
+            expected
+        );
+        assert!(picks.iter().all(|(_, hit)| hit.camera == camera));
+        #[cfg(feature = "2d")]
+        assert_relative_eq!(picks[1].1.depth, 999.9, epsilon = 1e-3);
+        #[cfg(feature = "3d")]
+        {
+            assert_relative_eq!(picks[0].1.depth, 9.5, epsilon = 1e-4);
+            assert_relative_eq!(picks[0].1.position.unwrap(), Vec3::Z * 0.5, epsilon = 1e-4);
+        }
+
+        // Only colliders with `PhysicsPickable` are reported when markers are required
+        app.world
+            .resource_mut::<PhysicsPickingSettings>()
+            .require_markers = true;
+        app.world.entity_mut(pickable).insert(PhysicsPickable);
+        let picks = hits(&mut app);
+        assert_eq!(picks.len(), 1);
+        assert_eq!(picks[0].0, pickable);
+
+        // Inactive cameras don't report hits
+        app.world.get_mut::<Camera>(camera).unwrap().is_active = false;
+        tick_60_fps(&mut app);
+        assert!(app
+            .world
+            .resource::<Events<PointerHits>>()
+            .iter_current_update_events()
+            .next()
+            .is_none());
+    }
+}
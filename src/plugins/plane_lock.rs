@@ -0,0 +1,358 @@
+//! Locks bodies to planes for 3D games that are played on a plane, like side-scrollers with 3D visuals.
+//!
+//! See [`PlaneLockPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Restricts the movement of bodies with the [`LockToPlane`] component to their planes.
+///
+/// The plugin does the following:
+///
+/// - Configures the [`LockedAxes`] of the bodies so that they only translate along their plane
+/// and rotate around its normal. The axes are unlocked again when the [`LockToPlane`] is changed or removed,
+/// unless they were already locked before.
+/// - Removes pairs of bodies that are locked to different planes from the [`BroadCollisionPairs`],
+/// so that bodies on different planes don't collide.
+/// - Snaps the bodies back to their planes after each substep. This keeps bodies with normals that
+/// [`LockedAxes`] can't represent on their planes, and corrects drift, for example from joints or contacts
+/// with bodies that aren't locked to the plane.
+pub struct PlaneLockPlugin;
+
+impl Plugin for PlaneLockPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<LockToPlane>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                (unlock_axes_of_removed_planes, lock_axes_to_planes)
+                    .chain()
+                    .before(PhysicsStepSet::BroadPhase),
+            )
+            .add_systems(filter_pairs_on_different_planes.in_set(PhysicsStepSet::FilterPairs));
+
+        app.get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first")
+            .add_systems(snap_to_planes.after(SubstepSet::ApplyTranslation));
+    }
+}
+
+/// Locks a [rigid body](RigidBody) to a plane, so that it can only move along the plane and rotate around its normal.
+///
+/// This is useful for 3D games that are played on a plane, like side-scrollers with 3D visuals.
+/// Bodies that are locked to different planes don't collide with each other, which can be used for things like
+/// background and foreground layers. Bodies without the component collide with bodies on all planes.
+///
+/// The [`LockedAxes`] of the body are configured automatically, and any [`Gravity`] along the normal of the plane
+/// is ignored. Drift away from the plane is corrected after each substep.
+///
+/// [`LockedAxes`] can only lock the coordinate axes, so they are only configured for normals that are
+/// coordinate axes. Bodies locked to other planes are moved back to their planes after each substep instead,
+/// which is less precise within a substep, for example for contacts.
+///
+/// See [`PlaneLockPlugin`] for more information.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A side-scroller where the bodies move on the XY plane
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         LockToPlane::XY,
+///     ));
+///
+///     // A background layer that the bodies above don't collide with
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         LockToPlane::XY.with_depth(-5.0),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct LockToPlane {
+    /// The normal of the plane.
+    pub normal: Vector,
+    /// The distance of the plane from the origin along the `normal`.
+    pub depth: Scalar,
+}
+
+impl LockToPlane {
+    /// The XY plane, with the Z axis as the normal. This is typical for side-scrollers.
+    pub const XY: Self = Self::new(Vector::Z, 0.0);
+    /// The XZ plane, with the Y axis as the normal. This is typical for top-down games.
+    pub const XZ: Self = Self::new(Vector::Y, 0.0);
+    /// The YZ plane, with the X axis as the normal.
+    pub const YZ: Self = Self::new(Vector::X, 0.0);
+
+    /// Creates a new [`LockToPlane`] with the given `normal` and `depth`,
+    /// the distance of the plane from the origin along the normal.
+    pub const fn new(normal: Vector, depth: Scalar) -> Self {
+        Self { normal, depth }
+    }
+
+    /// Sets the distance of the plane from the origin along the normal.
+    pub const fn with_depth(self, depth: Scalar) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Returns the [`LockedAxes`] that restrict movement to the plane, added to the given `locked_axes`.
+    ///
+    /// If the normal is not one of the coordinate axes, the given `locked_axes` are returned unchanged.
+    pub fn locked_axes(&self, locked_axes: LockedAxes) -> LockedAxes {
+        let normal = self.normal.normalize_or_zero().abs();
+        if normal == Vector::X {
+            locked_axes
+                .lock_translation_x()
+                .lock_rotation_y()
+                .lock_rotation_z()
+        } else if normal == Vector::Y {
+            locked_axes
+                .lock_translation_y()
+                .lock_rotation_x()
+                .lock_rotation_z()
+        } else if normal == Vector::Z {
+            locked_axes
+                .lock_translation_z()
+                .lock_rotation_x()
+                .lock_rotation_y()
+        } else {
+            locked_axes
+        }
+    }
+
+    /// Returns true if the body is on the same plane as a body with the given `other` lock.
+    pub fn is_same_plane(&self, other: &Self) -> bool {
+        let normal1 = self.normal.normalize_or_zero();
+        let normal2 = other.normal.normalize_or_zero();
+
+        // Opposite normals describe the same plane if the depths are mirrored as well
+        (normal1 == normal2 && self.depth == other.depth)
+            || (normal1 == -normal2 && self.depth == -other.depth)
+    }
+}
+
+impl Default for LockToPlane {
+    /// Locks the body to the [XY plane](Self::XY).
+    fn default() -> Self {
+        Self::XY
+    }
+}
+
+/// The [`LockedAxes`] that were locked by the [`LockToPlane`] of a body and weren't locked before.
+#[derive(Component, Clone, Copy, Debug, Default)]
+struct PlaneLockedAxes(LockedAxes);
+
+/// Configures the [`LockedAxes`] of bodies whose [`LockToPlane`] was added or changed,
+/// replacing the axes locked by the previous plane.
+fn lock_axes_to_planes(
+    mut commands: Commands,
+    mut bodies: Query<
+        (
+            Entity,
+            &LockToPlane,
+            Option<&mut LockedAxes>,
+            Option<&PlaneLockedAxes>,
+        ),
+        Changed<LockToPlane>,
+    >,
+) {
+    for (entity, lock, locked_axes, plane_locked_axes) in &mut bodies {
+        let previous_plane_bits = plane_locked_axes.map_or(0, |axes| axes.0.to_bits());
+        let own_bits = locked_axes.as_ref().map_or(0, |axes| axes.to_bits()) & !previous_plane_bits;
+        let plane_bits = lock.locked_axes(LockedAxes::new()).to_bits() & !own_bits;
+        let new_locked_axes = LockedAxes::from_bits(own_bits | plane_bits);
+
+        if let Some(mut locked_axes) = locked_axes {
+            *locked_axes = new_locked_axes;
+        } else {
+            commands.entity(entity).insert(new_locked_axes);
+        }
+        commands
+            .entity(entity)
+            .insert(PlaneLockedAxes(LockedAxes::from_bits(plane_bits)));
+    }
+}
+
+/// Unlocks the [`LockedAxes`] that were locked by removed [`LockToPlane`] components.
+fn unlock_axes_of_removed_planes(
+    mut commands: Commands,
+    mut removed_locks: RemovedComponents<LockToPlane>,
+    mut bodies: Query<(&mut LockedAxes, &PlaneLockedAxes), Without<LockToPlane>>,
+) {
+    for entity in removed_locks.iter() {
+        if let Ok((mut locked_axes, plane_locked_axes)) = bodies.get_mut(entity) {
+            *locked_axes =
+                LockedAxes::from_bits(locked_axes.to_bits() & !plane_locked_axes.0.to_bits());
+            commands.entity(entity).remove::<PlaneLockedAxes>();
+        }
+    }
+}
+
+/// Removes collision pairs of bodies that are locked to different planes.
+fn filter_pairs_on_different_planes(
+    mut pairs: ResMut<BroadCollisionPairs>,
+    colliders: Query<&ColliderParent>,
+    locks: Query<&LockToPlane>,
+) {
+    if locks.is_empty() {
+        return;
+    }
+
+    let lock = |entity: Entity| {
+        let body = colliders.get(entity).map_or(entity, |parent| parent.get());
+        locks.get(body).ok()
+    };
+
+    pairs.retain(|entity1, entity2| match (lock(entity1), lock(entity2)) {
+        (Some(lock1), Some(lock2)) => lock1.is_same_plane(lock2),
+        _ => true,
+    });
+}
+
+/// Moves bodies back to their planes and removes velocity and rotation that would move them away from the planes.
+fn snap_to_planes(
+    mut bodies: Query<(
+        &LockToPlane,
+        &mut Position,
+        &mut Rotation,
+        &mut LinearVelocity,
+        &mut AngularVelocity,
+    )>,
+) {
+    for (lock, mut position, mut rotation, mut lin_vel, mut ang_vel) in &mut bodies {
+        let normal = lock.normal.normalize_or_zero();
+
+        if normal == Vector::ZERO {
+            continue;
+        }
+
+        // Avoid triggering change detection unnecessarily
+        let distance = position.dot(normal) - lock.depth;
+        if distance != 0.0 {
+            position.0 -= normal * distance;
+        }
+
+        let normal_speed = lin_vel.dot(normal);
+        if normal_speed != 0.0 {
+            lin_vel.0 -= normal * normal_speed;
+        }
+
+        let tangent_ang_vel = ang_vel.0 - normal * ang_vel.dot(normal);
+        if tangent_ang_vel != Vector::ZERO {
+            ang_vel.0 -= tangent_ang_vel;
+        }
+
+        // Keep only the twist of the rotation around the normal
+        let axis = rotation.0.xyz();
+        let twist = Quaternion::from_xyzw(0.0, 0.0, 0.0, rotation.0.w)
+            + Quaternion::from_vec4((normal * axis.dot(normal)).extend(0.0));
+        let twist = if twist.length_squared() > Scalar::EPSILON {
+            twist.normalize()
+        } else {
+            Quaternion::IDENTITY
+        };
+        if !twist.abs_diff_eq(rotation.0, Scalar::EPSILON) {
+            rotation.0 = twist;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn plane_locks_replace_their_locked_axes() {
+        let mut app = create_app();
+        app.add_plugins(PlaneLockPlugin);
+
+        let user_axes = LockedAxes::new().lock_rotation_x();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                user_axes,
+                LockToPlane::XY,
+            ))
+            .id();
+
+        let locked_bits = |app: &App| app.world.get::<LockedAxes>(body).unwrap().to_bits();
+
+        app.step_physics(1);
+        assert_eq!(
+            locked_bits(&app),
+            LockToPlane::XY.locked_axes(user_axes).to_bits()
+        );
+
+        // Changing the plane unlocks the axes of the old plane, but not the axes locked by the user
+        *app.world.get_mut::<LockToPlane>(body).unwrap() = LockToPlane::YZ;
+        app.step_physics(1);
+        assert_eq!(
+            locked_bits(&app),
+            LockedAxes::new()
+                .lock_translation_x()
+                .lock_rotation_x()
+                .lock_rotation_y()
+                .lock_rotation_z()
+                .to_bits()
+        );
+
+        app.world.entity_mut(body).remove::<LockToPlane>();
+        app.step_physics(1);
+        assert_eq!(locked_bits(&app), user_axes.to_bits());
+    }
+
+    #[test]
+    fn bodies_stay_on_planes_that_are_not_axis_aligned() {
+        let mut app = create_app();
+        app.add_plugins(PlaneLockPlugin);
+
+        let normal = Vector::new(1.0, 1.0, 0.0).normalize();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LockToPlane::new(normal, 0.0),
+                AngularVelocity(Vector::new(1.0, 2.0, 3.0)),
+            ))
+            .id();
+
+        for _ in 0..30 {
+            app.step_physics(1);
+
+            // Gravity moves the body down along the plane, but not away from it
+            let position = app.world.get::<Position>(body).unwrap().0;
+            assert_relative_eq!(position.dot(normal), 0.0, epsilon = 1e-4);
+            assert!(
+                app.world
+                    .get::<LinearVelocity>(body)
+                    .unwrap()
+                    .dot(normal)
+                    .abs()
+                    < 1e-4
+            );
+        }
+
+        let position = app.world.get::<Position>(body).unwrap().0;
+        assert!(position.x > 0.0 && position.y < 0.0);
+
+        // The body only rotates around the normal
+        let ang_vel = app.world.get::<AngularVelocity>(body).unwrap().0;
+        assert_relative_eq!(ang_vel.normalize(), normal, epsilon = 1e-4);
+        assert_eq!(app.world.get::<LockedAxes>(body).unwrap().to_bits(), 0);
+    }
+}
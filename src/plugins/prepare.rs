@@ -18,8 +18,10 @@ use bevy::{ecs::query::Has, prelude::*, utils::HashMap};
 /// - Adds missing mass properties for entities with a [`RigidBody`] or [`Collider`] component
 /// - Updates mass properties and adds [`ColliderMassProperties`] on top of the existing mass properties
 /// - Clamps restitution coefficients between 0 and 1
+/// - Computes the local anchors of [joints] that have a [`JointWorldAnchor`]
+/// - Sends [`RigidBodyAdded`], [`RigidBodyRemoved`], [`ColliderAdded`] and [`ColliderRemoved`] events
 ///
-/// The systems run in [`PhysicsSet::Prepare`].
+/// The systems run in [`PhysicsSet::Prepare`] and are ordered by the stages in [`PrepareSet`].
 pub struct PreparePlugin {
     schedule: Box<dyn ScheduleLabel>,
 }
@@ -43,38 +45,87 @@ impl Default for PreparePlugin {
 
 impl Plugin for PreparePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<ColliderStorageMap>().add_systems(
+        app.init_resource::<ColliderStorageMap>()
+            .add_event::<RigidBodyAdded>()
+            .add_event::<RigidBodyRemoved>()
+            .add_event::<ColliderAdded>()
+            .add_event::<ColliderRemoved>();
+
+        app.configure_sets(
+            self.schedule.dyn_clone(),
+            (
+                PrepareSet::PreInit,
+                PrepareSet::PropagateTransforms,
+                PrepareSet::InitRigidBodies,
+                PrepareSet::InitMassProperties,
+                PrepareSet::InitColliders,
+                PrepareSet::InitTransforms,
+                PrepareSet::Finalize,
+            )
+                .chain()
+                .in_set(PhysicsSet::Prepare),
+        );
+
+        app.add_systems(
             self.schedule.dyn_clone(),
             (
-                apply_deferred,
+                (
+                    apply_deferred,
+                    (
+                        send_rigid_body_lifecycle_events,
+                        send_collider_lifecycle_events,
+                    ),
+                )
+                    .chain()
+                    .in_set(PrepareSet::PreInit),
                 // Run transform propagation if new bodies or colliders have been added
                 (
                     bevy::transform::systems::sync_simple_transforms,
                     bevy::transform::systems::propagate_transforms,
                 )
                     .chain()
-                    .run_if(any_new_physics_entities),
-                init_rigid_bodies,
-                init_mass_properties,
-                init_colliders,
-                apply_deferred,
-                update_collider_parents,
-                apply_deferred,
-                init_transforms,
+                    .run_if(any_new_physics_entities)
+                    .in_set(PrepareSet::PropagateTransforms),
+                init_rigid_bodies.in_set(PrepareSet::InitRigidBodies),
+                init_mass_properties.in_set(PrepareSet::InitMassProperties),
                 (
-                    sync::propagate_collider_transforms,
-                    sync::update_child_collider_position,
+                    init_colliders,
+                    apply_deferred,
+                    update_collider_parents,
+                    apply_deferred,
                 )
                     .chain()
-                    .run_if(any_new_physics_entities),
-                update_mass_properties,
-                clamp_collider_density,
-                clamp_restitution,
-                // all the components we added above must exist before we can simulate the bodies
-                apply_deferred,
-            )
-                .chain()
-                .in_set(PhysicsSet::Prepare),
+                    .in_set(PrepareSet::InitColliders),
+                (
+                    init_transforms,
+                    (
+                        sync::propagate_collider_transforms,
+                        sync::update_child_collider_position,
+                    )
+                        .chain()
+                        .run_if(any_new_physics_entities),
+                )
+                    .chain()
+                    .in_set(PrepareSet::InitTransforms),
+                (
+                    update_mass_properties,
+                    clamp_collider_density,
+                    clamp_restitution,
+                    // all the components we added above must exist before we can simulate the bodies
+                    apply_deferred,
+                    // joint anchors are computed from the initialized positions and rotations
+                    (
+                        resolve_joint_world_anchors::<FixedJoint>,
+                        resolve_joint_world_anchors::<RevoluteJoint>,
+                        resolve_joint_world_anchors::<SphericalJoint>,
+                        resolve_joint_world_anchors::<PrismaticJoint>,
+                        resolve_joint_world_anchors::<DistanceJoint>,
+                        resolve_joint_world_anchors::<SpringJoint>,
+                    ),
+                )
+                    .chain()
+                    .in_set(PrepareSet::Finalize),
+            ),
         );
 
         app.add_systems(
@@ -86,12 +137,16 @@ impl Plugin for PreparePlugin {
             ),
         );
 
+        // The asset and scene resources don't exist in headless apps that only use `MinimalPlugins`
         #[cfg(all(feature = "3d", feature = "async-collider"))]
         app.add_systems(
             Update,
             (
-                init_async_colliders,
-                init_async_scene_colliders.after(bevy::scene::scene_spawner_system),
+                init_async_colliders.run_if(resource_exists::<Assets<Mesh>>()),
+                init_async_scene_colliders
+                    .after(bevy::scene::scene_spawner_system)
+                    .run_if(resource_exists::<Assets<Mesh>>())
+                    .run_if(resource_exists::<SceneSpawner>()),
             ),
         );
     }
@@ -116,6 +171,32 @@ pub(crate) struct ColliderStorageMap(
     HashMap<Entity, (ColliderParent, ColliderMassProperties, ColliderTransform)>,
 );
 
+/// An event that is sent when a [rigid body](RigidBody) is added to the simulation.
+///
+/// The event is sent during [`PhysicsSet::Prepare`] after the [`RigidBody`] component has been added.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RigidBodyAdded(pub Entity);
+
+/// An event that is sent when a [rigid body](RigidBody) is removed from the simulation,
+/// either by removing the [`RigidBody`] component or by despawning the entity.
+///
+/// The entity may no longer exist when the event is read.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RigidBodyRemoved(pub Entity);
+
+/// An event that is sent when a [collider](Collider) is added to the simulation.
+///
+/// The event is sent during [`PhysicsSet::Prepare`] after the [`Collider`] component has been added.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColliderAdded(pub Entity);
+
+/// An event that is sent when a [collider](Collider) is removed from the simulation,
+/// either by removing the [`Collider`] component or by despawning the entity.
+///
+/// The entity may no longer exist when the event is read.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColliderRemoved(pub Entity);
+
 /// A run condition that returns `true` if new [rigid bodies](RigidBody) or [colliders](Collider)
 /// have been added. Used for avoiding unnecessary transform propagation.
 fn any_new_physics_entities(query: Query<(), Or<(Added<RigidBody>, Added<Collider>)>>) -> bool {
@@ -334,6 +415,7 @@ fn init_rigid_bodies(
             *lin_vel.unwrap_or(&LinearVelocity::default()),
             *ang_vel.unwrap_or(&AngularVelocity::default()),
             PreSolveLinearVelocity::default(),
+            IntegratorVelocityCorrection::default(),
             PreSolveAngularVelocity::default(),
             *force.unwrap_or(&ExternalForce::default()),
             *torque.unwrap_or(&ExternalTorque::default()),
@@ -487,42 +569,63 @@ pub fn init_async_scene_colliders(
     }
 }
 
+/// Attaches colliders to their closest ancestor with a [`RigidBody`], or to the body on the same entity.
 fn update_collider_parents(
     mut commands: Commands,
-    mut bodies: Query<(Entity, Option<&mut ColliderParent>, Has<Collider>), With<RigidBody>>,
-    children: Query<&Children>,
-    mut child_colliders: Query<Option<&mut ColliderParent>, (With<Collider>, Without<RigidBody>)>,
+    mut colliders: Query<(Entity, Option<&mut ColliderParent>, Has<RigidBody>), With<Collider>>,
+    bodies: Query<(), With<RigidBody>>,
+    parents: Query<&Parent>,
 ) {
-    for (entity, collider_parent, has_collider) in &mut bodies {
-        if has_collider {
-            if let Some(mut collider_parent) = collider_parent {
-                collider_parent.0 = entity;
-            } else {
-                commands.entity(entity).insert((
-                    ColliderParent(entity),
-                    // Todo: This probably causes a one frame delay. Compute real value?
-                    ColliderTransform::default(),
-                    PreviousColliderTransform::default(),
-                ));
-            }
-        }
-        for child in children.iter_descendants(entity) {
-            if let Ok(collider_parent) = child_colliders.get_mut(child) {
-                if let Some(mut collider_parent) = collider_parent {
-                    collider_parent.0 = entity;
-                } else {
-                    commands.entity(child).insert((
-                        ColliderParent(entity),
-                        // Todo: This probably causes a one frame delay. Compute real value?
-                        ColliderTransform::default(),
-                        PreviousColliderTransform::default(),
-                    ));
-                }
+    for (entity, collider_parent, is_body) in &mut colliders {
+        let body = if is_body {
+            Some(entity)
+        } else {
+            parents
+                .iter_ancestors(entity)
+                .find(|&ancestor| bodies.contains(ancestor))
+        };
+        let Some(body) = body else {
+            continue;
+        };
+
+        if let Some(mut collider_parent) = collider_parent {
+            if collider_parent.0 != body {
+                collider_parent.0 = body;
             }
+        } else {
+            commands.entity(entity).insert((
+                ColliderParent(body),
+                // Todo: This probably causes a one frame delay. Compute real value?
+                ColliderTransform::default(),
+                PreviousColliderTransform::default(),
+            ));
         }
     }
 }
 
+/// Sends [`RigidBodyAdded`] and [`RigidBodyRemoved`] events.
+fn send_rigid_body_lifecycle_events(
+    bodies: Query<Entity, Added<RigidBody>>,
+    mut removals: RemovedComponents<RigidBody>,
+    mut added_events: EventWriter<RigidBodyAdded>,
+    mut removed_events: EventWriter<RigidBodyRemoved>,
+) {
+    // Removals are sent first so that a body that was removed and added again ends up being added
+    removed_events.send_batch(removals.iter().map(RigidBodyRemoved));
+    added_events.send_batch(bodies.iter().map(RigidBodyAdded));
+}
+
+/// Sends [`ColliderAdded`] and [`ColliderRemoved`] events.
+fn send_collider_lifecycle_events(
+    colliders: Query<Entity, Added<Collider>>,
+    mut removals: RemovedComponents<Collider>,
+    mut added_events: EventWriter<ColliderAdded>,
+    mut removed_events: EventWriter<ColliderRemoved>,
+) {
+    removed_events.send_batch(removals.iter().map(ColliderRemoved));
+    added_events.send_batch(colliders.iter().map(ColliderAdded));
+}
+
 /// Updates colliders when the rigid bodies they were attached to have been removed.
 fn handle_rigid_body_removals(
     mut commands: Commands,
@@ -602,6 +705,7 @@ fn update_mass_properties(
             Ref<Collider>,
             &ColliderDensity,
             &mut ColliderMassProperties,
+            Has<QueryOnly>,
         ),
         Or<(
             Changed<Collider>,
@@ -620,6 +724,7 @@ fn update_mass_properties(
         collider,
         density,
         mut collider_mass_properties,
+        is_query_only,
     ) in &mut colliders
     {
         if let Ok((_, _, mut mass_properties)) = bodies.get_mut(collider_parent.0) {
@@ -637,8 +742,12 @@ fn update_mass_properties(
 
             previous_collider_transform.0 = *collider_transform;
 
-            // Update collider mass props
-            *collider_mass_properties = collider.mass_properties(density.max(Scalar::EPSILON));
+            // Update collider mass props. Query-only colliders don't affect the simulation, so they have no mass.
+            *collider_mass_properties = if is_query_only {
+                ColliderMassProperties::ZERO
+            } else {
+                collider.mass_properties(density.max(Scalar::EPSILON))
+            };
 
             // Add new collider mass props to the body's mass props
             mass_properties += ColliderMassProperties {
@@ -707,3 +816,154 @@ fn clamp_collider_density(mut query: Query<&mut ColliderDensity, Changed<Collide
         density.0 = density.max(Scalar::EPSILON);
     }
 }
+
+/// Computes the local anchors of [joints] that have a [`JointWorldAnchor`] from the current
+/// positions and rotations of the attached bodies, and removes the [`JointWorldAnchor`].
+fn resolve_joint_world_anchors<T: Joint + XpbdConstraint<2> + Clone>(
+    mut commands: Commands,
+    mut joints: Query<(Entity, &mut T, &JointWorldAnchor)>,
+    bodies: Query<(&Position, &Rotation)>,
+) {
+    for (entity, mut joint, anchor) in &mut joints {
+        let [entity1, entity2] = joint.entities();
+        let (Ok((pos1, rot1)), Ok((pos2, rot2))) = (bodies.get(entity1), bodies.get(entity2))
+        else {
+            continue;
+        };
+
+        let local_anchor1 = rot1.inverse().rotate(anchor.0 - pos1.0);
+        let local_anchor2 = rot2.inverse().rotate(anchor.0 - pos2.0);
+        *joint = joint
+            .clone()
+            .with_local_anchor_1(local_anchor1)
+            .with_local_anchor_2(local_anchor2);
+
+        commands.entity(entity).remove::<JointWorldAnchor>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::prelude::*;
+
+    #[test]
+    fn nested_child_colliders_are_attached_to_closest_body() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let body = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Dynamic))
+            .id();
+        // An intermediate entity without any physics components, like a glTF node
+        let node = app
+            .world
+            .spawn(TransformBundle::from_transform(Transform::from_xyz(
+                1.0, 0.0, 0.0,
+            )))
+            .set_parent(body)
+            .id();
+        let collider = app
+            .world
+            .spawn((
+                TransformBundle::from_transform(Transform::from_xyz(1.0, 0.0, 0.0)),
+                Collider::ball(0.5),
+            ))
+            .set_parent(node)
+            .id();
+        let nested_body = app
+            .world
+            .spawn((
+                TransformBundle::from_transform(Transform::from_xyz(0.0, 5.0, 0.0)),
+                RigidBody::Dynamic,
+            ))
+            .set_parent(node)
+            .id();
+        let nested_collider = app
+            .world
+            .spawn((TransformBundle::default(), Collider::ball(0.5)))
+            .set_parent(nested_body)
+            .id();
+        let obstacle = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * 2.5),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        assert_eq!(
+            app.world.get::<ColliderParent>(collider).unwrap().get(),
+            body
+        );
+        assert_eq!(
+            app.world
+                .get::<ColliderParent>(nested_collider)
+                .unwrap()
+                .get(),
+            nested_body
+        );
+
+        // The child collider is at x = 2.0 through the hierarchy, so it touches the obstacle
+        let collisions = app.world.resource::<Collisions>();
+        let contacts = collisions
+            .get(collider, obstacle)
+            .expect("child collider should touch the obstacle");
+        let (collider_body, obstacle_body) = if contacts.entity1 == collider {
+            (contacts.body_entity1, contacts.body_entity2)
+        } else {
+            (contacts.body_entity2, contacts.body_entity1)
+        };
+        assert_eq!(collider_body, Some(body));
+        assert_eq!(obstacle_body, Some(obstacle));
+    }
+
+    #[test]
+    fn body_lifecycle_events_are_sent() {
+        let mut app = create_app();
+
+        let body = app
+            .world
+            .spawn((RigidBody::Dynamic, Collider::ball(0.5)))
+            .id();
+        let collider = app
+            .world
+            .spawn((Collider::ball(0.5), TransformBundle::default()))
+            .set_parent(body)
+            .id();
+
+        app.step_physics(1);
+
+        let events = app.world.resource::<Events<RigidBodyAdded>>();
+        let added: Vec<_> = events.iter_current_update_events().copied().collect();
+        assert_eq!(added, vec![RigidBodyAdded(body)]);
+        let events = app.world.resource::<Events<ColliderAdded>>();
+        let mut added: Vec<_> = events.iter_current_update_events().map(|e| e.0).collect();
+        added.sort();
+        let mut expected = vec![body, collider];
+        expected.sort();
+        assert_eq!(added, expected);
+
+        // Despawning the body also removes its child collider
+        app.world.entity_mut(body).despawn_recursive();
+
+        app.step_physics(1);
+
+        let events = app.world.resource::<Events<RigidBodyRemoved>>();
+        let removed: Vec<_> = events.iter_current_update_events().copied().collect();
+        assert_eq!(removed, vec![RigidBodyRemoved(body)]);
+        let events = app.world.resource::<Events<ColliderRemoved>>();
+        assert_eq!(events.iter_current_update_events().count(), 2);
+        assert!(app
+            .world
+            .resource::<Events<RigidBodyAdded>>()
+            .iter_current_update_events()
+            .next()
+            .is_none());
+    }
+}
@@ -0,0 +1,559 @@
+//! A compatibility layer for migrating from [bevy_rapier](https://github.com/dimforge/bevy_rapier).
+//!
+//! See [`RapierCompatPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A plugin that converts common [bevy_rapier](https://github.com/dimforge/bevy_rapier) components
+/// into their Bevy XPBD equivalents when they are spawned. This lets you port an existing project
+/// one system at a time instead of rewriting all of your spawning code at once.
+///
+/// The components in this module have the same names and fields as their bevy_rapier counterparts,
+/// so in most cases you only need to change your imports:
+///
+/// | bevy_rapier           | Bevy XPBD                                                                            |
+/// | --------------------- | ------------------------------------------------------------------------------------ |
+/// | [`RigidBody`]         | [`RigidBody`](crate::prelude::RigidBody)                                             |
+/// | [`Velocity`]          | [`LinearVelocity`] and [`AngularVelocity`]                                           |
+/// | [`Damping`]           | [`LinearDamping`] and [`AngularDamping`]                                             |
+/// | [`ExternalForce`]     | A persistent [`ExternalForce`](crate::prelude::ExternalForce) and [`ExternalTorque`] |
+/// | [`ExternalImpulse`]   | [`ExternalImpulse`](crate::prelude::ExternalImpulse) and [`ExternalAngularImpulse`]  |
+/// | [`LockedAxes`]        | [`LockedAxes`](crate::prelude::LockedAxes)                                           |
+///
+/// The compatibility components are removed once they have been converted, so reading or writing them
+/// after spawning has no effect. Use the Bevy XPBD components directly in your systems instead.
+///
+/// bevy_rapier's collider constructors take half-extents, while Bevy XPBD's take full extents.
+/// The functions in the [`collider`] module use bevy_rapier's conventions.
+///
+/// This plugin requires the `rapier-compat` feature. It doesn't depend on bevy_rapier itself.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::{plugins::rapier_compat::{self, collider}, prelude::*};
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::{plugins::rapier_compat::{self, collider}, prelude::*};
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         rapier_compat::RigidBody::Dynamic,
+///         # #[cfg(feature = "2d")]
+///         # collider::cuboid(0.5, 0.5),
+///         # #[cfg(feature = "3d")]
+///         collider::cuboid(0.5, 0.5, 0.5),
+///         # #[cfg(feature = "2d")]
+///         # rapier_compat::Velocity::linear(Vec2::X),
+///         # #[cfg(feature = "3d")]
+///         rapier_compat::Velocity::linear(Vec3::X),
+///         rapier_compat::Damping {
+///             linear_damping: 0.5,
+///             angular_damping: 1.0,
+///         },
+///         rapier_compat::LockedAxes::ROTATION_LOCKED,
+///     ));
+/// }
+/// ```
+pub struct RapierCompatPlugin {
+    schedule: Box<dyn ScheduleLabel>,
+}
+
+impl RapierCompatPlugin {
+    /// Creates a [`RapierCompatPlugin`] with the schedule that is used for running the [`PhysicsSchedule`].
+    ///
+    /// The default schedule is `PostUpdate`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: Box::new(schedule),
+        }
+    }
+}
+
+impl Default for RapierCompatPlugin {
+    fn default() -> Self {
+        Self::new(PostUpdate)
+    }
+}
+
+impl Plugin for RapierCompatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            self.schedule.dyn_clone(),
+            (
+                (
+                    convert::<RigidBody>,
+                    convert::<Velocity>,
+                    convert::<Damping>,
+                    convert::<ExternalForce>,
+                    convert::<ExternalImpulse>,
+                    convert::<LockedAxes>,
+                ),
+                apply_deferred,
+            )
+                .chain()
+                .before(PhysicsSet::Prepare),
+        );
+    }
+}
+
+/// A bevy_rapier component that can be converted into Bevy XPBD components.
+pub trait IntoXpbd: Component + Copy {
+    /// The Bevy XPBD components that replace this component.
+    type Output: Bundle;
+
+    /// Converts the component into its Bevy XPBD equivalent.
+    fn into_xpbd(self) -> Self::Output;
+}
+
+/// Replaces newly added `T` components with their Bevy XPBD equivalents.
+fn convert<T: IntoXpbd>(mut commands: Commands, query: Query<(Entity, &T), Added<T>>) {
+    for (entity, component) in &query {
+        commands
+            .entity(entity)
+            .remove::<T>()
+            .insert(component.into_xpbd());
+    }
+}
+
+/// The type of a rigid body, using bevy_rapier's naming. Converted into a [`RigidBody`](crate::prelude::RigidBody).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RigidBody {
+    /// A body that is affected by forces and contacts.
+    #[default]
+    Dynamic,
+    /// A body that never moves. Converted into [`RigidBody::Static`](crate::prelude::RigidBody::Static).
+    Fixed,
+    /// A kinematic body moved by changing its position. Converted into
+    /// [`RigidBody::Kinematic`](crate::prelude::RigidBody::Kinematic).
+    KinematicPositionBased,
+    /// A kinematic body moved by changing its velocity. Converted into
+    /// [`RigidBody::Kinematic`](crate::prelude::RigidBody::Kinematic).
+    KinematicVelocityBased,
+}
+
+impl IntoXpbd for RigidBody {
+    type Output = crate::prelude::RigidBody;
+
+    fn into_xpbd(self) -> Self::Output {
+        match self {
+            Self::Dynamic => crate::prelude::RigidBody::Dynamic,
+            Self::Fixed => crate::prelude::RigidBody::Static,
+            Self::KinematicPositionBased | Self::KinematicVelocityBased => {
+                crate::prelude::RigidBody::Kinematic
+            }
+        }
+    }
+}
+
+/// The linear and angular velocity of a body. Converted into [`LinearVelocity`] and [`AngularVelocity`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Velocity {
+    /// The linear velocity.
+    pub linvel: Vector,
+    /// The angular velocity.
+    pub angvel: AngularVelocityValue,
+}
+
+impl Velocity {
+    /// Zero linear and angular velocity.
+    pub const ZERO: Self = Self {
+        linvel: Vector::ZERO,
+        #[cfg(feature = "2d")]
+        angvel: 0.0,
+        #[cfg(feature = "3d")]
+        angvel: Vector::ZERO,
+    };
+
+    /// Creates a [`Velocity`] with the given linear velocity and no angular velocity.
+    pub fn linear(linvel: Vector) -> Self {
+        Self {
+            linvel,
+            ..Self::ZERO
+        }
+    }
+
+    /// Creates a [`Velocity`] with the given angular velocity and no linear velocity.
+    pub fn angular(angvel: AngularVelocityValue) -> Self {
+        Self {
+            angvel,
+            ..Self::ZERO
+        }
+    }
+}
+
+impl IntoXpbd for Velocity {
+    type Output = (LinearVelocity, AngularVelocity);
+
+    fn into_xpbd(self) -> Self::Output {
+        (LinearVelocity(self.linvel), AngularVelocity(self.angvel))
+    }
+}
+
+/// Linear and angular damping. Converted into [`LinearDamping`] and [`AngularDamping`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Damping {
+    /// The linear damping coefficient.
+    pub linear_damping: Scalar,
+    /// The angular damping coefficient.
+    pub angular_damping: Scalar,
+}
+
+impl IntoXpbd for Damping {
+    type Output = (LinearDamping, AngularDamping);
+
+    fn into_xpbd(self) -> Self::Output {
+        (
+            LinearDamping(self.linear_damping),
+            AngularDamping(self.angular_damping),
+        )
+    }
+}
+
+/// A force and torque that are applied continuously. Converted into a persistent
+/// [`ExternalForce`](crate::prelude::ExternalForce) and [`ExternalTorque`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExternalForce {
+    /// The force applied at the center of mass.
+    pub force: Vector,
+    /// The torque.
+    pub torque: Torque,
+}
+
+impl IntoXpbd for ExternalForce {
+    type Output = (crate::prelude::ExternalForce, ExternalTorque);
+
+    fn into_xpbd(self) -> Self::Output {
+        (
+            crate::prelude::ExternalForce::new(self.force).with_persistence(true),
+            ExternalTorque::new(self.torque).with_persistence(true),
+        )
+    }
+}
+
+/// An impulse and angular impulse that are applied once. Converted into an
+/// [`ExternalImpulse`](crate::prelude::ExternalImpulse) and [`ExternalAngularImpulse`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExternalImpulse {
+    /// The impulse applied at the center of mass.
+    pub impulse: Vector,
+    /// The angular impulse.
+    pub torque_impulse: Torque,
+}
+
+impl IntoXpbd for ExternalImpulse {
+    type Output = (crate::prelude::ExternalImpulse, ExternalAngularImpulse);
+
+    fn into_xpbd(self) -> Self::Output {
+        (
+            crate::prelude::ExternalImpulse::new(self.impulse).with_persistence(false),
+            ExternalAngularImpulse::new(self.torque_impulse).with_persistence(false),
+        )
+    }
+}
+
+/// Flags for locking translational and rotational axes, using bevy_rapier's bit layout.
+/// Converted into [`LockedAxes`](crate::prelude::LockedAxes).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LockedAxes(u8);
+
+impl LockedAxes {
+    /// Locks translation along the `X` axis.
+    pub const TRANSLATION_LOCKED_X: Self = Self(1 << 0);
+    /// Locks translation along the `Y` axis.
+    pub const TRANSLATION_LOCKED_Y: Self = Self(1 << 1);
+    /// Locks translation along the `Z` axis.
+    pub const TRANSLATION_LOCKED_Z: Self = Self(1 << 2);
+    /// Locks translation along all axes.
+    pub const TRANSLATION_LOCKED: Self = Self(0b111);
+    /// Locks rotation around the `X` axis.
+    pub const ROTATION_LOCKED_X: Self = Self(1 << 3);
+    /// Locks rotation around the `Y` axis.
+    pub const ROTATION_LOCKED_Y: Self = Self(1 << 4);
+    /// Locks rotation around the `Z` axis.
+    pub const ROTATION_LOCKED_Z: Self = Self(1 << 5);
+    /// Locks rotation around all axes.
+    pub const ROTATION_LOCKED: Self = Self(0b111 << 3);
+
+    /// Returns the raw bits of the flags.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if all of the flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for LockedAxes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for LockedAxes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl IntoXpbd for LockedAxes {
+    type Output = crate::prelude::LockedAxes;
+
+    fn into_xpbd(self) -> Self::Output {
+        let mut locked_axes = crate::prelude::LockedAxes::new();
+        if self.contains(Self::TRANSLATION_LOCKED_X) {
+            locked_axes = locked_axes.lock_translation_x();
+        }
+        if self.contains(Self::TRANSLATION_LOCKED_Y) {
+            locked_axes = locked_axes.lock_translation_y();
+        }
+        #[cfg(feature = "3d")]
+        if self.contains(Self::TRANSLATION_LOCKED_Z) {
+            locked_axes = locked_axes.lock_translation_z();
+        }
+        #[cfg(feature = "3d")]
+        if self.contains(Self::ROTATION_LOCKED_X) {
+            locked_axes = locked_axes.lock_rotation_x();
+        }
+        #[cfg(feature = "3d")]
+        if self.contains(Self::ROTATION_LOCKED_Y) {
+            locked_axes = locked_axes.lock_rotation_y();
+        }
+        #[cfg(feature = "3d")]
+        if self.contains(Self::ROTATION_LOCKED_Z) {
+            locked_axes = locked_axes.lock_rotation_z();
+        }
+        // In 2D, the only rotation axis is `Z`
+        #[cfg(feature = "2d")]
+        if self.contains(Self::ROTATION_LOCKED_Z) {
+            locked_axes = locked_axes.lock_rotation();
+        }
+        locked_axes
+    }
+}
+
+impl From<LockedAxes> for crate::prelude::LockedAxes {
+    fn from(value: LockedAxes) -> Self {
+        value.into_xpbd()
+    }
+}
+
+/// [`Collider`] constructors that use bevy_rapier's conventions.
+///
+/// Unlike Bevy XPBD's constructors, these take half-extents and half-heights.
+pub mod collider {
+    use crate::prelude::*;
+
+    /// Creates a ball collider with the given radius.
+    pub fn ball(radius: Scalar) -> Collider {
+        Collider::ball(radius)
+    }
+
+    /// Creates a cuboid collider with the given half-extents.
+    #[cfg(feature = "2d")]
+    pub fn cuboid(hx: Scalar, hy: Scalar) -> Collider {
+        Collider::cuboid(2.0 * hx, 2.0 * hy)
+    }
+
+    /// Creates a cuboid collider with the given half-extents.
+    #[cfg(feature = "3d")]
+    pub fn cuboid(hx: Scalar, hy: Scalar, hz: Scalar) -> Collider {
+        Collider::cuboid(2.0 * hx, 2.0 * hy, 2.0 * hz)
+    }
+
+    /// Creates a capsule collider along the `X` axis with the given half-height and radius.
+    pub fn capsule_x(half_height: Scalar, radius: Scalar) -> Collider {
+        Collider::capsule_endpoints(Vector::NEG_X * half_height, Vector::X * half_height, radius)
+    }
+
+    /// Creates a capsule collider along the `Y` axis with the given half-height and radius.
+    pub fn capsule_y(half_height: Scalar, radius: Scalar) -> Collider {
+        Collider::capsule(2.0 * half_height, radius)
+    }
+
+    /// Creates a capsule collider along the `Z` axis with the given half-height and radius.
+    #[cfg(feature = "3d")]
+    pub fn capsule_z(half_height: Scalar, radius: Scalar) -> Collider {
+        Collider::capsule_endpoints(Vector::NEG_Z * half_height, Vector::Z * half_height, radius)
+    }
+
+    /// Creates a cylinder collider along the `Y` axis with the given half-height and radius.
+    #[cfg(feature = "3d")]
+    pub fn cylinder(half_height: Scalar, radius: Scalar) -> Collider {
+        Collider::cylinder(2.0 * half_height, radius)
+    }
+
+    /// Creates a cone collider along the `Y` axis with the given half-height and base radius.
+    #[cfg(feature = "3d")]
+    pub fn cone(half_height: Scalar, radius: Scalar) -> Collider {
+        Collider::cone(2.0 * half_height, radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collider, IntoXpbd};
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn locked_axes_use_rapier_bit_layout() {
+        use super::LockedAxes;
+
+        assert_eq!(LockedAxes::TRANSLATION_LOCKED_X.bits(), 0b000_001);
+        assert_eq!(LockedAxes::TRANSLATION_LOCKED_Z.bits(), 0b000_100);
+        assert_eq!(LockedAxes::ROTATION_LOCKED_X.bits(), 0b001_000);
+        assert_eq!(LockedAxes::ROTATION_LOCKED_Z.bits(), 0b100_000);
+        assert_eq!(
+            LockedAxes::TRANSLATION_LOCKED,
+            LockedAxes::TRANSLATION_LOCKED_X
+                | LockedAxes::TRANSLATION_LOCKED_Y
+                | LockedAxes::TRANSLATION_LOCKED_Z
+        );
+        assert_eq!(
+            LockedAxes::ROTATION_LOCKED,
+            LockedAxes::ROTATION_LOCKED_X
+                | LockedAxes::ROTATION_LOCKED_Y
+                | LockedAxes::ROTATION_LOCKED_Z
+        );
+
+        // Bevy XPBD stores rotation locks in the low bits and translation locks in the high bits
+        let converted =
+            (LockedAxes::TRANSLATION_LOCKED_Y | LockedAxes::ROTATION_LOCKED_Z).into_xpbd();
+        #[cfg(feature = "2d")]
+        let expected = crate::prelude::LockedAxes::new()
+            .lock_translation_y()
+            .lock_rotation();
+        #[cfg(feature = "3d")]
+        let expected = crate::prelude::LockedAxes::new()
+            .lock_translation_y()
+            .lock_rotation_z();
+        assert_eq!(converted.to_bits(), expected.to_bits());
+
+        #[cfg(feature = "2d")]
+        {
+            // Axes that don't exist in 2D are ignored
+            let converted = (LockedAxes::TRANSLATION_LOCKED_Z
+                | LockedAxes::ROTATION_LOCKED_X
+                | LockedAxes::ROTATION_LOCKED_Y)
+                .into_xpbd();
+            assert_eq!(converted.to_bits(), 0);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let converted =
+                (LockedAxes::TRANSLATION_LOCKED | LockedAxes::ROTATION_LOCKED).into_xpbd();
+            assert_eq!(
+                converted.to_bits(),
+                crate::prelude::LockedAxes::ALL_LOCKED.to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn collider_constructors_take_half_extents() {
+        #[cfg(feature = "2d")]
+        let cuboid = collider::cuboid(0.5, 2.0);
+        #[cfg(feature = "3d")]
+        let cuboid = collider::cuboid(0.5, 2.0, 1.0);
+        #[cfg(feature = "2d")]
+        let half_extents = Vector::new(0.5, 2.0);
+        #[cfg(feature = "3d")]
+        let half_extents = Vector::new(0.5, 2.0, 1.0);
+        assert_relative_eq!(
+            Vector::from(cuboid.shape().as_cuboid().unwrap().half_extents),
+            half_extents
+        );
+
+        // The half-height is measured from the origin to the centers of the caps
+        let capsule = collider::capsule_y(1.5, 0.5);
+        let capsule = capsule.shape().as_capsule().unwrap();
+        assert_relative_eq!(capsule.half_height(), 1.5);
+        assert_relative_eq!(Vector::from(capsule.segment.a).abs(), Vector::Y * 1.5);
+        assert_relative_eq!(capsule.radius, 0.5);
+
+        let capsule = collider::capsule_x(1.5, 0.5);
+        let capsule = capsule.shape().as_capsule().unwrap();
+        assert_relative_eq!(capsule.half_height(), 1.5);
+        assert_relative_eq!(Vector::from(capsule.segment.a).abs(), Vector::X * 1.5);
+
+        #[cfg(feature = "3d")]
+        {
+            let cylinder = collider::cylinder(1.5, 0.5);
+            let cylinder = cylinder.shape().as_cylinder().unwrap();
+            assert_relative_eq!(cylinder.half_height, 1.5);
+            assert_relative_eq!(cylinder.radius, 0.5);
+
+            let cone = collider::cone(1.5, 0.5);
+            let cone = cone.shape().as_cone().unwrap();
+            assert_relative_eq!(cone.half_height, 1.5);
+            assert_relative_eq!(cone.radius, 0.5);
+        }
+    }
+
+    #[test]
+    fn components_are_converted_when_spawned() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, super::RapierCompatPlugin::default()));
+
+        #[cfg(feature = "2d")]
+        let (angvel, torque) = (2.0, 3.0);
+        #[cfg(feature = "3d")]
+        let (angvel, torque) = (Vector::Z * 2.0, Vector::Z * 3.0);
+
+        let entity = app
+            .world
+            .spawn((
+                super::RigidBody::KinematicVelocityBased,
+                super::Velocity {
+                    linvel: Vector::X,
+                    angvel,
+                },
+                super::Damping {
+                    linear_damping: 0.5,
+                    angular_damping: 1.0,
+                },
+                super::ExternalForce {
+                    force: Vector::Y,
+                    torque,
+                },
+            ))
+            .id();
+
+        app.update();
+
+        let entity = app.world.entity(entity);
+        assert_eq!(entity.get::<RigidBody>(), Some(&RigidBody::Kinematic));
+        assert_eq!(
+            entity.get::<LinearVelocity>(),
+            Some(&LinearVelocity(Vector::X))
+        );
+        assert_eq!(
+            entity.get::<AngularVelocity>(),
+            Some(&AngularVelocity(angvel))
+        );
+        assert_eq!(entity.get::<LinearDamping>().unwrap().0, 0.5);
+        assert_eq!(entity.get::<AngularDamping>().unwrap().0, 1.0);
+
+        // bevy_rapier's forces are applied until they are changed
+        let force = entity.get::<ExternalForce>().unwrap();
+        assert_eq!(force.force(), Vector::Y);
+        assert!(force.persistent);
+        let external_torque = entity.get::<ExternalTorque>().unwrap();
+        assert_eq!(external_torque.torque(), torque);
+        assert!(external_torque.persistent);
+
+        // The compatibility components are removed
+        assert!(!entity.contains::<super::RigidBody>());
+        assert!(!entity.contains::<super::Velocity>());
+        assert!(!entity.contains::<super::Damping>());
+        assert!(!entity.contains::<super::ExternalForce>());
+    }
+}
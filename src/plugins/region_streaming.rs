@@ -0,0 +1,604 @@
+//! Freezes and restores all physics in chunks of a streamed world.
+//!
+//! See [`RegionStreamingPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::system::Command,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+/// A plugin for streaming worlds that freezes all physics in a chunk of the world at once and restores it
+/// exactly later, for example when the player moves away from a part of an open world and comes back.
+///
+/// Regions are frozen and thawed using the [`RegionStreamingCommandsExt`] trait. A region is a cube
+/// (or a square in 2D) of the [chunk size](RegionStreamingPlugin::chunk_size) in the same
+/// [`ChunkCoordinates`] as the [`StaticChunkPlugin`].
+///
+/// Freezing a region removes the [`RigidBody`] and [`Collider`] components of all bodies whose [`Position`]
+/// is inside the region, as well as the colliders attached to them. This removes the bodies from the simulation,
+/// the broad phase and spatial queries, while keeping the entities and their other components. The state that is
+/// needed for restoring the bodies is stored in their [`FrozenBody`] component.
+///
+/// Joints attached to a frozen body are disabled with the [`JointDisabled`] and [`FrozenJoint`] components,
+/// because they can't be solved without both bodies. They are enabled again once none of their bodies are frozen.
+/// Joints that were already disabled are left untouched.
+///
+/// Thawing the region adds the bodies back with the exact positions, rotations, velocities and mass properties
+/// that they had when they were frozen. The mass properties are restored before [`PhysicsStepSet::BroadPhase`]
+/// of the next physics step.
+///
+/// [`RegionFrozen`] and [`RegionThawed`] events are sent so that world streaming systems can react to the changes.
+/// The frozen regions are stored in the [`FrozenRegions`] resource.
+pub struct RegionStreamingPlugin {
+    /// The size of a region along each axis.
+    pub chunk_size: Scalar,
+}
+
+impl RegionStreamingPlugin {
+    /// Creates a new [`RegionStreamingPlugin`] with the given region size.
+    pub fn new(chunk_size: Scalar) -> Self {
+        Self { chunk_size }
+    }
+}
+
+impl Default for RegionStreamingPlugin {
+    fn default() -> Self {
+        Self::new(64.0)
+    }
+}
+
+impl Plugin for RegionStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(FrozenRegions {
+            chunk_size: self.chunk_size,
+            regions: HashMap::default(),
+        })
+        .add_event::<RegionFrozen>()
+        .add_event::<RegionThawed>()
+        .register_type::<FrozenBody>()
+        .register_type::<FrozenJoint>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(restore_thawed_bodies.before(PhysicsStepSet::BroadPhase));
+    }
+}
+
+/// A resource that stores the regions frozen using [`RegionStreamingCommandsExt`].
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct FrozenRegions {
+    chunk_size: Scalar,
+    regions: HashMap<ChunkCoordinates, Vec<Entity>>,
+}
+
+impl FrozenRegions {
+    /// Returns the size of a region along each axis.
+    pub fn chunk_size(&self) -> Scalar {
+        self.chunk_size
+    }
+
+    /// Returns the coordinates of the region that contains the given point.
+    pub fn region_at(&self, point: Vector) -> ChunkCoordinates {
+        region_at(point, self.chunk_size)
+    }
+
+    /// Returns true if the region at the given coordinates is frozen.
+    pub fn is_frozen(&self, region: ChunkCoordinates) -> bool {
+        self.regions.contains_key(&region)
+    }
+
+    /// Returns the bodies of the frozen region at the given coordinates, if it is frozen.
+    pub fn bodies(&self, region: ChunkCoordinates) -> Option<&[Entity]> {
+        self.regions.get(&region).map(|bodies| bodies.as_slice())
+    }
+
+    /// Returns an iterator over the coordinates of the frozen regions.
+    pub fn iter(&self) -> impl Iterator<Item = ChunkCoordinates> + '_ {
+        self.regions.keys().copied()
+    }
+}
+
+/// The state of a body in a region frozen by the [`RegionStreamingPlugin`].
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct FrozenBody {
+    /// The coordinates of the frozen region.
+    pub region: ChunkCoordinates,
+    rigid_body: RigidBody,
+    position: Position,
+    rotation: Rotation,
+    linear_velocity: LinearVelocity,
+    angular_velocity: AngularVelocity,
+    mass: Mass,
+    inverse_mass: InverseMass,
+    inertia: Inertia,
+    inverse_inertia: InverseInertia,
+    center_of_mass: CenterOfMass,
+    /// True if the region has been thawed and the mass properties still need to be restored.
+    thawed: bool,
+}
+
+/// The collider of a body in a region frozen by the [`RegionStreamingPlugin`].
+#[derive(Clone, Component)]
+pub struct FrozenCollider {
+    /// The frozen body that the collider is attached to.
+    pub body: Entity,
+    /// The removed collider.
+    pub collider: Collider,
+}
+
+/// A marker component for joints that were disabled by the [`RegionStreamingPlugin`]
+/// because one of their bodies is in a frozen region.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct FrozenJoint;
+
+/// An event that is sent when a region is frozen.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegionFrozen {
+    /// The coordinates of the region.
+    pub region: ChunkCoordinates,
+    /// The number of bodies that were frozen.
+    pub body_count: usize,
+}
+
+/// An event that is sent when a region is thawed.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegionThawed {
+    /// The coordinates of the region.
+    pub region: ChunkCoordinates,
+    /// The number of bodies that were restored.
+    pub body_count: usize,
+}
+
+/// A [`Command`] that freezes all bodies in a region. Nothing happens if the region is already frozen.
+///
+/// Usually, this is used through [`RegionStreamingCommandsExt::freeze_region`].
+pub struct FreezeRegion {
+    /// The coordinates of the region.
+    pub region: ChunkCoordinates,
+}
+
+impl Command for FreezeRegion {
+    fn apply(self, world: &mut World) {
+        let Some(chunk_size) = world
+            .get_resource::<FrozenRegions>()
+            .filter(|regions| !regions.is_frozen(self.region))
+            .map(|regions| regions.chunk_size)
+        else {
+            return;
+        };
+
+        let mut bodies_query = world.query::<(
+            Entity,
+            &RigidBody,
+            &Position,
+            &Rotation,
+            &LinearVelocity,
+            &AngularVelocity,
+            &Mass,
+            &InverseMass,
+            &Inertia,
+            &InverseInertia,
+            &CenterOfMass,
+        )>();
+        let bodies: Vec<(Entity, FrozenBody)> = bodies_query
+            .iter(world)
+            .filter(|(_, _, position, ..)| region_at(position.0, chunk_size) == self.region)
+            .map(
+                |(
+                    entity,
+                    rb,
+                    position,
+                    rotation,
+                    lin_vel,
+                    ang_vel,
+                    mass,
+                    inv_mass,
+                    inertia,
+                    inv_inertia,
+                    center_of_mass,
+                )| {
+                    let frozen_body = FrozenBody {
+                        region: self.region,
+                        rigid_body: *rb,
+                        position: *position,
+                        rotation: *rotation,
+                        linear_velocity: *lin_vel,
+                        angular_velocity: *ang_vel,
+                        mass: *mass,
+                        inverse_mass: *inv_mass,
+                        inertia: *inertia,
+                        inverse_inertia: *inv_inertia,
+                        center_of_mass: *center_of_mass,
+                        thawed: false,
+                    };
+                    (entity, frozen_body)
+                },
+            )
+            .collect();
+
+        let body_entities: HashSet<Entity> = bodies.iter().map(|(entity, _)| *entity).collect();
+
+        // Find the colliders attached to the bodies, including the colliders on the bodies themselves
+        let mut colliders_query = world.query::<(Entity, &Collider, &ColliderParent)>();
+        let colliders: Vec<(Entity, FrozenCollider)> = colliders_query
+            .iter(world)
+            .filter(|(_, _, parent)| body_entities.contains(&parent.get()))
+            .map(|(entity, collider, parent)| {
+                let frozen_collider = FrozenCollider {
+                    body: parent.get(),
+                    collider: collider.clone(),
+                };
+                (entity, frozen_collider)
+            })
+            .collect();
+
+        for (entity, frozen_collider) in colliders {
+            world
+                .entity_mut(entity)
+                .remove::<Collider>()
+                .insert(frozen_collider);
+        }
+        for (entity, frozen_body) in bodies.iter() {
+            world
+                .entity_mut(*entity)
+                .remove::<RigidBody>()
+                .insert(*frozen_body);
+        }
+
+        freeze_joints::<FixedJoint>(world, &body_entities);
+        freeze_joints::<DistanceJoint>(world, &body_entities);
+        freeze_joints::<PrismaticJoint>(world, &body_entities);
+        freeze_joints::<RevoluteJoint>(world, &body_entities);
+        freeze_joints::<SphericalJoint>(world, &body_entities);
+        freeze_joints::<SpringJoint>(world, &body_entities);
+
+        let body_count = bodies.len();
+        world.resource_mut::<FrozenRegions>().regions.insert(
+            self.region,
+            bodies.into_iter().map(|(entity, _)| entity).collect(),
+        );
+        world
+            .resource_mut::<Events<RegionFrozen>>()
+            .send(RegionFrozen {
+                region: self.region,
+                body_count,
+            });
+    }
+}
+
+/// A [`Command`] that restores all bodies in a frozen region. Nothing happens if the region isn't frozen.
+///
+/// Usually, this is used through [`RegionStreamingCommandsExt::thaw_region`].
+pub struct ThawRegion {
+    /// The coordinates of the region.
+    pub region: ChunkCoordinates,
+}
+
+impl Command for ThawRegion {
+    fn apply(self, world: &mut World) {
+        let Some(bodies) = world
+            .get_resource_mut::<FrozenRegions>()
+            .and_then(|mut regions| regions.regions.remove(&self.region))
+        else {
+            return;
+        };
+
+        let bodies: HashSet<Entity> = bodies.into_iter().collect();
+
+        // Colliders lose their `ColliderParent` when the body is frozen, so the body is stored in the frozen collider
+        let mut colliders_query = world.query::<(Entity, &FrozenCollider)>();
+        let colliders: Vec<(Entity, Collider)> = colliders_query
+            .iter(world)
+            .filter(|(_, frozen_collider)| bodies.contains(&frozen_collider.body))
+            .map(|(entity, frozen_collider)| (entity, frozen_collider.collider.clone()))
+            .collect();
+
+        let mut body_count = 0;
+        for entity in bodies {
+            let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            let Some(mut frozen_body) = entity_mut.get_mut::<FrozenBody>() else {
+                continue;
+            };
+            frozen_body.thawed = true;
+            let frozen_body = *frozen_body;
+            entity_mut.insert((
+                frozen_body.rigid_body,
+                frozen_body.position,
+                frozen_body.rotation,
+                frozen_body.linear_velocity,
+                frozen_body.angular_velocity,
+            ));
+            body_count += 1;
+        }
+
+        for (entity, collider) in colliders {
+            world
+                .entity_mut(entity)
+                .remove::<FrozenCollider>()
+                .insert(collider);
+        }
+
+        thaw_joints::<FixedJoint>(world);
+        thaw_joints::<DistanceJoint>(world);
+        thaw_joints::<PrismaticJoint>(world);
+        thaw_joints::<RevoluteJoint>(world);
+        thaw_joints::<SphericalJoint>(world);
+        thaw_joints::<SpringJoint>(world);
+
+        world
+            .resource_mut::<Events<RegionThawed>>()
+            .send(RegionThawed {
+                region: self.region,
+                body_count,
+            });
+    }
+}
+
+/// Disables the enabled joints of type `T` that are attached to one of the given frozen `bodies`.
+fn freeze_joints<T: Joint>(world: &mut World, bodies: &HashSet<Entity>) {
+    let mut joints_query = world.query_filtered::<(Entity, &T), Without<JointDisabled>>();
+    let joints: Vec<Entity> = joints_query
+        .iter(world)
+        .filter(|(_, joint)| joint.entities().iter().any(|body| bodies.contains(body)))
+        .map(|(entity, _)| entity)
+        .collect();
+
+    for entity in joints {
+        world
+            .entity_mut(entity)
+            .insert((JointDisabled, FrozenJoint));
+    }
+}
+
+/// Enables the joints of type `T` disabled by [`freeze_joints`] once none of their bodies are frozen.
+fn thaw_joints<T: Joint>(world: &mut World) {
+    let mut joints_query = world.query_filtered::<(Entity, &T), With<FrozenJoint>>();
+    let mut frozen_bodies = world.query::<&FrozenBody>();
+    let joints: Vec<Entity> = joints_query
+        .iter(world)
+        .filter(|(_, joint)| {
+            !joint.entities().iter().any(|body| {
+                frozen_bodies
+                    .get(world, *body)
+                    .is_ok_and(|frozen_body| !frozen_body.thawed)
+            })
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+
+    for entity in joints {
+        world
+            .entity_mut(entity)
+            .remove::<(JointDisabled, FrozenJoint)>();
+    }
+}
+
+/// An extension trait for freezing and thawing regions using `Commands`. Requires the [`RegionStreamingPlugin`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn stream_regions(mut commands: Commands, regions: Res<FrozenRegions>, player: Query<&Position>) {
+///     let Ok(position) = player.get_single() else {
+///         return;
+///     };
+///     let player_region = regions.region_at(position.0);
+///
+///     // Thaw the regions that are close to the player again
+///     for region in regions.iter() {
+///         if (region - player_region).abs().max_element() <= 1 {
+///             commands.thaw_region(region);
+///         }
+///     }
+/// }
+///
+/// fn on_region_frozen(mut events: EventReader<RegionFrozen>) {
+///     for event in events.iter() {
+///         println!("Froze {} bodies in region {}", event.body_count, event.region);
+///     }
+/// }
+/// ```
+pub trait RegionStreamingCommandsExt {
+    /// Freezes all bodies in the given region.
+    fn freeze_region(&mut self, region: ChunkCoordinates);
+
+    /// Restores all bodies in the given frozen region.
+    fn thaw_region(&mut self, region: ChunkCoordinates);
+}
+
+impl RegionStreamingCommandsExt for Commands<'_, '_> {
+    fn freeze_region(&mut self, region: ChunkCoordinates) {
+        self.add(FreezeRegion { region });
+    }
+
+    fn thaw_region(&mut self, region: ChunkCoordinates) {
+        self.add(ThawRegion { region });
+    }
+}
+
+/// Restores the exact mass properties of thawed bodies after their colliders have been added back,
+/// because adding the colliders changes the mass properties.
+fn restore_thawed_bodies(
+    mut commands: Commands,
+    mut bodies: Query<(Entity, &FrozenBody, MassPropertiesQuery)>,
+) {
+    for (entity, frozen_body, mut mass_properties) in &mut bodies {
+        if !frozen_body.thawed {
+            continue;
+        }
+
+        *mass_properties.mass = frozen_body.mass;
+        *mass_properties.inverse_mass = frozen_body.inverse_mass;
+        *mass_properties.inertia = frozen_body.inertia;
+        *mass_properties.inverse_inertia = frozen_body.inverse_inertia;
+        *mass_properties.center_of_mass = frozen_body.center_of_mass;
+        commands.entity(entity).remove::<FrozenBody>();
+    }
+}
+
+/// Returns the coordinates of the region of the given size that contains the given point.
+pub(crate) fn region_at(point: Vector, chunk_size: Scalar) -> ChunkCoordinates {
+    #[cfg(feature = "2d")]
+    {
+        (point / chunk_size).floor().as_ivec2()
+    }
+    #[cfg(feature = "3d")]
+    {
+        (point / chunk_size).floor().as_ivec3()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::{ecs::system::CommandQueue, prelude::*};
+
+    /// Queues commands using the given function and applies them to the world of the app.
+    fn apply_commands(app: &mut App, f: impl FnOnce(&mut Commands)) {
+        let mut queue = CommandQueue::default();
+        f(&mut Commands::new(&mut queue, &app.world));
+        queue.apply(&mut app.world);
+    }
+
+    /// Spawns a dynamic ball at the given position.
+    fn spawn_ball(app: &mut App, position: Vector) -> Entity {
+        app.world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(position),
+                Collider::ball(0.5),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn frozen_regions_are_restored_exactly() {
+        let mut app = create_app();
+        app.add_plugins(RegionStreamingPlugin::new(10.0));
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::splat(2.0)),
+                LinearVelocity(Vector::X * 2.0),
+                GravityScale(0.0),
+                Collider::ball(0.5),
+                MassPropertiesBundle::new_computed(&Collider::ball(0.5), 2.0),
+            ))
+            .id();
+
+        app.step_physics(2);
+
+        let position = *app.world.get::<Position>(body).unwrap();
+        let velocity = *app.world.get::<LinearVelocity>(body).unwrap();
+        let mass = *app.world.get::<Mass>(body).unwrap();
+
+        let region = app.world.resource::<FrozenRegions>().region_at(position.0);
+        assert_eq!(region, ChunkCoordinates::ZERO);
+
+        apply_commands(&mut app, |commands| commands.freeze_region(region));
+
+        app.step_physics(30);
+
+        // The body isn't simulated or found by spatial queries while it's frozen
+        assert!(app.world.get::<RigidBody>(body).is_none());
+        assert!(app.world.get::<Collider>(body).is_none());
+        assert!(app.world.get::<FrozenBody>(body).is_some());
+        assert_eq!(*app.world.get::<Position>(body).unwrap(), position);
+        assert!(app
+            .world
+            .resource::<SpatialQueryPipeline>()
+            .point_intersections(position.0, SpatialQueryFilter::default())
+            .is_empty());
+        assert_eq!(
+            app.world.resource::<FrozenRegions>().bodies(region),
+            Some([body].as_slice())
+        );
+
+        apply_commands(&mut app, |commands| commands.thaw_region(region));
+
+        // The state is restored exactly
+        assert_eq!(*app.world.get::<Position>(body).unwrap(), position);
+        assert_eq!(*app.world.get::<LinearVelocity>(body).unwrap(), velocity);
+        assert!(!app.world.resource::<FrozenRegions>().is_frozen(region));
+
+        app.step_physics(1);
+
+        assert_eq!(*app.world.get::<Mass>(body).unwrap(), mass);
+        assert!(app.world.get::<FrozenBody>(body).is_none());
+        assert!(app.world.get::<Position>(body).unwrap().x > position.x);
+    }
+
+    #[test]
+    fn joints_of_frozen_bodies_are_disabled_until_thawed() {
+        let mut app = create_app();
+        app.add_plugins(RegionStreamingPlugin::new(10.0));
+
+        let frozen = spawn_ball(&mut app, Vector::splat(2.0));
+        let frozen_other = spawn_ball(&mut app, Vector::splat(12.0));
+        let active = spawn_ball(&mut app, Vector::splat(-2.0));
+
+        let joint = app.world.spawn(DistanceJoint::new(frozen, active)).id();
+        let joint_between_regions = app.world.spawn(FixedJoint::new(frozen, frozen_other)).id();
+        let disabled_joint = app
+            .world
+            .spawn((SpringJoint::new(frozen, active), JointDisabled))
+            .id();
+
+        app.step_physics(1);
+
+        apply_commands(&mut app, |commands| {
+            commands.freeze_region(ChunkCoordinates::ZERO);
+            commands.freeze_region(ChunkCoordinates::ONE);
+        });
+
+        for entity in [joint, joint_between_regions] {
+            assert!(app.world.get::<JointDisabled>(entity).is_some());
+            assert!(app.world.get::<FrozenJoint>(entity).is_some());
+        }
+        assert!(app.world.get::<FrozenJoint>(disabled_joint).is_none());
+
+        // The active body isn't held in place by the frozen body
+        let start = app.world.get::<Position>(active).unwrap().0;
+        app.step_physics(30);
+        assert!(app.world.get::<Position>(active).unwrap().y < start.y - 0.5);
+
+        apply_commands(&mut app, |commands| {
+            commands.thaw_region(ChunkCoordinates::ZERO)
+        });
+
+        // Joints are only enabled again once none of their bodies are frozen
+        assert!(app.world.get::<JointDisabled>(joint).is_none());
+        assert!(app.world.get::<FrozenJoint>(joint).is_none());
+        assert!(app
+            .world
+            .get::<JointDisabled>(joint_between_regions)
+            .is_some());
+        assert!(app.world.get::<JointDisabled>(disabled_joint).is_some());
+
+        apply_commands(&mut app, |commands| {
+            commands.thaw_region(ChunkCoordinates::ONE)
+        });
+
+        assert!(app
+            .world
+            .get::<JointDisabled>(joint_between_regions)
+            .is_none());
+        assert!(app.world.get::<JointDisabled>(disabled_joint).is_some());
+    }
+}
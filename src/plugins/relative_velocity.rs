@@ -0,0 +1,273 @@
+//! Tracks the relative velocity between pairs of entities.
+//!
+//! See [`RelativeVelocityPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{
+        entity::{EntityMapper, MapEntities},
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+
+/// Updates the relative linear and angular velocities of the entities tracked by [`RelativeVelocitySensor`]s
+/// after each physics step.
+///
+/// The velocities are updated after [`PhysicsStepSet::Substeps`] and before [`PhysicsStepSet::ReportContacts`],
+/// so they are up to date when they are read after [`PhysicsSet::StepSimulation`].
+pub struct RelativeVelocityPlugin;
+
+impl Plugin for RelativeVelocityPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RelativeVelocitySensor>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                update_relative_velocity_sensors
+                    .after(PhysicsStepSet::Substeps)
+                    .before(PhysicsStepSet::ReportContacts),
+            );
+    }
+}
+
+/// Tracks the velocity of a point on `entity2` relative to a point on `entity1` each physics step.
+/// Requires the [`RelativeVelocityPlugin`].
+///
+/// This can be used for gameplay thresholds that depend on how two entities move relative to each other,
+/// like only allowing a ship to dock when it approaches the docking port slowly enough,
+/// or detaching a trailer when the hitch is yanked too hard.
+///
+/// The points are given by `local_anchor1` and `local_anchor2` relative to the origins of the entities.
+/// Entities without a [`LinearVelocity`] or [`AngularVelocity`] are treated as stationary,
+/// so the sensor can also track the velocity of a body relative to an entity that is not a body.
+///
+/// The sensor is a separate entity like a [joint](joints), so an entity can have any number of sensors.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::{math::*, prelude::*};
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::{math::*, prelude::*};
+///
+/// #[derive(Component)]
+/// struct DockingPort;
+///
+/// fn setup(mut commands: Commands) {
+///     let station = commands.spawn(RigidBody::Static).id();
+///     let ship = commands.spawn(RigidBody::Dynamic).id();
+///
+///     commands.spawn((
+///         RelativeVelocitySensor::new(station, ship).with_local_anchor_1(Vector::X * 10.0),
+///         DockingPort,
+///     ));
+/// }
+///
+/// fn dock(ports: Query<&RelativeVelocitySensor, With<DockingPort>>) {
+///     for port in &ports {
+///         if port.speed() < 0.5 && port.angular_speed() < 0.1 {
+///             println!("{:?} can dock with {:?}", port.entity2, port.entity1);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component, MapEntities)]
+pub struct RelativeVelocitySensor {
+    /// The entity that the velocity is measured relative to.
+    pub entity1: Entity,
+    /// The entity whose velocity is measured.
+    pub entity2: Entity,
+    /// The point on the first entity that the velocity is measured relative to.
+    pub local_anchor1: Vector,
+    /// The point on the second entity whose velocity is measured.
+    pub local_anchor2: Vector,
+    /// The velocity of the point on the second entity relative to the point on the first entity
+    /// in world space during the last physics step.
+    pub linear_velocity: Vector,
+    /// The angular velocity of the second entity relative to the first entity
+    /// in world space during the last physics step.
+    pub angular_velocity: AngularVelocity,
+    /// The speed at which the points are approaching each other during the last physics step.
+    /// Negative values mean that the points are moving apart.
+    pub closing_speed: Scalar,
+}
+
+impl RelativeVelocitySensor {
+    /// Creates a new [`RelativeVelocitySensor`] that tracks the velocity of `entity2` relative to `entity1`.
+    pub fn new(entity1: Entity, entity2: Entity) -> Self {
+        Self {
+            entity1,
+            entity2,
+            local_anchor1: Vector::ZERO,
+            local_anchor2: Vector::ZERO,
+            linear_velocity: Vector::ZERO,
+            angular_velocity: AngularVelocity::ZERO,
+            closing_speed: 0.0,
+        }
+    }
+
+    /// Sets the point on the first entity that the velocity is measured relative to.
+    pub fn with_local_anchor_1(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor1: anchor,
+            ..self
+        }
+    }
+
+    /// Sets the point on the second entity whose velocity is measured.
+    pub fn with_local_anchor_2(self, anchor: Vector) -> Self {
+        Self {
+            local_anchor2: anchor,
+            ..self
+        }
+    }
+
+    /// Returns the relative speed of the points, the length of the relative `linear_velocity`.
+    pub fn speed(&self) -> Scalar {
+        self.linear_velocity.length()
+    }
+
+    /// Returns the relative angular speed of the entities in radians per second.
+    pub fn angular_speed(&self) -> Scalar {
+        #[cfg(feature = "2d")]
+        {
+            self.angular_velocity.0.abs()
+        }
+        #[cfg(feature = "3d")]
+        {
+            self.angular_velocity.length()
+        }
+    }
+}
+
+impl Default for RelativeVelocitySensor {
+    /// Creates a sensor with placeholder entities and anchors at the entity origins.
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER, Entity::PLACEHOLDER)
+    }
+}
+
+impl MapEntities for RelativeVelocitySensor {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+        self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+    }
+}
+
+type SensorBodyQuery = (
+    &'static Position,
+    &'static Rotation,
+    Option<&'static LinearVelocity>,
+    Option<&'static AngularVelocity>,
+    Option<&'static CenterOfMass>,
+);
+
+/// Updates the relative velocities tracked by [`RelativeVelocitySensor`]s.
+fn update_relative_velocity_sensors(
+    mut sensors: Query<&mut RelativeVelocitySensor>,
+    bodies: Query<SensorBodyQuery>,
+) {
+    for mut sensor in &mut sensors {
+        let (Ok(body1), Ok(body2)) = (bodies.get(sensor.entity1), bodies.get(sensor.entity2))
+        else {
+            continue;
+        };
+
+        let (point1, velocity1, angular_velocity1) = anchor_velocity(body1, sensor.local_anchor1);
+        let (point2, velocity2, angular_velocity2) = anchor_velocity(body2, sensor.local_anchor2);
+
+        let linear_velocity = velocity2 - velocity1;
+        let offset = point2 - point1;
+        let closing_speed = if offset.length_squared() > Scalar::EPSILON {
+            -linear_velocity.dot(offset.normalize())
+        } else {
+            0.0
+        };
+
+        sensor.linear_velocity = linear_velocity;
+        sensor.angular_velocity = AngularVelocity(angular_velocity2.0 - angular_velocity1.0);
+        sensor.closing_speed = closing_speed;
+    }
+}
+
+/// Returns the world-space position and velocity of the given local point on a body, and the body's angular velocity.
+fn anchor_velocity(
+    (position, rotation, linear_velocity, angular_velocity, center_of_mass): (
+        &Position,
+        &Rotation,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+        Option<&CenterOfMass>,
+    ),
+    local_anchor: Vector,
+) -> (Vector, Vector, AngularVelocity) {
+    let point = position.0 + rotation.rotate(local_anchor);
+    let center_of_mass = position.0 + rotation.rotate(center_of_mass.map_or(Vector::ZERO, |c| c.0));
+    let angular_velocity = angular_velocity.copied().unwrap_or_default();
+    let velocity = linear_velocity.copied().unwrap_or_default().at_point(
+        angular_velocity,
+        point,
+        center_of_mass,
+    );
+    (point, velocity, angular_velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn relative_velocity_sensor_tracks_approach() {
+        let mut app = create_app();
+        app.add_plugins(RelativeVelocityPlugin)
+            .insert_resource(Gravity::ZERO);
+
+        let station = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Kinematic,
+                AngularVelocity::ZERO,
+            ))
+            .id();
+        #[cfg(feature = "2d")]
+        let angular_velocity = AngularVelocity(1.0);
+        #[cfg(feature = "3d")]
+        let angular_velocity = AngularVelocity(Vector::Z);
+        let ship = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Kinematic,
+                Position(Vector::X * 10.0),
+                LinearVelocity(Vector::NEG_X * 2.0 + Vector::Y),
+                angular_velocity,
+            ))
+            .id();
+        let sensor = app
+            .world
+            .spawn(RelativeVelocitySensor::new(station, ship))
+            .id();
+
+        app.step_physics(1);
+
+        let sensor = app.world.get::<RelativeVelocitySensor>(sensor).unwrap();
+        assert_relative_eq!(
+            sensor.linear_velocity,
+            Vector::NEG_X * 2.0 + Vector::Y,
+            epsilon = 1e-5
+        );
+        assert_relative_eq!(sensor.speed(), (5.0 as Scalar).sqrt(), epsilon = 1e-5);
+        assert_relative_eq!(sensor.angular_speed(), 1.0, epsilon = 1e-5);
+
+        // The ship is approaching the station along the X axis
+        assert!(sensor.closing_speed > 1.9 && sensor.closing_speed < 2.1);
+    }
+}
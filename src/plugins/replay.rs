@@ -0,0 +1,610 @@
+//! Records the inputs of the physics simulation and replays them deterministically.
+//!
+//! See [`ReplayPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{query::WorldQuery, schedule::ScheduleLabel},
+    prelude::*,
+    utils::HashMap,
+};
+
+/// A plugin for recording the inputs of the physics simulation and replaying them later.
+///
+/// A [`PhysicsRecording`] stores the inputs of each frame, like spawned and despawned rigid bodies,
+/// external forces and impulses, and changes to [`Gravity`] and the [`SubstepCount`], along with the
+/// delta time of each physics step that was run during the frame. Replaying the recording runs the
+/// exact same steps with the exact same inputs, so the simulation produces the same results as long as
+/// it is deterministic. This is useful for attaching reproducible recordings to bug reports and for
+/// regression tests of the solver.
+///
+/// Use the [`PhysicsRecorder`] resource to record and the [`PhysicsReplayer`] resource to replay.
+/// While a recording is being replayed, the [physics loop](PhysicsLoop) is paused and the steps are
+/// run by the replayer instead.
+///
+/// The following components are recorded for spawned rigid bodies:
+///
+/// - [`RigidBody`], [`Position`], [`Rotation`], [`LinearVelocity`] and [`AngularVelocity`]
+/// - [`Collider`], [`Friction`] and [`Restitution`]
+/// - [`Mass`], [`Inertia`] and [`CenterOfMass`]
+/// - [`LinearDamping`], [`AngularDamping`] and [`LockedAxes`]
+/// - `Transform`
+///
+/// Other components, like joints and custom components, are not recorded. The recording only contains
+/// bodies that were spawned while recording, so replaying should start from the same world state
+/// that the recording started from, usually an empty world.
+///
+/// The inputs are recorded and replayed before [`PhysicsSet::Prepare`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct SavedRecording(PhysicsRecording);
+///
+/// fn start_recording(mut recorder: ResMut<PhysicsRecorder>) {
+///     recorder.start();
+/// }
+///
+/// fn save_recording(mut commands: Commands, mut recorder: ResMut<PhysicsRecorder>) {
+///     if let Some(recording) = recorder.stop() {
+///         commands.insert_resource(SavedRecording(recording));
+///     }
+/// }
+///
+/// fn replay_recording(recording: Res<SavedRecording>, mut replayer: ResMut<PhysicsReplayer>) {
+///     replayer.play(recording.0.clone());
+/// }
+/// ```
+pub struct ReplayPlugin {
+    schedule: Box<dyn ScheduleLabel>,
+}
+
+impl ReplayPlugin {
+    /// Creates a [`ReplayPlugin`] with the schedule that is used for running the [`PhysicsSchedule`].
+    ///
+    /// The default schedule is `PostUpdate`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: Box::new(schedule),
+        }
+    }
+}
+
+impl Default for ReplayPlugin {
+    fn default() -> Self {
+        Self::new(PostUpdate)
+    }
+}
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsRecorder>()
+            .init_resource::<PhysicsReplayer>();
+
+        app.add_systems(
+            self.schedule.dyn_clone(),
+            (
+                (replay_inputs, apply_deferred, record_inputs)
+                    .chain()
+                    .before(PhysicsSet::Prepare),
+                run_replayed_steps.in_set(PhysicsSet::StepSimulation),
+            ),
+        );
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(record_step.before(PhysicsStepSet::BroadPhase));
+    }
+}
+
+/// An identifier of a rigid body in a [`PhysicsRecording`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecordedBodyId(pub u32);
+
+/// The components of a rigid body when it was spawned during recording. See [`ReplayPlugin`].
+#[derive(Clone, Debug)]
+pub struct RecordedBody {
+    /// The [`RigidBody`] type.
+    pub rb: RigidBody,
+    /// The `Transform` of the body.
+    pub transform: Option<Transform>,
+    /// The [`Position`] of the body.
+    pub position: Option<Position>,
+    /// The [`Rotation`] of the body.
+    pub rotation: Option<Rotation>,
+    /// The [`LinearVelocity`] of the body.
+    pub linear_velocity: Option<LinearVelocity>,
+    /// The [`AngularVelocity`] of the body.
+    pub angular_velocity: Option<AngularVelocity>,
+    /// The [`Collider`] of the body.
+    pub collider: Option<Collider>,
+    /// The [`Friction`] of the body.
+    pub friction: Option<Friction>,
+    /// The [`Restitution`] of the body.
+    pub restitution: Option<Restitution>,
+    /// The [`Mass`] of the body.
+    pub mass: Option<Mass>,
+    /// The [`Inertia`] of the body.
+    pub inertia: Option<Inertia>,
+    /// The [`CenterOfMass`] of the body.
+    pub center_of_mass: Option<CenterOfMass>,
+    /// The [`LinearDamping`] of the body.
+    pub linear_damping: Option<LinearDamping>,
+    /// The [`AngularDamping`] of the body.
+    pub angular_damping: Option<AngularDamping>,
+    /// The [`LockedAxes`] of the body.
+    pub locked_axes: Option<LockedAxes>,
+}
+
+/// An input of the physics simulation stored in a [`PhysicsRecording`].
+#[derive(Clone, Debug)]
+pub enum PhysicsInput {
+    /// A rigid body was spawned.
+    Spawn(RecordedBodyId, Box<RecordedBody>),
+    /// A rigid body was despawned or its [`RigidBody`] component was removed.
+    Despawn(RecordedBodyId),
+    /// The [`ExternalForce`] of a rigid body was changed.
+    Force(RecordedBodyId, ExternalForce),
+    /// The [`ExternalTorque`] of a rigid body was changed.
+    Torque(RecordedBodyId, ExternalTorque),
+    /// The [`ExternalImpulse`] of a rigid body was changed.
+    Impulse(RecordedBodyId, ExternalImpulse),
+    /// The [`ExternalAngularImpulse`] of a rigid body was changed.
+    AngularImpulse(RecordedBodyId, ExternalAngularImpulse),
+    /// The [`Gravity`] was changed.
+    Gravity(Vector),
+    /// The [`SubstepCount`] was changed.
+    SubstepCount(u32),
+}
+
+/// The inputs of a single frame in a [`PhysicsRecording`].
+#[derive(Clone, Debug, Default)]
+pub struct RecordedFrame {
+    /// The inputs that were applied before the physics steps of the frame.
+    pub inputs: Vec<PhysicsInput>,
+    /// The delta time of each physics step that was run during the frame.
+    pub steps: Vec<Scalar>,
+}
+
+/// A recording of the inputs of the physics simulation, created by the [`PhysicsRecorder`]
+/// and replayed by the [`PhysicsReplayer`].
+#[derive(Clone, Debug, Default)]
+pub struct PhysicsRecording {
+    /// The recorded frames.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl PhysicsRecording {
+    /// Returns the total number of physics steps in the recording.
+    pub fn step_count(&self) -> usize {
+        self.frames.iter().map(|frame| frame.steps.len()).sum()
+    }
+}
+
+/// A resource for recording the inputs of the physics simulation. Requires the [`ReplayPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct PhysicsRecorder {
+    recording: Option<PhysicsRecording>,
+    ids: HashMap<Entity, RecordedBodyId>,
+    next_id: u32,
+}
+
+impl PhysicsRecorder {
+    /// Starts a new recording, discarding the current one.
+    pub fn start(&mut self) {
+        self.recording = Some(PhysicsRecording::default());
+        self.ids.clear();
+        self.next_id = 0;
+    }
+
+    /// Stops recording and returns the recording, or `None` if nothing was being recorded.
+    pub fn stop(&mut self) -> Option<PhysicsRecording> {
+        self.ids.clear();
+        self.recording.take()
+    }
+
+    /// Returns true if the inputs are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Returns the recording that is in progress.
+    pub fn recording(&self) -> Option<&PhysicsRecording> {
+        self.recording.as_ref()
+    }
+}
+
+/// A resource for replaying a [`PhysicsRecording`]. Requires the [`ReplayPlugin`].
+#[derive(Resource, Debug, Default)]
+pub struct PhysicsReplayer {
+    recording: Option<PhysicsRecording>,
+    frame: usize,
+    entities: HashMap<RecordedBodyId, Entity>,
+    was_paused: bool,
+}
+
+impl PhysicsReplayer {
+    /// Starts replaying the given recording from the beginning, one recorded frame per frame.
+    pub fn play(&mut self, recording: PhysicsRecording) {
+        self.recording = Some(recording);
+        self.frame = 0;
+        self.entities.clear();
+    }
+
+    /// Stops replaying. The bodies spawned by the replay are not despawned.
+    pub fn stop(&mut self) {
+        self.recording = None;
+    }
+
+    /// Returns true if a recording is being replayed.
+    pub fn is_playing(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Returns the index of the next frame to be replayed.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns the entity that was spawned for the given recorded body during the replay.
+    pub fn entity(&self, id: RecordedBodyId) -> Option<Entity> {
+        self.entities.get(&id).copied()
+    }
+
+    /// Returns the delta times of the physics steps of the current frame and advances to the next frame.
+    fn next_frame_steps(&mut self) -> Option<Vec<Scalar>> {
+        let steps = self
+            .recording
+            .as_ref()?
+            .frames
+            .get(self.frame)?
+            .steps
+            .clone();
+        self.frame += 1;
+        Some(steps)
+    }
+}
+
+/// A [`WorldQuery`] for the components of rigid bodies that are recorded when they are spawned.
+#[derive(WorldQuery)]
+struct RecordedBodyQuery<'w> {
+    entity: Entity,
+    rb: &'w RigidBody,
+    transform: Option<&'w Transform>,
+    position: Option<&'w Position>,
+    rotation: Option<&'w Rotation>,
+    linear_velocity: Option<&'w LinearVelocity>,
+    angular_velocity: Option<&'w AngularVelocity>,
+    collider: Option<&'w Collider>,
+    friction: Option<&'w Friction>,
+    restitution: Option<&'w Restitution>,
+    mass: Option<&'w Mass>,
+    inertia: Option<&'w Inertia>,
+    center_of_mass: Option<&'w CenterOfMass>,
+    linear_damping: Option<&'w LinearDamping>,
+    angular_damping: Option<&'w AngularDamping>,
+    locked_axes: Option<&'w LockedAxes>,
+}
+
+/// Records the inputs of the frame into a new [`RecordedFrame`].
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn record_inputs(
+    mut recorder: ResMut<PhysicsRecorder>,
+    spawned: Query<RecordedBodyQuery, Added<RigidBody>>,
+    mut removed: RemovedComponents<RigidBody>,
+    forces: Query<(Entity, &ExternalForce), Changed<ExternalForce>>,
+    torques: Query<(Entity, &ExternalTorque), Changed<ExternalTorque>>,
+    impulses: Query<(Entity, &ExternalImpulse), Changed<ExternalImpulse>>,
+    angular_impulses: Query<(Entity, &ExternalAngularImpulse), Changed<ExternalAngularImpulse>>,
+    gravity: Res<Gravity>,
+    substep_count: Res<SubstepCount>,
+) {
+    let PhysicsRecorder {
+        recording,
+        ids,
+        next_id,
+    } = &mut *recorder;
+    let Some(recording) = recording else {
+        return;
+    };
+
+    let mut inputs = vec![];
+
+    // Record the initial configuration on the first frame
+    if recording.frames.is_empty() || gravity.is_changed() {
+        inputs.push(PhysicsInput::Gravity(gravity.0));
+    }
+    if recording.frames.is_empty() || substep_count.is_changed() {
+        inputs.push(PhysicsInput::SubstepCount(substep_count.0));
+    }
+
+    for entity in removed.iter() {
+        if let Some(id) = ids.remove(&entity) {
+            inputs.push(PhysicsInput::Despawn(id));
+        }
+    }
+
+    for body in &spawned {
+        let id = RecordedBodyId(*next_id);
+        *next_id += 1;
+        ids.insert(body.entity, id);
+
+        inputs.push(PhysicsInput::Spawn(
+            id,
+            Box::new(RecordedBody {
+                rb: *body.rb,
+                transform: body.transform.copied(),
+                position: body.position.copied(),
+                rotation: body.rotation.copied(),
+                linear_velocity: body.linear_velocity.copied(),
+                angular_velocity: body.angular_velocity.copied(),
+                collider: body.collider.cloned(),
+                friction: body.friction.copied(),
+                restitution: body.restitution.copied(),
+                mass: body.mass.copied(),
+                inertia: body.inertia.copied(),
+                center_of_mass: body.center_of_mass.copied(),
+                linear_damping: body.linear_damping.copied(),
+                angular_damping: body.angular_damping.copied(),
+                locked_axes: body.locked_axes.copied(),
+            }),
+        ));
+    }
+
+    for (entity, force) in &forces {
+        if let Some(id) = ids.get(&entity) {
+            inputs.push(PhysicsInput::Force(*id, *force));
+        }
+    }
+    for (entity, torque) in &torques {
+        if let Some(id) = ids.get(&entity) {
+            inputs.push(PhysicsInput::Torque(*id, *torque));
+        }
+    }
+    for (entity, impulse) in &impulses {
+        if let Some(id) = ids.get(&entity) {
+            inputs.push(PhysicsInput::Impulse(*id, *impulse));
+        }
+    }
+    for (entity, impulse) in &angular_impulses {
+        if let Some(id) = ids.get(&entity) {
+            inputs.push(PhysicsInput::AngularImpulse(*id, *impulse));
+        }
+    }
+
+    recording.frames.push(RecordedFrame {
+        inputs,
+        steps: vec![],
+    });
+}
+
+/// Records the delta time of a physics step into the current [`RecordedFrame`].
+fn record_step(mut recorder: ResMut<PhysicsRecorder>, dt: Res<DeltaTime>) {
+    if let Some(frame) = recorder
+        .recording
+        .as_mut()
+        .and_then(|recording| recording.frames.last_mut())
+    {
+        frame.steps.push(dt.0);
+    }
+}
+
+/// Applies the inputs of the current [`RecordedFrame`] that is being replayed.
+fn replay_inputs(
+    mut commands: Commands,
+    mut replayer: ResMut<PhysicsReplayer>,
+    mut physics_loop: ResMut<PhysicsLoop>,
+    mut gravity: ResMut<Gravity>,
+    mut substep_count: ResMut<SubstepCount>,
+) {
+    let PhysicsReplayer {
+        recording,
+        frame,
+        entities,
+        was_paused,
+    } = &mut *replayer;
+    let Some(recording) = recording else {
+        return;
+    };
+
+    // The physics loop is paused during the replay, and the steps are run by the replayer instead
+    if *frame == 0 {
+        *was_paused = physics_loop.paused;
+    }
+    physics_loop.paused = true;
+
+    let Some(recorded_frame) = recording.frames.get(*frame) else {
+        physics_loop.paused = *was_paused;
+        *recording = None;
+        return;
+    };
+
+    for input in recorded_frame.inputs.iter() {
+        match input {
+            PhysicsInput::Spawn(id, body) => {
+                let mut entity_commands = commands.spawn(body.rb);
+                if let Some(transform) = body.transform {
+                    entity_commands.insert(TransformBundle::from_transform(transform));
+                }
+                if let Some(position) = body.position {
+                    entity_commands.insert(position);
+                }
+                if let Some(rotation) = body.rotation {
+                    entity_commands.insert(rotation);
+                }
+                if let Some(linear_velocity) = body.linear_velocity {
+                    entity_commands.insert(linear_velocity);
+                }
+                if let Some(angular_velocity) = body.angular_velocity {
+                    entity_commands.insert(angular_velocity);
+                }
+                if let Some(collider) = body.collider.clone() {
+                    entity_commands.insert(collider);
+                }
+                if let Some(friction) = body.friction {
+                    entity_commands.insert(friction);
+                }
+                if let Some(restitution) = body.restitution {
+                    entity_commands.insert(restitution);
+                }
+                if let Some(mass) = body.mass {
+                    entity_commands.insert(mass);
+                }
+                if let Some(inertia) = body.inertia {
+                    entity_commands.insert(inertia);
+                }
+                if let Some(center_of_mass) = body.center_of_mass {
+                    entity_commands.insert(center_of_mass);
+                }
+                if let Some(linear_damping) = body.linear_damping {
+                    entity_commands.insert(linear_damping);
+                }
+                if let Some(angular_damping) = body.angular_damping {
+                    entity_commands.insert(angular_damping);
+                }
+                if let Some(locked_axes) = body.locked_axes {
+                    entity_commands.insert(locked_axes);
+                }
+                entities.insert(*id, entity_commands.id());
+            }
+            PhysicsInput::Despawn(id) => {
+                if let Some(entity) = entities.remove(id) {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            PhysicsInput::Force(id, force) => {
+                if let Some(entity) = entities.get(id) {
+                    commands.entity(*entity).insert(*force);
+                }
+            }
+            PhysicsInput::Torque(id, torque) => {
+                if let Some(entity) = entities.get(id) {
+                    commands.entity(*entity).insert(*torque);
+                }
+            }
+            PhysicsInput::Impulse(id, impulse) => {
+                if let Some(entity) = entities.get(id) {
+                    commands.entity(*entity).insert(*impulse);
+                }
+            }
+            PhysicsInput::AngularImpulse(id, impulse) => {
+                if let Some(entity) = entities.get(id) {
+                    commands.entity(*entity).insert(*impulse);
+                }
+            }
+            PhysicsInput::Gravity(value) => gravity.0 = *value,
+            PhysicsInput::SubstepCount(value) => substep_count.0 = *value,
+        }
+    }
+}
+
+/// Runs the physics steps of the current [`RecordedFrame`] that is being replayed.
+fn run_replayed_steps(world: &mut World) {
+    let Some(steps) = world.resource_mut::<PhysicsReplayer>().next_frame_steps() else {
+        return;
+    };
+
+    for dt in steps {
+        world.resource_mut::<DeltaTime>().0 = dt;
+        world.run_schedule(PhysicsSchedule);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        tests::{create_app, floor_collider, tick_60_fps},
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    fn replayed_recording_reproduces_simulation() {
+        fn create_replay_app() -> App {
+            let mut app = create_app();
+            app.add_plugins((ReplayPlugin::default(), StateHashPlugin));
+            app
+        }
+
+        let mut app = create_replay_app();
+        app.world.resource_mut::<PhysicsRecorder>().start();
+
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Static,
+            Position(Vector::NEG_Y),
+            floor_collider(20.0),
+        ));
+        let ball1 = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 2.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+        let ball2 = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 1.5 + Vector::Y * 4.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        app.world
+            .get_mut::<ExternalImpulse>(ball1)
+            .unwrap()
+            .apply_impulse(Vector::X * 2.0);
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        app.world.resource_mut::<Gravity>().0 = Vector::NEG_Y * 20.0;
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        app.world.despawn(ball2);
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        let recording = app.world.resource_mut::<PhysicsRecorder>().stop().unwrap();
+        let expected_hash = *app.world.resource::<PhysicsStateHash>();
+        assert_eq!(recording.frames.len(), 40);
+        assert_eq!(recording.step_count() as u64, expected_hash.step);
+
+        // Replay the recording in a new app
+        let mut app = create_replay_app();
+        app.world.resource_mut::<PhysicsReplayer>().play(recording);
+
+        for _ in 0..40 {
+            tick_60_fps(&mut app);
+        }
+
+        assert_eq!(*app.world.resource::<PhysicsStateHash>(), expected_hash);
+
+        // The replay finishes and unpauses the physics loop on the next frame
+        tick_60_fps(&mut app);
+        assert!(!app.world.resource::<PhysicsReplayer>().is_playing());
+        assert!(!app.world.resource::<PhysicsLoop>().paused);
+    }
+}
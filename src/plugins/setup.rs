@@ -3,8 +3,12 @@
 //! See [`PhysicsSetupPlugin`].
 
 use bevy::{
-    ecs::schedule::{ExecutorKind, ScheduleBuildSettings},
+    ecs::{
+        query::Has,
+        schedule::{ExecutorKind, ScheduleBuildSettings},
+    },
     transform::TransformSystem,
+    utils::{Duration, Instant},
 };
 
 use crate::prelude::*;
@@ -32,6 +36,18 @@ use super::sync::PreviousGlobalTransform;
 /// the constraint solver.
 /// - [`PostProcessCollisions`]: Responsible for running collision post-processing systems.
 /// Empty by default.
+///
+/// The stages of [`PhysicsSet::Prepare`] and [`PhysicsSet::Sync`] are further divided into the
+/// [`PrepareSet`] and [`SyncSet`] system sets, which are configured by the [`PreparePlugin`] and [`SyncPlugin`].
+///
+/// The order of all of these sets is stable, so you can use them to order your own systems precisely,
+/// for example after the narrow phase and before the solver:
+///
+/// ```ignore
+/// app.get_schedule_mut(SubstepSchedule)
+///     .expect("add SubstepSchedule first")
+///     .add_systems(my_system.after(SubstepSet::NarrowPhase).before(SubstepSet::SolveConstraints));
+/// ```
 pub struct PhysicsSetupPlugin {
     schedule: Box<dyn ScheduleLabel>,
 }
@@ -55,6 +71,16 @@ impl Default for PhysicsSetupPlugin {
 
 impl Plugin for PhysicsSetupPlugin {
     fn build(&self, app: &mut App) {
+        // Check if the schedule is configured to run in FixedUpdate.
+        let schedule = &self.schedule;
+        let fixed_update = schedule.inner_type_id() == FixedUpdate::inner_type_id(&FixedUpdate);
+
+        // In FixedUpdate, advance the simulation by Bevy's fixed timestep by default
+        // instead of using a separate accumulator.
+        if fixed_update && !app.world.contains_resource::<PhysicsTimestep>() {
+            app.insert_resource(PhysicsTimestep::FixedUpdate);
+        }
+
         // Init resources and register component types
         app.init_resource::<PhysicsTimestep>()
             .init_resource::<PhysicsTimescale>()
@@ -65,22 +91,33 @@ impl Plugin for PhysicsSetupPlugin {
             .init_resource::<SleepingThreshold>()
             .init_resource::<DeactivationTime>()
             .init_resource::<Gravity>()
+            .init_resource::<LayerGravity>()
+            .init_resource::<PhysicsTimings>()
+            .init_resource::<PhaseTimers>()
+            .init_resource::<PhysicsStats>()
+            .add_event::<PhysicsTimeDropped>()
             .register_type::<PhysicsTimestep>()
             .register_type::<PhysicsTimescale>()
             .register_type::<DeltaTime>()
             .register_type::<SubDeltaTime>()
             .register_type::<SubstepCount>()
+            .register_type::<AdaptiveSubsteps>()
             .register_type::<BroadCollisionPairs>()
             .register_type::<SleepingThreshold>()
             .register_type::<DeactivationTime>()
             .register_type::<PhysicsLoop>()
             .register_type::<Gravity>()
+            .register_type::<LayerGravity>()
+            .register_type::<PhysicsTimings>()
+            .register_type::<PhysicsStats>()
             .register_type::<RigidBody>()
             .register_type::<Sleeping>()
             .register_type::<SleepingDisabled>()
             .register_type::<TimeSleeping>()
             .register_type::<Position>()
             .register_type::<Rotation>()
+            .register_type::<Teleport>()
+            .register_type::<FractureFragment>()
             .register_type::<PreviousPosition>()
             .register_type::<PreviousRotation>()
             .register_type::<PreviousGlobalTransform>()
@@ -88,9 +125,12 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<LinearVelocity>()
             .register_type::<AngularVelocity>()
             .register_type::<PreSolveLinearVelocity>()
+            .register_type::<IntegratorVelocityCorrection>()
             .register_type::<PreSolveAngularVelocity>()
             .register_type::<Restitution>()
             .register_type::<Friction>()
+            .register_type::<ContactCompliance>()
+            .register_type::<Adhesion>()
             .register_type::<LinearDamping>()
             .register_type::<AngularDamping>()
             .register_type::<ExternalForce>()
@@ -98,6 +138,7 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<ExternalImpulse>()
             .register_type::<ExternalAngularImpulse>()
             .register_type::<GravityScale>()
+            .register_type::<SpringToTarget>()
             .register_type::<Mass>()
             .register_type::<InverseMass>()
             .register_type::<Inertia>()
@@ -109,14 +150,35 @@ impl Plugin for PhysicsSetupPlugin {
             .register_type::<ColliderParent>()
             .register_type::<Dominance>()
             .register_type::<CollisionLayers>()
+            .register_type::<CollisionEventLayers>()
             .register_type::<CollidingEntities>()
+            .register_type::<IgnoreCollisions>()
             .register_type::<CoefficientCombine>()
             .register_type::<Sensor>()
+            .register_type::<QueryOnly>()
             .register_type::<ColliderTransform>()
-            .register_type::<PreviousColliderTransform>();
+            .register_type::<PreviousColliderTransform>()
+            .register_type::<FixedJoint>()
+            .register_type::<RevoluteJoint>()
+            .register_type::<SphericalJoint>()
+            .register_type::<PrismaticJoint>()
+            .register_type::<DistanceJoint>()
+            .register_type::<SpringJoint>()
+            .register_type::<JointWorldAnchor>()
+            .register_type::<JointDisabled>()
+            .register_type::<Articulated>()
+            .register_type::<JointLimitState>()
+            .register_type::<DistanceLimit>()
+            .register_type::<AngleLimit>()
+            .register_type::<Option<DistanceLimit>>()
+            .register_type::<Option<AngleLimit>>()
+            .register_type::<JointMotor>()
+            .register_type::<MotorMode>()
+            .register_type::<Option<JointMotor>>()
+            .register_type::<JointSpring>()
+            .register_type::<Option<JointSpring>>();
 
         // Configure higher level system sets for the given schedule
-        let schedule = &self.schedule;
         app.configure_sets(
             schedule.dyn_clone(),
             (
@@ -128,9 +190,7 @@ impl Plugin for PhysicsSetupPlugin {
                 .before(TransformSystem::TransformPropagate),
         );
 
-        // Check and store if schedule is configured to run in FixedUpdate.
-        let fixed_update = schedule.inner_type_id() == FixedUpdate::inner_type_id(&FixedUpdate);
-
+        // Store if the schedule is configured to run in FixedUpdate.
         app.insert_resource(PhysicsLoop {
             fixed_update,
             ..Default::default()
@@ -149,6 +209,7 @@ impl Plugin for PhysicsSetupPlugin {
         physics_schedule.configure_sets(
             (
                 PhysicsStepSet::BroadPhase,
+                PhysicsStepSet::FilterPairs,
                 PhysicsStepSet::Substeps,
                 PhysicsStepSet::ReportContacts,
                 PhysicsStepSet::Sleeping,
@@ -161,7 +222,29 @@ impl Plugin for PhysicsSetupPlugin {
 
         app.add_systems(
             schedule.dyn_clone(),
-            run_physics_schedule.in_set(PhysicsSet::StepSimulation),
+            (
+                run_physics_schedule.in_set(PhysicsSet::StepSimulation),
+                start_sync_timer
+                    .after(PhysicsSet::StepSimulation)
+                    .before(PhysicsSet::Sync),
+                stop_sync_timer
+                    .after(PhysicsSet::Sync)
+                    .before(TransformSystem::TransformPropagate),
+            ),
+        );
+
+        app.add_systems(
+            PhysicsSchedule,
+            (
+                start_broad_phase_timer.before(PhysicsStepSet::BroadPhase),
+                update_adaptive_substep_count
+                    .run_if(resource_exists::<AdaptiveSubsteps>())
+                    .before(PhysicsStepSet::BroadPhase),
+                stop_broad_phase_timer
+                    .after(PhysicsStepSet::BroadPhase)
+                    .before(PhysicsStepSet::FilterPairs),
+                update_physics_stats.after(PhysicsStepSet::SpatialQuery),
+            ),
         );
 
         // Create substep schedule, the schedule that runs the inner substepping loop
@@ -190,6 +273,18 @@ impl Plugin for PhysicsSetupPlugin {
 
         app.add_schedule(SubstepSchedule, substep_schedule);
 
+        app.add_systems(
+            SubstepSchedule,
+            (
+                start_narrow_phase_timer
+                    .after(SubstepSet::Integrate)
+                    .before(SubstepSet::NarrowPhase),
+                stop_narrow_phase_timer
+                    .after(SubstepSet::NarrowPhase)
+                    .before(SubstepSet::PostProcessCollisions),
+            ),
+        );
+
         app.add_systems(
             PhysicsSchedule,
             run_substep_schedule.in_set(PhysicsStepSet::Substeps),
@@ -216,7 +311,7 @@ impl Plugin for PhysicsSetupPlugin {
 }
 
 /// Data related to the physics simulation loop.
-#[derive(Reflect, Resource, Debug, Default)]
+#[derive(Reflect, Resource, Debug)]
 #[reflect(Resource)]
 pub struct PhysicsLoop {
     /// Time accumulated into the physics loop. This is consumed by the [`PhysicsSchedule`].
@@ -227,6 +322,27 @@ pub struct PhysicsLoop {
     pub(crate) fixed_update: bool,
     /// Determines if the simulation is paused.
     pub paused: bool,
+    /// The maximum number of times the [`PhysicsSchedule`] can be run in a single frame when time has been
+    /// accumulated. If the accumulated time would require more steps, the rest of it is dropped and
+    /// a [`PhysicsTimeDropped`] event is sent. If `None`, there is no limit.
+    ///
+    /// This prevents a long hitch, like loading assets or hitting a breakpoint, from causing a cascade
+    /// of catch-up steps that freezes the application, which is also known as the "spiral of death".
+    ///
+    /// Defaults to `Some(10)`.
+    pub max_steps_per_frame: Option<u32>,
+}
+
+impl Default for PhysicsLoop {
+    fn default() -> Self {
+        Self {
+            accumulator: 0.0,
+            queued_steps: 0,
+            fixed_update: false,
+            paused: false,
+            max_steps_per_frame: Some(10),
+        }
+    }
 }
 
 impl PhysicsLoop {
@@ -244,6 +360,18 @@ impl PhysicsLoop {
     }
 }
 
+/// An event that is sent when the physics loop drops accumulated time because running all of the required steps
+/// would have exceeded [`PhysicsLoop::max_steps_per_frame`].
+///
+/// This typically happens after a long hitch, like loading assets or hitting a breakpoint.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct PhysicsTimeDropped {
+    /// The amount of simulation time that was dropped, in seconds.
+    pub time: Scalar,
+    /// The number of steps that were dropped.
+    pub steps: u32,
+}
+
 /// Pause the simulation.
 pub fn pause(mut physics_loop: ResMut<PhysicsLoop>) {
     physics_loop.pause();
@@ -281,6 +409,10 @@ fn run_physics_schedule(world: &mut World) {
     let (raw_dt, accumulate) = match time_step {
         PhysicsTimestep::Fixed(fixed_delta_seconds) => (fixed_delta_seconds, true),
         PhysicsTimestep::FixedOnce(fixed_delta_seconds) => (fixed_delta_seconds, false),
+        #[cfg(feature = "f32")]
+        PhysicsTimestep::FixedUpdate => (world.resource::<FixedTime>().period.as_secs_f32(), false),
+        #[cfg(feature = "f64")]
+        PhysicsTimestep::FixedUpdate => (world.resource::<FixedTime>().period.as_secs_f64(), false),
         PhysicsTimestep::Variable { max_dt } => (delta_seconds.min(max_dt), true),
     };
 
@@ -300,8 +432,7 @@ fn run_physics_schedule(world: &mut World) {
             if physics_loop.queued_steps > 0 {
                 physics_loop.queued_steps -= 1;
             }
-            debug!("running PhysicsSchedule");
-            world.run_schedule(PhysicsSchedule);
+            run_physics_step(world);
         }
         true => {
             // Add time to the accumulator
@@ -314,10 +445,31 @@ fn run_physics_schedule(world: &mut World) {
 
             // Step the simulation until the accumulator has been consumed.
             // Note that a small remainder may be passed on to the next run of the physics schedule.
+            let mut steps = 0;
             while physics_loop.accumulator >= dt && dt > 0.0 {
-                debug!("running PhysicsSchedule");
-                world.run_schedule(PhysicsSchedule);
+                if physics_loop
+                    .max_steps_per_frame
+                    .is_some_and(|max_steps| steps >= max_steps)
+                {
+                    // Drop the remaining whole steps to avoid a spiral of death
+                    let remainder = physics_loop.accumulator % dt;
+                    let dropped_time = physics_loop.accumulator - remainder;
+                    let dropped_steps = (dropped_time / dt).round() as u32;
+                    physics_loop.accumulator = remainder;
+
+                    warn!(
+                        "physics fell behind by {dropped_steps} steps ({dropped_time} seconds), dropping the accumulated time"
+                    );
+                    world.send_event(PhysicsTimeDropped {
+                        time: dropped_time,
+                        steps: dropped_steps,
+                    });
+                    break;
+                }
+
+                run_physics_step(world);
                 physics_loop.accumulator -= dt;
+                steps += 1;
             }
         }
     }
@@ -325,6 +477,44 @@ fn run_physics_schedule(world: &mut World) {
     world.insert_resource(physics_loop);
 }
 
+/// Runs the [`PhysicsSchedule`] once and measures the duration of the step.
+fn run_physics_step(world: &mut World) {
+    // The narrow phase is run in each substep, so its timings are accumulated during the step
+    world.resource_mut::<PhysicsTimings>().narrow_phase = Duration::ZERO;
+
+    let start = Instant::now();
+    debug!("running PhysicsSchedule");
+    world.run_schedule(PhysicsSchedule);
+    world.resource_mut::<PhysicsTimings>().step = start.elapsed();
+}
+
+/// Adjusts the [`SubstepCount`] according to the [`AdaptiveSubsteps`] configuration.
+fn update_adaptive_substep_count(
+    bodies: Query<(&RigidBody, &LinearVelocity), Without<Sleeping>>,
+    collisions: Option<Res<Collisions>>,
+    adaptive_substeps: Res<AdaptiveSubsteps>,
+    dt: Res<DeltaTime>,
+    mut substep_count: ResMut<SubstepCount>,
+) {
+    let max_speed = bodies
+        .iter()
+        .filter(|(rb, _)| !rb.is_static())
+        .map(|(_, lin_vel)| lin_vel.length())
+        .fold(0.0, Scalar::max);
+
+    // Use the penetration depths of the previous step
+    let max_penetration = collisions.map_or(0.0, |collisions| {
+        collisions
+            .iter()
+            .flat_map(|contacts| contacts.manifolds.iter())
+            .flat_map(|manifold| manifold.contacts.iter())
+            .map(|contact| contact.penetration)
+            .fold(0.0, Scalar::max)
+    });
+
+    substep_count.0 = adaptive_substeps.substep_count(dt.0, max_speed, max_penetration);
+}
+
 /// Runs the [`SubstepSchedule`].
 fn run_substep_schedule(world: &mut World) {
     let SubstepCount(substeps) = *world.resource::<SubstepCount>();
@@ -334,10 +524,12 @@ fn run_substep_schedule(world: &mut World) {
     let mut sub_delta_time = world.resource_mut::<SubDeltaTime>();
     sub_delta_time.0 = dt / substeps as Scalar;
 
+    let start = Instant::now();
     for i in 0..substeps {
         debug!("running SubstepSchedule: {i}");
         world.run_schedule(SubstepSchedule);
     }
+    world.resource_mut::<PhysicsTimings>().substeps = start.elapsed();
 }
 
 /// Runs the [`PostProcessCollisions`] schedule.
@@ -345,3 +537,270 @@ fn run_post_process_collisions_schedule(world: &mut World) {
     debug!("running PostProcessCollisions");
     world.run_schedule(PostProcessCollisions);
 }
+
+/// Stores the start times of the phases measured in [`PhysicsTimings`].
+#[derive(Resource, Default)]
+struct PhaseTimers {
+    broad_phase: Option<Instant>,
+    narrow_phase: Option<Instant>,
+    sync: Option<Instant>,
+}
+
+fn start_broad_phase_timer(mut timers: ResMut<PhaseTimers>) {
+    timers.broad_phase = Some(Instant::now());
+}
+
+fn stop_broad_phase_timer(mut timers: ResMut<PhaseTimers>, mut timings: ResMut<PhysicsTimings>) {
+    if let Some(start) = timers.broad_phase.take() {
+        timings.broad_phase = start.elapsed();
+    }
+}
+
+fn start_narrow_phase_timer(mut timers: ResMut<PhaseTimers>) {
+    timers.narrow_phase = Some(Instant::now());
+}
+
+fn stop_narrow_phase_timer(mut timers: ResMut<PhaseTimers>, mut timings: ResMut<PhysicsTimings>) {
+    if let Some(start) = timers.narrow_phase.take() {
+        timings.narrow_phase += start.elapsed();
+    }
+}
+
+fn start_sync_timer(mut timers: ResMut<PhaseTimers>) {
+    timers.sync = Some(Instant::now());
+}
+
+fn stop_sync_timer(mut timers: ResMut<PhaseTimers>, mut timings: ResMut<PhysicsTimings>) {
+    if let Some(start) = timers.sync.take() {
+        timings.sync = start.elapsed();
+    }
+}
+
+type JointFilter = Or<(
+    With<FixedJoint>,
+    With<DistanceJoint>,
+    With<PrismaticJoint>,
+    With<RevoluteJoint>,
+    With<SphericalJoint>,
+    With<SpringJoint>,
+)>;
+
+/// Updates the [`PhysicsStats`] at the end of each physics step.
+pub(crate) fn update_physics_stats(
+    bodies: Query<(&RigidBody, Has<Sleeping>)>,
+    joints: Query<(), JointFilter>,
+    broad_collision_pairs: Res<BroadCollisionPairs>,
+    collisions: Option<Res<Collisions>>,
+    islands: Option<Res<PhysicsIslands>>,
+    mut stats: ResMut<PhysicsStats>,
+) {
+    let mut new_stats = PhysicsStats {
+        broad_phase_pairs: broad_collision_pairs.0.len(),
+        joints: joints.iter().count(),
+        islands: islands.map_or(0, |islands| islands.len()),
+        ..default()
+    };
+
+    for (rb, sleeping) in &bodies {
+        if rb.is_static() {
+            new_stats.static_bodies += 1;
+        } else if sleeping {
+            new_stats.sleeping_bodies += 1;
+        } else {
+            new_stats.active_bodies += 1;
+        }
+    }
+
+    if let Some(collisions) = collisions {
+        for contacts in collisions.iter().filter(|c| c.during_current_frame) {
+            new_stats.contact_pairs += 1;
+            new_stats.manifold_points += contacts
+                .manifolds
+                .iter()
+                .map(|manifold| manifold.contacts.len())
+                .sum::<usize>();
+        }
+    }
+
+    *stats = new_stats;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, tick_60_fps},
+    };
+    use approx::assert_relative_eq;
+    use bevy::{log::LogPlugin, prelude::*, time::TimeUpdateStrategy, utils::Instant};
+    use std::time::Duration;
+
+    #[test]
+    fn physics_timings_are_measured_each_step() {
+        let mut app = create_app();
+
+        app.add_systems(Startup, |mut commands: Commands| {
+            #[cfg(feature = "2d")]
+            let floor = Collider::cuboid(10.0, 1.0);
+            #[cfg(feature = "3d")]
+            let floor = Collider::cuboid(10.0, 1.0, 10.0);
+            commands.spawn((TransformBundle::default(), RigidBody::Static, floor));
+            commands.spawn((
+                TransformBundle::from_transform(Transform::from_xyz(0.0, 1.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ));
+        });
+
+        assert_eq!(
+            *app.world.resource::<PhysicsTimings>(),
+            PhysicsTimings::default()
+        );
+
+        // Fill the timings with a duration that no step takes, so that the test
+        // doesn't depend on the resolution of the clock to see that they were measured
+        let unmeasured = Duration::from_secs(3600);
+        *app.world.resource_mut::<PhysicsTimings>() = PhysicsTimings {
+            step: unmeasured,
+            broad_phase: unmeasured,
+            narrow_phase: unmeasured,
+            substeps: unmeasured,
+            sync: unmeasured,
+        };
+
+        app.step_physics(1);
+
+        let timings = *app.world.resource::<PhysicsTimings>();
+        assert!(timings.step < unmeasured);
+        assert!(timings.broad_phase < unmeasured);
+        assert!(timings.narrow_phase < unmeasured);
+        assert!(timings.substeps < unmeasured);
+        assert!(timings.sync < unmeasured);
+
+        // The phases are run inside of the step, so they can't take longer than it
+        assert!(timings.broad_phase <= timings.step);
+        assert!(timings.narrow_phase <= timings.substeps);
+        assert!(timings.substeps <= timings.step);
+    }
+
+    fn spawn_touching_balls(mut commands: Commands) {
+        commands.spawn((
+            SpatialBundle::default(),
+            RigidBody::Static,
+            Collider::ball(0.5),
+        ));
+        commands.spawn((
+            SpatialBundle::default(),
+            RigidBody::Dynamic,
+            Position(Vector::X * 0.5),
+            Collider::ball(0.5),
+        ));
+    }
+
+    #[test]
+    fn physics_stats_count_bodies_and_contacts() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.add_systems(Startup, spawn_touching_balls);
+
+        app.step_physics(1);
+
+        let stats = *app.world.resource::<PhysicsStats>();
+        assert_eq!(stats.static_bodies, 1);
+        assert_eq!(stats.active_bodies, 1);
+        assert_eq!(stats.sleeping_bodies, 0);
+        assert_eq!(stats.broad_phase_pairs, 1);
+        assert_eq!(stats.contact_pairs, 1);
+        assert!(stats.manifold_points > 0);
+        assert_eq!(stats.joints, 0);
+        // Islands are only found by the `IslandPlugin`, which isn't part of `PhysicsPlugins`
+        assert_eq!(stats.islands, 0);
+    }
+
+    #[test]
+    fn physics_stats_count_islands_with_island_plugin() {
+        let mut app = create_app();
+        app.add_plugins(IslandPlugin);
+        app.insert_resource(Gravity::ZERO);
+        app.add_systems(Startup, spawn_touching_balls);
+
+        app.step_physics(1);
+
+        // Static bodies don't belong to islands, so the dynamic body is alone in its island
+        assert_eq!(app.world.resource::<PhysicsStats>().islands, 1);
+    }
+
+    #[test]
+    fn physics_in_fixed_update_uses_fixed_time_period() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            LogPlugin::default(),
+            PhysicsPlugins::new(FixedUpdate),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(Instant::now()));
+        app.insert_resource(FixedTime::new_from_secs(1.0 / 50.0));
+        app.insert_resource(Gravity::ZERO);
+
+        // The timestep defaults to Bevy's fixed timestep in `FixedUpdate`
+        assert_eq!(
+            *app.world.resource::<PhysicsTimestep>(),
+            PhysicsTimestep::FixedUpdate
+        );
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                LinearVelocity(Vector::X),
+            ))
+            .id();
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+        }
+
+        // Each physics step is as long as the fixed timestep
+        assert_relative_eq!(
+            app.world.resource::<DeltaTime>().0,
+            1.0 / 50.0,
+            epsilon = 1e-6
+        );
+
+        // The body has moved by one step for each run of `FixedUpdate`
+        let steps = (app.world.get::<Position>(body).unwrap().x * 50.0).round();
+        assert!((6.0..=8.0).contains(&steps));
+        assert_relative_eq!(
+            app.world.get::<Position>(body).unwrap().x,
+            steps / 50.0,
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn long_frame_drops_accumulated_time() {
+        let mut app = create_app();
+        tick_60_fps(&mut app);
+
+        // Simulate a one second hitch
+        let mut update_strategy = app.world.resource_mut::<TimeUpdateStrategy>();
+        let TimeUpdateStrategy::ManualInstant(prev_time) = *update_strategy else {
+            unimplemented!()
+        };
+        *update_strategy = TimeUpdateStrategy::ManualInstant(prev_time + Duration::from_secs(1));
+        app.update();
+
+        let max_steps = app
+            .world
+            .resource::<PhysicsLoop>()
+            .max_steps_per_frame
+            .unwrap();
+        let events = app.world.resource::<Events<PhysicsTimeDropped>>();
+        let dropped = events.iter_current_update_events().next().unwrap();
+        assert_eq!(dropped.steps, 60 - max_steps);
+        assert!(app.world.resource::<PhysicsLoop>().accumulator < 1.0 / 60.0);
+    }
+}
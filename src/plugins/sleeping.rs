@@ -28,7 +28,9 @@ impl Plugin for SleepingPlugin {
                     mark_sleeping_bodies,
                     wake_on_changed,
                     wake_on_collider_removed,
-                    wake_all_sleeping_bodies.run_if(resource_changed::<Gravity>()),
+                    wake_all_sleeping_bodies.run_if(
+                        resource_changed::<Gravity>().or_else(resource_changed::<LayerGravity>()),
+                    ),
                 )
                     .chain()
                     .in_set(PhysicsStepSet::Sleeping),
@@ -145,7 +147,7 @@ fn wake_on_collider_removed(
 }
 
 /// Removes the [`Sleeping`] component from all sleeping bodies.
-/// Triggered automatically when [`Gravity`] is changed.
+/// Triggered automatically when [`Gravity`] or [`LayerGravity`] is changed.
 fn wake_all_sleeping_bodies(
     mut commands: Commands,
     mut bodies: Query<(Entity, &mut TimeSleeping), With<Sleeping>>,
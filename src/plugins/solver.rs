@@ -10,8 +10,14 @@ use crate::{
 use bevy::{
     ecs::query::{Has, WorldQuery},
     prelude::*,
+    utils::{HashMap, HashSet},
 };
 use constraints::penetration::PenetrationConstraint;
+use std::{
+    any::TypeId,
+    collections::{BTreeSet, VecDeque},
+    ops::Bound,
+};
 
 /// Solves positional and angular [constraints], updates velocities and solves velocity constraints
 /// (dynamic [friction](Friction) and [restitution](Restitution) and [joint damping](joints#damping)).
@@ -32,29 +38,125 @@ use constraints::penetration::PenetrationConstraint;
 /// In the case of collisions, [`PenetrationConstraint`]s are created for each contact pair.
 /// The constraints are resolved by moving the bodies so that they no longer penetrate.
 /// Then, the velocities are updated, and velocity corrections caused by dynamic friction and restitution are applied.
-pub struct SolverPlugin;
+///
+/// The number of iterations used for the constraint projection and the velocity solve
+/// can be configured using the [`SolverConfig`] resource.
+///
+/// ## Solver backends
+///
+/// The steps above are implemented by the [`XpbdSolverBackend`]. The constraint solving stage can be replaced
+/// by creating the plugin with a custom [`SolverBackend`] using [`SolverPlugin::new`]. This lets experimental solvers,
+/// like impulse-based or parallel block solvers, be developed outside of Bevy XPBD while reusing the broad phase,
+/// narrow phase and components of the engine.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// struct ImpulseSolverBackend;
+///
+/// impl SolverBackend for ImpulseSolverBackend {
+///     fn build(&self, app: &mut App) {
+///         app.get_schedule_mut(SubstepSchedule)
+///             .expect("add SubstepSchedule first")
+///             .add_systems(solve_contacts.in_set(SubstepSet::SolveConstraints));
+///     }
+/// }
+///
+/// fn solve_contacts(collisions: Res<Collisions>) {
+///     // Solve the contacts
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             PhysicsPlugins::default()
+///                 .build()
+///                 .set(SolverPlugin::new(ImpulseSolverBackend)),
+///         ))
+///         .run();
+/// }
+/// ```
+pub struct SolverPlugin {
+    backend: Box<dyn SolverBackend>,
+}
+
+impl SolverPlugin {
+    /// Creates a [`SolverPlugin`] that solves constraints using the given [`SolverBackend`].
+    pub fn new(backend: impl SolverBackend) -> Self {
+        Self {
+            backend: Box::new(backend),
+        }
+    }
+}
+
+impl Default for SolverPlugin {
+    fn default() -> Self {
+        Self::new(XpbdSolverBackend)
+    }
+}
 
 impl Plugin for SolverPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<PenetrationConstraints>();
+        app.init_resource::<PenetrationConstraints>()
+            .init_resource::<SolverConfig>()
+            .register_type::<SolverConfig>();
 
-        let substeps = app
-            .get_schedule_mut(SubstepSchedule)
-            .expect("add SubstepSchedule first");
+        app.get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first")
+            .add_systems(apply_translation.in_set(SubstepSet::ApplyTranslation));
 
-        substeps.add_systems(
+        self.backend.build(app);
+    }
+}
+
+/// A constraint solving stage used by the [`SolverPlugin`].
+///
+/// A backend adds its systems to the [`SubstepSchedule`] in [`SubstepSet::SolveConstraints`],
+/// [`SubstepSet::UpdateVelocities`] and [`SubstepSet::SolveVelocities`]. The contacts computed by the narrow phase
+/// can be read from the [`Collisions`] resource, and the predicted positions of the bodies are stored in
+/// [`Position`] and [`AccumulatedTranslation`]. The accumulated translation is applied to the positions
+/// by the [`SolverPlugin`] in [`SubstepSet::ApplyTranslation`] regardless of the backend.
+pub trait SolverBackend: Send + Sync + 'static {
+    /// Adds the systems and resources of the backend to the app.
+    fn build(&self, app: &mut App);
+}
+
+/// The default [`SolverBackend`], which solves constraints using Extended Position-Based Dynamics.
+///
+/// See the [`SolverPlugin`] for the steps of the solver.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XpbdSolverBackend;
+
+impl SolverBackend for XpbdSolverBackend {
+    fn build(&self, app: &mut App) {
+        app.add_event::<JointLimitReached>();
+
+        let physics_schedule = app
+            .get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first");
+
+        physics_schedule.add_systems(
             (
-                penetration_constraints,
-                solve_constraint::<FixedJoint, 2>,
-                solve_constraint::<RevoluteJoint, 2>,
-                solve_constraint::<SphericalJoint, 2>,
-                solve_constraint::<PrismaticJoint, 2>,
-                solve_constraint::<DistanceJoint, 2>,
+                report_joint_limits::<FixedJoint>,
+                report_joint_limits::<RevoluteJoint>,
+                report_joint_limits::<SphericalJoint>,
+                report_joint_limits::<PrismaticJoint>,
+                report_joint_limits::<DistanceJoint>,
             )
                 .chain()
-                .in_set(SubstepSet::SolveConstraints),
+                .after(PhysicsStepSet::Substeps)
+                .before(PhysicsStepSet::ReportContacts),
         );
 
+        let substeps = app
+            .get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first");
+
         substeps.add_systems((update_lin_vel, update_ang_vel).in_set(SubstepSet::UpdateVelocities));
 
         substeps.add_systems(
@@ -65,12 +167,40 @@ impl Plugin for SolverPlugin {
                 joint_damping::<SphericalJoint>,
                 joint_damping::<PrismaticJoint>,
                 joint_damping::<DistanceJoint>,
+                joint_damping::<SpringJoint>,
             )
                 .chain()
                 .in_set(SubstepSet::SolveVelocities),
         );
 
-        substeps.add_systems(apply_translation.in_set(SubstepSet::ApplyTranslation));
+        register_constraint_system(
+            app,
+            TypeId::of::<PenetrationConstraint>(),
+            Some(ConstraintPriority::CONTACTS),
+            penetration_constraints,
+        );
+
+        app.add_constraint_with_priority::<FixedJoint, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<RevoluteJoint, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<SphericalJoint, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<PrismaticJoint, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<DistanceJoint, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<SpringJoint, 2>(ConstraintPriority::JOINTS);
+
+        register_constraint_system(
+            app,
+            TypeId::of::<Articulated>(),
+            Some(ConstraintPriority::JOINTS),
+            (
+                solve_articulated_joints::<FixedJoint>,
+                solve_articulated_joints::<RevoluteJoint>,
+                solve_articulated_joints::<SphericalJoint>,
+                solve_articulated_joints::<PrismaticJoint>,
+                solve_articulated_joints::<DistanceJoint>,
+                solve_articulated_joints::<SpringJoint>,
+            )
+                .chain(),
+        );
     }
 }
 
@@ -78,6 +208,143 @@ impl Plugin for SolverPlugin {
 #[derive(Resource, Debug, Default)]
 pub struct PenetrationConstraints(pub Vec<PenetrationConstraint>);
 
+/// Configures the number of solver iterations used in each substep.
+///
+/// Some games want very stiff positional correction but cheap velocity smoothing, or vice versa.
+/// Note that increasing the [`SubstepCount`] is usually more effective than adding iterations,
+/// but iterations are cheaper, because they don't require running the narrow phase or integrating velocities.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(SolverConfig {
+///             position_iterations: 4,
+///             velocity_iterations: 1,
+///             ..default()
+///         })
+///         .run();
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct SolverConfig {
+    /// The number of times the positional and angular [constraints] are projected in each substep.
+    /// This includes contacts and joints. Higher values make constraints stiffer.
+    ///
+    /// Defaults to 1.
+    pub position_iterations: u32,
+    /// The number of times the velocity corrections caused by dynamic [friction](Friction)
+    /// and [restitution](Restitution) are applied in each substep.
+    ///
+    /// Defaults to 1.
+    pub velocity_iterations: u32,
+    /// The number of forward and backward sweeps used for solving the [`Articulated`] joints in each substep.
+    /// Higher values make long chains of joints stiffer.
+    ///
+    /// Defaults to 4.
+    pub articulation_iterations: u32,
+    /// The way contacts are solved. See [`SolverMode`].
+    ///
+    /// Defaults to [`SolverMode::Xpbd`].
+    pub mode: SolverMode,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            position_iterations: 1,
+            velocity_iterations: 1,
+            articulation_iterations: 4,
+            mode: SolverMode::Xpbd,
+        }
+    }
+}
+
+/// The way contacts are solved by the [solver]. Configured using [`SolverConfig::mode`].
+///
+/// Joints and other constraints are always solved using XPBD.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(SolverConfig {
+///             mode: SolverMode::tgs_soft(),
+///             ..default()
+///         })
+///         .run();
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub enum SolverMode {
+    /// Penetration is resolved fully during each position iteration, limited only by the [`ContactCompliance`].
+    #[default]
+    Xpbd,
+    /// Contacts are treated as soft constraints in the style of TGS-soft (Temporal Gauss-Seidel).
+    ///
+    /// Only a part of the penetration is resolved during each substep based on the stiffness and damping
+    /// of the contacts, and bodies are never pushed apart faster than the `max_pushout_velocity`.
+    /// The velocity solve then acts as a relaxation step, removing the velocity caused by the position
+    /// correction so that overlapping bodies don't pop apart.
+    ///
+    /// This can make large stacks of bodies more stable at the cost of allowing some penetration.
+    TgsSoft {
+        /// The stiffness of contacts as a frequency in Hertz. It is clamped to a quarter of the substep rate.
+        contact_hertz: Scalar,
+        /// The damping ratio of contacts. Values above 1.0 are overdamped and don't overshoot.
+        damping_ratio: Scalar,
+        /// The maximum speed in meters per second at which overlapping bodies are pushed apart.
+        max_pushout_velocity: Scalar,
+    },
+}
+
+impl SolverMode {
+    /// Returns [`SolverMode::TgsSoft`] with a contact frequency of 30 Hz, a damping ratio of 10
+    /// and a maximum pushout velocity of 3 meters per second.
+    pub const fn tgs_soft() -> Self {
+        Self::TgsSoft {
+            contact_hertz: 30.0,
+            damping_ratio: 10.0,
+            max_pushout_velocity: 3.0,
+        }
+    }
+
+    /// Returns the soft constraint coefficients used for contacts with the given substep duration,
+    /// or `None` if contacts are solved using plain XPBD.
+    pub fn contact_softness(&self, sub_dt: Scalar) -> Option<ContactSoftness> {
+        match *self {
+            Self::Xpbd => None,
+            Self::TgsSoft {
+                contact_hertz,
+                damping_ratio,
+                max_pushout_velocity,
+            } => Some(ContactSoftness::new(
+                contact_hertz.min(0.25 / sub_dt),
+                damping_ratio,
+                max_pushout_velocity,
+                sub_dt,
+            )),
+        }
+    }
+}
+
 /// A [`WorldQuery`] to make code handling colliders in collisions cleaner.
 #[derive(WorldQuery)]
 struct ColliderQuery<'w> {
@@ -87,6 +354,8 @@ struct ColliderQuery<'w> {
     is_sensor: Has<Sensor>,
     friction: Option<&'w Friction>,
     restitution: Option<&'w Restitution>,
+    compliance: Option<&'w ContactCompliance>,
+    adhesion: Option<&'w Adhesion>,
 }
 
 /// Iterates through broad phase collision pairs, checks which ones are actually colliding, and uses [`PenetrationConstraint`]s to resolve the collisions.
@@ -94,14 +363,33 @@ struct ColliderQuery<'w> {
 #[allow(clippy::type_complexity)]
 fn penetration_constraints(
     mut commands: Commands,
-    mut bodies: Query<(RigidBodyQuery, Option<&Sensor>, Option<&Sleeping>)>,
+    mut bodies: Query<(
+        RigidBodyQuery,
+        Option<&Sensor>,
+        Option<&Sleeping>,
+        Option<&ContactCompliance>,
+        Option<&Adhesion>,
+    )>,
     colliders: Query<ColliderQuery>,
     mut penetration_constraints: ResMut<PenetrationConstraints>,
     mut collisions: ResMut<Collisions>,
+    solver_config: Res<SolverConfig>,
     sub_dt: Res<SubDeltaTime>,
+    mut touching_bodies: Local<HashSet<(Entity, Entity)>>,
 ) {
+    // Find the bodies that penetrated or adhered to each other in the previous substep
+    touching_bodies.clear();
+    touching_bodies.extend(
+        penetration_constraints
+            .0
+            .iter()
+            .filter(|c| c.contact.penetration > Scalar::EPSILON || c.is_adhering())
+            .map(|c| (c.entity1, c.entity2)),
+    );
     penetration_constraints.0.clear();
 
+    let softness = solver_config.mode.contact_softness(sub_dt.0);
+
     for ((collider_entity1, collider_entity2), contacts) in collisions
         .get_internal_mut()
         .iter_mut()
@@ -126,8 +414,8 @@ fn penetration_constraints(
         contacts.during_current_substep = false;
 
         if let Ok([bundle1, bundle2]) = bodies.get_many_mut([collider_parent1, collider_parent2]) {
-            let (mut body1, sensor1, sleeping1) = bundle1;
-            let (mut body2, sensor2, sleeping2) = bundle2;
+            let (mut body1, sensor1, sleeping1, body_compliance1, body_adhesion1) = bundle1;
+            let (mut body2, sensor2, sleeping2, body_compliance2, body_adhesion2) = bundle2;
 
             let inactive1 = body1.rb.is_static() || sleeping1.is_some();
             let inactive2 = body2.rb.is_static() || sleeping2.is_some();
@@ -154,17 +442,60 @@ fn penetration_constraints(
             }
 
             // Get combined friction and restitution coefficients of the colliders
-            // or the bodies they are attached to.
-            let friction = collider1
-                .friction
-                .unwrap_or(body1.friction)
-                .combine(*collider2.friction.unwrap_or(body2.friction));
-            let restitution_coefficient = collider1
+            // or the bodies they are attached to, unless they are overridden for this contact pair.
+            let friction = contacts.friction.unwrap_or_else(|| {
+                collider1
+                    .friction
+                    .unwrap_or(body1.friction)
+                    .combine(*collider2.friction.unwrap_or(body2.friction))
+            });
+            let restitution_coefficient = contacts
                 .restitution
-                .unwrap_or(body1.restitution)
-                .combine(*collider2.restitution.unwrap_or(body2.restitution))
+                .unwrap_or_else(|| {
+                    collider1
+                        .restitution
+                        .unwrap_or(body1.restitution)
+                        .combine(*collider2.restitution.unwrap_or(body2.restitution))
+                })
                 .coefficient;
 
+            // Soft contacts use the combined compliance of the colliders or the bodies they are attached to.
+            let compliance = collider1
+                .compliance
+                .or(body_compliance1)
+                .copied()
+                .unwrap_or_default()
+                .combine(
+                    collider2
+                        .compliance
+                        .or(body_compliance2)
+                        .copied()
+                        .unwrap_or_default(),
+                )
+                .0;
+
+            // Sticky contacts only hold bodies together once they have touched.
+            // Speculative contacts of bodies that are only approaching each other don't count.
+            let touching = touching_bodies.contains(&(body1.entity, body2.entity))
+                || touching_bodies.contains(&(body2.entity, body1.entity));
+            let adhesion = if touching {
+                collider1
+                    .adhesion
+                    .or(body_adhesion1)
+                    .copied()
+                    .unwrap_or_default()
+                    .combine(
+                        collider2
+                            .adhesion
+                            .or(body_adhesion2)
+                            .copied()
+                            .unwrap_or_default(),
+                    )
+                    .0
+            } else {
+                0.0
+            };
+
             // Create and solve penetration constraints for each contact.
             for contact_manifold in contacts.manifolds.iter() {
                 for contact in contact_manifold.contacts.iter() {
@@ -183,14 +514,36 @@ fn penetration_constraints(
                         dynamic_friction_coefficient: friction.dynamic_coefficient,
                         static_friction_coefficient: friction.static_coefficient,
                         restitution_coefficient,
+                        compliance,
+                        adhesion,
+                        softness,
                         ..PenetrationConstraint::new(&body1, &body2, contact)
                     };
+
+                    // Record the fastest approach during the frame the collision started.
+                    // This is reported as the impact speed for the rest of the collision.
+                    if !contacts.during_previous_frame {
+                        let normal = contact.global_normal1(&body1.rotation);
+                        let contact_vel1 = compute_contact_vel(
+                            body1.linear_velocity.0,
+                            body1.angular_velocity.0,
+                            body1.rotation.rotate(constraint.r1),
+                        );
+                        let contact_vel2 = compute_contact_vel(
+                            body2.linear_velocity.0,
+                            body2.angular_velocity.0,
+                            body2.rotation.rotate(constraint.r2),
+                        );
+                        let normal_speed = normal.dot(contact_vel1 - contact_vel2);
+                        contacts.impact_speed = contacts.impact_speed.max(normal_speed);
+                    }
+
                     constraint.solve([&mut body1, &mut body2], sub_dt.0);
                     penetration_constraints.0.push(constraint);
 
                     // Set collision as penetrating for this frame and substep.
                     // This is used for detecting when the collision has started or ended.
-                    if contact.penetration > Scalar::EPSILON {
+                    if contact.penetration > Scalar::EPSILON || constraint.is_adhering() {
                         contacts.during_current_frame = true;
                         contacts.during_current_substep = true;
                     }
@@ -198,74 +551,409 @@ fn penetration_constraints(
             }
         }
     }
+
+    // Solve the constraints again for any additional position iterations
+    for _ in 1..solver_config.position_iterations {
+        for constraint in penetration_constraints.0.iter_mut() {
+            if let Ok([(mut body1, _, _, _, _), (mut body2, _, _, _, _)]) =
+                bodies.get_many_mut(constraint.entities())
+            {
+                constraint.solve([&mut body1, &mut body2], sub_dt.0);
+            }
+        }
+    }
+}
+
+/// An extension trait for registering custom [constraints] that are solved by the [solver].
+///
+/// See [`ConstraintAppExt::add_constraint`] and the documentation on [creating custom constraints](constraints#custom-constraints).
+pub trait ConstraintAppExt {
+    /// Registers a constraint component implementing [`XpbdConstraint`] so that it is solved
+    /// in [`SubstepSet::SolveUserConstraints`] during each substep.
+    ///
+    /// Custom constraints are solved after the engine's own constraints, in the order that they were registered.
+    /// To solve a constraint before or between the engine's own constraints, use
+    /// [`add_constraint_with_priority`](ConstraintAppExt::add_constraint_with_priority) instead.
+    ///
+    /// Registering the same constraint type more than once has no effect.
+    ///
+    /// This must be called after adding the [`PhysicsPlugins`], because the constraint system is added
+    /// to the [`SubstepSchedule`].
+    ///
+    /// If the constraint is a [joint](joints), you may also want to add [`joint_damping`] to
+    /// [`SubstepSet::SolveVelocities`] to support velocity damping.
+    ///
+    /// ## Example
+    ///
+    /// ```ignore
+    /// App::new()
+    ///     .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+    ///     .add_constraint::<YourConstraint, ENTITY_COUNT>()
+    ///     .run();
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the [`SubstepSchedule`] doesn't exist.
+    fn add_constraint<C: XpbdConstraint<ENTITY_COUNT> + Component, const ENTITY_COUNT: usize>(
+        &mut self,
+    ) -> &mut Self;
+
+    /// Registers a constraint component implementing [`XpbdConstraint`] so that it is solved
+    /// in [`SubstepSet::SolveConstraints`] with the given [`ConstraintPriority`] during each substep.
+    ///
+    /// Constraints with a lower priority are solved first. Constraints with the same priority
+    /// are solved in the order that they were registered, after the engine's own constraints of that priority.
+    ///
+    /// Registering the same constraint type more than once has no effect.
+    ///
+    /// ## Example
+    ///
+    /// ```ignore
+    /// App::new()
+    ///     .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+    ///     // Solve the suspension before contacts
+    ///     .add_constraint_with_priority::<SuspensionConstraint, 2>(ConstraintPriority(-1))
+    ///     .run();
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the [`SubstepSchedule`] doesn't exist.
+    fn add_constraint_with_priority<
+        C: XpbdConstraint<ENTITY_COUNT> + Component,
+        const ENTITY_COUNT: usize,
+    >(
+        &mut self,
+        priority: ConstraintPriority,
+    ) -> &mut Self;
+}
+
+impl ConstraintAppExt for App {
+    fn add_constraint<C: XpbdConstraint<ENTITY_COUNT> + Component, const ENTITY_COUNT: usize>(
+        &mut self,
+    ) -> &mut Self {
+        register_constraint_system(
+            self,
+            TypeId::of::<C>(),
+            None,
+            solve_constraint::<C, ENTITY_COUNT>,
+        )
+    }
+
+    fn add_constraint_with_priority<
+        C: XpbdConstraint<ENTITY_COUNT> + Component,
+        const ENTITY_COUNT: usize,
+    >(
+        &mut self,
+        priority: ConstraintPriority,
+    ) -> &mut Self {
+        register_constraint_system(
+            self,
+            TypeId::of::<C>(),
+            Some(priority),
+            solve_constraint::<C, ENTITY_COUNT>,
+        )
+    }
 }
 
+/// The priority group in which a type of constraint is solved within [`SubstepSet::SolveConstraints`].
+/// Constraints with a lower priority are solved first.
+///
+/// The order in which constraints are solved can have a large effect on stability, especially for coupled systems
+/// like vehicles, where the suspension should often be solved before the contacts of the wheels.
+///
+/// Contacts are solved with [`ConstraintPriority::CONTACTS`] and the built-in [joints] are solved with
+/// [`ConstraintPriority::JOINTS`]. Custom constraints can be given a priority using
+/// [`ConstraintAppExt::add_constraint_with_priority`].
+///
+/// The priority is also a system set, so you can order your own solver systems relative to it.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ConstraintPriority(pub i32);
+
+impl ConstraintPriority {
+    /// The priority of [contacts](PenetrationConstraint).
+    pub const CONTACTS: Self = Self(0);
+    /// The priority of the built-in [joints].
+    pub const JOINTS: Self = Self(100);
+}
+
+/// Adds the solver system of a constraint type to the [`SubstepSchedule`] and orders it relative to
+/// the other registered constraints. Constraint types that are already registered are ignored.
+///
+/// If `priority` is `None`, the constraint is solved in [`SubstepSet::SolveUserConstraints`].
+fn register_constraint_system<M>(
+    app: &mut App,
+    type_id: TypeId,
+    priority: Option<ConstraintPriority>,
+    system: impl IntoSystemConfigs<M>,
+) -> &mut App {
+    let mut registered = app
+        .world
+        .get_resource_or_insert_with(RegisteredConstraints::default);
+
+    if registered
+        .constraints
+        .iter()
+        .any(|(registered_type, _)| *registered_type == type_id)
+    {
+        return app;
+    }
+
+    // The previously registered constraint in the same group, which should be solved before this one
+    let previous = registered
+        .constraints
+        .iter()
+        .rposition(|(_, registered_priority)| *registered_priority == priority);
+    let index = registered.constraints.len();
+    registered.constraints.push((type_id, priority));
+
+    // Order a new priority group between the neighboring groups
+    let neighbors = priority
+        .filter(|priority| registered.priorities.insert(*priority))
+        .map(|priority| {
+            (
+                registered.priorities.range(..priority).next_back().copied(),
+                registered
+                    .priorities
+                    .range((Bound::Excluded(priority), Bound::Unbounded))
+                    .next()
+                    .copied(),
+            )
+        });
+
+    let substeps = app
+        .get_schedule_mut(SubstepSchedule)
+        .expect("add SubstepSchedule first");
+
+    if let Some(priority) = priority {
+        substeps.add_systems(
+            system
+                .in_set(priority)
+                .in_set(RegisteredConstraintSet(index)),
+        );
+    } else {
+        substeps.add_systems(
+            system
+                .in_set(SubstepSet::SolveUserConstraints)
+                .in_set(RegisteredConstraintSet(index)),
+        );
+    }
+
+    if let (Some(priority), Some((lower, higher))) = (priority, neighbors) {
+        substeps.configure_set(priority.in_set(SubstepSet::SolveConstraints));
+        if let Some(lower) = lower {
+            substeps.configure_set(priority.after(lower));
+        }
+        if let Some(higher) = higher {
+            substeps.configure_set(priority.before(higher));
+        }
+    }
+
+    // Solve the constraints of a group in the order of registration to avoid system order ambiguities
+    if let Some(previous) = previous {
+        substeps
+            .configure_set(RegisteredConstraintSet(index).after(RegisteredConstraintSet(previous)));
+    }
+
+    app
+}
+
+/// The constraint types registered using [`ConstraintAppExt`], in the order of registration.
+#[derive(Resource, Default)]
+struct RegisteredConstraints {
+    /// The registered constraint types and their priorities.
+    constraints: Vec<(TypeId, Option<ConstraintPriority>)>,
+    /// The priority groups that have been added to [`SubstepSet::SolveConstraints`].
+    priorities: BTreeSet<ConstraintPriority>,
+}
+
+/// A system set for the solver system of the constraint registered at the given index.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct RegisteredConstraintSet(usize);
+
 /// Iterates through the constraints of a given type and solves them. Sleeping bodies are woken up when
 /// active bodies interact with them in a constraint.
 ///
 /// Note that this system only works for constraints that are modeled as entities.
 /// If you store constraints in a resource, you must create your own system for solving them.
 ///
+/// [`Articulated`] joints are skipped, because they are solved by [`solve_articulated_joints`].
+///
 /// ## User constraints
 ///
-/// To create a new constraint, implement [`XpbdConstraint`] for a component, get the [`SubstepSchedule`] and add this system into
-/// the [`SubstepSet::SolveUserConstraints`] set.
+/// To create a new constraint, implement [`XpbdConstraint`] for a component and register it using
+/// [`ConstraintAppExt::add_constraint`], which adds this system to the [`SubstepSet::SolveUserConstraints`] set.
 /// You must provide the number of entities in the constraint using generics.
 ///
 /// It should look something like this:
 ///
 /// ```ignore
-/// let substeps = app
-///     .get_schedule_mut(SubstepSchedule)
-///     .expect("add SubstepSchedule first");
-///
-/// substeps.add_systems(
-///     solve_constraint::<YourConstraint, ENTITY_COUNT>
-///         .in_set(SubstepSet::SolveUserConstraints),
-/// );
+/// app.add_constraint::<YourConstraint, ENTITY_COUNT>();
 /// ```
+#[allow(clippy::type_complexity)]
 pub fn solve_constraint<C: XpbdConstraint<ENTITY_COUNT> + Component, const ENTITY_COUNT: usize>(
     mut commands: Commands,
     mut bodies: Query<(RigidBodyQuery, Option<&Sleeping>)>,
-    mut constraints: Query<&mut C, Without<RigidBody>>,
+    mut constraints: Query<
+        (&mut C, Has<JointDisabled>),
+        (Without<RigidBody>, Without<Articulated>),
+    >,
+    solver_config: Res<SolverConfig>,
     sub_dt: Res<SubDeltaTime>,
 ) {
     // Clear Lagrange multipliers
     constraints
         .iter_mut()
-        .for_each(|mut c| c.clear_lagrange_multipliers());
-
-    for mut constraint in &mut constraints {
-        // Get components for entities
-        if let Ok(mut bodies) = bodies.get_many_mut(constraint.entities()) {
-            let none_dynamic = bodies.iter().all(|(body, _)| !body.rb.is_dynamic());
-            let all_inactive = bodies
-                .iter()
-                .all(|(body, sleeping)| body.rb.is_static() || sleeping.is_some());
-
-            // No constraint solving if none of the bodies is dynamic,
-            // or if all of the bodies are either static or sleeping
-            if none_dynamic || all_inactive {
+        .for_each(|(mut c, _)| c.clear_lagrange_multipliers());
+
+    for _ in 0..solver_config.position_iterations.max(1) {
+        for (mut constraint, disabled) in &mut constraints {
+            if disabled {
                 continue;
             }
 
-            // At least one of the participating bodies is active, so wake up any sleeping bodies
-            for (body, sleeping) in &bodies {
-                if sleeping.is_some() {
-                    commands.entity(body.entity).remove::<Sleeping>();
+            solve_constraint_with_bodies(&mut commands, &mut bodies, &mut *constraint, sub_dt.0);
+        }
+    }
+}
+
+/// Solves the [`Articulated`] joints of a given type using ordered Gauss-Seidel sweeps along the chains that they form.
+/// Sleeping bodies are woken up when active bodies interact with them in a joint.
+///
+/// The joints are ordered by their distance from the root of each chain, which is a [static](RigidBody::Static)
+/// or [kinematic](RigidBody::Kinematic) body or the end of the chain. Each iteration solves the joints from the root
+/// to the tips and then back, so that corrections propagate through the whole chain instead of causing it to stretch.
+///
+/// The built-in joints are solved this way automatically. For custom joints, add this system to the
+/// [`ConstraintPriority`] group that the joint is solved in.
+#[allow(clippy::type_complexity)]
+pub fn solve_articulated_joints<J: Joint>(
+    mut commands: Commands,
+    mut bodies: Query<(RigidBodyQuery, Option<&Sleeping>)>,
+    mut joints: Query<
+        &mut J,
+        (
+            With<Articulated>,
+            Without<JointDisabled>,
+            Without<RigidBody>,
+        ),
+    >,
+    solver_config: Res<SolverConfig>,
+    sub_dt: Res<SubDeltaTime>,
+) {
+    let mut joints = joints.iter_mut().collect::<Vec<_>>();
+
+    if joints.is_empty() {
+        return;
+    }
+
+    // Clear Lagrange multipliers
+    joints
+        .iter_mut()
+        .for_each(|joint| joint.clear_lagrange_multipliers());
+
+    let order = articulation_order(
+        &joints
+            .iter()
+            .map(|joint| joint.entities())
+            .collect::<Vec<_>>(),
+        |entity| matches!(bodies.get(entity), Ok((body, _)) if !body.rb.is_dynamic()),
+    );
+
+    for _ in 0..solver_config.articulation_iterations.max(1) {
+        // Solve from the roots to the tips, and then back
+        for &i in order.iter().chain(order.iter().rev()) {
+            solve_constraint_with_bodies(&mut commands, &mut bodies, &mut *joints[i], sub_dt.0);
+        }
+    }
+}
+
+/// Orders the given joints, represented by the entities that they connect, by their distance
+/// from the roots of the chains that they form.
+///
+/// Bodies for which `is_root` returns true are used as roots first. Chains without such bodies start
+/// from a body at an end of the chain, or from any body if the joints form a loop.
+fn articulation_order(joints: &[[Entity; 2]], is_root: impl Fn(Entity) -> bool) -> Vec<usize> {
+    let mut adjacency = HashMap::<Entity, Vec<usize>>::default();
+    for (i, entities) in joints.iter().enumerate() {
+        for entity in entities {
+            adjacency.entry(*entity).or_default().push(i);
+        }
+    }
+
+    let bodies = joints.iter().flatten().copied();
+    let roots = bodies
+        .clone()
+        .filter(|entity| is_root(*entity))
+        .chain(bodies.clone().filter(|entity| adjacency[entity].len() == 1))
+        .chain(bodies);
+
+    let mut order = Vec::with_capacity(joints.len());
+    let mut visited = vec![false; joints.len()];
+    let mut queue = VecDeque::new();
+
+    // Traverse the chains breadth-first from the roots
+    for root in roots {
+        if order.len() == joints.len() {
+            break;
+        }
+
+        queue.push_back(root);
+
+        while let Some(body) = queue.pop_front() {
+            for &i in &adjacency[&body] {
+                if visited[i] {
+                    continue;
                 }
+                visited[i] = true;
+                order.push(i);
+
+                let [entity1, entity2] = joints[i];
+                queue.push_back(if entity1 == body { entity2 } else { entity1 });
             }
+        }
+    }
 
-            // Get the bodies as an array and solve the constraint
-            if let Ok(bodies) = bodies
-                .iter_mut()
-                .map(|(ref mut body, _)| body)
-                .collect::<Vec<&mut RigidBodyQueryItem>>()
-                .try_into()
-            {
-                constraint.solve(bodies, sub_dt.0);
+    order
+}
+
+/// Solves a constraint if at least one of the participating bodies is active, waking up any sleeping bodies.
+fn solve_constraint_with_bodies<C: XpbdConstraint<ENTITY_COUNT>, const ENTITY_COUNT: usize>(
+    commands: &mut Commands,
+    bodies: &mut Query<(RigidBodyQuery, Option<&Sleeping>)>,
+    constraint: &mut C,
+    dt: Scalar,
+) {
+    // Get components for entities
+    if let Ok(mut bodies) = bodies.get_many_mut(constraint.entities()) {
+        let none_dynamic = bodies.iter().all(|(body, _)| !body.rb.is_dynamic());
+        let all_inactive = bodies
+            .iter()
+            .all(|(body, sleeping)| body.rb.is_static() || sleeping.is_some());
+
+        // No constraint solving if none of the bodies is dynamic,
+        // or if all of the bodies are either static or sleeping
+        if none_dynamic || all_inactive {
+            return;
+        }
+
+        // At least one of the participating bodies is active, so wake up any sleeping bodies
+        for (body, sleeping) in &bodies {
+            if sleeping.is_some() {
+                commands.entity(body.entity).remove::<Sleeping>();
             }
         }
+
+        // Get the bodies as an array and solve the constraint
+        if let Ok(bodies) = bodies
+            .iter_mut()
+            .map(|(ref mut body, _)| body)
+            .collect::<Vec<&mut RigidBodyQueryItem>>()
+            .try_into()
+        {
+            constraint.solve(bodies, dt);
+        }
     }
 }
 
@@ -380,110 +1068,150 @@ fn solve_vel(
     mut bodies: Query<RigidBodyQuery, Without<Sleeping>>,
     penetration_constraints: Res<PenetrationConstraints>,
     gravity: Res<Gravity>,
+    solver_config: Res<SolverConfig>,
     sub_dt: Res<SubDeltaTime>,
+    mut friction_impulses: Local<Vec<Scalar>>,
 ) {
-    for constraint in penetration_constraints.0.iter() {
-        if let Ok([mut body1, mut body2]) = bodies.get_many_mut(constraint.entities()) {
-            if !body1.rb.is_dynamic() && !body2.rb.is_dynamic() {
-                continue;
-            }
+    // The magnitudes of the friction impulses applied to each contact during this substep
+    friction_impulses.clear();
+    friction_impulses.resize(penetration_constraints.0.len(), 0.0);
+
+    for _ in 0..solver_config.velocity_iterations.max(1) {
+        for (constraint, friction_impulse_sum) in penetration_constraints
+            .0
+            .iter()
+            .zip(friction_impulses.iter_mut())
+        {
+            if let Ok([mut body1, mut body2]) = bodies.get_many_mut(constraint.entities()) {
+                if !body1.rb.is_dynamic() && !body2.rb.is_dynamic() {
+                    continue;
+                }
 
-            // Skip constraint if it didn't apply a correction
-            if constraint.normal_lagrange == 0.0 {
-                continue;
-            }
+                // Skip constraint if it didn't apply a correction or only held the bodies together
+                if constraint.normal_lagrange == 0.0 || constraint.is_adhering() {
+                    continue;
+                }
 
-            let normal = constraint.contact.global_normal1(&body1.rotation);
-            let r1 = body1.rotation.rotate(constraint.r1);
-            let r2 = body2.rotation.rotate(constraint.r2);
+                let normal = constraint.contact.global_normal1(&body1.rotation);
+                let r1 = body1.rotation.rotate(constraint.r1);
+                let r2 = body2.rotation.rotate(constraint.r2);
 
-            // Compute pre-solve relative normal velocities at the contact point (used for restitution)
-            let pre_solve_contact_vel1 = compute_contact_vel(
-                body1.pre_solve_linear_velocity.0,
-                body1.pre_solve_angular_velocity.0,
-                r1,
-            );
-            let pre_solve_contact_vel2 = compute_contact_vel(
-                body2.pre_solve_linear_velocity.0,
-                body2.pre_solve_angular_velocity.0,
-                r2,
-            );
-            let pre_solve_relative_vel = pre_solve_contact_vel1 - pre_solve_contact_vel2;
-            let pre_solve_normal_speed = normal.dot(pre_solve_relative_vel);
-
-            // Compute relative normal and tangential velocities at the contact point (equation 29)
-            let contact_vel1 =
-                compute_contact_vel(body1.linear_velocity.0, body1.angular_velocity.0, r1);
-            let contact_vel2 =
-                compute_contact_vel(body2.linear_velocity.0, body2.angular_velocity.0, r2);
-            let relative_vel = contact_vel1 - contact_vel2;
-
-            let normal_speed = normal.dot(relative_vel);
-            let tangent_vel = relative_vel - normal * normal_speed;
-            let tangent_speed = tangent_vel.length();
-
-            let inv_mass1 = body1.effective_inv_mass();
-            let inv_mass2 = body2.effective_inv_mass();
-            let inv_inertia1 = body1.effective_world_inv_inertia();
-            let inv_inertia2 = body2.effective_world_inv_inertia();
-
-            let mut p = Vector::ZERO;
-
-            // Compute restitution
-            let restitution_speed = compute_restitution(
-                normal_speed,
-                pre_solve_normal_speed,
-                constraint.restitution_coefficient,
-                gravity.0,
-                sub_dt.0,
-            );
-            if restitution_speed.abs() > Scalar::EPSILON {
-                let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, normal);
-                let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, normal);
-                p += restitution_speed / (w1 + w2) * normal;
-            }
-
-            // Compute dynamic friction
-            if tangent_speed > Scalar::EPSILON {
-                let tangent_dir = tangent_vel / tangent_speed;
-                let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, tangent_dir);
-                let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, tangent_dir);
-                let friction_impulse = compute_dynamic_friction(
-                    tangent_speed,
-                    w1 + w2,
-                    constraint.dynamic_friction_coefficient,
-                    constraint.normal_lagrange,
+                // Compute pre-solve relative normal velocities at the contact point (used for restitution)
+                let pre_solve_contact_vel1 = compute_contact_vel(
+                    body1.pre_solve_linear_velocity.0,
+                    body1.pre_solve_angular_velocity.0,
+                    r1,
+                );
+                let pre_solve_contact_vel2 = compute_contact_vel(
+                    body2.pre_solve_linear_velocity.0,
+                    body2.pre_solve_angular_velocity.0,
+                    r2,
+                );
+                let pre_solve_relative_vel = pre_solve_contact_vel1 - pre_solve_contact_vel2;
+                let pre_solve_normal_speed = normal.dot(pre_solve_relative_vel);
+
+                // Compute relative normal and tangential velocities at the contact point (equation 29)
+                let contact_vel1 =
+                    compute_contact_vel(body1.linear_velocity.0, body1.angular_velocity.0, r1);
+                let contact_vel2 =
+                    compute_contact_vel(body2.linear_velocity.0, body2.angular_velocity.0, r2);
+                let relative_vel = contact_vel1 - contact_vel2;
+
+                let normal_speed = normal.dot(relative_vel);
+                let tangent_vel = relative_vel - normal * normal_speed;
+                let tangent_speed = tangent_vel.length();
+
+                let inv_mass1 = body1.effective_inv_mass();
+                let inv_mass2 = body2.effective_inv_mass();
+                let inv_inertia1 = body1.effective_world_inv_inertia();
+                let inv_inertia2 = body2.effective_world_inv_inertia();
+
+                let mut p = Vector::ZERO;
+
+                // Compute restitution
+                let restitution_speed = compute_restitution(
+                    normal_speed,
+                    pre_solve_normal_speed,
+                    constraint.restitution_coefficient,
+                    gravity.0,
                     sub_dt.0,
                 );
-                p += friction_impulse * tangent_dir;
-            }
-
-            if body1.rb.is_dynamic() && body1.dominance() <= body2.dominance() {
-                let delta_lin_vel = p * inv_mass1;
-                let delta_ang_vel = compute_delta_ang_vel(inv_inertia1, r1, p);
-
-                if delta_lin_vel != Vector::ZERO {
-                    body1.linear_velocity.0 += delta_lin_vel;
+                if restitution_speed.abs() > Scalar::EPSILON {
+                    let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, normal);
+                    let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, normal);
+                    p += restitution_speed / (w1 + w2) * normal;
                 }
-                if delta_ang_vel != AngularVelocity::ZERO.0 {
-                    body1.angular_velocity.0 += delta_ang_vel;
+
+                // Compute dynamic friction
+                if tangent_speed > Scalar::EPSILON {
+                    let tangent_dir = tangent_vel / tangent_speed;
+                    let w1 = constraint.compute_generalized_inverse_mass(&body1, r1, tangent_dir);
+                    let w2 = constraint.compute_generalized_inverse_mass(&body2, r2, tangent_dir);
+                    let friction_impulse = compute_dynamic_friction(
+                        tangent_speed,
+                        w1 + w2,
+                        constraint.dynamic_friction_coefficient,
+                        constraint.normal_lagrange,
+                        *friction_impulse_sum,
+                        sub_dt.0,
+                    );
+                    *friction_impulse_sum -= friction_impulse;
+                    p += friction_impulse * tangent_dir;
                 }
-            }
-            if body2.rb.is_dynamic() && body2.dominance() <= body1.dominance() {
-                let delta_lin_vel = p * inv_mass2;
-                let delta_ang_vel = compute_delta_ang_vel(inv_inertia2, r2, p);
 
-                if delta_lin_vel != Vector::ZERO {
-                    body2.linear_velocity.0 -= delta_lin_vel;
+                if body1.rb.is_dynamic() && body1.dominance() <= body2.dominance() {
+                    let delta_lin_vel = p * inv_mass1;
+                    let delta_ang_vel = compute_delta_ang_vel(inv_inertia1, r1, p);
+
+                    if delta_lin_vel != Vector::ZERO {
+                        body1.linear_velocity.0 += delta_lin_vel;
+                    }
+                    if delta_ang_vel != AngularVelocity::ZERO.0 {
+                        body1.angular_velocity.0 += delta_ang_vel;
+                    }
                 }
-                if delta_ang_vel != AngularVelocity::ZERO.0 {
-                    body2.angular_velocity.0 -= delta_ang_vel;
+                if body2.rb.is_dynamic() && body2.dominance() <= body1.dominance() {
+                    let delta_lin_vel = p * inv_mass2;
+                    let delta_ang_vel = compute_delta_ang_vel(inv_inertia2, r2, p);
+
+                    if delta_lin_vel != Vector::ZERO {
+                        body2.linear_velocity.0 -= delta_lin_vel;
+                    }
+                    if delta_ang_vel != AngularVelocity::ZERO.0 {
+                        body2.angular_velocity.0 -= delta_ang_vel;
+                    }
                 }
             }
         }
     }
 }
 
+/// Sends [`JointLimitReached`] events for joints that have reached one of their limits
+/// since the previous physics frame.
+pub fn report_joint_limits<T: Joint>(
+    joints: Query<(Entity, &T)>,
+    mut previous_states: Local<HashMap<Entity, JointLimitState>>,
+    mut limit_ev_writer: EventWriter<JointLimitReached>,
+) {
+    let mut states = HashMap::with_capacity(previous_states.len());
+
+    for (entity, joint) in &joints {
+        let state = joint.limit_state();
+
+        if state != JointLimitState::Free && previous_states.get(&entity) != Some(&state) {
+            limit_ev_writer.send(JointLimitReached {
+                joint: entity,
+                limit: state,
+            });
+        }
+
+        states.insert(entity, state);
+    }
+
+    // Only keep the states of joints that still exist
+    *previous_states = states;
+}
+
 /// Applies velocity corrections caused by joint damping.
 #[allow(clippy::type_complexity)]
 pub fn joint_damping<T: Joint>(
@@ -497,7 +1225,7 @@ pub fn joint_damping<T: Joint>(
         ),
         Without<Sleeping>,
     >,
-    joints: Query<&T, Without<RigidBody>>,
+    joints: Query<&T, (Without<RigidBody>, Without<JointDisabled>)>,
     sub_dt: Res<SubDeltaTime>,
 ) {
     for joint in &joints {
@@ -574,3 +1302,479 @@ fn compute_delta_ang_vel(inverse_inertia: Scalar, r: Vector, p: Vector) -> Scala
 fn compute_delta_ang_vel(inverse_inertia: Matrix3, r: Vector, p: Vector) -> Vector {
     inverse_inertia * r.cross(p)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider, floor_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::{
+        ecs::entity::{EntityMapper, MapEntities},
+        prelude::*,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// A constraint between two bodies that records `N` in a shared list every time it is solved.
+    #[derive(Component)]
+    struct RecordingConstraint<const N: usize> {
+        entity1: Entity,
+        entity2: Entity,
+        solves: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl<const N: usize> RecordingConstraint<N> {
+        fn new(entity1: Entity, entity2: Entity, solves: &Arc<Mutex<Vec<usize>>>) -> Self {
+            Self {
+                entity1,
+                entity2,
+                solves: solves.clone(),
+            }
+        }
+    }
+
+    impl<const N: usize> XpbdConstraint<2> for RecordingConstraint<N> {
+        fn entities(&self) -> [Entity; 2] {
+            [self.entity1, self.entity2]
+        }
+
+        fn clear_lagrange_multipliers(&mut self) {}
+
+        fn solve(&mut self, _bodies: [&mut RigidBodyQueryItem; 2], _dt: Scalar) {
+            self.solves.lock().unwrap().push(N);
+        }
+    }
+
+    impl<const N: usize> MapEntities for RecordingConstraint<N> {
+        fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+            self.entity1 = entity_mapper.get_or_reserve(self.entity1);
+            self.entity2 = entity_mapper.get_or_reserve(self.entity2);
+        }
+    }
+
+    /// Spawns two dynamic balls that don't touch each other.
+    fn spawn_two_balls(app: &mut App) -> (Entity, Entity) {
+        let body1 = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+        let body2 = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::X * 2.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+        (body1, body2)
+    }
+
+    #[test]
+    fn dynamic_friction_does_not_grow_with_velocity_iterations() {
+        fn simulate(velocity_iterations: u32) -> Scalar {
+            let mut app = create_app();
+            app.insert_resource(SolverConfig {
+                velocity_iterations,
+                ..default()
+            });
+
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(100.0),
+                Friction::new(0.5),
+                Restitution::ZERO,
+            ));
+
+            // The box rests on the floor and slides along it
+            let body = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y),
+                    LinearVelocity(Vector::X * 5.0),
+                    cube,
+                    Friction::new(0.5),
+                    Restitution::ZERO,
+                ))
+                .id();
+
+            app.step_physics(30);
+
+            app.world.get::<LinearVelocity>(body).unwrap().x
+        }
+
+        let speed = simulate(1);
+
+        // Friction decelerates the box at roughly μg ≈ 4.9 m/s² for half a second
+        assert!(speed > 1.5 && speed < 3.5);
+        assert_relative_eq!(simulate(4), speed, epsilon = 0.05);
+    }
+
+    #[test]
+    fn contact_pair_overrides_replace_collider_coefficients() {
+        // Overrides the friction and restitution of all contact pairs if `overrides` is true
+        fn create_app_with_overrides(overrides: bool) -> App {
+            let mut app = create_app();
+            if overrides {
+                app.add_systems(
+                    PostProcessCollisions,
+                    |mut collisions: ResMut<Collisions>| {
+                        for contacts in collisions.iter_mut() {
+                            contacts.friction = Some(Friction::ZERO);
+                            contacts.restitution = Some(Restitution::PERFECTLY_ELASTIC);
+                        }
+                    },
+                );
+            }
+
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(100.0),
+                Friction::new(0.5),
+                Restitution::ZERO,
+            ));
+            app
+        }
+
+        // Returns how far a box slides along the floor in one second
+        fn slide(overrides: bool) -> Scalar {
+            let mut app = create_app_with_overrides(overrides);
+
+            let body = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y),
+                    LinearVelocity(Vector::X * 2.0),
+                    cube_collider(1.0),
+                    Friction::new(0.5),
+                    Restitution::ZERO,
+                ))
+                .id();
+
+            app.step_physics(60);
+
+            app.world.get::<Position>(body).unwrap().x
+        }
+
+        // Returns the highest point a ball reaches after hitting the floor
+        fn bounce(overrides: bool) -> Scalar {
+            let mut app = create_app_with_overrides(overrides);
+
+            let body = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y * 3.0),
+                    Collider::ball(0.5),
+                    Friction::new(0.5),
+                    Restitution::ZERO,
+                ))
+                .id();
+
+            // Wait for the ball to hit the floor
+            app.step_physics(45);
+
+            let mut max_height = Scalar::MIN;
+            for _ in 0..60 {
+                app.step_physics(1);
+                max_height = max_height.max(app.world.get::<Position>(body).unwrap().y);
+            }
+            max_height
+        }
+
+        // Friction stops the box in less than half a second, but without friction it keeps sliding
+        assert!(slide(false) < 0.6);
+        assert_relative_eq!(slide(true), 2.0, epsilon = 0.05);
+
+        // Without restitution the ball stays on the floor, but the elastic override makes it bounce back up
+        assert!(bounce(false) < 1.1);
+        assert!(bounce(true) > 2.0);
+    }
+
+    #[test]
+    fn custom_constraints_are_solved_once_per_substep() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // Registering the same constraint twice should only add one solver system
+        app.add_constraint::<RecordingConstraint<0>, 2>()
+            .add_constraint::<RecordingConstraint<1>, 2>()
+            .add_constraint::<RecordingConstraint<0>, 2>();
+
+        let (body1, body2) = spawn_two_balls(&mut app);
+        let solves = Arc::new(Mutex::new(vec![]));
+        app.world
+            .spawn(RecordingConstraint::<0>::new(body1, body2, &solves));
+        app.world
+            .spawn(RecordingConstraint::<1>::new(body1, body2, &solves));
+
+        app.step_physics(1);
+
+        let substeps = app.world.resource::<SubstepCount>().0 as usize;
+        let solves = solves.lock().unwrap();
+        assert_eq!(solves.iter().filter(|&&n| n == 0).count(), substeps);
+        assert_eq!(solves.iter().filter(|&&n| n == 1).count(), substeps);
+    }
+
+    #[test]
+    fn constraints_are_solved_in_priority_order() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO)
+            .insert_resource(SubstepCount(1));
+
+        app.add_constraint::<RecordingConstraint<0>, 2>()
+            .add_constraint_with_priority::<RecordingConstraint<1>, 2>(ConstraintPriority::JOINTS)
+            .add_constraint_with_priority::<RecordingConstraint<2>, 2>(ConstraintPriority(-1))
+            .add_constraint_with_priority::<RecordingConstraint<3>, 2>(
+                ConstraintPriority::CONTACTS,
+            );
+
+        let (body1, body2) = spawn_two_balls(&mut app);
+        let solves = Arc::new(Mutex::new(vec![]));
+        app.world.spawn((
+            RecordingConstraint::<0>::new(body1, body2, &solves),
+            RecordingConstraint::<1>::new(body1, body2, &solves),
+            RecordingConstraint::<2>::new(body1, body2, &solves),
+            RecordingConstraint::<3>::new(body1, body2, &solves),
+        ));
+
+        app.step_physics(1);
+
+        // Lower priorities are solved first, and constraints without a priority are solved last
+        assert_eq!(*solves.lock().unwrap(), vec![2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn soft_contacts_let_bodies_sink_in() {
+        let mut app = create_app();
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        // A rigid floor and a soft floor far apart from each other
+        let mut balls = vec![];
+        for (x, compliance) in [
+            (-10.0, ContactCompliance::RIGID),
+            (10.0, ContactCompliance(0.01)),
+        ] {
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * x),
+                floor_collider(4.0),
+                compliance,
+            ));
+
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::X * x + Vector::Y * 1.5),
+                    Collider::ball(0.5),
+                ))
+                .id();
+            balls.push(ball);
+        }
+
+        app.step_physics(180);
+
+        let rigid_y = app.world.get::<Position>(balls[0]).unwrap().0.y;
+        let soft_y = app.world.get::<Position>(balls[1]).unwrap().0.y;
+
+        // The ball on the rigid floor rests on the surface, while the soft floor gives in
+        assert_relative_eq!(rigid_y, 1.0, epsilon = 0.05);
+        assert!(soft_y < rigid_y - 0.02);
+        assert!(soft_y > 0.5);
+    }
+
+    #[test]
+    fn adhesion_holds_bodies_up_to_threshold() {
+        let mut app = create_app();
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        // Balls touching the bottom of a ceiling, one weakly and one strongly sticky
+        let mut balls = vec![];
+        for (x, adhesion) in [(-10.0, Adhesion(1.0)), (10.0, Adhesion(100.0))] {
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * x),
+                floor_collider(4.0),
+            ));
+
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::X * x - Vector::Y * 0.99),
+                    Collider::ball(0.5),
+                    adhesion,
+                ))
+                .id();
+            balls.push(ball);
+        }
+
+        app.step_physics(60);
+
+        // The weak adhesion can't hold the weight of the ball, but the strong adhesion can
+        let weak_y = app.world.get::<Position>(balls[0]).unwrap().0.y;
+        let strong_y = app.world.get::<Position>(balls[1]).unwrap().0.y;
+        assert!(weak_y < -2.0);
+        assert_relative_eq!(strong_y, -1.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn adhesion_does_not_attract_separated_bodies() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // The gap is within the prediction distance, so the bodies have speculative contacts
+        let gap = app
+            .world
+            .resource::<NarrowPhaseConfig>()
+            .prediction_distance
+            * 0.5;
+        // Pad the AABBs so that the broad phase finds the pair even though the bodies don't move
+        app.insert_resource(BroadPhaseConfig {
+            aabb_padding: gap * 2.0,
+            ..default()
+        });
+
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Static,
+            floor_collider(4.0),
+            Adhesion(1000.0),
+        ));
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::NEG_Y * (1.0 + gap)),
+                Collider::ball(0.5),
+                Adhesion(1000.0),
+            ))
+            .id();
+
+        app.step_physics(30);
+
+        // The bodies never touched, so the ball isn't pulled towards the other body
+        let y = app.world.get::<Position>(ball).unwrap().0.y;
+        assert_relative_eq!(y, -(1.0 + gap), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn tgs_soft_contacts_resolve_penetration_gradually() {
+        fn simulate(mode: SolverMode, steps: u32) -> (Scalar, Vector) {
+            let mut app = create_app();
+            app.insert_resource(Gravity::ZERO);
+            app.insert_resource(SolverConfig { mode, ..default() });
+
+            app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                floor_collider(4.0),
+            ));
+
+            // The ball overlaps the floor by 0.25
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y * 0.75),
+                    Collider::ball(0.5),
+                ))
+                .id();
+
+            app.step_physics(steps);
+
+            (
+                app.world.get::<Position>(ball).unwrap().y,
+                app.world.get::<LinearVelocity>(ball).unwrap().0,
+            )
+        }
+
+        // XPBD pushes the ball out right away, while TGS-soft only resolves a part of the penetration
+        let (xpbd_y, _) = simulate(SolverMode::Xpbd, 3);
+        let (soft_y, _) = simulate(SolverMode::tgs_soft(), 3);
+        assert_relative_eq!(xpbd_y, 1.0, epsilon = 0.01);
+        assert!(soft_y > 0.75 && soft_y < 0.9);
+
+        // The penetration is resolved eventually without the ball popping out
+        let (soft_y, soft_vel) = simulate(SolverMode::tgs_soft(), 120);
+        assert_relative_eq!(soft_y, 1.0, epsilon = 0.01);
+        assert!(soft_vel.length() < 0.1);
+    }
+
+    #[test]
+    fn custom_solver_backend_replaces_constraint_solving() {
+        #[derive(Resource, Default)]
+        struct ContactCount(usize);
+
+        /// A backend that only counts the contacts computed by the narrow phase without solving them.
+        struct CountingBackend;
+
+        impl SolverBackend for CountingBackend {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<ContactCount>();
+                app.get_schedule_mut(SubstepSchedule)
+                    .expect("add SubstepSchedule first")
+                    .add_systems(count_contacts.in_set(SubstepSet::SolveConstraints));
+            }
+        }
+
+        fn count_contacts(collisions: Res<Collisions>, mut count: ResMut<ContactCount>) {
+            count.0 += collisions
+                .get_internal()
+                .values()
+                .filter(|contacts| contacts.during_current_substep)
+                .count();
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            PhysicsPlugins::default()
+                .build()
+                .set(SolverPlugin::new(CountingBackend)),
+        ));
+        app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+        app.world.spawn((
+            TransformBundle::default(),
+            RigidBody::Static,
+            floor_collider(4.0),
+        ));
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 1.5),
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        app.step_physics(60);
+
+        // The contacts are still computed, but nothing stops the ball from falling through the floor
+        assert!(app.world.resource::<ContactCount>().0 > 0);
+        assert!(app.world.get::<Position>(ball).unwrap().y < 0.0);
+    }
+}
@@ -25,8 +25,8 @@
 //! in the [`RayHits`] component every frame. It uses local coordinates, so it will automatically follow the entity
 //! it's attached to or its parent.
 //! 2. When you need more control or don't want to cast every frame, use the ray casting methods provided by
-//! [`SpatialQuery`], like [`cast_ray`](SpatialQuery#method.cast_ray), [`ray_hits`](SpatialQuery#method.ray_hits) or
-//! [`ray_hits_callback`](SpatialQuery#method.ray_hits_callback).
+//! [`SpatialQuery`], like [`cast_ray`](SpatialQuery#method.cast_ray), [`cast_ray_batch`](SpatialQuery#method.cast_ray_batch),
+//! [`ray_hits`](SpatialQuery#method.ray_hits) or [`ray_hits_callback`](SpatialQuery#method.ray_hits_callback).
 //!
 //! See the documentation of the components and methods for more information.
 //!
@@ -83,6 +83,10 @@
 //! [`SpatialQuery`], like [`cast_shape`](SpatialQuery#method.cast_shape), [`shape_hits`](SpatialQuery#method.shape_hits) or
 //! [`shape_hits_callback`](SpatialQuery#method.shape_hits_callback).
 //!
+//! [`cast_shape`](SpatialQuery#method.cast_shape) only computes the first hit, while [`shape_hits`](SpatialQuery#method.shape_hits)
+//! computes every hit along the sweep in the order of the time of impact, up to a maximum number of hits.
+//! This can be used for things like piercing attacks that damage everything in their path.
+//!
 //! See the documentation of the components and methods for more information.
 //!
 //! A simple example using the component-based method looks like this:
@@ -132,13 +136,16 @@
 //! **Intersection tests** are spatial queries that return the entities of colliders that are intersecting a given
 //! shape or area.
 //!
-//! There are three types of intersection tests. They are all methods of the [`SpatialQuery`] system parameter,
+//! There are four types of intersection tests. They are all methods of the [`SpatialQuery`] system parameter,
 //! and they all have callback variants that call a given callback on each intersection.
 //!
 //! - [`point_intersections`](SpatialQuery#method.point_intersections): Finds all entities with a collider that contains
 //! the given point.
 //! - [`aabb_intersections_with_aabb`](SpatialQuery#method.aabb_intersections_with_aabb):
 //! Finds all entities with a [`ColliderAabb`] that is intersecting the given [`ColliderAabb`].
+//! - [`sphere_intersections`](SpatialQuery#method.sphere_intersections): Finds all entities with a [collider](Collider)
+//! that is intersecting a sphere (a circle in 2D) with the given center and radius. This is a faster alternative to
+//! shape intersections for the most common kind of area query.
 //! - [`shape_intersections`](SpatialQuery#method.shape_intersections): Finds all entities with a [collider](Collider)
 //! that is intersecting the given shape.
 //!
@@ -147,16 +154,20 @@
 //! To specify which colliders should be considered in the query, use a [spatial query filter](`SpatialQueryFilter`).
 
 mod pipeline;
+mod projectile;
 mod query_filter;
 mod ray_caster;
 mod shape_caster;
 mod system_param;
+mod trajectory;
 
 pub use pipeline::*;
+pub use projectile::*;
 pub use query_filter::*;
 pub use ray_caster::*;
 pub use shape_caster::*;
 pub use system_param::*;
+pub use trajectory::*;
 
 use crate::prelude::*;
 use bevy::prelude::*;
@@ -187,10 +198,12 @@ impl Default for SpatialQueryPlugin {
 
 impl Plugin for SpatialQueryPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<SpatialQueryPipeline>().add_systems(
-            self.schedule.dyn_clone(),
-            (init_ray_hits, init_shape_hit).in_set(PhysicsSet::Prepare),
-        );
+        app.init_resource::<SpatialQueryPipeline>()
+            .add_event::<ProjectileHit>()
+            .add_systems(
+                self.schedule.dyn_clone(),
+                (init_ray_hits, init_shape_hit).in_set(PhysicsSet::Prepare),
+            );
 
         let physics_schedule = app
             .get_schedule_mut(PhysicsSchedule)
@@ -203,6 +216,7 @@ impl Plugin for SpatialQueryPlugin {
                 |mut spatial_query: SpatialQuery| spatial_query.update_pipeline(),
                 raycast,
                 shapecast,
+                projectile::sweep_projectiles,
             )
                 .chain()
                 .in_set(PhysicsStepSet::SpatialQuery),
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use crate::prelude::*;
+#[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+use bevy::tasks::{ComputeTaskPool, ParallelSlice};
 use bevy::{prelude::*, utils::HashMap};
 use parry::{
     partitioning::Qbvh,
@@ -155,12 +157,68 @@ impl SpatialQueryPipeline {
         query_filter: SpatialQueryFilter,
     ) -> Option<RayHitData> {
         let pipeline_shape = self.as_composite_shape(query_filter);
-        let ray = parry::query::Ray::new(origin.into(), direction.into());
-        let mut visitor = RayCompositeShapeToiAndNormalBestFirstVisitor::new(
+        self.cast_ray_with_shape(
             &pipeline_shape,
-            &ray,
-            max_time_of_impact,
-            solid,
+            &QueryRay {
+                origin,
+                direction,
+                max_time_of_impact,
+                solid,
+            },
+        )
+    }
+
+    /// Casts a batch of [rays](spatial_query#ray-casting) and computes the closest [hit](RayHitData)
+    /// with a collider for each ray. The results are in the same order as the rays, and rays that don't hit
+    /// anything have a result of `None`.
+    ///
+    /// With the `parallel` feature and without `single-threaded`, the rays are split between the threads of the `ComputeTaskPool`.
+    ///
+    /// ## Arguments
+    ///
+    /// - `rays`: The rays to cast.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// See also: [SpatialQuery::cast_ray_batch]
+    pub fn cast_ray_batch(
+        &self,
+        rays: &[QueryRay],
+        query_filter: SpatialQueryFilter,
+    ) -> Vec<Option<RayHitData>> {
+        #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+        {
+            let pool = ComputeTaskPool::get();
+            rays.par_splat_map(pool, None, |chunk| {
+                let pipeline_shape = self.as_composite_shape(query_filter.clone());
+                chunk
+                    .iter()
+                    .map(|ray| self.cast_ray_with_shape(&pipeline_shape, ray))
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .flatten()
+            .collect()
+        }
+        #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+        {
+            let pipeline_shape = self.as_composite_shape(query_filter);
+            rays.iter()
+                .map(|ray| self.cast_ray_with_shape(&pipeline_shape, ray))
+                .collect()
+        }
+    }
+
+    fn cast_ray_with_shape(
+        &self,
+        pipeline_shape: &QueryPipelineAsCompositeShape,
+        ray: &QueryRay,
+    ) -> Option<RayHitData> {
+        let parry_ray = parry::query::Ray::new(ray.origin.into(), ray.direction.into());
+        let mut visitor = RayCompositeShapeToiAndNormalBestFirstVisitor::new(
+            pipeline_shape,
+            &parry_ray,
+            ray.max_time_of_impact,
+            ray.solid,
         );
 
         self.qbvh
@@ -269,6 +327,50 @@ impl SpatialQueryPipeline {
         self.qbvh.traverse_depth_first(&mut visitor);
     }
 
+    /// Casts a piercing [ray](spatial_query#ray-casting) that passes through colliders until `callback` returns false.
+    /// The `callback` is called for each [hit](RayHitData) in the order of the time of impact, and it decides
+    /// whether the ray continues through the hit collider.
+    ///
+    /// Returns the hit that stopped the ray, or `None` if the ray passed through all of the hits.
+    ///
+    /// ## Arguments
+    ///
+    /// - `origin`: Where the ray is cast from.
+    /// - `direction`: What direction the ray is cast in.
+    /// - `max_time_of_impact`: The maximum distance that the ray can travel.
+    /// - `solid`: If true and the ray origin is inside of a collider, the hit point will be the ray origin itself.
+    /// Otherwise, the collider will be treated as hollow, and the hit point will be at the collider's boundary.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    /// - `callback`: A callback function called for each hit. Returning true lets the ray continue.
+    ///
+    /// See also: [SpatialQuery::cast_ray_and_continue]
+    pub fn cast_ray_and_continue(
+        &self,
+        origin: Vector,
+        direction: Vector,
+        max_time_of_impact: Scalar,
+        solid: bool,
+        query_filter: SpatialQueryFilter,
+        mut callback: impl FnMut(&RayHitData) -> bool,
+    ) -> Option<RayHitData> {
+        // All hits are found with a single traversal and sorted, instead of re-casting the ray for each hit
+        let mut hits = vec![];
+        self.ray_hits_callback(
+            origin,
+            direction,
+            max_time_of_impact,
+            solid,
+            query_filter,
+            |hit| {
+                hits.push(hit);
+                true
+            },
+        );
+        hits.sort_by(|a, b| a.time_of_impact.total_cmp(&b.time_of_impact));
+
+        hits.into_iter().find(|hit| !callback(hit))
+    }
+
     /// Casts a [shape](spatial_query#shape-casting) with a given rotation and computes the closest [hit](ShapeHits)
     /// with a collider. If there are no hits, `None` is returned.
     ///
@@ -578,6 +680,75 @@ impl SpatialQueryPipeline {
         self.qbvh.traverse_depth_first(&mut visitor);
     }
 
+    /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
+    /// that is intersecting a sphere (a circle in 2D) with the given `center` and `radius`.
+    ///
+    /// This is faster than [`shape_intersections`](SpatialQueryPipeline::shape_intersections) with a ball collider,
+    /// because it only needs to compute the distance from the center to each collider.
+    ///
+    /// ## Arguments
+    ///
+    /// - `center`: The center of the sphere.
+    /// - `radius`: The radius of the sphere.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// See also: [SpatialQuery::sphere_intersections]
+    pub fn sphere_intersections(
+        &self,
+        center: Vector,
+        radius: Scalar,
+        query_filter: SpatialQueryFilter,
+    ) -> Vec<Entity> {
+        let mut intersections = vec![];
+        self.sphere_intersections_callback(center, radius, query_filter, |e| {
+            intersections.push(e);
+            true
+        });
+        intersections
+    }
+
+    /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
+    /// that is intersecting a sphere (a circle in 2D) with the given `center` and `radius`,
+    /// calling `callback` for each intersection. The search stops when `callback` returns `false`
+    /// or all intersections have been found.
+    ///
+    /// ## Arguments
+    ///
+    /// - `center`: The center of the sphere.
+    /// - `radius`: The radius of the sphere.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    /// - `callback`: A callback function called for each intersection.
+    ///
+    /// See also: [SpatialQuery::sphere_intersections_callback]
+    pub fn sphere_intersections_callback(
+        &self,
+        center: Vector,
+        radius: Scalar,
+        query_filter: SpatialQueryFilter,
+        mut callback: impl FnMut(Entity) -> bool,
+    ) {
+        let point = center.into();
+
+        let mut leaf_callback = &mut |entity_index: &u32| {
+            let entity = self.entity_from_index(*entity_index);
+            if let Some((isometry, shape, layers)) = self.colliders.get(&entity) {
+                if query_filter.test(entity, *layers)
+                    && shape
+                        .shape_scaled()
+                        .distance_to_point(isometry, &point, true)
+                        <= radius
+                {
+                    return callback(entity);
+                }
+            }
+            true
+        };
+
+        let aabb = ColliderAabb::new(center, Vector::splat(radius));
+        let mut visitor = BoundingVolumeIntersectionsVisitor::new(&aabb, &mut leaf_callback);
+        self.qbvh.traverse_depth_first(&mut visitor);
+    }
+
     /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
     /// that is intersecting the given `shape` with a given position and rotation.
     ///
@@ -725,3 +896,198 @@ pub struct PointProjection {
     /// True if the point was inside of the collider.
     pub is_inside: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    /// Spawns a static ball with the given radius at the given position.
+    fn spawn_static_ball(app: &mut App, position: Vector, radius: Scalar) -> Entity {
+        app.world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(position),
+                Collider::ball(radius),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn batched_ray_casts_match_individual_casts() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // A row of balls along the X axis
+        let balls: Vec<Entity> = (0..10)
+            .map(|i| spawn_static_ball(&mut app, Vector::X * i as Scalar * 3.0, 1.0))
+            .collect();
+
+        app.step_physics(2);
+
+        // Rays cast downwards at the balls and between them
+        let rays: Vec<QueryRay> = (0..200)
+            .map(|i| {
+                QueryRay::new(
+                    Vector::X * i as Scalar * 0.15 + Vector::Y * 5.0,
+                    Vector::NEG_Y,
+                    10.0,
+                )
+            })
+            .collect();
+
+        let spatial_query = app.world.resource::<SpatialQueryPipeline>();
+        let hits = spatial_query.cast_ray_batch(&rays, SpatialQueryFilter::default());
+
+        assert_eq!(hits.len(), rays.len());
+        for (ray, hit) in rays.iter().zip(hits.iter()) {
+            let expected = spatial_query.cast_ray(
+                ray.origin,
+                ray.direction,
+                ray.max_time_of_impact,
+                ray.solid,
+                SpatialQueryFilter::default(),
+            );
+            assert_eq!(*hit, expected);
+        }
+
+        // The first ray hits the top of the first ball, and a ray between the balls misses
+        assert_eq!(hits[0].unwrap().entity, balls[0]);
+        assert_relative_eq!(hits[0].unwrap().time_of_impact, 4.0, epsilon = 0.001);
+        assert!(hits[10].is_none());
+    }
+
+    #[test]
+    fn shape_hits_are_sorted_by_time_of_impact() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // Balls along the X axis, spawned in a shuffled order
+        let mut balls = vec![];
+        for i in [3, 0, 4, 1, 2] {
+            let ball = spawn_static_ball(&mut app, Vector::X * (i as Scalar * 3.0 + 5.0), 0.5);
+            balls.push((i, ball));
+        }
+        balls.sort_by_key(|(i, _)| *i);
+
+        app.step_physics(2);
+
+        #[cfg(feature = "2d")]
+        let shape_rotation = 0.0;
+        #[cfg(feature = "3d")]
+        let shape_rotation = Quaternion::IDENTITY;
+
+        let spatial_query = app.world.resource::<SpatialQueryPipeline>();
+        let cast = |max_hits: u32| {
+            spatial_query.shape_hits(
+                &Collider::ball(0.5),
+                Vector::ZERO,
+                shape_rotation,
+                Vector::X,
+                100.0,
+                max_hits,
+                true,
+                SpatialQueryFilter::default(),
+            )
+        };
+
+        // Every ball is hit in the order along the sweep
+        let hits = cast(10);
+        assert_eq!(hits.len(), 5);
+        for (hit, (i, ball)) in hits.iter().zip(balls.iter()) {
+            assert_eq!(hit.entity, *ball);
+            assert_relative_eq!(
+                hit.time_of_impact,
+                *i as Scalar * 3.0 + 4.0,
+                epsilon = 0.001
+            );
+        }
+
+        // Limiting the number of hits keeps the closest ones
+        let hits = cast(2);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entity, balls[0].1);
+        assert_eq!(hits[1].entity, balls[1].1);
+    }
+
+    #[test]
+    fn piercing_rays_continue_through_accepted_hits() {
+        #[derive(Component)]
+        struct Glass;
+
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // Two panes of glass in front of a wall
+        for x in [2.0, 4.0] {
+            let pane = spawn_static_ball(&mut app, Vector::X * x, 0.5);
+            app.world.entity_mut(pane).insert(Glass);
+        }
+        let wall = spawn_static_ball(&mut app, Vector::X * 6.0, 0.5);
+
+        app.step_physics(2);
+
+        let mut pierced = vec![];
+        let hit = app
+            .world
+            .resource::<SpatialQueryPipeline>()
+            .cast_ray_and_continue(
+                Vector::ZERO,
+                Vector::X,
+                100.0,
+                true,
+                SpatialQueryFilter::default(),
+                |hit| {
+                    let is_glass = app.world.get::<Glass>(hit.entity).is_some();
+                    if is_glass {
+                        pierced.push(hit.time_of_impact);
+                    }
+                    is_glass
+                },
+            )
+            .expect("the wall should stop the ray");
+
+        assert_eq!(hit.entity, wall);
+        assert_relative_eq!(hit.time_of_impact, 5.5, epsilon = 0.001);
+        assert_eq!(pierced.len(), 2);
+        assert_relative_eq!(pierced[0], 1.5, epsilon = 0.001);
+        assert_relative_eq!(pierced[1], 3.5, epsilon = 0.001);
+    }
+
+    #[test]
+    fn sphere_intersections_find_overlapping_colliders() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let near = spawn_static_ball(&mut app, Vector::X * 2.0, 0.5);
+        // The AABB of the cuboid overlaps the AABB of the sphere, but the corner is too far away
+        let corner = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::splat(2.9)),
+                cube_collider(2.0),
+            ))
+            .id();
+        let excluded = spawn_static_ball(&mut app, Vector::NEG_X * 2.0, 0.5);
+
+        app.step_physics(2);
+
+        let spatial_query = app.world.resource::<SpatialQueryPipeline>();
+        let intersections = spatial_query.sphere_intersections(
+            Vector::ZERO,
+            2.0,
+            SpatialQueryFilter::default().without_entities([excluded]),
+        );
+
+        assert_eq!(intersections, vec![near]);
+        assert!(!intersections.contains(&corner));
+    }
+}
@@ -0,0 +1,279 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A projectile with travel time that is moved along a ballistic trajectory by sweeping a ray or a small shape
+/// through the world each physics step, without simulating a full [rigid body](RigidBody).
+///
+/// This is the standard technique for fast bullets: each step, the projectile is moved by its velocity
+/// and [gravity](Gravity), and the path between the old and new position is checked for hits using
+/// [ray casting](spatial_query#ray-casting) or [shape casting](spatial_query#shape-casting). This way, fast projectiles
+/// never tunnel through thin walls, and thousands of them are much cheaper than rigid bodies.
+///
+/// When the projectile hits a [collider](Collider), a [`ProjectileHit`] event is sent, and the projectile entity
+/// is despawned. If `despawn_on_hit` is false, the projectile instead stops at the point of impact and the
+/// [`Projectile`] component is removed, which is useful for things like arrows that stick to their targets.
+/// Projectiles are also despawned after `max_lifetime` seconds.
+///
+/// If the entity has a `Transform`, its translation is updated to follow the projectile,
+/// so visuals can be attached to it.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn shoot(mut commands: Commands) {
+///     commands.spawn(
+///         Projectile::new(Vector::ZERO, Vector::X * 300.0)
+///             .with_shape(Collider::ball(0.05))
+///             .with_max_lifetime(3.0),
+///     );
+/// }
+///
+/// fn print_hits(mut hits: EventReader<ProjectileHit>) {
+///     for hit in hits.iter() {
+///         println!("Projectile {:?} hit {:?} at {:?}", hit.projectile, hit.entity, hit.point);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone)]
+pub struct Projectile {
+    /// The current position of the projectile in world space.
+    pub position: Vector,
+    /// The current velocity of the projectile.
+    pub velocity: Vector,
+    /// The shape swept along the trajectory. If `None`, a ray is cast instead.
+    pub shape: Option<Collider>,
+    /// How strongly [gravity](Gravity) affects the projectile.
+    pub gravity_scale: Scalar,
+    /// How long the projectile has existed in seconds.
+    pub lifetime: Scalar,
+    /// The lifetime in seconds after which the projectile is despawned.
+    pub max_lifetime: Scalar,
+    /// If true, the projectile entity is despawned when it hits something. Otherwise, the projectile stops
+    /// at the point of impact and only the [`Projectile`] component is removed. True by default.
+    pub despawn_on_hit: bool,
+    /// Rules that determine which colliders the projectile can hit.
+    pub query_filter: SpatialQueryFilter,
+}
+
+impl Projectile {
+    /// Creates a new [`Projectile`] at the given position with the given velocity.
+    pub fn new(position: Vector, velocity: Vector) -> Self {
+        Self {
+            position,
+            velocity,
+            shape: None,
+            gravity_scale: 1.0,
+            lifetime: 0.0,
+            max_lifetime: Scalar::MAX,
+            despawn_on_hit: true,
+            query_filter: SpatialQueryFilter::default(),
+        }
+    }
+
+    /// Sets the shape swept along the trajectory.
+    pub fn with_shape(self, shape: Collider) -> Self {
+        Self {
+            shape: Some(shape),
+            ..self
+        }
+    }
+
+    /// Sets how strongly [gravity](Gravity) affects the projectile.
+    pub fn with_gravity_scale(self, gravity_scale: Scalar) -> Self {
+        Self {
+            gravity_scale,
+            ..self
+        }
+    }
+
+    /// Sets the lifetime in seconds after which the projectile is despawned.
+    pub fn with_max_lifetime(self, max_lifetime: Scalar) -> Self {
+        Self {
+            max_lifetime,
+            ..self
+        }
+    }
+
+    /// Sets whether the projectile entity is despawned when it hits something.
+    pub fn with_despawn_on_hit(self, despawn_on_hit: bool) -> Self {
+        Self {
+            despawn_on_hit,
+            ..self
+        }
+    }
+
+    /// Sets the rules that determine which colliders the projectile can hit.
+    pub fn with_query_filter(self, query_filter: SpatialQueryFilter) -> Self {
+        Self {
+            query_filter,
+            ..self
+        }
+    }
+}
+
+/// An event that is sent when a [`Projectile`] hits a [collider](Collider).
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct ProjectileHit {
+    /// The projectile entity.
+    pub projectile: Entity,
+    /// The entity of the collider that was hit.
+    pub entity: Entity,
+    /// The position of the projectile at the time of impact in world space.
+    pub point: Vector,
+    /// The world-space normal of the surface that was hit.
+    pub normal: Vector,
+    /// The velocity of the projectile at the time of impact.
+    pub velocity: Vector,
+}
+
+/// Moves [`Projectile`]s along their trajectories and sends [`ProjectileHit`] events for hits along the way.
+pub(super) fn sweep_projectiles(
+    mut commands: Commands,
+    mut projectiles: Query<(Entity, &mut Projectile, Option<&mut Transform>)>,
+    colliders: Query<&Rotation>,
+    spatial_query: SpatialQuery,
+    gravity: Res<Gravity>,
+    dt: Res<DeltaTime>,
+    mut hit_events: EventWriter<ProjectileHit>,
+) {
+    for (entity, mut projectile, transform) in &mut projectiles {
+        let gravity = gravity.0 * projectile.gravity_scale;
+        let displacement = projectile.velocity * dt.0 + 0.5 * gravity * dt.0 * dt.0;
+        let distance = displacement.length();
+        let direction = displacement.normalize_or_zero();
+
+        let hit = if distance <= Scalar::EPSILON {
+            None
+        } else if let Some(shape) = &projectile.shape {
+            #[cfg(feature = "2d")]
+            let shape_rotation = 0.0;
+            #[cfg(feature = "3d")]
+            let shape_rotation = Quaternion::IDENTITY;
+
+            spatial_query
+                .cast_shape(
+                    shape,
+                    projectile.position,
+                    shape_rotation,
+                    direction,
+                    distance,
+                    false,
+                    projectile.query_filter.clone(),
+                )
+                .map(|hit| {
+                    let normal = colliders
+                        .get(hit.entity)
+                        .map_or(hit.normal1, |rotation| rotation.rotate(hit.normal1));
+                    (hit.entity, hit.time_of_impact, normal)
+                })
+        } else {
+            spatial_query
+                .cast_ray(
+                    projectile.position,
+                    direction,
+                    distance,
+                    true,
+                    projectile.query_filter.clone(),
+                )
+                .map(|hit| (hit.entity, hit.time_of_impact, hit.normal))
+        };
+
+        if let Some((hit_entity, time_of_impact, normal)) = hit {
+            // Approximate the time of the impact within the step by the fraction of the distance travelled
+            let fraction = time_of_impact / distance;
+            let point = projectile.position + direction * time_of_impact;
+            let velocity = projectile.velocity + gravity * dt.0 * fraction;
+
+            hit_events.send(ProjectileHit {
+                projectile: entity,
+                entity: hit_entity,
+                point,
+                normal,
+                velocity,
+            });
+
+            if projectile.despawn_on_hit {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+
+            // Stop at the point of impact
+            projectile.position = point;
+            projectile.velocity = velocity;
+            commands.entity(entity).remove::<Projectile>();
+        } else {
+            projectile.position += displacement;
+            projectile.velocity += gravity * dt.0;
+            projectile.lifetime += dt.0;
+
+            if projectile.lifetime >= projectile.max_lifetime {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+        }
+
+        if let Some(mut transform) = transform {
+            #[cfg(feature = "2d")]
+            {
+                transform.translation =
+                    projectile.position.as_f32().extend(transform.translation.z);
+            }
+            #[cfg(feature = "3d")]
+            {
+                transform.translation = projectile.position.as_f32();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn projectiles_hit_colliders_in_their_path() {
+        let mut app = create_app();
+
+        let wall = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Position(Vector::X * 20.0),
+                #[cfg(feature = "2d")]
+                Collider::cuboid(0.1, 10.0),
+                #[cfg(feature = "3d")]
+                Collider::cuboid(0.1, 10.0, 10.0),
+            ))
+            .id();
+
+        // Fast enough to pass the whole wall in a single step
+        let projectile = app
+            .world
+            .spawn(Projectile::new(Vector::ZERO, Vector::X * 300.0).with_gravity_scale(0.0))
+            .id();
+
+        let mut reader = app.world.resource::<Events<ProjectileHit>>().get_reader();
+        let mut hits = vec![];
+
+        for _ in 0..10 {
+            app.step_physics(1);
+            let events = app.world.resource::<Events<ProjectileHit>>();
+            hits.extend(reader.iter(events).copied());
+        }
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].projectile, projectile);
+        assert_eq!(hits[0].entity, wall);
+        assert_relative_eq!(hits[0].point.x, 19.9, epsilon = 0.01);
+        assert!(app.world.get_entity(projectile).is_none());
+    }
+}
@@ -28,7 +28,7 @@ use crate::prelude::*;
 #[derive(Clone)]
 pub struct SpatialQueryFilter {
     /// Specifies which [collision groups](CollisionLayers) will be included in a [spatial query](crate::spatial_query).
-    pub masks: u32,
+    pub masks: u64,
     /// Entities that will not be included in [spatial queries](crate::spatial_query).
     pub excluded_entities: HashSet<Entity>,
 }
@@ -36,7 +36,7 @@ pub struct SpatialQueryFilter {
 impl Default for SpatialQueryFilter {
     fn default() -> Self {
         Self {
-            masks: 0xffff_ffff,
+            masks: u64::MAX,
             excluded_entities: default(),
         }
     }
@@ -50,7 +50,7 @@ impl SpatialQueryFilter {
 
     /// Sets the masks of the filter configuration using a bitmask. Colliders with the corresponding
     /// [collision group](CollisionLayers) will be included in the [spatial query](crate::spatial_query).
-    pub fn with_masks_from_bits(mut self, masks: u32) -> Self {
+    pub fn with_masks_from_bits(mut self, masks: u64) -> Self {
         self.masks = masks;
         self
     }
@@ -77,8 +77,7 @@ impl SpatialQueryFilter {
     /// filter configuration.
     pub fn test(&self, entity: Entity, layers: CollisionLayers) -> bool {
         !self.excluded_entities.contains(&entity)
-            && CollisionLayers::from_bits(0xffff_ffff, self.masks).interacts_with(
-                CollisionLayers::from_bits(layers.groups_bits(), 0xffff_ffff),
-            )
+            && CollisionLayers::from_bits(u64::MAX, self.masks)
+                .interacts_with(CollisionLayers::from_bits(layers.groups_bits(), u64::MAX))
     }
 }
@@ -372,3 +372,39 @@ impl MapEntities for RayHitData {
         self.entity = entity_mapper.get_or_reserve(self.entity);
     }
 }
+
+/// A ray cast as part of a batch using [`SpatialQuery::cast_ray_batch`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueryRay {
+    /// Where the ray is cast from.
+    pub origin: Vector,
+    /// What direction the ray is cast in.
+    pub direction: Vector,
+    /// The maximum distance that the ray can travel.
+    pub max_time_of_impact: Scalar,
+    /// If true and the ray origin is inside of a collider, the hit point will be the ray origin itself.
+    /// Otherwise, the collider will be treated as hollow, and the hit point will be at the collider's boundary.
+    pub solid: bool,
+}
+
+impl QueryRay {
+    /// Creates a new solid [`QueryRay`] with the given origin, direction and maximum time of impact.
+    pub fn new(origin: Vector, direction: Vector, max_time_of_impact: Scalar) -> Self {
+        Self {
+            origin,
+            direction,
+            max_time_of_impact,
+            solid: true,
+        }
+    }
+
+    /// Sets if the ray treats [colliders](Collider) as solid.
+    ///
+    /// If `solid` is true, the point of intersection will be the ray origin itself.\
+    /// If `solid` is false, the collider will be considered to have no interior, and the point of intersection
+    /// will be at the collider shape's boundary.
+    pub fn with_solidness(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+}
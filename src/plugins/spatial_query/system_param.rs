@@ -6,15 +6,20 @@ use bevy::{ecs::system::SystemParam, prelude::*};
 /// ## Methods
 ///
 /// - [Ray casting](spatial_query#ray-casting): [`cast_ray`](SpatialQuery#method.cast_ray),
-/// [`ray_hits`](SpatialQuery#method.ray_hits), [`ray_hits_callback`](SpatialQuery#method.ray_hits_callback)
+/// [`cast_ray_batch`](SpatialQuery#method.cast_ray_batch), [`cast_ray_and_continue`](SpatialQuery#method.cast_ray_and_continue),
+/// [`ray_hits`](SpatialQuery#method.ray_hits), [`ray_hits_callback`](SpatialQuery#method.ray_hits_callback),
+/// [`cast_ray_from_camera`](SpatialQuery#method.cast_ray_from_camera)
 /// - [Shape casting](spatial_query#shape-casting): [`cast_shape`](SpatialQuery#method.cast_shape),
 /// [`shape_hits`](SpatialQuery#method.shape_hits), [`shape_hits_callback`](SpatialQuery#method.shape_hits_callback)
+/// - [Trajectory prediction](TrajectoryQuery): [`predict_trajectory`](SpatialQuery#method.predict_trajectory)
 /// - [Point projection](spatial_query#point-projection): [`project_point`](SpatialQuery#method.project_point)
 /// - [Intersection tests](spatial_query#intersection-tests)
 ///     - Point intersections: [`point_intersections`](SpatialQuery#method.point_intersections),
 /// [`point_intersections_callback`](SpatialQuery#method.point_intersections_callback)
 ///     - AABB intersections: [`aabb_intersections_with_aabb`](SpatialQuery#method.aabb_intersections_with_aabb),
 /// [`aabb_intersections_with_aabb_callback`](SpatialQuery#method.aabb_intersections_with_aabb_callback)
+///     - Sphere intersections: [`sphere_intersections`](SpatialQuery#method.sphere_intersections),
+/// [`sphere_intersections_callback`](SpatialQuery#method.sphere_intersections_callback)
 ///     - Shape intersections: [`shape_intersections`](SpatialQuery#method.shape_intersections)
 /// [`shape_intersections_callback`](SpatialQuery#method.shape_intersections_callback)
 ///
@@ -133,6 +138,147 @@ impl<'w, 's> SpatialQuery<'w, 's> {
             .cast_ray(origin, direction, max_time_of_impact, solid, query_filter)
     }
 
+    /// Casts a [ray](spatial_query#ray-casting) from the given position in the viewport of a camera,
+    /// like the cursor position, and computes the closest [hit](RayHitData) with a collider.
+    /// If the position can't be converted into a ray or there are no hits, `None` is returned.
+    ///
+    /// The ray is constructed using `Camera::viewport_to_world`, so it works for both perspective and
+    /// orthographic cameras. The point that was hit can be computed using the camera ray and
+    /// [`RayHitData::time_of_impact`].
+    ///
+    /// This requires the `camera-raycast` feature.
+    ///
+    /// ## Arguments
+    ///
+    /// - `camera`: The camera that the ray is cast from.
+    /// - `camera_transform`: The `GlobalTransform` of the camera.
+    /// - `viewport_position`: The position in the viewport of the camera, in logical pixels.
+    /// - `max_time_of_impact`: The maximum distance that the ray can travel.
+    /// - `solid`: If true and the ray origin is inside of a collider, the hit point will be the ray origin itself.
+    /// Otherwise, the collider will be treated as hollow, and the hit point will be at the collider's boundary.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::{prelude::*, window::PrimaryWindow};
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// # #[cfg(feature = "camera-raycast")]
+    /// fn print_hovered(
+    ///     windows: Query<&Window, With<PrimaryWindow>>,
+    ///     cameras: Query<(&Camera, &GlobalTransform)>,
+    ///     spatial_query: SpatialQuery,
+    /// ) {
+    ///     let Some(cursor) = windows.get_single().ok().and_then(|w| w.cursor_position()) else {
+    ///         return;
+    ///     };
+    ///     let (camera, camera_transform) = cameras.single();
+    ///
+    ///     if let Some(hit) = spatial_query.cast_ray_from_camera(
+    ///         camera,
+    ///         camera_transform,
+    ///         cursor,
+    ///         100.0,
+    ///         true,
+    ///         SpatialQueryFilter::default(),
+    ///     ) {
+    ///         println!("Hovering over {:?}", hit.entity);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// In 2D, the ray points into the screen, so colliders at the given position are hit at a
+    /// time of impact of zero, and `max_time_of_impact` and `solid` have no effect.
+    #[cfg(feature = "camera-raycast")]
+    #[cfg_attr(feature = "2d", allow(unused_variables))]
+    pub fn cast_ray_from_camera(
+        &self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        viewport_position: Vec2,
+        max_time_of_impact: Scalar,
+        solid: bool,
+        query_filter: SpatialQueryFilter,
+    ) -> Option<RayHitData> {
+        #[cfg(feature = "2d")]
+        {
+            let point = camera
+                .viewport_to_world_2d(camera_transform, viewport_position)?
+                .adjust_precision();
+            let mut hit = None;
+            self.query_pipeline
+                .point_intersections_callback(point, query_filter, |entity| {
+                    hit = Some(RayHitData {
+                        entity,
+                        time_of_impact: 0.0,
+                        normal: Vector::ZERO,
+                    });
+                    false
+                });
+            hit
+        }
+        #[cfg(feature = "3d")]
+        {
+            let ray = camera.viewport_to_world(camera_transform, viewport_position)?;
+            self.cast_ray(
+                ray.origin.adjust_precision(),
+                ray.direction.adjust_precision(),
+                max_time_of_impact,
+                solid,
+                query_filter,
+            )
+        }
+    }
+
+    /// Casts a batch of [rays](spatial_query#ray-casting) and computes the closest [hit](RayHitData)
+    /// with a collider for each ray. The results are in the same order as the rays, and rays that don't hit
+    /// anything have a result of `None`.
+    ///
+    /// This is useful for systems that cast hundreds of rays per frame, like AI vision cones or audio occlusion.
+    /// With the `parallel` feature and without `single-threaded`, the rays are split between the threads of the `ComputeTaskPool`.
+    ///
+    /// ## Arguments
+    ///
+    /// - `rays`: The [rays](QueryRay) to cast.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// # #[cfg(all(feature = "3d", feature = "f32"))]
+    /// fn vision_cone(spatial_query: SpatialQuery) {
+    ///     // Cast 100 rays in a cone in front of the origin
+    ///     let rays: Vec<QueryRay> = (0..100)
+    ///         .map(|i| {
+    ///             let angle = (i as f32 / 99.0 - 0.5) * std::f32::consts::FRAC_PI_2;
+    ///             let direction = Quat::from_rotation_y(angle) * Vec3::NEG_Z;
+    ///             QueryRay::new(Vec3::ZERO, direction, 50.0)
+    ///         })
+    ///         .collect();
+    ///
+    ///     let hits = spatial_query.cast_ray_batch(&rays, SpatialQueryFilter::default());
+    ///     let visible = hits.iter().flatten().count();
+    ///     println!("{visible} rays hit something");
+    /// }
+    /// ```
+    pub fn cast_ray_batch(
+        &self,
+        rays: &[QueryRay],
+        query_filter: SpatialQueryFilter,
+    ) -> Vec<Option<RayHitData>> {
+        self.query_pipeline.cast_ray_batch(rays, query_filter)
+    }
+
     /// Casts a [ray](spatial_query#ray-casting) and computes all [hits](RayHitData) until `max_hits` is reached.
     ///
     /// Note that the order of the results is not guaranteed, and if there are more hits than `max_hits`,
@@ -260,6 +406,73 @@ impl<'w, 's> SpatialQuery<'w, 's> {
         )
     }
 
+    /// Casts a piercing [ray](spatial_query#ray-casting) that passes through colliders until `callback` returns false.
+    /// The `callback` is called for each [hit](RayHitData) in the order of the time of impact, and it decides
+    /// whether the ray continues through the hit collider.
+    ///
+    /// Returns the hit that stopped the ray, or `None` if the ray passed through all of the hits.
+    ///
+    /// This is useful for things like bullets that pass through glass and foliage, and it is cheaper than
+    /// casting the ray again with a growing list of excluded entities after each hit.
+    ///
+    /// ## Arguments
+    ///
+    /// - `origin`: Where the ray is cast from.
+    /// - `direction`: What direction the ray is cast in.
+    /// - `max_time_of_impact`: The maximum distance that the ray can travel.
+    /// - `solid`: If true and the ray origin is inside of a collider, the hit point will be the ray origin itself.
+    /// Otherwise, the collider will be treated as hollow, and the hit point will be at the collider's boundary.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    /// - `callback`: A callback function called for each hit. Returning true lets the ray continue.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Glass;
+    ///
+    /// # #[cfg(all(feature = "3d", feature = "f32"))]
+    /// fn shoot(spatial_query: SpatialQuery, glass: Query<(), With<Glass>>) {
+    ///     // Cast a ray that passes through glass
+    ///     let blocking_hit = spatial_query.cast_ray_and_continue(
+    ///         Vec3::ZERO,                    // Origin
+    ///         Vec3::X,                       // Direction
+    ///         100.0,                         // Maximum time of impact (travel distance)
+    ///         true,                          // Does the ray treat colliders as "solid"
+    ///         SpatialQueryFilter::default(), // Query filter
+    ///         |hit| glass.contains(hit.entity), // Continue through glass
+    ///     );
+    ///
+    ///     if let Some(hit) = blocking_hit {
+    ///         println!("The ray was stopped by {:?}", hit.entity);
+    ///     }
+    /// }
+    /// ```
+    pub fn cast_ray_and_continue(
+        &self,
+        origin: Vector,
+        direction: Vector,
+        max_time_of_impact: Scalar,
+        solid: bool,
+        query_filter: SpatialQueryFilter,
+        callback: impl FnMut(&RayHitData) -> bool,
+    ) -> Option<RayHitData> {
+        self.query_pipeline.cast_ray_and_continue(
+            origin,
+            direction,
+            max_time_of_impact,
+            solid,
+            query_filter,
+            callback,
+        )
+    }
+
     /// Casts a [shape](spatial_query#shape-casting) with a given rotation and computes the closest [hit](ShapeHits)
     /// with a collider. If there are no hits, `None` is returned.
     ///
@@ -324,6 +537,50 @@ impl<'w, 's> SpatialQuery<'w, 's> {
         )
     }
 
+    /// Predicts a ballistic [trajectory](TrajectoryQuery) and finds the first hit along it by sweeping a ray or
+    /// a shape through the world for each step of the trajectory. The world itself is not modified.
+    ///
+    /// See [`TrajectoryQuery`] for an example.
+    pub fn predict_trajectory(&self, trajectory: &TrajectoryQuery) -> PredictedTrajectory {
+        #[cfg(feature = "2d")]
+        let shape_rotation = 0.0;
+        #[cfg(feature = "3d")]
+        let shape_rotation = Quaternion::IDENTITY;
+
+        trajectory.predict_with(|origin, direction, distance| {
+            if let Some(shape) = &trajectory.shape {
+                self.cast_shape(
+                    shape,
+                    origin,
+                    shape_rotation,
+                    direction,
+                    distance,
+                    false,
+                    trajectory.query_filter.clone(),
+                )
+                .map(|hit| {
+                    // The normal is in the local space of the collider that was hit
+                    let normal = self
+                        .colliders
+                        .get(hit.entity)
+                        .map_or(hit.normal1, |(_, _, rotation, ..)| {
+                            rotation.rotate(hit.normal1)
+                        });
+                    (hit.entity, hit.time_of_impact, normal)
+                })
+            } else {
+                self.cast_ray(
+                    origin,
+                    direction,
+                    distance,
+                    true,
+                    trajectory.query_filter.clone(),
+                )
+                .map(|hit| (hit.entity, hit.time_of_impact, hit.normal))
+            }
+        })
+    }
+
     /// Casts a [shape](spatial_query#shape-casting) with a given rotation and computes computes all [hits](ShapeHitData)
     /// in the order of the time of impact until `max_hits` is reached.
     ///
@@ -657,6 +914,72 @@ impl<'w, 's> SpatialQuery<'w, 's> {
             .aabb_intersections_with_aabb_callback(aabb, callback)
     }
 
+    /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
+    /// that is intersecting a sphere (a circle in 2D) with the given `center` and `radius`.
+    ///
+    /// This is faster than [`shape_intersections`](SpatialQuery#method.shape_intersections) with a ball collider,
+    /// because it only needs to compute the distance from the center to each collider.
+    ///
+    /// ## Arguments
+    ///
+    /// - `center`: The center of the sphere.
+    /// - `radius`: The radius of the sphere.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// # #[cfg(feature = "2d")]
+    /// # use bevy_xpbd_2d::prelude::*;
+    /// # #[cfg(feature = "3d")]
+    /// use bevy_xpbd_3d::prelude::*;
+    ///
+    /// # #[cfg(all(feature = "3d", feature = "f32"))]
+    /// fn print_sphere_intersections(spatial_query: SpatialQuery) {
+    ///     let intersections = spatial_query.sphere_intersections(
+    ///         Vec3::ZERO,                    // Center
+    ///         5.0,                           // Radius
+    ///         SpatialQueryFilter::default(), // Query filter
+    ///     );
+    ///
+    ///     for entity in intersections.iter() {
+    ///         println!("Entity: {:?}", entity);
+    ///     }
+    /// }
+    /// ```
+    pub fn sphere_intersections(
+        &self,
+        center: Vector,
+        radius: Scalar,
+        query_filter: SpatialQueryFilter,
+    ) -> Vec<Entity> {
+        self.query_pipeline
+            .sphere_intersections(center, radius, query_filter)
+    }
+
+    /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
+    /// that is intersecting a sphere (a circle in 2D) with the given `center` and `radius`,
+    /// calling `callback` for each intersection. The search stops when `callback` returns `false`
+    /// or all intersections have been found.
+    ///
+    /// ## Arguments
+    ///
+    /// - `center`: The center of the sphere.
+    /// - `radius`: The radius of the sphere.
+    /// - `query_filter`: A [`SpatialQueryFilter`] that determines which colliders are taken into account in the query.
+    /// - `callback`: A callback function called for each intersection.
+    pub fn sphere_intersections_callback(
+        &self,
+        center: Vector,
+        radius: Scalar,
+        query_filter: SpatialQueryFilter,
+        callback: impl FnMut(Entity) -> bool,
+    ) {
+        self.query_pipeline
+            .sphere_intersections_callback(center, radius, query_filter, callback)
+    }
+
     /// An [intersection test](spatial_query#intersection-tests) that finds all entities with a [`Collider`]
     /// that is intersecting the given `shape` with a given position and rotation.
     ///
@@ -759,3 +1082,57 @@ impl<'w, 's> SpatialQuery<'w, 's> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "camera-raycast")]
+    fn rays_cast_from_camera_hit_colliders_under_cursor() {
+        use crate::{
+            prelude::*,
+            test_utils::*,
+            tests::{create_app, spawn_camera_with_window},
+        };
+        use bevy::{ecs::system::SystemState, prelude::*};
+
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        let camera = spawn_camera_with_window(&mut app);
+
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                Collider::ball(1.0),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        let mut state =
+            SystemState::<(SpatialQuery, Query<(&Camera, &GlobalTransform)>)>::new(&mut app.world);
+        let (spatial_query, cameras) = state.get_mut(&mut app.world);
+        let (camera, camera_transform) = cameras.get(camera).unwrap();
+        let cast = |cursor: Vec2| {
+            spatial_query.cast_ray_from_camera(
+                camera,
+                camera_transform,
+                cursor,
+                100.0,
+                true,
+                SpatialQueryFilter::default(),
+            )
+        };
+
+        // The center of the window is at the origin
+        let hit = cast(Vec2::new(640.0, 360.0)).expect("the ray should hit the ball");
+        assert_eq!(hit.entity, ball);
+        // In 3D, the camera is 10 units away from the origin
+        #[cfg(feature = "3d")]
+        approx::assert_relative_eq!(hit.time_of_impact, 9.0, epsilon = 1e-4);
+
+        // The corner of the window is far away from the ball
+        assert!(cast(Vec2::ZERO).is_none());
+    }
+}
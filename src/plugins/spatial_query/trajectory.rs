@@ -0,0 +1,250 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A ballistic trajectory that can be predicted without simulating the physics world,
+/// for example for drawing aiming arcs or for finding out where an AI's throw would land.
+///
+/// The trajectory is integrated with [gravity](Gravity) and [linear damping](LinearDamping) in the same way as
+/// rigid bodies are, using steps of `time_step` seconds. The prediction matches the simulation most closely
+/// when the time step is the same as the [`SubDeltaTime`] of the simulation.
+///
+/// The trajectory can be predicted without collisions using [`predict`](Self::predict), or with a ray or shape
+/// swept through the world to find the first impact using [`SpatialQuery::predict_trajectory`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::{math::*, prelude::*};
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::{math::*, prelude::*};
+///
+/// fn draw_aiming_arc(spatial_query: SpatialQuery, gravity: Res<Gravity>) {
+///     let trajectory = TrajectoryQuery::new(Vector::ZERO, Vector::X * 10.0 + Vector::Y * 10.0, gravity.0)
+///         .with_duration(3.0)
+///         .with_shape(Collider::ball(0.1));
+///
+///     let prediction = spatial_query.predict_trajectory(&trajectory);
+///     for segment in prediction.points.windows(2) {
+///         println!("Line from {} to {}", segment[0], segment[1]);
+///     }
+///     if let Some(hit) = prediction.hit {
+///         println!("The projectile would hit {:?} at {} after {} seconds", hit.entity, hit.point, hit.time);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TrajectoryQuery {
+    /// The initial position in world space.
+    pub position: Vector,
+    /// The initial linear velocity.
+    pub velocity: Vector,
+    /// The gravitational acceleration. This usually comes from the [`Gravity`] resource,
+    /// multiplied by the [`GravityScale`] of the body.
+    pub gravity: Vector,
+    /// The linear damping coefficient. See [`LinearDamping`].
+    pub linear_damping: Scalar,
+    /// How many seconds of the trajectory are predicted.
+    pub duration: Scalar,
+    /// The length of the steps used for integrating the trajectory in seconds.
+    /// Smaller steps are more accurate, but produce more points and sweeps.
+    pub time_step: Scalar,
+    /// The shape swept along the trajectory by [`SpatialQuery::predict_trajectory`]. If `None`, a ray is cast instead.
+    pub shape: Option<Collider>,
+    /// Rules that determine which colliders can be hit by [`SpatialQuery::predict_trajectory`].
+    pub query_filter: SpatialQueryFilter,
+}
+
+impl TrajectoryQuery {
+    /// Creates a new [`TrajectoryQuery`] with the given initial position and velocity and gravitational acceleration.
+    ///
+    /// By default, one second of the trajectory is predicted in steps of 1/60th of a second.
+    pub fn new(position: Vector, velocity: Vector, gravity: Vector) -> Self {
+        Self {
+            position,
+            velocity,
+            gravity,
+            linear_damping: 0.0,
+            duration: 1.0,
+            time_step: 1.0 / 60.0,
+            shape: None,
+            query_filter: SpatialQueryFilter::default(),
+        }
+    }
+
+    /// Sets the linear damping coefficient.
+    pub fn with_linear_damping(self, linear_damping: Scalar) -> Self {
+        Self {
+            linear_damping,
+            ..self
+        }
+    }
+
+    /// Sets how many seconds of the trajectory are predicted.
+    pub fn with_duration(self, duration: Scalar) -> Self {
+        Self { duration, ..self }
+    }
+
+    /// Sets the length of the steps used for integrating the trajectory in seconds.
+    pub fn with_time_step(self, time_step: Scalar) -> Self {
+        Self { time_step, ..self }
+    }
+
+    /// Sets the shape swept along the trajectory.
+    pub fn with_shape(self, shape: Collider) -> Self {
+        Self {
+            shape: Some(shape),
+            ..self
+        }
+    }
+
+    /// Sets the rules that determine which colliders can be hit.
+    pub fn with_query_filter(self, query_filter: SpatialQueryFilter) -> Self {
+        Self {
+            query_filter,
+            ..self
+        }
+    }
+
+    /// Predicts the trajectory without checking for hits.
+    pub fn predict(&self) -> PredictedTrajectory {
+        self.predict_with(|_, _, _| None)
+    }
+
+    /// Integrates the trajectory, calling `sweep` with the start, direction and length of each step.
+    /// The sweep returns the hit entity, the distance along the step and the world-space normal of a hit.
+    pub(crate) fn predict_with(
+        &self,
+        mut sweep: impl FnMut(Vector, Vector, Scalar) -> Option<(Entity, Scalar, Vector)>,
+    ) -> PredictedTrajectory {
+        let mut position = self.position;
+        let mut velocity = self.velocity;
+        let mut time = 0.0;
+        let mut points = vec![position];
+
+        if self.time_step <= 0.0 {
+            return PredictedTrajectory { points, hit: None };
+        }
+
+        // Remainders that are much shorter than a step are caused by rounding errors and ignored
+        while time < self.duration - self.time_step * 1e-3 {
+            let dt = self.time_step.min(self.duration - time);
+
+            // Integrate in the same order as the integrator
+            let previous_velocity = velocity;
+            velocity *= 1.0 / (1.0 + dt * self.linear_damping);
+            velocity += self.gravity * dt;
+            let displacement = velocity * dt;
+            let distance = displacement.length();
+            let direction = displacement.normalize_or_zero();
+
+            if distance > Scalar::EPSILON {
+                if let Some((entity, time_of_impact, normal)) = sweep(position, direction, distance)
+                {
+                    // Approximate the time of the impact within the step by the fraction of the distance travelled
+                    let fraction = time_of_impact / distance;
+                    let point = position + direction * time_of_impact;
+                    points.push(point);
+                    return PredictedTrajectory {
+                        points,
+                        hit: Some(TrajectoryHit {
+                            entity,
+                            point,
+                            normal,
+                            velocity: previous_velocity.lerp(velocity, fraction),
+                            time: time + dt * fraction,
+                        }),
+                    };
+                }
+            }
+
+            position += displacement;
+            time += dt;
+            points.push(position);
+        }
+
+        PredictedTrajectory { points, hit: None }
+    }
+}
+
+/// The result of predicting a [`TrajectoryQuery`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PredictedTrajectory {
+    /// The points along the trajectory in world space, starting from the initial position and
+    /// including a point for each step. If something was hit, the last point is the point of impact.
+    pub points: Vec<Vector>,
+    /// The first hit along the trajectory, if any.
+    pub hit: Option<TrajectoryHit>,
+}
+
+/// The first hit along a [`PredictedTrajectory`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrajectoryHit {
+    /// The entity of the collider that was hit.
+    pub entity: Entity,
+    /// The position of the ray or shape at the time of impact in world space.
+    pub point: Vector,
+    /// The world-space normal of the surface that was hit.
+    pub normal: Vector,
+    /// The velocity at the time of impact.
+    pub velocity: Vector,
+    /// The time of the impact in seconds since the start of the trajectory.
+    pub time: Scalar,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::ecs::system::SystemState;
+
+    #[test]
+    fn trajectory_prediction_finds_first_impact() {
+        let trajectory = TrajectoryQuery::new(Vector::ZERO, Vector::X * 10.0, Vector::NEG_Y * 10.0)
+            .with_duration(1.0)
+            .with_time_step(1.0 / 60.0);
+
+        // Without collisions, the trajectory follows the ballistic arc for the whole duration
+        let prediction = trajectory.predict();
+        assert!(prediction.hit.is_none());
+        assert_eq!(prediction.points.len(), 61);
+        let end = *prediction.points.last().unwrap();
+        assert_relative_eq!(end.x, 10.0, epsilon = 1e-3);
+        assert_relative_eq!(end.y, -5.0, epsilon = 0.1);
+
+        let mut app = create_app();
+        #[cfg(feature = "2d")]
+        let wall = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(1.0, 100.0),
+                Position(Vector::X * 5.0),
+            ))
+            .id();
+        #[cfg(feature = "3d")]
+        let wall = app
+            .world
+            .spawn((
+                RigidBody::Static,
+                Collider::cuboid(1.0, 100.0, 100.0),
+                Position(Vector::X * 5.0),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        let mut state = SystemState::<SpatialQuery>::new(&mut app.world);
+        let spatial_query = state.get_mut(&mut app.world);
+        let prediction = spatial_query.predict_trajectory(&trajectory);
+
+        // The wall is hit after travelling 4.5 units horizontally
+        let hit = prediction.hit.expect("the trajectory should hit the wall");
+        assert_eq!(hit.entity, wall);
+        assert_relative_eq!(hit.point.x, 4.5, epsilon = 1e-3);
+        assert_relative_eq!(hit.normal, Vector::NEG_X, epsilon = 1e-3);
+        assert_relative_eq!(hit.time, 0.45, epsilon = 1e-3);
+        assert_eq!(*prediction.points.last().unwrap(), hit.point);
+    }
+}
@@ -0,0 +1,161 @@
+//! Computes a hash of the physics state for detecting desyncs in lockstep games.
+//!
+//! See [`StateHashPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Computes a deterministic hash of the positions, rotations and velocities of all non-static
+/// [rigid bodies](RigidBody) after each physics step and stores it in the [`PhysicsStateHash`] resource.
+///
+/// Lockstep multiplayer games can send the hash to their peers and compare it to the peers' hashes for the same step.
+/// If the hashes differ, the simulations have diverged, and a full snapshot of the state only needs to be sent then.
+///
+/// The hash depends on the exact bits of the values, so it only matches when the simulations are bit-for-bit identical.
+/// It doesn't depend on the order of the bodies or their entity IDs, so it can be compared between
+/// machines that spawn entities in a different order.
+///
+/// The hash is computed after [`PhysicsStepSet::SpatialQuery`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn send_state_hash(state_hash: Res<PhysicsStateHash>) {
+///     if state_hash.is_changed() {
+///         // Send the hash to the other players
+///         println!("Step {}: {:x}", state_hash.step, state_hash.hash);
+///     }
+/// }
+/// ```
+pub struct StateHashPlugin;
+
+impl Plugin for StateHashPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsStateHash>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(update_state_hash.after(PhysicsStepSet::SpatialQuery));
+    }
+}
+
+/// A deterministic hash of the physics state computed by the [`StateHashPlugin`] after each physics step.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PhysicsStateHash {
+    /// The hash of the positions, rotations and velocities of all non-static rigid bodies.
+    pub hash: u64,
+    /// The number of physics steps that have been hashed, starting at 1 after the first step.
+    pub step: u64,
+}
+
+/// The offset basis of the 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function) hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A 64-bit FNV-1a hasher. Unlike the hashers in the standard library, its output is guaranteed
+/// to stay the same across platforms and Rust versions.
+struct StateHasher(u64);
+
+impl StateHasher {
+    fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    fn write_scalar(&mut self, value: Scalar) {
+        for byte in value.to_bits().to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_scalars(&mut self, values: &[Scalar]) {
+        for value in values {
+            self.write_scalar(*value);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_state_hash(
+    bodies: Query<(
+        &RigidBody,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+        &AngularVelocity,
+    )>,
+    mut state_hash: ResMut<PhysicsStateHash>,
+) {
+    // The hashes of the bodies are added together so that the order of the bodies doesn't matter
+    let mut hash: u64 = 0;
+
+    for (rb, position, rotation, lin_vel, ang_vel) in &bodies {
+        if rb.is_static() {
+            continue;
+        }
+
+        let mut hasher = StateHasher::new();
+        hasher.write_scalars(&position.0.to_array());
+        #[cfg(feature = "2d")]
+        hasher.write_scalars(&[rotation.cos(), rotation.sin()]);
+        #[cfg(feature = "3d")]
+        hasher.write_scalars(&rotation.0.to_array());
+        hasher.write_scalars(&lin_vel.0.to_array());
+        #[cfg(feature = "2d")]
+        hasher.write_scalar(ang_vel.0);
+        #[cfg(feature = "3d")]
+        hasher.write_scalars(&ang_vel.0.to_array());
+
+        hash = hash.wrapping_add(hasher.0);
+    }
+
+    state_hash.hash = hash;
+    state_hash.step += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::prelude::*;
+
+    #[test]
+    fn state_hash_detects_diverging_simulations() {
+        fn run(spawn_reversed: bool, nudge: Scalar) -> PhysicsStateHash {
+            let mut app = create_app();
+            app.add_plugins(StateHashPlugin);
+            app.insert_resource(Gravity(Vector::NEG_Y * 9.81));
+
+            let mut offsets = vec![0.0, 3.0, 6.0];
+            if spawn_reversed {
+                offsets.reverse();
+            }
+            for x in offsets {
+                app.world.spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::X * (x + nudge)),
+                    Collider::ball(0.5),
+                ));
+            }
+
+            app.step_physics(30);
+
+            *app.world.resource::<PhysicsStateHash>()
+        }
+
+        let hash = run(false, 0.0);
+        assert_eq!(hash.step, 30);
+        assert_ne!(hash.hash, 0);
+
+        // The spawn order doesn't matter, but any difference in the state does
+        assert_eq!(run(true, 0.0), hash);
+        assert_ne!(run(false, 0.001).hash, hash.hash);
+    }
+}
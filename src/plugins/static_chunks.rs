@@ -0,0 +1,309 @@
+//! Spawns and despawns large batches of static colliders in chunks for streaming worlds.
+//!
+//! See [`StaticChunkPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::system::Command, hierarchy::despawn_with_children_recursive, prelude::*, utils::HashMap,
+};
+
+/// The coordinates of a chunk of a streamed world.
+#[cfg(feature = "2d")]
+pub type ChunkCoordinates = IVec2;
+
+/// The coordinates of a chunk of a streamed world.
+#[cfg(feature = "3d")]
+pub type ChunkCoordinates = IVec3;
+
+/// A plugin for streaming worlds that spawn and despawn large batches of [static](RigidBody::Static)
+/// colliders at a time, for example when the player moves between chunks of an open world.
+///
+/// Chunks are spawned and despawned using the [`StaticChunkCommandsExt`] trait. All colliders of a chunk
+/// are spawned with a single batch, and the broad phase registers them with a single rebuild of its
+/// static collider storage, so loading thousands of colliders doesn't add them one at a time.
+///
+/// Once all colliders of a chunk have been added to the broad phase, a [`StaticChunkRegistered`] event
+/// is sent. This can be used for waiting until the ground has loaded before spawning dynamic bodies on it.
+///
+/// The chunks are stored in the [`StaticChunks`] resource.
+pub struct StaticChunkPlugin;
+
+impl Plugin for StaticChunkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StaticChunks>()
+            .add_event::<StaticChunkRegistered>()
+            .register_type::<StaticChunkMember>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                report_registered_chunks
+                    .after(PhysicsStepSet::BroadPhase)
+                    .before(PhysicsStepSet::Substeps),
+            );
+    }
+}
+
+/// A resource that stores the chunks spawned using [`StaticChunkCommandsExt`].
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct StaticChunks {
+    chunks: HashMap<ChunkCoordinates, StaticChunk>,
+}
+
+impl StaticChunks {
+    /// Returns the chunk at the given coordinates, if it exists.
+    pub fn get(&self, chunk: ChunkCoordinates) -> Option<&StaticChunk> {
+        self.chunks.get(&chunk)
+    }
+
+    /// Returns true if a chunk exists at the given coordinates.
+    pub fn contains(&self, chunk: ChunkCoordinates) -> bool {
+        self.chunks.contains_key(&chunk)
+    }
+
+    /// Returns true if all colliders of the chunk at the given coordinates have been added to the broad phase.
+    pub fn is_registered(&self, chunk: ChunkCoordinates) -> bool {
+        self.chunks
+            .get(&chunk)
+            .map_or(false, |chunk| chunk.registered)
+    }
+
+    /// Returns an iterator over the coordinates and chunks.
+    pub fn iter(&self) -> impl Iterator<Item = (ChunkCoordinates, &StaticChunk)> {
+        self.chunks
+            .iter()
+            .map(|(coordinates, chunk)| (*coordinates, chunk))
+    }
+}
+
+/// A chunk of [static](RigidBody::Static) colliders stored in [`StaticChunks`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StaticChunk {
+    entities: Vec<Entity>,
+    registered: bool,
+}
+
+impl StaticChunk {
+    /// Returns the collider entities of the chunk.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+
+    /// Returns true if all colliders of the chunk have been added to the broad phase.
+    pub fn is_registered(&self) -> bool {
+        self.registered
+    }
+}
+
+/// A component for the collider entities of a chunk spawned using [`StaticChunkCommandsExt`].
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct StaticChunkMember(pub ChunkCoordinates);
+
+/// An event that is sent when all colliders of a chunk have been added to the broad phase.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticChunkRegistered {
+    /// The coordinates of the chunk.
+    pub chunk: ChunkCoordinates,
+    /// The number of colliders in the chunk.
+    pub collider_count: usize,
+}
+
+/// A [`Command`] that spawns a chunk of [static](RigidBody::Static) colliders in a single batch.
+/// If the chunk already exists, it is replaced.
+///
+/// Usually, this is used through [`StaticChunkCommandsExt::spawn_static_chunk`].
+pub struct SpawnStaticChunk {
+    /// The coordinates of the chunk.
+    pub chunk: ChunkCoordinates,
+    /// The colliders of the chunk and their world-space positions and rotations.
+    pub colliders: Vec<(Collider, Position, Rotation)>,
+}
+
+impl Command for SpawnStaticChunk {
+    fn apply(self, world: &mut World) {
+        DespawnStaticChunk { chunk: self.chunk }.apply(world);
+
+        let chunk = self.chunk;
+        let entities =
+            world
+                .spawn_batch(self.colliders.into_iter().map(
+                    move |(collider, position, rotation)| {
+                        (
+                            RigidBody::Static,
+                            collider,
+                            position,
+                            rotation,
+                            StaticChunkMember(chunk),
+                        )
+                    },
+                ))
+                .collect();
+
+        world
+            .get_resource_or_insert_with(StaticChunks::default)
+            .chunks
+            .insert(
+                chunk,
+                StaticChunk {
+                    entities,
+                    registered: false,
+                },
+            );
+    }
+}
+
+/// A [`Command`] that despawns all colliders of a chunk.
+///
+/// Usually, this is used through [`StaticChunkCommandsExt::despawn_static_chunk`].
+pub struct DespawnStaticChunk {
+    /// The coordinates of the chunk.
+    pub chunk: ChunkCoordinates,
+}
+
+impl Command for DespawnStaticChunk {
+    fn apply(self, world: &mut World) {
+        let Some(chunk) = world
+            .get_resource_mut::<StaticChunks>()
+            .and_then(|mut chunks| chunks.chunks.remove(&self.chunk))
+        else {
+            return;
+        };
+
+        for entity in chunk.entities {
+            despawn_with_children_recursive(world, entity);
+        }
+    }
+}
+
+/// An extension trait for spawning and despawning chunks of [static](RigidBody::Static) colliders
+/// using `Commands`. Requires the [`StaticChunkPlugin`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// # #[cfg(feature = "3d")]
+/// fn load_chunk(mut commands: Commands) {
+///     let chunk = IVec3::new(4, 0, -2);
+///     let colliders = (0..1000).map(|i| {
+///         (
+///             Collider::cuboid(1.0, 1.0, 1.0),
+///             Position(Vector::new(i as Scalar * 2.0, 0.0, 0.0)),
+///             Rotation::default(),
+///         )
+///     });
+///     commands.spawn_static_chunk(chunk, colliders);
+/// }
+///
+/// # #[cfg(feature = "3d")]
+/// fn unload_chunk(mut commands: Commands) {
+///     commands.despawn_static_chunk(IVec3::new(4, 0, -2));
+/// }
+///
+/// fn on_chunk_loaded(mut events: EventReader<StaticChunkRegistered>) {
+///     for event in events.iter() {
+///         println!("Chunk {} has {} colliders", event.chunk, event.collider_count);
+///     }
+/// }
+/// ```
+pub trait StaticChunkCommandsExt {
+    /// Spawns a chunk of [static](RigidBody::Static) colliders with the given world-space positions and rotations
+    /// in a single batch. If the chunk already exists, it is replaced.
+    fn spawn_static_chunk(
+        &mut self,
+        chunk: ChunkCoordinates,
+        colliders: impl IntoIterator<Item = (Collider, Position, Rotation)>,
+    );
+
+    /// Despawns all colliders of the given chunk.
+    fn despawn_static_chunk(&mut self, chunk: ChunkCoordinates);
+}
+
+impl StaticChunkCommandsExt for Commands<'_, '_> {
+    fn spawn_static_chunk(
+        &mut self,
+        chunk: ChunkCoordinates,
+        colliders: impl IntoIterator<Item = (Collider, Position, Rotation)>,
+    ) {
+        self.add(SpawnStaticChunk {
+            chunk,
+            colliders: colliders.into_iter().collect(),
+        });
+    }
+
+    fn despawn_static_chunk(&mut self, chunk: ChunkCoordinates) {
+        self.add(DespawnStaticChunk { chunk });
+    }
+}
+
+/// Sends [`StaticChunkRegistered`] events for chunks whose colliders have all been added to the broad phase.
+fn report_registered_chunks(
+    mut chunks: ResMut<StaticChunks>,
+    aabbs: Query<(), With<ColliderAabb>>,
+    mut registered_ev_writer: EventWriter<StaticChunkRegistered>,
+) {
+    if chunks.chunks.values().all(|chunk| chunk.registered) {
+        return;
+    }
+
+    for (coordinates, chunk) in chunks.chunks.iter_mut() {
+        if chunk.registered || !chunk.entities.iter().all(|entity| aabbs.contains(*entity)) {
+            continue;
+        }
+
+        chunk.registered = true;
+        registered_ev_writer.send(StaticChunkRegistered {
+            chunk: *coordinates,
+            collider_count: chunk.entities.len(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::{ecs::system::CommandQueue, prelude::*};
+
+    #[test]
+    fn static_chunks_are_registered_and_despawned_in_bulk() {
+        let mut app = create_app();
+        app.add_plugins(StaticChunkPlugin);
+
+        let chunk = ChunkCoordinates::ONE;
+        let colliders = (0..100).map(|i| {
+            (
+                Collider::ball(0.5),
+                Position(Vector::X * i as Scalar * 2.0),
+                Rotation::default(),
+            )
+        });
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &app.world).spawn_static_chunk(chunk, colliders);
+        queue.apply(&mut app.world);
+
+        app.step_physics(1);
+
+        let events = app.world.resource::<Events<StaticChunkRegistered>>();
+        let mut reader = events.get_reader();
+        let registered = reader.iter(events).collect::<Vec<_>>();
+        assert_eq!(registered.len(), 1);
+        assert_eq!(registered[0].chunk, chunk);
+        assert_eq!(registered[0].collider_count, 100);
+        assert!(app.world.resource::<StaticChunks>().is_registered(chunk));
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &app.world).despawn_static_chunk(chunk);
+        queue.apply(&mut app.world);
+
+        assert!(!app.world.resource::<StaticChunks>().contains(chunk));
+        let mut members = app.world.query::<&StaticChunkMember>();
+        assert_eq!(members.iter(&app.world).count(), 0);
+    }
+}
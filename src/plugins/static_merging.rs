@@ -0,0 +1,347 @@
+//! Merges large numbers of small static colliders into a few larger colliders.
+//!
+//! See [`merge_static_colliders`] and [`StaticMergingCommandsExt`].
+
+use crate::{plugins::region_streaming::region_at, prelude::*};
+use bevy::{ecs::system::Command, prelude::*, utils::HashMap};
+use parry::shape::TypedShape;
+
+/// Merges [static](RigidBody::Static) colliders into one collider per cell of a grid, which reduces the number
+/// of colliders that the broad phase has to handle for levels that are built from thousands of small pieces.
+///
+/// The colliders are assigned to cells of the given size based on their [`Position`], using the same
+/// [`ChunkCoordinates`] as the [`StaticChunkPlugin`]. In each cell, triangle meshes are merged into a single
+/// triangle mesh, and other shapes are merged into a [compound](Collider::compound) collider. The merged colliders
+/// are positioned at the minimum corner of their cell. Polylines, heightfields and compound shapes are returned
+/// as they are, as well as colliders that are the only ones of their kind in their cell.
+///
+/// This can be used offline, for example in an asset processing step, or when loading a level. The result can be
+/// spawned directly with [`StaticChunkCommandsExt::spawn_static_chunk`]. For merging colliders that have already
+/// been spawned, use [`StaticMergingCommandsExt::merge_static_colliders`] instead.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn load_level(mut commands: Commands) {
+///     // A floor made of 10,000 small tiles
+///     let tiles = (0..10_000).map(|i| {
+///         (
+///             Collider::ball(0.5),
+///             Position(Vector::X * i as Scalar),
+///             Rotation::default(),
+///         )
+///     });
+///
+///     // The tiles are merged into 625 colliders
+///     for (collider, position, rotation) in merge_static_colliders(tiles, 16.0) {
+///         commands.spawn((RigidBody::Static, collider, position, rotation));
+///     }
+/// }
+/// ```
+pub fn merge_static_colliders(
+    colliders: impl IntoIterator<Item = (Collider, Position, Rotation)>,
+    cell_size: Scalar,
+) -> Vec<(Collider, Position, Rotation)> {
+    let mut cells: HashMap<(ChunkCoordinates, MergeKind), Vec<(Collider, Position, Rotation)>> =
+        HashMap::default();
+    let mut merged = vec![];
+
+    for (collider, position, rotation) in colliders {
+        let kind = merge_kind(&collider);
+        if kind == MergeKind::None {
+            merged.push((collider, position, rotation));
+        } else {
+            cells
+                .entry((region_at(position.0, cell_size), kind))
+                .or_default()
+                .push((collider, position, rotation));
+        }
+    }
+
+    for ((cell, kind), mut colliders) in cells {
+        if colliders.len() == 1 {
+            merged.append(&mut colliders);
+        } else {
+            let origin = cell_origin(cell, cell_size);
+            merged.push((
+                merge_cell(kind, &colliders, origin),
+                Position(origin),
+                Rotation::default(),
+            ));
+        }
+    }
+
+    merged
+}
+
+/// A collider created by merging [static](RigidBody::Static) colliders using
+/// [`StaticMergingCommandsExt::merge_static_colliders`].
+#[derive(Clone, Component, Debug, Default, PartialEq, Eq)]
+pub struct MergedStaticCollider {
+    /// The coordinates of the cell that the merged colliders are in.
+    pub cell: ChunkCoordinates,
+    /// The entities whose colliders were merged. Their [`RigidBody`] and [`Collider`] components have been removed.
+    pub sources: Vec<Entity>,
+}
+
+/// A [`Command`] that merges the colliders of existing [static](RigidBody::Static) bodies into one collider
+/// per cell of a grid. See [`merge_static_colliders`].
+///
+/// Only bodies without a parent or children and with a single collider on the body itself are merged.
+/// [Sensors](Sensor) are never merged, and only colliders with the same [`CollisionLayers`], [`Friction`]
+/// and [`Restitution`] are merged together.
+///
+/// The merged colliders are spawned as new entities with the [`MergedStaticCollider`] component.
+/// The [`RigidBody`] and [`Collider`] components of the merged bodies are removed, but the entities
+/// and their other components, like meshes, are kept.
+///
+/// Usually, this is used through [`StaticMergingCommandsExt::merge_static_colliders`].
+pub struct MergeStaticColliders {
+    /// The size of the cells along each axis.
+    pub cell_size: Scalar,
+}
+
+/// The properties that need to be the same for colliders to be merged.
+type MaterialKey = (CollisionLayers, Friction, Restitution);
+
+impl Command for MergeStaticColliders {
+    fn apply(self, world: &mut World) {
+        let mut bodies_query = world.query_filtered::<(
+            Entity,
+            &RigidBody,
+            &Collider,
+            &Position,
+            &Rotation,
+            Option<&CollisionLayers>,
+            Option<&Friction>,
+            Option<&Restitution>,
+        ), (
+            Without<Sensor>,
+            Without<Parent>,
+            Without<Children>,
+            Without<MergedStaticCollider>,
+        )>();
+
+        // Colliders with different materials can't be merged, so they are grouped separately
+        let mut cells: HashMap<(ChunkCoordinates, MergeKind), Vec<(MaterialKey, Vec<Entity>)>> =
+            HashMap::default();
+        for (entity, rb, collider, position, _, layers, friction, restitution) in
+            bodies_query.iter(world)
+        {
+            let kind = merge_kind(collider);
+            if !rb.is_static() || kind == MergeKind::None {
+                continue;
+            }
+            let material = (
+                layers.copied().unwrap_or_default(),
+                friction.copied().unwrap_or_default(),
+                restitution.copied().unwrap_or_default(),
+            );
+            let groups = cells
+                .entry((region_at(position.0, self.cell_size), kind))
+                .or_default();
+            match groups.iter_mut().find(|(key, _)| *key == material) {
+                Some((_, entities)) => entities.push(entity),
+                None => groups.push((material, vec![entity])),
+            }
+        }
+
+        for ((cell, kind), groups) in cells {
+            for ((layers, friction, restitution), entities) in groups {
+                if entities.len() < 2 {
+                    continue;
+                }
+
+                let colliders: Vec<(Collider, Position, Rotation)> = entities
+                    .iter()
+                    .filter_map(|entity| {
+                        let entity_ref = world.get_entity(*entity)?;
+                        Some((
+                            entity_ref.get::<Collider>()?.clone(),
+                            *entity_ref.get::<Position>()?,
+                            *entity_ref.get::<Rotation>()?,
+                        ))
+                    })
+                    .collect();
+                let origin = cell_origin(cell, self.cell_size);
+                let collider = merge_cell(kind, &colliders, origin);
+
+                for entity in entities.iter() {
+                    world.entity_mut(*entity).remove::<(RigidBody, Collider)>();
+                }
+                world.spawn((
+                    RigidBody::Static,
+                    collider,
+                    Position(origin),
+                    Rotation::default(),
+                    layers,
+                    friction,
+                    restitution,
+                    MergedStaticCollider {
+                        cell,
+                        sources: entities,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// An extension trait for merging [static](RigidBody::Static) colliders using `Commands`.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn on_level_loaded(mut commands: Commands) {
+///     // Merge the static pieces of the level into one collider per 16x16 cell
+///     commands.merge_static_colliders(16.0);
+/// }
+/// ```
+pub trait StaticMergingCommandsExt {
+    /// Merges the colliders of existing [static](RigidBody::Static) bodies into one collider per cell
+    /// of the given size. See [`MergeStaticColliders`].
+    fn merge_static_colliders(&mut self, cell_size: Scalar);
+}
+
+impl StaticMergingCommandsExt for Commands<'_, '_> {
+    fn merge_static_colliders(&mut self, cell_size: Scalar) {
+        self.add(MergeStaticColliders { cell_size });
+    }
+}
+
+/// How a collider is merged with other colliders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum MergeKind {
+    /// The collider is added to a compound shape.
+    Compound,
+    /// The triangles of the collider are added to a triangle mesh.
+    TriMesh,
+    /// The collider is a composite shape that can't be merged.
+    None,
+}
+
+fn merge_kind(collider: &Collider) -> MergeKind {
+    match collider.shape_scaled().as_typed_shape() {
+        TypedShape::TriMesh(_) => MergeKind::TriMesh,
+        TypedShape::Compound(_) | TypedShape::Polyline(_) | TypedShape::HeightField(_) => {
+            MergeKind::None
+        }
+        _ => MergeKind::Compound,
+    }
+}
+
+/// Returns the minimum corner of the given cell.
+fn cell_origin(cell: ChunkCoordinates, cell_size: Scalar) -> Vector {
+    #[cfg(feature = "2d")]
+    let origin = cell.as_vec2().adjust_precision() * cell_size;
+    #[cfg(feature = "3d")]
+    let origin = cell.as_vec3().adjust_precision() * cell_size;
+    origin
+}
+
+/// Merges the colliders of a cell into a single collider relative to the `origin` of the cell.
+fn merge_cell(
+    kind: MergeKind,
+    colliders: &[(Collider, Position, Rotation)],
+    origin: Vector,
+) -> Collider {
+    if kind == MergeKind::TriMesh {
+        let mut vertices = vec![];
+        let mut indices = vec![];
+        for (collider, position, rotation) in colliders {
+            let Some(trimesh) = collider.as_trimesh() else {
+                continue;
+            };
+            let offset = vertices.len() as u32;
+            vertices.extend(
+                trimesh
+                    .vertices()
+                    .iter()
+                    .map(|p| position.0 - origin + rotation.rotate(Vector::from(*p))),
+            );
+            indices.extend(
+                trimesh
+                    .indices()
+                    .iter()
+                    .map(|[a, b, c]| [a + offset, b + offset, c + offset]),
+            );
+        }
+        Collider::trimesh(vertices, indices)
+    } else {
+        Collider::compound(
+            colliders
+                .iter()
+                .map(|(collider, position, rotation)| {
+                    (Position(position.0 - origin), *rotation, collider.clone())
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use bevy::{ecs::system::CommandQueue, prelude::*};
+
+    #[test]
+    fn static_colliders_are_merged_per_cell() {
+        let tiles: Vec<(Collider, Position, Rotation)> = (0..5)
+            .map(|i| {
+                (
+                    Collider::ball(0.5),
+                    Position(Vector::X * (i as Scalar * 2.0 + 1.0)),
+                    Rotation::default(),
+                )
+            })
+            .collect();
+
+        // The first four tiles are in the first cell, and the last one is alone in the second cell
+        let merged = merge_static_colliders(tiles.clone(), 8.0);
+        assert_eq!(merged.len(), 2);
+
+        let mut app = create_app();
+        let entities: Vec<Entity> = tiles
+            .into_iter()
+            .map(|tile| app.world.spawn((RigidBody::Static, tile)).id())
+            .collect();
+
+        app.step_physics(1);
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &app.world).merge_static_colliders(8.0);
+        queue.apply(&mut app.world);
+
+        app.step_physics(1);
+
+        let (merged_entity, merged_collider) = app
+            .world
+            .query::<(Entity, &MergedStaticCollider)>()
+            .single(&app.world);
+        assert_eq!(merged_collider.cell, ChunkCoordinates::ZERO);
+        assert_eq!(merged_collider.sources, entities[..4]);
+        for entity in &entities[..4] {
+            assert!(app.world.get::<Collider>(*entity).is_none());
+        }
+        assert!(app.world.get::<Collider>(entities[4]).is_some());
+
+        // The merged collider covers the original tiles
+        let hits = app
+            .world
+            .resource::<SpatialQueryPipeline>()
+            .point_intersections(Vector::X * 5.0, SpatialQueryFilter::default());
+        assert_eq!(hits, vec![merged_entity]);
+    }
+}
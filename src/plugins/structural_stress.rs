@@ -0,0 +1,281 @@
+//! Propagates stress through structures of bodies connected by [`FixedJoint`]s and breaks over-stressed joints.
+//!
+//! See [`StructuralStressPlugin`].
+
+use crate::prelude::*;
+use bevy::{ecs::query::Has, prelude::*, utils::HashMap};
+use std::collections::VecDeque;
+
+/// Breaks [`FixedJoint`]s with the [`BreakableJoint`] component when the stress acting on them exceeds their strength,
+/// which can be used for believable collapse of buildings in destruction games.
+///
+/// The forces computed by the solver only reflect the load on a joint after the solver has converged,
+/// which can take many substeps for tall structures. Because of this, the plugin also estimates the static
+/// load on each joint by propagating the weight of the bodies through the structure towards its supports.
+///
+/// The supports are the [static](RigidBody::Static) and [kinematic](RigidBody::Kinematic) bodies in the structure.
+/// Starting from the bodies furthest away from the supports, the weight of each body and the load it carries
+/// is split evenly between the joints connecting it to bodies closer to the supports. The stress of a joint
+/// is the larger of the propagated load and the force applied by the solver.
+///
+/// When a joint breaks, it is despawned, the connected bodies are woken up, and a [`JointBroken`] event is sent.
+///
+/// The stress is propagated after [`PhysicsStepSet::Substeps`] and before [`PhysicsStepSet::ReportContacts`].
+pub struct StructuralStressPlugin;
+
+impl Plugin for StructuralStressPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BreakableJoint>()
+            .add_event::<JointBroken>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                propagate_structural_stress
+                    .after(PhysicsStepSet::Substeps)
+                    .before(PhysicsStepSet::ReportContacts),
+            );
+    }
+}
+
+/// Makes a [`FixedJoint`] break when the stress acting on it exceeds `max_stress`.
+/// Requires the [`StructuralStressPlugin`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let ground = commands.spawn(RigidBody::Static).id();
+///     let pillar = commands.spawn(RigidBody::Dynamic).id();
+///
+///     // Breaks when the pillar carries more than 500 newtons
+///     commands.spawn((
+///         FixedJoint::new(ground, pillar).with_local_anchor_2(Vector::NEG_Y),
+///         BreakableJoint::new(500.0),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct BreakableJoint {
+    /// The stress at which the joint breaks.
+    pub max_stress: Scalar,
+    /// The stress acting on the joint during the last physics step.
+    pub stress: Scalar,
+}
+
+impl BreakableJoint {
+    /// Creates a new [`BreakableJoint`] that breaks when the stress acting on it exceeds `max_stress`.
+    pub const fn new(max_stress: Scalar) -> Self {
+        Self {
+            max_stress,
+            stress: 0.0,
+        }
+    }
+}
+
+impl Default for BreakableJoint {
+    /// Creates a joint that never breaks.
+    fn default() -> Self {
+        Self::new(Scalar::MAX)
+    }
+}
+
+/// An event that is sent when a [`BreakableJoint`] breaks.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct JointBroken {
+    /// The joint entity. It is despawned when the event is sent.
+    pub joint: Entity,
+    /// The first entity that was constrained by the joint.
+    pub entity1: Entity,
+    /// The second entity that was constrained by the joint.
+    pub entity2: Entity,
+    /// The stress that broke the joint.
+    pub stress: Scalar,
+}
+
+/// Propagates the weight of the bodies in jointed structures towards their supports
+/// and breaks the [`BreakableJoint`]s that are stressed too much.
+fn propagate_structural_stress(
+    mut commands: Commands,
+    mut joints: Query<(Entity, &FixedJoint, Option<&mut BreakableJoint>), Without<JointDisabled>>,
+    bodies: Query<(&RigidBody, &Mass, Has<Sleeping>)>,
+    gravity: Res<Gravity>,
+    mut broken_ev_writer: EventWriter<JointBroken>,
+) {
+    // The joints connected to each body
+    let mut graph = HashMap::<Entity, Vec<(usize, Entity)>>::default();
+    let mut loads: Vec<Scalar> = vec![];
+
+    for (i, (_, joint, _)) in joints.iter().enumerate() {
+        graph
+            .entry(joint.entity1)
+            .or_default()
+            .push((i, joint.entity2));
+        graph
+            .entry(joint.entity2)
+            .or_default()
+            .push((i, joint.entity1));
+        loads.push(0.0);
+    }
+
+    if loads.is_empty() {
+        return;
+    }
+
+    let is_support = |entity: Entity| {
+        bodies
+            .get(entity)
+            .map_or(false, |(rb, ..)| !rb.is_dynamic())
+    };
+
+    // Compute the distance of each body from the nearest support in the structure
+    let mut depths = HashMap::<Entity, usize>::default();
+    let mut queue = VecDeque::new();
+    for &entity in graph.keys().filter(|entity| is_support(**entity)) {
+        depths.insert(entity, 0);
+        queue.push_back(entity);
+    }
+    while let Some(entity) = queue.pop_front() {
+        let depth = depths[&entity];
+        for &(_, other) in &graph[&entity] {
+            if !depths.contains_key(&other) {
+                depths.insert(other, depth + 1);
+                queue.push_back(other);
+            }
+        }
+    }
+
+    // Propagate the weights from the furthest bodies towards the supports.
+    // Bodies that aren't connected to any support are falling freely and carry no static load.
+    let mut order = depths
+        .iter()
+        .filter(|(_, depth)| **depth > 0)
+        .map(|(entity, depth)| (*entity, *depth))
+        .collect::<Vec<_>>();
+    order.sort_unstable_by(|(_, depth1), (_, depth2)| depth2.cmp(depth1));
+
+    let weight = gravity.0.length();
+    let mut carried = HashMap::<Entity, Scalar>::default();
+
+    for (entity, depth) in order {
+        let own_weight = bodies
+            .get(entity)
+            .map_or(0.0, |(_, mass, _)| mass.0 * weight);
+        let load = own_weight + carried.get(&entity).copied().unwrap_or(0.0);
+
+        let supporting = graph[&entity]
+            .iter()
+            .filter(|(_, other)| depths.get(other) == Some(&(depth - 1)))
+            .collect::<Vec<_>>();
+        let share = load / supporting.len() as Scalar;
+
+        for &(joint_index, other) in supporting {
+            loads[joint_index] += share;
+            *carried.entry(other).or_default() += share;
+        }
+    }
+
+    for (i, (entity, joint, breakable)) in joints.iter_mut().enumerate() {
+        let Some(mut breakable) = breakable else {
+            continue;
+        };
+
+        let stress = loads[i].max(joint.force().length());
+
+        // Avoid triggering change detection unnecessarily
+        if breakable.stress != stress {
+            breakable.stress = stress;
+        }
+
+        if stress > breakable.max_stress {
+            commands.entity(entity).despawn_recursive();
+
+            for body in [joint.entity1, joint.entity2] {
+                if bodies.get(body).map_or(false, |(.., sleeping)| sleeping) {
+                    commands.entity(body).remove::<Sleeping>();
+                }
+            }
+
+            broken_ev_writer.send(JointBroken {
+                joint: entity,
+                entity1: joint.entity1,
+                entity2: joint.entity2,
+                stress,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider},
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    fn over_stressed_joints_break() {
+        let mut app = create_app();
+        app.add_plugins(StructuralStressPlugin);
+
+        let ground = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Static))
+            .id();
+
+        let shape = cube_collider(1.0);
+
+        // A tower of two bodies weighing about 9.81 newtons each
+        let mut spawn_box = |y: Scalar| {
+            app.world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y * y),
+                    MassPropertiesBundle::new_computed(&shape, 1.0),
+                ))
+                .id()
+        };
+        let lower = spawn_box(1.0);
+        let upper = spawn_box(2.0);
+
+        // The lower joint carries both bodies, so it breaks
+        let lower_joint = app
+            .world
+            .spawn((
+                FixedJoint::new(ground, lower).with_local_anchor_2(Vector::NEG_Y),
+                BreakableJoint::new(15.0),
+            ))
+            .id();
+        let upper_joint = app
+            .world
+            .spawn((
+                FixedJoint::new(lower, upper).with_local_anchor_2(Vector::NEG_Y),
+                BreakableJoint::new(15.0),
+            ))
+            .id();
+
+        let mut reader = app.world.resource::<Events<JointBroken>>().get_reader();
+        let mut broken = vec![];
+
+        for _ in 0..3 {
+            app.step_physics(1);
+            let events = app.world.resource::<Events<JointBroken>>();
+            broken.extend(reader.iter(events).copied());
+        }
+
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].joint, lower_joint);
+        assert!(app.world.get_entity(lower_joint).is_none());
+        assert!(app.world.get_entity(upper_joint).is_some());
+    }
+}
@@ -4,7 +4,7 @@
 //! See [`SyncPlugin`].
 
 use crate::prelude::*;
-use bevy::{ecs::query::Has, prelude::*};
+use bevy::{ecs::query::Has, prelude::*, transform::TransformSystem};
 
 /// Responsible for synchronizing physics components with other data, like keeping [`Position`]
 /// and [`Rotation`] in sync with `Transform`.
@@ -16,7 +16,19 @@ use bevy::{ecs::query::Has, prelude::*};
 /// or position bodies, and the changes be reflected in the other components.
 ///
 /// You can configure what data is synchronized and how it is synchronized
-/// using the [`SyncConfig`] resource.
+/// using the [`SyncConfig`] resource. The stages of the synchronization are in the [`SyncSet`] system sets.
+///
+/// ## Moving bodies using `Transform`
+///
+/// Modifying the `Transform` of a rigid body directly is treated as a teleport. The body's [`Position`]
+/// and [`Rotation`] are moved to the new location along with the previous position and rotation used
+/// by the solver, so the solver doesn't try to pull the body back or give it a huge velocity.
+///
+/// By default, teleported bodies keep their velocity. If you want them to start from rest instead,
+/// enable [`SyncConfig::reset_velocity_on_teleport`].
+///
+/// To teleport a single body explicitly, you can also use the [`Teleport`] component or
+/// [`teleport_to`](TeleportCommandsExt::teleport_to), which also remove the body's existing contacts.
 ///
 /// ## `Transform` hierarchies
 ///
@@ -26,6 +38,38 @@ use bevy::{ecs::query::Has, prelude::*};
 ///
 /// If you would like a child entity to be rigidly attached to its parent, you could use a [`FixedJoint`]
 /// or write your own system to handle hierarchies differently.
+///
+/// ## Transform interpolation
+///
+/// With a [fixed timestep](PhysicsTimestep::Fixed), the physics simulation usually runs at a different rate
+/// than the app, so bodies can appear to stutter when their `Transform` only changes on frames where
+/// a physics step was run. To smooth out the movement, the `Transform` can be interpolated between the
+/// [`Position`] and [`Rotation`] of the previous and current physics step based on the time accumulated
+/// towards the next step. This makes the rendered bodies lag behind the simulation by up to one step.
+///
+/// Interpolation is enabled for all rigid bodies with [`SyncConfig::interpolate_transforms`],
+/// and it can be enabled or disabled for individual bodies using the [`InterpolateTransform`] and
+/// [`NoTransformInterpolation`] components. For example, you could interpolate most bodies,
+/// but disable interpolation for bodies that UI elements are attached to.
+///
+/// Interpolation only applies to rigid bodies that don't have a parent. Teleported bodies
+/// snap to their new location instead of being interpolated there from their old one.
+///
+/// When the [`PhysicsSchedule`] runs in `FixedUpdate`, the time accumulated towards the next step is
+/// the time accumulated in Bevy's `FixedTime`, and the transforms are interpolated in `PostUpdate`,
+/// because `FixedUpdate` doesn't run on every frame.
+///
+/// ## Transform extrapolation
+///
+/// Because interpolation makes bodies lag behind the simulation, it can make player-controlled bodies
+/// feel less responsive. For these bodies, the [`ExtrapolateTransform`] component can be used instead.
+/// It projects the `Transform` forward from the current [`Position`] and [`Rotation`] using the body's
+/// [`LinearVelocity`] and [`AngularVelocity`] and the time accumulated towards the next step.
+/// This has no added latency, but it can overshoot when the velocity changes suddenly, for example on impacts.
+///
+/// Extrapolation takes priority over interpolation, so all modes can be used in the same world.
+/// Like interpolation, it only applies to rigid bodies that don't have a parent.
+/// In `FixedUpdate`, extrapolation also uses the time accumulated in `FixedTime` and runs in `PostUpdate`.
 pub struct SyncPlugin {
     schedule: Box<dyn ScheduleLabel>,
 }
@@ -50,25 +94,51 @@ impl Default for SyncPlugin {
 impl Plugin for SyncPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SyncConfig>()
-            .register_type::<SyncConfig>();
+            .register_type::<SyncConfig>()
+            .register_type::<InterpolateTransform>()
+            .register_type::<NoTransformInterpolation>()
+            .register_type::<ExtrapolateTransform>()
+            .register_type::<PreviousStepPosition>()
+            .register_type::<PreviousStepRotation>();
+
+        app.configure_set(
+            self.schedule.dyn_clone(),
+            SyncSet::PreStep
+                .after(PhysicsSet::Prepare)
+                .before(PhysicsSet::StepSimulation),
+        )
+        .configure_sets(
+            self.schedule.dyn_clone(),
+            (
+                SyncSet::TransformToPosition,
+                SyncSet::PositionToTransform,
+                SyncSet::Finalize,
+            )
+                .chain()
+                .in_set(PhysicsSet::Sync),
+        );
 
         // Initialize `PreviousGlobalTransform` and apply `Transform` changes that happened
         // between the end of the previous physics frame and the start of this physics frame.
         app.add_systems(
             self.schedule.dyn_clone(),
-            ((
-                bevy::transform::systems::sync_simple_transforms,
-                bevy::transform::systems::propagate_transforms,
-                init_previous_global_transform,
-                transform_to_position,
-                // Update `PreviousGlobalTransform` for the physics step's `GlobalTransform` change detection
-                update_previous_global_transforms,
+            (
+                (
+                    bevy::transform::systems::sync_simple_transforms,
+                    bevy::transform::systems::propagate_transforms,
+                    init_previous_global_transform,
+                    transform_to_position,
+                    // Update `PreviousGlobalTransform` for the physics step's `GlobalTransform` change detection
+                    update_previous_global_transforms,
+                )
+                    .chain()
+                    .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                // Apply teleports after transform changes so that they take priority
+                apply_teleports,
+                init_previous_step_transforms,
             )
                 .chain()
-                .after(PhysicsSet::Prepare)
-                .before(PhysicsSet::StepSimulation),)
-                .chain()
-                .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                .in_set(SyncSet::PreStep),
         );
 
         // Apply `Transform`, `Position` and `Rotation` changes that happened during the physics frame.
@@ -82,24 +152,70 @@ impl Plugin for SyncPlugin {
                     transform_to_position,
                 )
                     .chain()
-                    .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                    .run_if(|config: Res<SyncConfig>| config.transform_to_position)
+                    .in_set(SyncSet::TransformToPosition),
                 // Apply `Position` and `Rotation` changes to `Transform`
-                position_to_transform,
                 (
-                    // Update `PreviousGlobalTransform` for next frame's `GlobalTransform` change detection
-                    bevy::transform::systems::sync_simple_transforms,
-                    bevy::transform::systems::propagate_transforms,
-                    update_previous_global_transforms,
+                    position_to_transform,
+                    interpolate_transforms,
+                    extrapolate_transforms,
                 )
                     .chain()
-                    .run_if(|config: Res<SyncConfig>| config.transform_to_position),
-                update_collider_scale,
+                    .in_set(SyncSet::PositionToTransform),
+                (
+                    (
+                        // Update `PreviousGlobalTransform` for next frame's `GlobalTransform` change detection
+                        bevy::transform::systems::sync_simple_transforms,
+                        bevy::transform::systems::propagate_transforms,
+                        update_previous_global_transforms,
+                    )
+                        .chain()
+                        .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                    update_collider_scale,
+                )
+                    .chain()
+                    .in_set(SyncSet::Finalize),
             )
-                .chain()
-                .in_set(PhysicsSet::Sync)
                 .run_if(|config: Res<SyncConfig>| config.position_to_transform),
         );
 
+        // `FixedUpdate` doesn't run on every frame, so interpolate and extrapolate the transforms
+        // in `PostUpdate` using the time accumulated in `FixedTime`
+        if self.schedule.inner_type_id() == FixedUpdate::inner_type_id(&FixedUpdate) {
+            app.add_systems(
+                PostUpdate,
+                (
+                    // Apply `Transform` changes made since the last physics frame before overwriting them
+                    (
+                        bevy::transform::systems::sync_simple_transforms,
+                        bevy::transform::systems::propagate_transforms,
+                        transform_to_position,
+                    )
+                        .chain()
+                        .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                    interpolate_transforms,
+                    extrapolate_transforms,
+                    // Update `PreviousGlobalTransform` so that the interpolated transforms
+                    // aren't treated as `Transform` changes in the next physics frame
+                    (
+                        bevy::transform::systems::sync_simple_transforms,
+                        bevy::transform::systems::propagate_transforms,
+                        update_previous_global_transforms,
+                    )
+                        .chain()
+                        .run_if(|config: Res<SyncConfig>| config.transform_to_position),
+                )
+                    .chain()
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(|config: Res<SyncConfig>| config.position_to_transform),
+            );
+        }
+
+        // Store the position and rotation at the start of each physics step for interpolation
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(store_previous_step_transforms.before(PhysicsStepSet::BroadPhase));
+
         // Update child colliders before narrow phase in substepping loop
         let substep_schedule = app
             .get_schedule_mut(SubstepSchedule)
@@ -125,6 +241,17 @@ pub struct SyncConfig {
     /// Updates [`Position`] and [`Rotation`] based on transform changes,
     /// allowing you to move bodies using `Transform`. Defaults to true.
     pub transform_to_position: bool,
+    /// Resets the [`LinearVelocity`] and [`AngularVelocity`] of bodies whose `Transform` was modified
+    /// directly, so that teleported bodies start from rest. Defaults to false.
+    ///
+    /// Only used when [`transform_to_position`](#structfield.transform_to_position) is enabled.
+    pub reset_velocity_on_teleport: bool,
+    /// Interpolates the `Transform` of rigid bodies between physics steps to make movement
+    /// look smooth when the app runs at a different rate than the simulation. Defaults to false.
+    ///
+    /// Individual bodies can opt in or out using the [`InterpolateTransform`] and [`NoTransformInterpolation`]
+    /// components. See the [`SyncPlugin`] for more information.
+    pub interpolate_transforms: bool,
 }
 
 impl Default for SyncConfig {
@@ -132,10 +259,44 @@ impl Default for SyncConfig {
         SyncConfig {
             position_to_transform: true,
             transform_to_position: true,
+            reset_velocity_on_teleport: false,
+            interpolate_transforms: false,
         }
     }
 }
 
+/// Enables [transform interpolation](SyncPlugin#transform-interpolation) for a rigid body
+/// even when [`SyncConfig::interpolate_transforms`] is disabled.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct InterpolateTransform;
+
+/// Disables [transform interpolation](SyncPlugin#transform-interpolation) for a rigid body
+/// even when [`SyncConfig::interpolate_transforms`] is enabled.
+///
+/// This takes priority over [`InterpolateTransform`].
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct NoTransformInterpolation;
+
+/// Enables [transform extrapolation](SyncPlugin#transform-extrapolation) for a rigid body.
+/// The `Transform` is projected forward using the body's velocity between physics steps.
+///
+/// This takes priority over [transform interpolation](SyncPlugin#transform-interpolation).
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct ExtrapolateTransform;
+
+/// The [`Position`] of a body at the start of the latest physics step. Used for [transform interpolation](SyncPlugin#transform-interpolation).
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Component)]
+pub struct PreviousStepPosition(pub Vector);
+
+/// The [`Rotation`] of a body at the start of the latest physics step. Used for [transform interpolation](SyncPlugin#transform-interpolation).
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq)]
+#[reflect(Component)]
+pub struct PreviousStepRotation(pub Rotation);
+
 /// The global transform of a body at the end of the previous frame.
 /// Used for detecting if the transform was modified before the start of the physics schedule.
 #[derive(Component, Reflect, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq)]
@@ -173,16 +334,22 @@ pub(crate) fn update_child_collider_position(
             continue;
         };
 
-        position.0 = parent_pos.0 + parent_rot.rotate(collider_transform.translation);
+        // Only trigger change detection when the collider actually moved,
+        // so that the narrow phase can skip pairs of colliders that are at rest
+        position.set_if_neq(Position(
+            parent_pos.0 + parent_rot.rotate(collider_transform.translation),
+        ));
         #[cfg(feature = "2d")]
         {
-            *rotation = *parent_rot + collider_transform.rotation;
+            rotation.set_if_neq(*parent_rot + collider_transform.rotation);
         }
         #[cfg(feature = "3d")]
         {
-            *rotation = (parent_rot.0 * collider_transform.rotation.0)
-                .normalize()
-                .into();
+            rotation.set_if_neq(
+                (parent_rot.0 * collider_transform.rotation.0)
+                    .normalize()
+                    .into(),
+            );
         }
     }
 }
@@ -237,48 +404,52 @@ pub(crate) fn propagate_collider_transforms(
     >,
     parent_query: Query<(Entity, Ref<Transform>, Has<RigidBody>, Ref<Parent>)>,
 ) {
-    root_query.par_iter_mut().for_each_mut(
-        |(entity, transform,children)| {
-            for (child, child_transform, is_child_rb, parent) in parent_query.iter_many(children) {
-                assert_eq!(
+    let propagate = |(entity, transform, children): (Entity, Ref<Transform>, &Children)| {
+        for (child, child_transform, is_child_rb, parent) in parent_query.iter_many(children) {
+            assert_eq!(
                     parent.get(), entity,
                     "Malformed hierarchy. This probably means that your hierarchy has been improperly maintained, or contains a cycle"
                 );
-                let child_transform = ColliderTransform::from(*child_transform);
-
-                // SAFETY:
-                // - `child` must have consistent parentage, or the above assertion would panic.
-                // Since `child` is parented to a root entity, the entire hierarchy leading to it is consistent.
-                // - We may operate as if all descendants are consistent, since `propagate_collider_transform_recursive` will panic before 
-                //   continuing to propagate if it encounters an entity with inconsistent parentage.
-                // - Since each root entity is unique and the hierarchy is consistent and forest-like,
-                //   other root entities' `propagate_collider_transform_recursive` calls will not conflict with this one.
-                // - Since this is the only place where `transform_query` gets used, there will be no conflicting fetches elsewhere.
-                unsafe {
-                    propagate_collider_transforms_recursive(
-                        if is_child_rb {
-                            ColliderTransform {
-                                scale: child_transform.scale,
-                                ..default()
-                            }
-                        } else {
-                            let transform = ColliderTransform::from(*transform);
-
-                            ColliderTransform {
-                                translation: transform.scale * child_transform.translation,
-                                rotation: child_transform.rotation,
-                                scale: (transform.scale * child_transform.scale).max(Vector::splat(Scalar::EPSILON)),
-                            }
-                        },
-                        &collider_query,
-                        &parent_query,
-                        child,
-                        transform.is_changed() || parent.is_changed()
-                    );
-                }
+            let child_transform = ColliderTransform::from(*child_transform);
+
+            // SAFETY:
+            // - `child` must have consistent parentage, or the above assertion would panic.
+            // Since `child` is parented to a root entity, the entire hierarchy leading to it is consistent.
+            // - We may operate as if all descendants are consistent, since `propagate_collider_transform_recursive` will panic before
+            //   continuing to propagate if it encounters an entity with inconsistent parentage.
+            // - Since each root entity is unique and the hierarchy is consistent and forest-like,
+            //   other root entities' `propagate_collider_transform_recursive` calls will not conflict with this one.
+            // - Since this is the only place where `transform_query` gets used, there will be no conflicting fetches elsewhere.
+            unsafe {
+                propagate_collider_transforms_recursive(
+                    if is_child_rb {
+                        ColliderTransform {
+                            scale: child_transform.scale,
+                            ..default()
+                        }
+                    } else {
+                        let transform = ColliderTransform::from(*transform);
+
+                        ColliderTransform {
+                            translation: transform.scale * child_transform.translation,
+                            rotation: child_transform.rotation,
+                            scale: (transform.scale * child_transform.scale)
+                                .max(Vector::splat(Scalar::EPSILON)),
+                        }
+                    },
+                    &collider_query,
+                    &parent_query,
+                    child,
+                    transform.is_changed() || parent.is_changed(),
+                );
             }
-        },
-    );
+        }
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+    root_query.par_iter_mut().for_each_mut(propagate);
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+    root_query.iter_mut().for_each(propagate);
 }
 
 /// Recursively computes the [`ColliderTransform`] for `entity` and all of its descendants
@@ -398,25 +569,42 @@ unsafe fn propagate_collider_transforms_recursive(
     }
 }
 
+type TransformToPositionComponents = (
+    &'static GlobalTransform,
+    &'static PreviousGlobalTransform,
+    &'static mut Position,
+    Option<&'static AccumulatedTranslation>,
+    &'static mut Rotation,
+    Option<&'static mut PreviousPosition>,
+    Option<&'static mut PreviousRotation>,
+    Option<(
+        &'static mut PreviousStepPosition,
+        &'static mut PreviousStepRotation,
+    )>,
+    Option<&'static mut LinearVelocity>,
+    Option<&'static mut AngularVelocity>,
+);
+
 /// Copies `GlobalTransform` changes to [`Position`] and [`Rotation`].
 /// This allows users to use transforms for moving and positioning bodies and colliders.
 ///
+/// Transform changes are treated as teleports: the previous position and rotation of rigid bodies are
+/// moved along with the body, and the velocity is reset if [`SyncConfig::reset_velocity_on_teleport`]
+/// is enabled.
+///
 /// To account for hierarchies, transform propagation should be run before this system.
-fn transform_to_position(
-    mut query: Query<(
-        &GlobalTransform,
-        &PreviousGlobalTransform,
-        &mut Position,
-        Option<&AccumulatedTranslation>,
-        &mut Rotation,
-    )>,
-) {
+fn transform_to_position(mut query: Query<TransformToPositionComponents>, config: Res<SyncConfig>) {
     for (
         global_transform,
         previous_transform,
         mut position,
         accumulated_translation,
         mut rotation,
+        previous_position,
+        previous_rotation,
+        previous_step_transform,
+        linear_velocity,
+        angular_velocity,
     ) in &mut query
     {
         // Skip entity if the global transform value hasn't changed
@@ -457,6 +645,110 @@ fn transform_to_position(
                 .normalize()
                 .adjust_precision();
         }
+
+        // Move the previous state along with the body so that the teleport
+        // isn't interpreted as a huge displacement by the solver
+        if let Some(mut previous_position) = previous_position {
+            previous_position.0 = position.0;
+        }
+        if let Some(mut previous_rotation) = previous_rotation {
+            previous_rotation.0 = *rotation;
+        }
+
+        // Interpolate from the new pose so that the teleport isn't swept over
+        if let Some((mut previous_step_position, mut previous_step_rotation)) =
+            previous_step_transform
+        {
+            previous_step_position.0 = position.0;
+            previous_step_rotation.0 = *rotation;
+        }
+
+        if config.reset_velocity_on_teleport {
+            if let Some(mut linear_velocity) = linear_velocity {
+                linear_velocity.0 = Vector::ZERO;
+            }
+            if let Some(mut angular_velocity) = angular_velocity {
+                *angular_velocity = AngularVelocity::ZERO;
+            }
+        }
+    }
+}
+
+type TeleportComponents = (
+    Entity,
+    &'static Teleport,
+    &'static mut Position,
+    &'static mut Rotation,
+    Option<&'static mut PreviousPosition>,
+    Option<&'static mut PreviousRotation>,
+    Option<(
+        &'static mut PreviousStepPosition,
+        &'static mut PreviousStepRotation,
+    )>,
+    Option<&'static mut AccumulatedTranslation>,
+    Option<&'static mut LinearVelocity>,
+    Option<&'static mut AngularVelocity>,
+);
+
+/// Moves bodies with a [`Teleport`] component to their new location and removes their contacts.
+fn apply_teleports(
+    mut commands: Commands,
+    mut bodies: Query<TeleportComponents>,
+    mut collisions: Option<ResMut<Collisions>>,
+) {
+    for (
+        entity,
+        teleport,
+        mut position,
+        mut rotation,
+        previous_position,
+        previous_rotation,
+        previous_step_transform,
+        accumulated_translation,
+        linear_velocity,
+        angular_velocity,
+    ) in &mut bodies
+    {
+        position.0 = teleport.position;
+        *rotation = teleport.rotation;
+
+        if let Some(mut previous_position) = previous_position {
+            previous_position.0 = teleport.position;
+        }
+        if let Some(mut previous_rotation) = previous_rotation {
+            previous_rotation.0 = teleport.rotation;
+        }
+        if let Some((mut previous_step_position, mut previous_step_rotation)) =
+            previous_step_transform
+        {
+            previous_step_position.0 = teleport.position;
+            previous_step_rotation.0 = teleport.rotation;
+        }
+        if let Some(mut accumulated_translation) = accumulated_translation {
+            accumulated_translation.0 = Vector::ZERO;
+        }
+
+        if teleport.reset_velocity {
+            if let Some(mut linear_velocity) = linear_velocity {
+                linear_velocity.0 = Vector::ZERO;
+            }
+            if let Some(mut angular_velocity) = angular_velocity {
+                *angular_velocity = AngularVelocity::ZERO;
+            }
+        }
+
+        // Contacts from the old location are no longer valid.
+        // This also covers the body's child colliders.
+        if let Some(collisions) = collisions.as_mut() {
+            collisions.retain(|contacts| {
+                contacts.entity1 != entity
+                    && contacts.entity2 != entity
+                    && contacts.body_entity1 != Some(entity)
+                    && contacts.body_entity2 != Some(entity)
+            });
+        }
+
+        commands.entity(entity).remove::<Teleport>();
     }
 }
 
@@ -485,7 +777,12 @@ fn position_to_transform(
     mut query: Query<PosToTransformComponents, PosToTransformFilter>,
     parents: Query<ParentComponents, With<Children>>,
 ) {
-    for (mut transform, pos, rot, parent) in &mut query {
+    let sync = |(mut transform, pos, rot, parent): (
+        Mut<Transform>,
+        &Position,
+        &Rotation,
+        Option<&Parent>,
+    )| {
         if let Some(parent) = parent {
             if let Ok((parent_transform, parent_pos, parent_rot)) = parents.get(**parent) {
                 // Compute the global transform of the parent using its Position and Rotation
@@ -519,7 +816,12 @@ fn position_to_transform(
             transform.translation = pos.as_f32().extend(transform.translation.z);
             transform.rotation = Quaternion::from(*rot).as_f32();
         }
-    }
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+    query.par_iter_mut().for_each_mut(sync);
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+    query.iter_mut().for_each(sync);
 }
 
 /// Copies [`Position`] and [`Rotation`] changes to `Transform`.
@@ -532,7 +834,12 @@ fn position_to_transform(
     mut query: Query<PosToTransformComponents, PosToTransformFilter>,
     parents: Query<ParentComponents, With<Children>>,
 ) {
-    for (mut transform, pos, rot, parent) in &mut query {
+    let sync = |(mut transform, pos, rot, parent): (
+        Mut<Transform>,
+        &Position,
+        &Rotation,
+        Option<&Parent>,
+    )| {
         if let Some(parent) = parent {
             if let Ok((parent_transform, parent_pos, parent_rot)) = parents.get(**parent) {
                 // Compute the global transform of the parent using its Position and Rotation
@@ -559,6 +866,215 @@ fn position_to_transform(
             transform.translation = pos.as_f32();
             transform.rotation = rot.as_f32();
         }
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+    query.par_iter_mut().for_each_mut(sync);
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+    query.iter_mut().for_each(sync);
+}
+
+/// Adds [`PreviousStepPosition`] and [`PreviousStepRotation`] to new non-static rigid bodies.
+#[allow(clippy::type_complexity)]
+fn init_previous_step_transforms(
+    mut commands: Commands,
+    bodies: Query<
+        (Entity, &RigidBody, &Position, &Rotation),
+        (Changed<RigidBody>, Without<PreviousStepPosition>),
+    >,
+) {
+    for (entity, rb, position, rotation) in &bodies {
+        if rb.is_static() {
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            PreviousStepPosition(position.0),
+            PreviousStepRotation(*rotation),
+        ));
+    }
+}
+
+/// Stores the [`Position`] and [`Rotation`] of bodies at the start of the physics step.
+fn store_previous_step_transforms(
+    mut bodies: Query<(
+        &Position,
+        &Rotation,
+        &mut PreviousStepPosition,
+        &mut PreviousStepRotation,
+    )>,
+) {
+    for (position, rotation, mut previous_position, mut previous_rotation) in &mut bodies {
+        previous_position.0 = position.0;
+        previous_rotation.0 = *rotation;
+    }
+}
+
+type InterpolationComponents = (
+    &'static mut Transform,
+    &'static Position,
+    &'static Rotation,
+    &'static PreviousStepPosition,
+    &'static PreviousStepRotation,
+    Has<InterpolateTransform>,
+    Has<NoTransformInterpolation>,
+);
+
+/// Interpolates the `Transform` of rigid bodies between the previous and current physics step
+/// based on the time accumulated towards the next step.
+fn interpolate_transforms(
+    mut bodies: Query<
+        InterpolationComponents,
+        (
+            With<RigidBody>,
+            Without<Parent>,
+            Without<ExtrapolateTransform>,
+        ),
+    >,
+    config: Res<SyncConfig>,
+    physics_loop: Res<PhysicsLoop>,
+    time_step: Res<PhysicsTimestep>,
+    dt: Res<DeltaTime>,
+    fixed_time: Option<Res<FixedTime>>,
+) {
+    // Only timesteps that accumulate time leave a fraction of a step to interpolate over
+    let alpha = match accumulated_time(&physics_loop, &time_step, fixed_time.as_deref()) {
+        Some(time) if dt.0 > 0.0 => (time / dt.0).clamp(0.0, 1.0),
+        _ => 1.0,
+    };
+
+    let interpolate_transform = |(
+        mut transform,
+        position,
+        rotation,
+        previous_position,
+        previous_rotation,
+        interpolate,
+        no_interpolation,
+    ): (
+        Mut<Transform>,
+        &Position,
+        &Rotation,
+        &PreviousStepPosition,
+        &PreviousStepRotation,
+        bool,
+        bool,
+    )| {
+        if no_interpolation || !(interpolate || config.interpolate_transforms) {
+            return;
+        }
+
+        let translation = previous_position.0.lerp(position.0, alpha);
+
+        #[cfg(feature = "2d")]
+        let (translation, rotation) = {
+            let previous_rotation = previous_rotation.0;
+            let angle = previous_rotation.as_radians()
+                + (*rotation - previous_rotation).as_radians() * alpha;
+            (
+                translation.as_f32().extend(transform.translation.z),
+                Quaternion::from(Rotation::from_radians(angle)).as_f32(),
+            )
+        };
+        #[cfg(feature = "3d")]
+        let (translation, rotation) = {
+            let previous_rotation = previous_rotation.0;
+            (
+                translation.as_f32(),
+                previous_rotation.0.slerp(rotation.0, alpha).as_f32(),
+            )
+        };
+
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+    bodies.par_iter_mut().for_each_mut(interpolate_transform);
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+    bodies.iter_mut().for_each(interpolate_transform);
+}
+
+type ExtrapolationComponents = (
+    &'static mut Transform,
+    &'static Position,
+    &'static Rotation,
+    &'static LinearVelocity,
+    &'static AngularVelocity,
+);
+
+/// Projects the `Transform` of rigid bodies with [`ExtrapolateTransform`] forward using their velocity
+/// and the time accumulated towards the next step.
+fn extrapolate_transforms(
+    mut bodies: Query<
+        ExtrapolationComponents,
+        (With<RigidBody>, With<ExtrapolateTransform>, Without<Parent>),
+    >,
+    physics_loop: Res<PhysicsLoop>,
+    time_step: Res<PhysicsTimestep>,
+    dt: Res<DeltaTime>,
+    fixed_time: Option<Res<FixedTime>>,
+) {
+    // Only timesteps that accumulate time leave time between steps to extrapolate over
+    let time = accumulated_time(&physics_loop, &time_step, fixed_time.as_deref())
+        .map_or(0.0, |time| time.clamp(0.0, dt.0));
+
+    let extrapolate_transform = |(mut transform, position, rotation, lin_vel, ang_vel): (
+        Mut<Transform>,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+        &AngularVelocity,
+    )| {
+        let translation = position.0 + lin_vel.0 * time;
+
+        #[cfg(feature = "2d")]
+        let (translation, rotation) = (
+            translation.as_f32().extend(transform.translation.z),
+            Quaternion::from(*rotation + Rotation::from_radians(ang_vel.0 * time)).as_f32(),
+        );
+        #[cfg(feature = "3d")]
+        let (translation, rotation) = (
+            translation.as_f32(),
+            (Quaternion::from_scaled_axis(ang_vel.0 * time) * rotation.0)
+                .normalize()
+                .as_f32(),
+        );
+
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+        if transform.rotation != rotation {
+            transform.rotation = rotation;
+        }
+    };
+
+    #[cfg(all(feature = "parallel", not(feature = "single-threaded")))]
+    bodies.par_iter_mut().for_each_mut(extrapolate_transform);
+    #[cfg(any(not(feature = "parallel"), feature = "single-threaded"))]
+    bodies.iter_mut().for_each(extrapolate_transform);
+}
+
+/// Returns the time accumulated towards the next physics step,
+/// or `None` if the timestep doesn't accumulate time.
+fn accumulated_time(
+    physics_loop: &PhysicsLoop,
+    time_step: &PhysicsTimestep,
+    fixed_time: Option<&FixedTime>,
+) -> Option<Scalar> {
+    match time_step {
+        PhysicsTimestep::Fixed(_) | PhysicsTimestep::Variable { .. } => {
+            Some(physics_loop.accumulator)
+        }
+        // In `FixedUpdate`, the time is accumulated by Bevy instead of the physics loop
+        PhysicsTimestep::FixedUpdate => {
+            fixed_time.map(|fixed_time| fixed_time.accumulated().as_secs_f64() as Scalar)
+        }
+        PhysicsTimestep::FixedOnce(_) => None,
     }
 }
 
@@ -570,3 +1086,381 @@ fn update_previous_global_transforms(
         previous_transform.0 = *transform;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, tick_60_fps},
+    };
+    use approx::assert_relative_eq;
+    use bevy::{log::LogPlugin, prelude::*, time::TimeUpdateStrategy, utils::Instant};
+
+    #[test]
+    fn transform_writes_teleport_bodies() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(SyncConfig {
+            reset_velocity_on_teleport: true,
+            ..default()
+        });
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LinearVelocity(Vector::X),
+            ))
+            .id();
+
+        app.step_physics(1);
+
+        app.world.get_mut::<Transform>(body).unwrap().translation = Vec3::Y * 10.0;
+
+        app.step_physics(1);
+
+        let position = app.world.get::<Position>(body).unwrap();
+        assert_relative_eq!(position.y, 10.0, epsilon = 0.001);
+        assert_eq!(
+            app.world.get::<LinearVelocity>(body).unwrap().0,
+            Vector::ZERO
+        );
+    }
+
+    #[test]
+    fn transform_interpolation_can_be_toggled_per_entity() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(PhysicsTimestep::Fixed(1.0 / 30.0));
+
+        let a = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                LinearVelocity(Vector::X * 30.0),
+                InterpolateTransform,
+            ))
+            .id();
+        let b = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 5.0),
+                LinearVelocity(Vector::X * 30.0),
+            ))
+            .id();
+
+        // Returns the expected interpolated x coordinate and the simulated x coordinate
+        let expected_x = |app: &App, entity: Entity| {
+            let physics_loop = app.world.resource::<PhysicsLoop>();
+            let dt = app.world.resource::<DeltaTime>().0;
+            let alpha = (physics_loop.accumulator / dt).clamp(0.0, 1.0);
+            let previous = app.world.get::<PreviousStepPosition>(entity).unwrap().x;
+            let current = app.world.get::<Position>(entity).unwrap().x;
+            (previous + (current - previous) * alpha, current)
+        };
+
+        tick_60_fps(&mut app);
+
+        // Only the body with `InterpolateTransform` is interpolated
+        let mut lagging_frames = 0;
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+
+            let (interpolated, current) = expected_x(&app, a);
+            let translation = app.world.get::<Transform>(a).unwrap().translation;
+            assert_relative_eq!(translation.x, interpolated as f32, epsilon = 0.001);
+            if translation.x < current as f32 - 0.1 {
+                lagging_frames += 1;
+            }
+
+            let (_, current) = expected_x(&app, b);
+            let translation = app.world.get::<Transform>(b).unwrap().translation;
+            assert_relative_eq!(translation.x, current as f32, epsilon = 0.001);
+        }
+        assert!(lagging_frames > 0);
+
+        // Enable interpolation globally, but opt out for the first body
+        app.world
+            .resource_mut::<SyncConfig>()
+            .interpolate_transforms = true;
+        app.world.entity_mut(a).insert(NoTransformInterpolation);
+
+        // The transform of the first body is updated when the next physics step is run
+        for _ in 0..3 {
+            tick_60_fps(&mut app);
+        }
+
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+
+            let (_, current) = expected_x(&app, a);
+            let translation = app.world.get::<Transform>(a).unwrap().translation;
+            assert_relative_eq!(translation.x, current as f32, epsilon = 0.001);
+
+            let (interpolated, _) = expected_x(&app, b);
+            let translation = app.world.get::<Transform>(b).unwrap().translation;
+            assert_relative_eq!(translation.x, interpolated as f32, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn teleported_bodies_are_not_interpolated() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(PhysicsTimestep::Fixed(1.0 / 30.0));
+        app.world
+            .resource_mut::<SyncConfig>()
+            .interpolate_transforms = true;
+
+        let body = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Dynamic))
+            .id();
+
+        tick_60_fps(&mut app);
+
+        // Physics only runs every other frame, so teleport on frames with and without a physics step
+        for i in 1..=4 {
+            let target = i as Scalar * 10.0;
+            app.world
+                .entity_mut(body)
+                .insert(Teleport::new(Vector::X * target, Rotation::default()));
+
+            tick_60_fps(&mut app);
+
+            let translation = app.world.get::<Transform>(body).unwrap().translation;
+            assert_relative_eq!(translation.x, target as f32, epsilon = 0.001);
+        }
+
+        // Writing to the `Transform` directly also teleports the body without interpolation
+        for i in 1..=4 {
+            let target = i as f32 * -10.0;
+            app.world.get_mut::<Transform>(body).unwrap().translation.x = target;
+
+            tick_60_fps(&mut app);
+
+            let translation = app.world.get::<Transform>(body).unwrap().translation;
+            assert_relative_eq!(translation.x, target, epsilon = 0.001);
+        }
+    }
+
+    #[test]
+    fn transforms_are_interpolated_with_fixed_update_overstep() {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            LogPlugin::default(),
+            PhysicsPlugins::new(FixedUpdate),
+        ));
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(Instant::now()));
+        app.insert_resource(FixedTime::new_from_secs(1.0 / 30.0));
+        app.insert_resource(Gravity::ZERO);
+
+        let interpolated = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                LinearVelocity(Vector::X * 30.0),
+                InterpolateTransform,
+            ))
+            .id();
+        let extrapolated = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::Y * 5.0),
+                LinearVelocity(Vector::X * 30.0),
+                ExtrapolateTransform,
+            ))
+            .id();
+
+        tick_60_fps(&mut app);
+
+        let mut lagging_frames = 0;
+        let mut leading_frames = 0;
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+
+            let fixed_time = app.world.resource::<FixedTime>();
+            let accumulated_time = fixed_time.accumulated().as_secs_f64() as Scalar;
+            let alpha = accumulated_time / fixed_time.period.as_secs_f64() as Scalar;
+
+            let previous = app
+                .world
+                .get::<PreviousStepPosition>(interpolated)
+                .unwrap()
+                .x;
+            let current = app.world.get::<Position>(interpolated).unwrap().x;
+            let translation = app
+                .world
+                .get::<Transform>(interpolated)
+                .unwrap()
+                .translation;
+            assert_relative_eq!(
+                translation.x,
+                (previous + (current - previous) * alpha) as f32,
+                epsilon = 0.001
+            );
+            if translation.x < current as f32 - 0.1 {
+                lagging_frames += 1;
+            }
+
+            let current = app.world.get::<Position>(extrapolated).unwrap().x;
+            let translation = app
+                .world
+                .get::<Transform>(extrapolated)
+                .unwrap()
+                .translation;
+            assert_relative_eq!(
+                translation.x,
+                (current + 30.0 * accumulated_time) as f32,
+                epsilon = 0.001
+            );
+            if translation.x > current as f32 + 0.1 {
+                leading_frames += 1;
+            }
+        }
+        assert!(lagging_frames > 0);
+        assert!(leading_frames > 0);
+
+        // Writing to the `Transform` directly still moves the body
+        app.world
+            .get_mut::<Transform>(interpolated)
+            .unwrap()
+            .translation
+            .y = 10.0;
+        tick_60_fps(&mut app);
+        tick_60_fps(&mut app);
+        assert_relative_eq!(
+            app.world.get::<Position>(interpolated).unwrap().y,
+            10.0,
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn transform_extrapolation_projects_bodies_forward() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+        app.insert_resource(PhysicsTimestep::Fixed(1.0 / 30.0));
+        app.world
+            .resource_mut::<SyncConfig>()
+            .interpolate_transforms = true;
+
+        // Extrapolation takes priority over interpolation
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                LinearVelocity(Vector::X * 30.0),
+                ExtrapolateTransform,
+            ))
+            .id();
+
+        tick_60_fps(&mut app);
+
+        let mut leading_frames = 0;
+        for _ in 0..10 {
+            tick_60_fps(&mut app);
+
+            let accumulated_time = app.world.resource::<PhysicsLoop>().accumulator;
+            let current = app.world.get::<Position>(body).unwrap().x;
+            let translation = app.world.get::<Transform>(body).unwrap().translation;
+            assert_relative_eq!(
+                translation.x,
+                (current + 30.0 * accumulated_time) as f32,
+                epsilon = 0.001
+            );
+            if translation.x > current as f32 + 0.1 {
+                leading_frames += 1;
+            }
+        }
+        assert!(leading_frames > 0);
+    }
+
+    #[test]
+    fn transforms_are_synced_for_many_bodies() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        // Bodies spread far enough apart that they don't collide
+        let bodies: Vec<Entity> = (0..64)
+            .map(|i| {
+                let offset =
+                    Vector::X * (i % 8) as Scalar * 4.0 + Vector::Y * (i / 8) as Scalar * 4.0;
+                #[cfg(feature = "2d")]
+                let angular_velocity = AngularVelocity(i as Scalar * 0.1);
+                #[cfg(feature = "3d")]
+                let angular_velocity = AngularVelocity(Vector::Z * i as Scalar * 0.1);
+                app.world
+                    .spawn((
+                        TransformBundle::default(),
+                        RigidBody::Dynamic,
+                        Position(offset),
+                        LinearVelocity(offset * 0.1),
+                        angular_velocity,
+                        Collider::ball(0.5),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        // A body whose parent is moved by the user
+        let parent = app
+            .world
+            .spawn(TransformBundle::from_transform(Transform::from_xyz(
+                -10.0, 0.0, 0.0,
+            )))
+            .id();
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::NEG_Y * 10.0),
+                LinearVelocity(Vector::X),
+                Collider::ball(0.5),
+            ))
+            .set_parent(parent)
+            .id();
+
+        app.step_physics(10);
+
+        for entity in bodies {
+            let position = app.world.get::<Position>(entity).unwrap();
+            let rotation = app.world.get::<Rotation>(entity).unwrap();
+            let transform = app.world.get::<Transform>(entity).unwrap();
+
+            #[cfg(feature = "2d")]
+            let translation = transform.translation.truncate().adjust_precision();
+            #[cfg(feature = "3d")]
+            let translation = transform.translation.adjust_precision();
+            assert_relative_eq!(translation, position.0, epsilon = 1e-4);
+            assert!(
+                transform
+                    .rotation
+                    .angle_between(Quaternion::from(*rotation).as_f32())
+                    < 0.01
+            );
+        }
+
+        // The child body is synced relative to its parent
+        let child_transform = app.world.get::<Transform>(child).unwrap();
+        let child_position = app.world.get::<Position>(child).unwrap();
+        assert_relative_eq!(
+            child_transform.translation.x.adjust_precision(),
+            child_position.x + 10.0,
+            epsilon = 1e-4
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! Generates merged colliders for tile grids.
+//!
+//! See [`TilemapColliderPlugin`].
+
+use crate::prelude::*;
+use bevy::{
+    ecs::schedule::ScheduleLabel,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+/// A plugin that generates colliders for entities with the [`TileColliders`] component.
+///
+/// Spawning a separate collider for every tile of a large tilemap is expensive, and bodies sliding along
+/// the seams between neighboring tiles can catch on them. Instead, the solid tiles are merged into
+/// a minimal set of rectangles using greedy meshing, or into outlines made of long line segments,
+/// depending on the [`TileColliderMode`].
+///
+/// The grid is split into chunks, and each chunk gets its own child collider entity. When tiles change,
+/// only the colliders of the affected chunks are regenerated, so editing the map at runtime stays cheap
+/// even for large worlds like those made with [bevy_ecs_tilemap](https://github.com/StarArawn/bevy_ecs_tilemap).
+///
+/// The colliders are generated before [`PhysicsSet::Prepare`] in the schedule given in
+/// [`TilemapColliderPlugin::new`].
+pub struct TilemapColliderPlugin {
+    schedule: Box<dyn ScheduleLabel>,
+}
+
+impl TilemapColliderPlugin {
+    /// Creates a [`TilemapColliderPlugin`] with the schedule that is used for running the [`PhysicsSchedule`].
+    ///
+    /// The default schedule is `PostUpdate`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: Box::new(schedule),
+        }
+    }
+}
+
+impl Default for TilemapColliderPlugin {
+    fn default() -> Self {
+        Self::new(PostUpdate)
+    }
+}
+
+impl Plugin for TilemapColliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TileColliderMode>()
+            .register_type::<TileColliderChunk>();
+
+        app.add_systems(
+            self.schedule.dyn_clone(),
+            (update_tile_colliders, apply_deferred)
+                .chain()
+                .before(PhysicsSet::Prepare),
+        );
+    }
+}
+
+/// Determines what kind of colliders are generated for the solid tiles of [`TileColliders`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TileColliderMode {
+    /// The solid tiles are merged into as few rectangles as possible, and each chunk gets a compound
+    /// collider made of cuboids. The colliders are solid, so they work well for dynamic bodies.
+    #[default]
+    Cuboids,
+    /// The outlines of the solid tiles are merged into long segments, and each chunk gets a polyline
+    /// collider. Outlines are hollow, but they have no internal edges for bodies to catch on.
+    Outlines,
+}
+
+/// A grid of tiles that gets merged colliders for its solid tiles. Requires the [`TilemapColliderPlugin`].
+///
+/// The tile at `(x, y)` is centered at `(x * tile_size.x, y * tile_size.y)` in the local space of the entity,
+/// matching the layout of [bevy_ecs_tilemap](https://github.com/StarArawn/bevy_ecs_tilemap).
+/// The colliders are spawned as children of the entity, so it should usually be a [static](RigidBody::Static)
+/// rigid body with a `TransformBundle`.
+///
+/// Tiles can be changed at any time using [`TileColliders::set`], and only the colliders of the chunks
+/// containing the changed tiles are regenerated.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     let level = [
+///         "#....#",
+///         "#....#",
+///         "######",
+///     ];
+///
+///     let mut tiles = TileColliders::new(UVec2::new(6, 3), Vector::splat(16.0));
+///     for (row, line) in level.iter().enumerate() {
+///         for (x, tile) in line.chars().enumerate() {
+///             // Rows are listed from top to bottom, but the Y axis points up
+///             let y = level.len() - 1 - row;
+///             tiles.set(UVec2::new(x as u32, y as u32), tile == '#');
+///         }
+///     }
+///
+///     commands.spawn((RigidBody::Static, tiles, TransformBundle::default()));
+/// }
+///
+/// fn break_tile(mut query: Query<&mut TileColliders>) {
+///     for mut tiles in &mut query {
+///         // Only the collider of the chunk containing this tile is regenerated
+///         tiles.set(UVec2::new(0, 2), false);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct TileColliders {
+    size: UVec2,
+    tile_size: Vector,
+    chunk_size: u32,
+    mode: TileColliderMode,
+    solid: Vec<bool>,
+    dirty_chunks: HashSet<UVec2>,
+    chunk_entities: HashMap<UVec2, Entity>,
+}
+
+impl TileColliders {
+    /// The default width and height of a chunk in tiles.
+    pub const DEFAULT_CHUNK_SIZE: u32 = 32;
+
+    /// Creates a new grid with the given size in tiles where every tile is empty.
+    pub fn new(size: UVec2, tile_size: Vector) -> Self {
+        Self {
+            size,
+            tile_size,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            mode: TileColliderMode::default(),
+            solid: vec![false; (size.x * size.y) as usize],
+            dirty_chunks: HashSet::default(),
+            chunk_entities: HashMap::default(),
+        }
+    }
+
+    /// Creates a new grid with the given size in tiles where the tiles for which `is_solid` returns true are solid.
+    pub fn from_fn(size: UVec2, tile_size: Vector, is_solid: impl Fn(UVec2) -> bool) -> Self {
+        let mut tiles = Self::new(size, tile_size);
+        for y in 0..size.y {
+            for x in 0..size.x {
+                let tile = UVec2::new(x, y);
+                if is_solid(tile) {
+                    tiles.set(tile, true);
+                }
+            }
+        }
+        tiles
+    }
+
+    /// Sets the kind of colliders that are generated.
+    pub fn with_mode(mut self, mode: TileColliderMode) -> Self {
+        self.mode = mode;
+        self.mark_all_dirty();
+        self
+    }
+
+    /// Sets the width and height of a chunk in tiles. Smaller chunks are faster to regenerate
+    /// when tiles change, but produce more colliders.
+    pub fn with_chunk_size(mut self, chunk_size: u32) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self.dirty_chunks.clear();
+        self.mark_all_dirty();
+        self
+    }
+
+    /// Returns the size of the grid in tiles.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Returns the size of a tile.
+    pub fn tile_size(&self) -> Vector {
+        self.tile_size
+    }
+
+    /// Returns the kind of colliders that are generated.
+    pub fn mode(&self) -> TileColliderMode {
+        self.mode
+    }
+
+    /// Returns true if the given tile is solid. Tiles outside of the grid are empty.
+    pub fn is_solid(&self, tile: UVec2) -> bool {
+        tile.x < self.size.x && tile.y < self.size.y && self.solid[self.index(tile)]
+    }
+
+    /// Sets whether the given tile is solid. Tiles outside of the grid are ignored.
+    ///
+    /// The chunks that are affected by the change are regenerated before the next physics step.
+    pub fn set(&mut self, tile: UVec2, solid: bool) {
+        if tile.x >= self.size.x || tile.y >= self.size.y {
+            return;
+        }
+
+        let index = self.index(tile);
+        if self.solid[index] == solid {
+            return;
+        }
+        self.solid[index] = solid;
+
+        // The outlines of neighboring chunks can depend on the tile
+        let chunk = tile / self.chunk_size;
+        self.dirty_chunks.insert(chunk);
+        for neighbor in [
+            tile.saturating_sub(UVec2::X),
+            tile.saturating_sub(UVec2::Y),
+            tile + UVec2::X,
+            tile + UVec2::Y,
+        ] {
+            if neighbor.x < self.size.x && neighbor.y < self.size.y {
+                self.dirty_chunks.insert(neighbor / self.chunk_size);
+            }
+        }
+    }
+
+    /// Returns the collider entity of the given chunk, if it has been spawned.
+    pub fn chunk_entity(&self, chunk: UVec2) -> Option<Entity> {
+        self.chunk_entities.get(&chunk).copied()
+    }
+
+    /// Builds a collider for the solid tiles in the rectangle between `min` (inclusive) and `max` (exclusive),
+    /// in the local space of the grid. Returns `None` if there are no solid tiles in the rectangle.
+    ///
+    /// This can also be used for generating colliders without the [`TilemapColliderPlugin`].
+    pub fn build_collider(&self, min: UVec2, max: UVec2) -> Option<Collider> {
+        let max = max.min(self.size);
+        if min.x >= max.x || min.y >= max.y {
+            return None;
+        }
+
+        match self.mode {
+            TileColliderMode::Cuboids => {
+                let rectangles = self.merge_rectangles(min, max);
+                if rectangles.is_empty() {
+                    return None;
+                }
+
+                let shapes = rectangles
+                    .into_iter()
+                    .map(|(start, size)| {
+                        let center = (start.as_vec2() + size.as_vec2() * 0.5 - 0.5)
+                            .adjust_precision()
+                            * self.tile_size;
+                        let extents = size.as_vec2().adjust_precision() * self.tile_size;
+                        (
+                            Position(center),
+                            Rotation::default(),
+                            Collider::cuboid(extents.x, extents.y),
+                        )
+                    })
+                    .collect();
+                Some(Collider::compound(shapes))
+            }
+            TileColliderMode::Outlines => {
+                let segments = self.merge_outlines(min, max);
+                if segments.is_empty() {
+                    return None;
+                }
+
+                let vertices = segments
+                    .iter()
+                    .flat_map(|(start, end)| [*start, *end])
+                    .map(|corner| (corner - 0.5) * self.tile_size)
+                    .collect();
+                let indices = (0..segments.len() as u32)
+                    .map(|i| [2 * i, 2 * i + 1])
+                    .collect();
+                Some(Collider::polyline(vertices, Some(indices)))
+            }
+        }
+    }
+
+    fn index(&self, tile: UVec2) -> usize {
+        (tile.y * self.size.x + tile.x) as usize
+    }
+
+    fn chunk_count(&self) -> UVec2 {
+        (self.size + self.chunk_size - 1) / self.chunk_size
+    }
+
+    fn mark_all_dirty(&mut self) {
+        let chunks = self.chunk_count();
+        for y in 0..chunks.y {
+            for x in 0..chunks.x {
+                self.dirty_chunks.insert(UVec2::new(x, y));
+            }
+        }
+    }
+
+    /// Merges the solid tiles in the given rectangle into rectangles using greedy meshing.
+    /// Returns the first tile and the size in tiles of each rectangle.
+    fn merge_rectangles(&self, min: UVec2, max: UVec2) -> Vec<(UVec2, UVec2)> {
+        let width = (max.x - min.x) as usize;
+        let mut merged = vec![false; width * (max.y - min.y) as usize];
+        let is_free = |merged: &[bool], tile: UVec2| {
+            self.is_solid(tile)
+                && !merged[(tile.y - min.y) as usize * width + (tile.x - min.x) as usize]
+        };
+
+        let mut rectangles = vec![];
+
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let start = UVec2::new(x, y);
+                if !is_free(&merged, start) {
+                    continue;
+                }
+
+                // Grow the rectangle as far as possible along the row, and then upwards one row at a time
+                let mut end_x = x + 1;
+                while end_x < max.x && is_free(&merged, UVec2::new(end_x, y)) {
+                    end_x += 1;
+                }
+                let mut end_y = y + 1;
+                while end_y < max.y && (x..end_x).all(|x| is_free(&merged, UVec2::new(x, end_y))) {
+                    end_y += 1;
+                }
+
+                for merged_y in y..end_y {
+                    for merged_x in x..end_x {
+                        merged[(merged_y - min.y) as usize * width + (merged_x - min.x) as usize] =
+                            true;
+                    }
+                }
+
+                rectangles.push((start, UVec2::new(end_x - x, end_y - y)));
+            }
+        }
+
+        rectangles
+    }
+
+    /// Merges the edges between the solid tiles in the given rectangle and empty tiles into long segments.
+    /// The returned points are tile corners, where the corner `(x, y)` is the bottom left corner of the tile `(x, y)`.
+    fn merge_outlines(&self, min: UVec2, max: UVec2) -> Vec<(Vector, Vector)> {
+        let is_solid =
+            |x: i64, y: i64| x >= 0 && y >= 0 && self.is_solid(UVec2::new(x as u32, y as u32));
+        let mut segments = vec![];
+
+        // Each side of a tile is an edge of the outline if the neighbor on that side is empty
+        for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+            let horizontal = dy != 0;
+            let (lines, steps) = if horizontal {
+                (min.y..max.y, min.x..max.x)
+            } else {
+                (min.x..max.x, min.y..max.y)
+            };
+
+            for line in lines {
+                let mut start = None;
+
+                for step in steps.clone().chain(std::iter::once(steps.end)) {
+                    let (x, y) = if horizontal {
+                        (step as i64, line as i64)
+                    } else {
+                        (line as i64, step as i64)
+                    };
+                    let is_edge = step < steps.end && is_solid(x, y) && !is_solid(x + dx, y + dy);
+
+                    match (is_edge, start) {
+                        (true, None) => start = Some(step),
+                        (false, Some(first)) => {
+                            // The edge lies on the side of the tile facing the empty neighbor
+                            let offset = if dx > 0 || dy > 0 { 1 } else { 0 };
+                            let line = (line + offset) as Scalar;
+                            segments.push(if horizontal {
+                                (
+                                    Vector::new(first as Scalar, line),
+                                    Vector::new(step as Scalar, line),
+                                )
+                            } else {
+                                (
+                                    Vector::new(line, first as Scalar),
+                                    Vector::new(line, step as Scalar),
+                                )
+                            });
+                            start = None;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        segments
+    }
+}
+
+/// A child entity of a [`TileColliders`] entity that holds the collider of one chunk of the grid.
+#[derive(Reflect, Clone, Copy, Component, Debug, Default, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct TileColliderChunk {
+    /// The position of the chunk in the grid of chunks.
+    pub chunk: UVec2,
+}
+
+/// Regenerates the colliders of the chunks that have changed.
+fn update_tile_colliders(
+    mut commands: Commands,
+    mut tilemaps: Query<(Entity, &mut TileColliders), Changed<TileColliders>>,
+) {
+    for (entity, mut tiles) in &mut tilemaps {
+        // Avoid triggering change detection for our own bookkeeping
+        let tiles = tiles.bypass_change_detection();
+
+        let mut dirty_chunks = std::mem::take(&mut tiles.dirty_chunks)
+            .into_iter()
+            .collect::<Vec<_>>();
+        // Sort the chunks to make the generated entities deterministic
+        dirty_chunks.sort_unstable_by_key(|chunk| (chunk.y, chunk.x));
+
+        for chunk in dirty_chunks {
+            let min = chunk * tiles.chunk_size;
+            let collider = tiles.build_collider(min, min + tiles.chunk_size);
+
+            match (tiles.chunk_entities.get(&chunk).copied(), collider) {
+                (Some(chunk_entity), Some(collider)) => {
+                    commands.entity(chunk_entity).insert(collider);
+                }
+                (Some(chunk_entity), None) => {
+                    commands.entity(chunk_entity).despawn_recursive();
+                    tiles.chunk_entities.remove(&chunk);
+                }
+                (None, Some(collider)) => {
+                    let chunk_entity = commands
+                        .spawn((
+                            collider,
+                            TileColliderChunk { chunk },
+                            TransformBundle::default(),
+                        ))
+                        .set_parent(entity)
+                        .id();
+                    tiles.chunk_entities.insert(chunk, chunk_entity);
+                }
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn tile_colliders_are_merged_and_updated() {
+        let mut app = create_app();
+        app.add_plugins(TilemapColliderPlugin::default());
+
+        // A 4x2 block of solid tiles is merged into a single cuboid
+        let tiles = TileColliders::from_fn(UVec2::new(8, 8), Vector::ONE, |tile| {
+            tile.x < 4 && tile.y < 2
+        })
+        .with_chunk_size(4);
+        let tilemap = app
+            .world
+            .spawn((RigidBody::Static, tiles, TransformBundle::default()))
+            .id();
+
+        app.step_physics(1);
+
+        let chunk_collider = |app: &App, chunk: UVec2| {
+            app.world
+                .get::<TileColliders>(tilemap)
+                .unwrap()
+                .chunk_entity(chunk)
+                .and_then(|entity| app.world.get::<Collider>(entity).cloned())
+        };
+
+        let collider = chunk_collider(&app, UVec2::ZERO).expect("chunk should have a collider");
+        assert_eq!(collider.as_compound().unwrap().shapes().len(), 1);
+        let aabb = collider.compute_aabb(Vector::ZERO, 0.0);
+        assert_relative_eq!(Vector::from(aabb.mins), Vector::new(-0.5, -0.5));
+        assert_relative_eq!(Vector::from(aabb.maxs), Vector::new(3.5, 1.5));
+        assert!(chunk_collider(&app, UVec2::X).is_none());
+
+        // Changing a tile only regenerates the affected chunks
+        app.world
+            .get_mut::<TileColliders>(tilemap)
+            .unwrap()
+            .set(UVec2::new(5, 0), true);
+
+        app.step_physics(1);
+
+        let collider = chunk_collider(&app, UVec2::ZERO).unwrap();
+        assert_eq!(collider.as_compound().unwrap().shapes().len(), 1);
+        assert!(chunk_collider(&app, UVec2::X).is_some());
+    }
+}
@@ -0,0 +1,341 @@
+//! A preset for top-down 2D games, where bodies slide on a floor that is perpendicular to the view.
+//!
+//! See [`TopDownPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Configures the simulation for top-down 2D games, where gravity points into the screen instead of along it.
+///
+/// The plugin does the following:
+///
+/// - Sets [`Gravity`] to zero, since gravity in a top-down view only presses bodies against the floor.
+/// - Applies [ground friction](GroundFriction) to dynamic bodies so that they slow down and come to a stop
+/// like objects sliding on a floor.
+/// - Adds [`LinearDamping`] and [`AngularDamping`] to new dynamic bodies that don't have them, using the defaults
+/// in [`TopDownConfig`].
+///
+/// Without this, bodies keep sliding forever, and imitating the floor requires tweaking the damping of every body.
+///
+/// The ground friction is applied in [`SubstepSet::SolveVelocities`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_xpbd_2d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default(), TopDownPlugin))
+///         .run();
+/// }
+/// ```
+pub struct TopDownPlugin;
+
+impl Plugin for TopDownPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TopDownConfig>()
+            .register_type::<GroundFriction>();
+
+        app.insert_resource(Gravity::ZERO)
+            .init_resource::<TopDownConfig>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(add_default_damping.before(PhysicsStepSet::BroadPhase));
+
+        app.get_schedule_mut(SubstepSchedule)
+            .expect("add SubstepSchedule first")
+            .add_systems(apply_ground_friction.in_set(SubstepSet::SolveVelocities));
+    }
+}
+
+/// Configures the floor used by the [`TopDownPlugin`].
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_xpbd_2d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default(), TopDownPlugin))
+///         // Make the floor slippery like ice
+///         .insert_resource(TopDownConfig {
+///             ground_friction: GroundFriction::new(0.05),
+///             ..default()
+///         })
+///         .run();
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct TopDownConfig {
+    /// The magnitude of the gravity that presses bodies against the floor.
+    ///
+    /// Together with the [`GroundFriction`], this determines how quickly bodies slow down.
+    pub ground_gravity: Scalar,
+    /// The [`GroundFriction`] used for bodies that don't have the component.
+    pub ground_friction: GroundFriction,
+    /// The [`LinearDamping`] added to new dynamic bodies that don't have it, for example to simulate air resistance.
+    pub linear_damping: Scalar,
+    /// The [`AngularDamping`] added to new dynamic bodies that don't have it.
+    pub angular_damping: Scalar,
+}
+
+impl Default for TopDownConfig {
+    fn default() -> Self {
+        Self {
+            ground_gravity: 9.81,
+            ground_friction: GroundFriction::default(),
+            linear_damping: 0.1,
+            angular_damping: 0.1,
+        }
+    }
+}
+
+/// Friction between a body and the floor in a top-down view. Used by the [`TopDownPlugin`].
+///
+/// Unlike [`LinearDamping`], which slows bodies down in proportion to their velocity, ground friction
+/// slows bodies down at a constant rate given by the coefficient and [`TopDownConfig::ground_gravity`],
+/// so that they come to a complete stop like objects sliding on a floor.
+///
+/// Like [`Friction`], ground friction has a static and a dynamic coefficient. Bodies at rest stay in place
+/// until the forces pushing them overcome the static friction, and sliding bodies are slowed down using
+/// the dynamic friction. Once a sliding body is slow enough for static friction to hold it, it stops immediately.
+///
+/// Bodies without the component use [`TopDownConfig::ground_friction`].
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_xpbd_2d::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+///     // A puck that slides far once it gets going
+///     commands.spawn((
+///         RigidBody::Dynamic,
+///         Collider::ball(0.5),
+///         GroundFriction::new(0.02).with_static_coefficient(0.1),
+///     ));
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Component, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct GroundFriction {
+    /// The coefficient of friction that slows down the translation of a sliding body.
+    pub dynamic_coefficient: Scalar,
+    /// The coefficient of friction that keeps a body at rest until the forces pushing it overcome the friction.
+    ///
+    /// This should usually be larger than or equal to the dynamic coefficient.
+    pub static_coefficient: Scalar,
+    /// The coefficient of friction that slows down the rotation of the body.
+    ///
+    /// The angular deceleration is the product of this coefficient and the ground gravity, in radians per second squared.
+    pub angular: Scalar,
+}
+
+impl Default for GroundFriction {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl GroundFriction {
+    /// Zero ground friction.
+    pub const ZERO: Self = Self {
+        dynamic_coefficient: 0.0,
+        static_coefficient: 0.0,
+        angular: 0.0,
+    };
+
+    /// Creates a new [`GroundFriction`] with the given coefficient for static and dynamic friction and rotation.
+    pub const fn new(coefficient: Scalar) -> Self {
+        Self {
+            dynamic_coefficient: coefficient,
+            static_coefficient: coefficient,
+            angular: coefficient,
+        }
+    }
+
+    /// Sets the coefficient of friction that slows down the translation of a sliding body.
+    pub const fn with_dynamic_coefficient(self, coefficient: Scalar) -> Self {
+        Self {
+            dynamic_coefficient: coefficient,
+            ..self
+        }
+    }
+
+    /// Sets the coefficient of friction that keeps a body at rest.
+    pub const fn with_static_coefficient(self, coefficient: Scalar) -> Self {
+        Self {
+            static_coefficient: coefficient,
+            ..self
+        }
+    }
+
+    /// Sets the coefficient of friction that slows down the rotation of the body.
+    pub const fn with_angular(self, angular: Scalar) -> Self {
+        Self { angular, ..self }
+    }
+}
+
+/// Adds the default damping in [`TopDownConfig`] to new dynamic bodies that don't have damping.
+fn add_default_damping(
+    mut commands: Commands,
+    bodies: Query<
+        (
+            Entity,
+            &RigidBody,
+            Option<&LinearDamping>,
+            Option<&AngularDamping>,
+        ),
+        Added<RigidBody>,
+    >,
+    config: Res<TopDownConfig>,
+) {
+    for (entity, rb, lin_damping, ang_damping) in &bodies {
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let mut entity_commands = commands.entity(entity);
+        if lin_damping.is_none() {
+            entity_commands.insert(LinearDamping(config.linear_damping));
+        }
+        if ang_damping.is_none() {
+            entity_commands.insert(AngularDamping(config.angular_damping));
+        }
+    }
+}
+
+/// Slows down dynamic bodies by a constant deceleration caused by friction against the floor.
+fn apply_ground_friction(
+    mut bodies: Query<
+        (
+            &RigidBody,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+            Option<&GroundFriction>,
+        ),
+        Without<Sleeping>,
+    >,
+    config: Res<TopDownConfig>,
+    sub_dt: Res<SubDeltaTime>,
+) {
+    for (rb, mut lin_vel, mut ang_vel, friction) in &mut bodies {
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let friction = friction.unwrap_or(&config.ground_friction);
+
+        // If static friction can stop the body during this substep, it sticks to the floor.
+        // Otherwise, it slides and is slowed down by dynamic friction.
+        let speed = lin_vel.length();
+        if speed > 0.0 {
+            let max_static_delta = friction.static_coefficient * config.ground_gravity * sub_dt.0;
+            if speed <= max_static_delta {
+                lin_vel.0 = Vector::ZERO;
+            } else {
+                // The friction can only bring the body to a stop, not reverse its direction
+                let delta_speed =
+                    (friction.dynamic_coefficient * config.ground_gravity * sub_dt.0).min(speed);
+                lin_vel.0 -= lin_vel.0 / speed * delta_speed;
+            }
+        }
+
+        let angular_speed = ang_vel.0.abs();
+        if angular_speed > 0.0 {
+            let delta_speed =
+                (friction.angular * config.ground_gravity * sub_dt.0).min(angular_speed);
+            ang_vel.0 -= ang_vel.0.signum() * delta_speed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_utils::*, tests::create_app};
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn ground_friction_stops_sliding_bodies() {
+        let mut app = create_app();
+        app.add_plugins(TopDownPlugin);
+        app.insert_resource(TopDownConfig {
+            ground_gravity: 10.0,
+            ground_friction: GroundFriction::new(0.5),
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+        });
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LinearVelocity(Vector::X * 5.0),
+            ))
+            .id();
+
+        app.step_physics(120);
+
+        // The body decelerates at a constant rate of 5 m/s², so it stops after one second
+        // and slides v² / 2a = 2.5 meters
+        assert_eq!(
+            app.world.get::<LinearVelocity>(body).unwrap().0,
+            Vector::ZERO
+        );
+        let position = app.world.get::<Position>(body).unwrap();
+        assert_relative_eq!(position.x, 2.5, max_relative = 0.05);
+        assert_eq!(app.world.resource::<Gravity>().0, Vector::ZERO);
+    }
+
+    #[test]
+    fn ground_friction_keeps_bodies_at_rest_until_static_friction_is_overcome() {
+        let mut app = create_app();
+        app.add_plugins(TopDownPlugin);
+        app.insert_resource(TopDownConfig {
+            ground_gravity: 10.0,
+            ground_friction: GroundFriction::new(0.2).with_static_coefficient(0.5),
+            linear_damping: 0.0,
+            angular_damping: 0.0,
+        });
+
+        let collider = Collider::ball(0.5);
+        let mass = collider.mass_properties(1.0).mass.0;
+
+        // Forces that are slightly weaker and stronger than the static friction
+        let mut bodies = vec![];
+        for (y, force) in [(0.0, 4.5 * mass), (5.0, 5.5 * mass)] {
+            let body = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    RigidBody::Dynamic,
+                    Position(Vector::Y * y),
+                    collider.clone(),
+                    ExternalForce::new(Vector::X * force),
+                ))
+                .id();
+            bodies.push(body);
+        }
+
+        app.step_physics(60);
+
+        // The weaker force can't move the body
+        let resting = app.world.get::<Position>(bodies[0]).unwrap();
+        assert_eq!(resting.x, 0.0);
+
+        // Once the stronger force overcomes static friction, the body accelerates at 5.5 - 2 = 3.5 m/s²
+        // because it is slowed down by the weaker dynamic friction, reaching 3.5 m/s after one second
+        let sliding = app.world.get::<LinearVelocity>(bodies[1]).unwrap();
+        assert_relative_eq!(sliding.x, 3.5, max_relative = 0.05);
+    }
+}
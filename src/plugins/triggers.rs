@@ -0,0 +1,234 @@
+//! Sends events when entities enter or exit sensors and keeps track of how long they have been inside.
+//!
+//! See [`TriggerPlugin`].
+
+use crate::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
+
+/// Sends [`TriggerEnter`] and [`TriggerExit`] events when colliders start or stop overlapping [`Sensor`] colliders,
+/// and keeps track of how long each collider has been inside a sensor in the sensor's [`SensorOverlaps`].
+///
+/// This is useful for things like charge-up zones, damage-over-time areas and checkpoints.
+///
+/// [`SensorOverlaps`] is added to sensors automatically. The events are sent and the overlaps are updated
+/// after [`PhysicsStepSet::ReportContacts`], using the same collision start and end detection
+/// as the [collision events](Collider#collision-events).
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn damage_over_time(zones: Query<&SensorOverlaps>) {
+///     for overlaps in &zones {
+///         for (entity, duration) in overlaps.iter() {
+///             // The damage increases the longer the entity stays inside
+///             println!("{:?} takes {} damage", entity, duration * 2.0);
+///         }
+///     }
+/// }
+///
+/// fn checkpoints(mut exit_events: EventReader<TriggerExit>) {
+///     for event in exit_events.iter() {
+///         println!(
+///             "{:?} left {:?} after {} seconds",
+///             event.entity, event.sensor, event.duration
+///         );
+///     }
+/// }
+/// ```
+pub struct TriggerPlugin;
+
+impl Plugin for TriggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TriggerEnter>()
+            .add_event::<TriggerExit>()
+            .register_type::<SensorOverlaps>();
+
+        app.get_schedule_mut(PhysicsSchedule)
+            .expect("add PhysicsSchedule first")
+            .add_systems(
+                (init_sensor_overlaps, update_sensor_overlaps)
+                    .chain()
+                    .after(PhysicsStepSet::ReportContacts)
+                    .before(PhysicsStepSet::Sleeping),
+            );
+    }
+}
+
+/// An event that is sent when a collider starts overlapping a [`Sensor`]. Requires the [`TriggerPlugin`].
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct TriggerEnter {
+    /// The sensor collider.
+    pub sensor: Entity,
+    /// The collider that entered the sensor.
+    pub entity: Entity,
+}
+
+/// An event that is sent when a collider stops overlapping a [`Sensor`]. Requires the [`TriggerPlugin`].
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct TriggerExit {
+    /// The sensor collider.
+    pub sensor: Entity,
+    /// The collider that exited the sensor.
+    pub entity: Entity,
+    /// How long the collider was inside the sensor in seconds of physics time.
+    pub duration: Scalar,
+}
+
+/// Contains the colliders that are inside a [`Sensor`] and how long they have been inside in seconds of physics time.
+///
+/// This component is automatically added for all sensors by the [`TriggerPlugin`].
+#[derive(Reflect, Clone, Component, Debug, Default, PartialEq)]
+#[reflect(Component)]
+pub struct SensorOverlaps(HashMap<Entity, Scalar>);
+
+impl SensorOverlaps {
+    /// Returns how long the given collider has been inside the sensor, or `None` if it isn't inside.
+    pub fn duration(&self, entity: Entity) -> Option<Scalar> {
+        self.0.get(&entity).copied()
+    }
+
+    /// Returns true if the given collider is inside the sensor.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.0.contains_key(&entity)
+    }
+
+    /// Returns an iterator over the colliders inside the sensor and how long they have been inside.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Scalar)> + '_ {
+        self.0.iter().map(|(entity, duration)| (*entity, *duration))
+    }
+
+    /// Returns the number of colliders inside the sensor.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no colliders inside the sensor.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn init_sensor_overlaps(
+    mut commands: Commands,
+    sensors: Query<Entity, (With<Sensor>, Without<SensorOverlaps>)>,
+) {
+    for entity in &sensors {
+        commands.entity(entity).insert(SensorOverlaps::default());
+    }
+}
+
+fn update_sensor_overlaps(
+    mut sensors: Query<&mut SensorOverlaps>,
+    collisions: Res<Collisions>,
+    dt: Res<DeltaTime>,
+    mut enter_ev_writer: EventWriter<TriggerEnter>,
+    mut exit_ev_writer: EventWriter<TriggerExit>,
+) {
+    for contacts in collisions.get_internal().values() {
+        for (sensor, entity) in [
+            (contacts.entity1, contacts.entity2),
+            (contacts.entity2, contacts.entity1),
+        ] {
+            let Ok(mut overlaps) = sensors.get_mut(sensor) else {
+                continue;
+            };
+
+            if contacts.during_current_frame {
+                if !contacts.during_previous_frame {
+                    enter_ev_writer.send(TriggerEnter { sensor, entity });
+                }
+                *overlaps.0.entry(entity).or_default() += dt.0;
+            } else if let Some(duration) = overlaps.0.remove(&entity) {
+                exit_ev_writer.send(TriggerExit {
+                    sensor,
+                    entity,
+                    duration,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::*,
+        tests::{create_app, cube_collider},
+    };
+    use approx::assert_relative_eq;
+    use bevy::prelude::*;
+
+    #[test]
+    fn sensors_report_trigger_events_with_overlap_duration() {
+        let mut app = create_app();
+        app.add_plugins(TriggerPlugin);
+
+        let zone = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Static,
+                cube_collider(2.0),
+                Sensor,
+            ))
+            .id();
+        // The ball overlaps the zone while it travels 2.5 units, which takes 1.25 seconds
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Position(Vector::NEG_X * 3.0),
+                LinearVelocity(Vector::X * 2.0),
+                GravityScale(0.0),
+                Collider::ball(0.25),
+            ))
+            .id();
+
+        let mut enter_reader = app.world.resource::<Events<TriggerEnter>>().get_reader();
+        let mut exit_reader = app.world.resource::<Events<TriggerExit>>().get_reader();
+        let mut enter_events = vec![];
+        let mut exit_events = vec![];
+
+        for i in 0..240 {
+            app.step_physics(1);
+            enter_events.extend(
+                enter_reader
+                    .iter(app.world.resource::<Events<TriggerEnter>>())
+                    .copied(),
+            );
+            exit_events.extend(
+                exit_reader
+                    .iter(app.world.resource::<Events<TriggerExit>>())
+                    .copied(),
+            );
+
+            // Halfway through the zone, the ball has been inside for half of the time
+            if i == 90 {
+                let overlaps = app.world.get::<SensorOverlaps>(zone).unwrap();
+                let duration = overlaps.duration(ball).expect("ball isn't inside the zone");
+                assert_relative_eq!(duration, 0.625, epsilon = 1.0 / 30.0);
+            }
+        }
+
+        assert_eq!(
+            enter_events,
+            vec![TriggerEnter {
+                sensor: zone,
+                entity: ball
+            }]
+        );
+        assert_eq!(exit_events.len(), 1);
+        assert_eq!(exit_events[0].sensor, zone);
+        assert_eq!(exit_events[0].entity, ball);
+        assert_relative_eq!(exit_events[0].duration, 1.25, epsilon = 1.0 / 30.0);
+        assert!(app.world.get::<SensorOverlaps>(zone).unwrap().is_empty());
+    }
+}
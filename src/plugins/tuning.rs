@@ -0,0 +1,319 @@
+//! An [egui](https://github.com/emilk/egui) panel for tuning the simulation at runtime.
+//!
+//! See [`PhysicsTuningPlugin`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+/// A plugin that shows an [egui](https://github.com/emilk/egui) window for tuning the simulation at runtime,
+/// so that you don't need to restart your app to try out different values.
+///
+/// The panel can be used for live-editing the following properties:
+///
+/// - Pausing and resuming the [`PhysicsLoop`]
+/// - [`SubstepCount`]
+/// - Solver iterations and the [solver mode](SolverMode) in [`SolverConfig`]
+/// - [`Gravity`]
+/// - [`SleepingThreshold`] and [`DeactivationTime`]
+/// - The [`Mass`], [`Friction`] and [`Restitution`] of individual [rigid bodies](RigidBody)
+///
+/// Bodies are listed by their `Name` if they have one, and by their `Entity` otherwise.
+///
+/// This plugin requires the `egui` feature and `bevy_egui`'s `EguiPlugin`.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_egui::EguiPlugin;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             EguiPlugin,
+///             PhysicsPlugins::default(),
+///             PhysicsTuningPlugin,
+///         ))
+///         .run();
+/// }
+/// ```
+pub struct PhysicsTuningPlugin;
+
+impl Plugin for PhysicsTuningPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, physics_tuning_panel);
+    }
+}
+
+type TunableBodyComponents = (
+    Entity,
+    Option<&'static Name>,
+    &'static RigidBody,
+    &'static mut Mass,
+    &'static mut InverseMass,
+    &'static mut Inertia,
+    &'static mut InverseInertia,
+    Option<&'static mut Friction>,
+    Option<&'static mut Restitution>,
+);
+
+#[allow(clippy::too_many_arguments)]
+fn physics_tuning_panel(
+    mut contexts: EguiContexts,
+    mut physics_loop: ResMut<PhysicsLoop>,
+    mut substep_count: ResMut<SubstepCount>,
+    solver_config: Option<ResMut<SolverConfig>>,
+    mut gravity: ResMut<Gravity>,
+    mut sleeping_threshold: ResMut<SleepingThreshold>,
+    mut deactivation_time: ResMut<DeactivationTime>,
+    mut bodies: Query<TunableBodyComponents>,
+) {
+    egui::Window::new("Physics").show(contexts.ctx_mut(), |ui| {
+        let mut paused = physics_loop.paused;
+        if ui.checkbox(&mut paused, "Paused").changed() {
+            physics_loop.paused = paused;
+        }
+
+        ui.heading("Simulation");
+
+        let mut substeps = substep_count.0;
+        if ui
+            .add(egui::Slider::new(&mut substeps, 1..=64).text("Substeps"))
+            .changed()
+        {
+            substep_count.0 = substeps;
+        }
+
+        if let Some(mut solver_config) = solver_config {
+            let mut config = *solver_config;
+            let config_changed = ui
+                .add(
+                    egui::Slider::new(&mut config.position_iterations, 1..=16)
+                        .text("Position iterations"),
+                )
+                .changed()
+                | ui.add(
+                    egui::Slider::new(&mut config.velocity_iterations, 1..=16)
+                        .text("Velocity iterations"),
+                )
+                .changed()
+                | ui.add(
+                    egui::Slider::new(&mut config.articulation_iterations, 1..=16)
+                        .text("Articulation iterations"),
+                )
+                .changed();
+            let mut tgs_soft = matches!(config.mode, SolverMode::TgsSoft { .. });
+            let mode_changed = ui.checkbox(&mut tgs_soft, "TGS-soft contacts").changed();
+            if mode_changed {
+                config.mode = if tgs_soft {
+                    SolverMode::tgs_soft()
+                } else {
+                    SolverMode::Xpbd
+                };
+            }
+            if config_changed || mode_changed {
+                *solver_config = config;
+            }
+        }
+
+        let mut gravity_value = gravity.0;
+        let gravity_changed = ui
+            .horizontal(|ui| {
+                ui.label("Gravity");
+                let mut changed = ui
+                    .add(egui::DragValue::new(&mut gravity_value.x).prefix("x: "))
+                    .changed();
+                changed |= ui
+                    .add(egui::DragValue::new(&mut gravity_value.y).prefix("y: "))
+                    .changed();
+                #[cfg(feature = "3d")]
+                {
+                    changed |= ui
+                        .add(egui::DragValue::new(&mut gravity_value.z).prefix("z: "))
+                        .changed();
+                }
+                changed
+            })
+            .inner;
+        if gravity_changed {
+            gravity.0 = gravity_value;
+        }
+
+        ui.heading("Sleeping");
+
+        let mut threshold = *sleeping_threshold;
+        let threshold_changed = ui
+            .add(
+                egui::DragValue::new(&mut threshold.linear)
+                    .clamp_range(0.0..=Scalar::MAX)
+                    .speed(0.01)
+                    .prefix("Linear threshold: "),
+            )
+            .changed()
+            | ui.add(
+                egui::DragValue::new(&mut threshold.angular)
+                    .clamp_range(0.0..=Scalar::MAX)
+                    .speed(0.01)
+                    .prefix("Angular threshold: "),
+            )
+            .changed();
+        if threshold_changed {
+            *sleeping_threshold = threshold;
+        }
+
+        let mut time = deactivation_time.0;
+        if ui
+            .add(
+                egui::DragValue::new(&mut time)
+                    .clamp_range(0.0..=Scalar::MAX)
+                    .speed(0.01)
+                    .prefix("Deactivation time: ")
+                    .suffix(" s"),
+            )
+            .changed()
+        {
+            deactivation_time.0 = time;
+        }
+
+        ui.heading("Bodies");
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for (
+                entity,
+                name,
+                rb,
+                mut mass,
+                mut inverse_mass,
+                mut inertia,
+                mut inverse_inertia,
+                friction,
+                restitution,
+            ) in &mut bodies
+            {
+                let label = name.map_or_else(|| format!("{entity:?}"), |name| name.to_string());
+
+                egui::CollapsingHeader::new(label)
+                    .id_source(entity)
+                    .show(ui, |ui| {
+                        ui.label(format!("{rb:?}"));
+
+                        // Mass only affects dynamic bodies
+                        if rb.is_dynamic() {
+                            let mut new_mass = mass.0;
+                            if ui
+                                .add(
+                                    egui::DragValue::new(&mut new_mass)
+                                        .clamp_range(Scalar::EPSILON..=Scalar::MAX)
+                                        .speed(0.1)
+                                        .prefix("Mass: "),
+                                )
+                                .changed()
+                            {
+                                set_mass(
+                                    new_mass,
+                                    &mut mass,
+                                    &mut inverse_mass,
+                                    &mut inertia,
+                                    &mut inverse_inertia,
+                                );
+                            }
+                        }
+
+                        if let Some(mut friction) = friction {
+                            let mut new_friction = *friction;
+                            let friction_changed = ui
+                                .add(
+                                    egui::DragValue::new(&mut new_friction.static_coefficient)
+                                        .clamp_range(0.0..=Scalar::MAX)
+                                        .speed(0.01)
+                                        .prefix("Static friction: "),
+                                )
+                                .changed()
+                                | ui.add(
+                                    egui::DragValue::new(&mut new_friction.dynamic_coefficient)
+                                        .clamp_range(0.0..=Scalar::MAX)
+                                        .speed(0.01)
+                                        .prefix("Dynamic friction: "),
+                                )
+                                .changed();
+                            if friction_changed {
+                                *friction = new_friction;
+                            }
+                        }
+
+                        if let Some(mut restitution) = restitution {
+                            let mut coefficient = restitution.coefficient;
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut coefficient, 0.0..=1.0)
+                                        .text("Restitution"),
+                                )
+                                .changed()
+                            {
+                                restitution.coefficient = coefficient;
+                            }
+                        }
+                    });
+            }
+        });
+    });
+}
+
+/// Sets the mass of a body and scales its inertia with the mass
+/// so that the body's mass distribution stays the same.
+fn set_mass(
+    new_mass: Scalar,
+    mass: &mut Mass,
+    inverse_mass: &mut InverseMass,
+    inertia: &mut Inertia,
+    inverse_inertia: &mut InverseInertia,
+) {
+    if mass.0 > 0.0 {
+        inertia.0 *= new_mass / mass.0;
+        *inverse_inertia = inertia.inverse();
+    }
+    mass.0 = new_mass;
+    inverse_mass.0 = 1.0 / new_mass;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::set_mass;
+    use crate::prelude::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn changing_mass_keeps_mass_distribution() {
+        let collider = Collider::ball(0.5);
+        let MassPropertiesBundle {
+            mut mass,
+            mut inverse_mass,
+            mut inertia,
+            mut inverse_inertia,
+            ..
+        } = MassPropertiesBundle::new_computed(&collider, 1.0);
+
+        // Quadrupling the mass has the same effect as quadrupling the density
+        set_mass(
+            mass.0 * 4.0,
+            &mut mass,
+            &mut inverse_mass,
+            &mut inertia,
+            &mut inverse_inertia,
+        );
+
+        let expected = MassPropertiesBundle::new_computed(&collider, 4.0);
+        assert_relative_eq!(mass.0, expected.mass.0, epsilon = 1e-5);
+        assert_relative_eq!(inverse_mass.0, expected.inverse_mass.0, epsilon = 1e-5);
+        assert_relative_eq!(inertia.0, expected.inertia.0, epsilon = 1e-5);
+        assert_relative_eq!(
+            inverse_inertia.0,
+            expected.inverse_inertia.0,
+            epsilon = 1e-5
+        );
+    }
+}
@@ -1,11 +1,15 @@
 //! Resources used in the simulation.
 
-use bevy::prelude::Resource;
+use bevy::{prelude::Resource, utils::Duration};
 
 use crate::prelude::*;
 
 /// Configures how many times per second the physics simulation is run.
 ///
+/// The default timestep is a 60 Hz [fixed timestep](PhysicsTimestep::Fixed). When the physics plugins
+/// are added to `FixedUpdate` and no timestep has been inserted, [`PhysicsTimestep::FixedUpdate`]
+/// is used instead, so that the simulation is advanced by the period of Bevy's `FixedTime` on every tick.
+///
 /// ## Example
 ///
 /// You can change the timestep by inserting the [`PhysicsTimestep`] resource:
@@ -32,6 +36,12 @@ pub enum PhysicsTimestep {
     Fixed(Scalar),
     /// **Fixed delta, once per frame**: the physics simulation will be advanced by a fixed value `dt` once every frame. This should only be used in cases where you can guarantee a fixed number of executions, like in FixedUpdate or on a server.
     FixedOnce(Scalar),
+    /// **Bevy's fixed timestep**: the physics simulation will be advanced by the period of Bevy's `FixedTime`
+    /// once every time the [`PhysicsSchedule`] is run, without using a separate accumulator.
+    ///
+    /// This should be used when physics is run in `FixedUpdate`, so that physics and other fixed-step systems
+    /// share the same tick without drifting apart. This is the default when the physics plugins are added to `FixedUpdate`.
+    FixedUpdate,
     /// **Variable timestep**: the physics simulation will be advanced by `Time::delta_seconds().min(max_dt)` seconds at each Bevy tick.
     Variable {
         /// The maximum amount of time the physics simulation can be advanced at each Bevy tick. This makes sure that the simulation doesn't break when the delta time is large.
@@ -110,6 +120,8 @@ pub struct SubDeltaTime(pub Scalar);
 /// feature as shown in the [getting started guide](crate#getting-started) to avoid floating point
 /// precision problems.
 ///
+/// The number of substeps can also be adjusted automatically using [`AdaptiveSubsteps`].
+///
 /// ## Example
 ///
 /// You can change the number of substeps by inserting the [`SubstepCount`] resource:
@@ -138,6 +150,90 @@ impl Default for SubstepCount {
     }
 }
 
+/// When this resource exists, the [`SubstepCount`] is adjusted automatically at the start of each physics step
+/// based on the fastest moving body and the deepest penetration in the scene.
+///
+/// This way, quiet scenes can use a small number of substeps, while violent moments automatically get
+/// more solver resolution.
+///
+/// The number of substeps is chosen so that no body moves further than `max_distance_per_substep`
+/// during a single substep, and so that the deepest penetration of the previous step is at most
+/// `max_penetration_per_substep` per substep. The result is clamped between `min` and `max`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         .insert_resource(AdaptiveSubsteps {
+///             min: 4,
+///             max: 32,
+///             ..default()
+///         })
+///         .run();
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct AdaptiveSubsteps {
+    /// The minimum number of substeps.
+    pub min: u32,
+    /// The maximum number of substeps.
+    pub max: u32,
+    /// The maximum distance that the fastest body should move during a single substep.
+    pub max_distance_per_substep: Scalar,
+    /// The maximum penetration depth that should be resolved during a single substep.
+    pub max_penetration_per_substep: Scalar,
+}
+
+impl Default for AdaptiveSubsteps {
+    fn default() -> Self {
+        Self {
+            min: 4,
+            max: 24,
+            #[cfg(feature = "2d")]
+            max_distance_per_substep: 5.0,
+            #[cfg(feature = "3d")]
+            max_distance_per_substep: 0.05,
+            #[cfg(feature = "2d")]
+            max_penetration_per_substep: 1.0,
+            #[cfg(feature = "3d")]
+            max_penetration_per_substep: 0.01,
+        }
+    }
+}
+
+impl AdaptiveSubsteps {
+    /// Computes the number of substeps for a step of length `dt`, given the speed of the fastest body
+    /// and the deepest penetration depth.
+    pub fn substep_count(&self, dt: Scalar, max_speed: Scalar, max_penetration: Scalar) -> u32 {
+        let for_speed = if self.max_distance_per_substep > 0.0 {
+            (max_speed * dt / self.max_distance_per_substep).ceil()
+        } else {
+            0.0
+        };
+        let for_penetration = if self.max_penetration_per_substep > 0.0 {
+            (max_penetration / self.max_penetration_per_substep).ceil()
+        } else {
+            0.0
+        };
+        let count = for_speed.max(for_penetration);
+
+        if count.is_finite() {
+            (count as u32).clamp(self.min.max(1), self.max.max(self.min.max(1)))
+        } else {
+            self.max.max(1)
+        }
+    }
+}
+
 /// A threshold that indicates the maximum linear and angular velocity allowed for a body to be deactivated.
 ///
 /// Setting a negative sleeping threshold disables sleeping entirely.
@@ -182,7 +278,8 @@ impl Default for DeactivationTime {
 /// this gravity will be tiny. You should modify the gravity to fit your application.
 ///
 /// You can also control how gravity affects a specific [rigid body](RigidBody) using the [`GravityScale`]
-/// component. The magnitude of the gravity will be multiplied by this scaling factor.
+/// component. The magnitude of the gravity will be multiplied by this scaling factor. Whole [collision layers](CollisionLayers)
+/// can be given gravity multipliers using the [`LayerGravity`] resource.
 ///
 /// ## Example
 ///
@@ -221,3 +318,218 @@ impl Gravity {
     /// Zero gravity.
     pub const ZERO: Gravity = Gravity(Vector::ZERO);
 }
+
+/// Multipliers for the [`Gravity`] affecting bodies on specific [collision layers](CollisionLayers).
+///
+/// Each of the 64 layers has its own multiplier, which defaults to `1.0`. The gravity of a body is multiplied
+/// by the multipliers of all layers in the groups of its [`CollisionLayers`], in addition to its [`GravityScale`].
+/// Bodies without [`CollisionLayers`] are not affected.
+///
+/// This can be used to make whole categories of bodies, like entities in a "spirit world",
+/// fall slower or faster without changing the [`GravityScale`] of every body.
+///
+/// ## Example
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// #[derive(PhysicsLayer)]
+/// enum Layer {
+///     Normal,
+///     Spirit,
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, PhysicsPlugins::default()))
+///         // Bodies in the spirit world fall at a quarter of the speed
+///         .insert_resource(LayerGravity::default().with_multiplier(Layer::Spirit, 0.25))
+///         .run();
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource)]
+pub struct LayerGravity {
+    multipliers: [Scalar; 64],
+}
+
+impl Default for LayerGravity {
+    fn default() -> Self {
+        Self {
+            multipliers: [1.0; 64],
+        }
+    }
+}
+
+impl LayerGravity {
+    /// Sets the gravity multiplier of the given layer.
+    pub fn with_multiplier(mut self, layer: impl PhysicsLayer, multiplier: Scalar) -> Self {
+        self.set_multiplier(layer, multiplier);
+        self
+    }
+
+    /// Sets the gravity multiplier of the given layer.
+    pub fn set_multiplier(&mut self, layer: impl PhysicsLayer, multiplier: Scalar) {
+        let bits = layer.to_bits();
+        for (i, value) in self.multipliers.iter_mut().enumerate() {
+            if bits & (1 << i) != 0 {
+                *value = multiplier;
+            }
+        }
+    }
+
+    /// Returns the gravity multiplier of the given layer.
+    ///
+    /// If the layer consists of several bits, the multipliers of the bits are multiplied together.
+    pub fn multiplier(&self, layer: impl PhysicsLayer) -> Scalar {
+        self.multiplier_for_bits(layer.to_bits())
+    }
+
+    /// Returns the combined gravity multiplier of a body with the given [`CollisionLayers`].
+    pub fn multiplier_for_layers(&self, layers: &CollisionLayers) -> Scalar {
+        self.multiplier_for_bits(layers.groups_bits())
+    }
+
+    fn multiplier_for_bits(&self, bits: u64) -> Scalar {
+        self.multipliers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| bits & (1 << i) != 0)
+            .map(|(_, multiplier)| multiplier)
+            .product()
+    }
+}
+
+/// Timings of the different phases of the most recent physics step.
+///
+/// This can be used for displaying your own profiling information or for detecting
+/// performance regressions in your scenes.
+///
+/// If the [`PhysicsSchedule`] is run several times in a single frame, the timings are
+/// for the last step, except for `sync`, which is only run once per frame.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn print_timings(timings: Res<PhysicsTimings>) {
+///     println!(
+///         "step: {:?}, broad phase: {:?}, narrow phase: {:?}",
+///         timings.step, timings.broad_phase, timings.narrow_phase
+///     );
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, Default, PartialEq)]
+#[reflect(Resource)]
+pub struct PhysicsTimings {
+    /// The total duration of the physics step, i.e. one run of the [`PhysicsSchedule`].
+    pub step: Duration,
+    /// The duration of the broad phase in [`PhysicsStepSet::BroadPhase`].
+    pub broad_phase: Duration,
+    /// The total duration of the narrow phase in [`SubstepSet::NarrowPhase`] across all substeps.
+    pub narrow_phase: Duration,
+    /// The duration of the substepping loop in [`PhysicsStepSet::Substeps`], including the narrow phase.
+    pub substeps: Duration,
+    /// The duration of the synchronization of physics components with `Transform` in [`PhysicsSet::Sync`].
+    pub sync: Duration,
+}
+
+/// Statistics about the state of the simulation, updated at the end of each physics step.
+///
+/// This can be used for things like performance budget dashboards and automated performance tests.
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::prelude::*;
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::prelude::*;
+///
+/// fn print_stats(stats: Res<PhysicsStats>) {
+///     println!(
+///         "active bodies: {}, sleeping bodies: {}, contact pairs: {}",
+///         stats.active_bodies, stats.sleeping_bodies, stats.contact_pairs
+///     );
+/// }
+/// ```
+#[derive(Reflect, Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct PhysicsStats {
+    /// The number of dynamic and kinematic [rigid bodies](RigidBody) that are not [sleeping](Sleeping).
+    pub active_bodies: usize,
+    /// The number of [sleeping](Sleeping) rigid bodies.
+    pub sleeping_bodies: usize,
+    /// The number of static rigid bodies.
+    pub static_bodies: usize,
+    /// The number of [broad phase pairs](BroadCollisionPairs) whose AABBs intersect.
+    pub broad_phase_pairs: usize,
+    /// The number of entity pairs that were in contact during the step.
+    pub contact_pairs: usize,
+    /// The total number of contact points in the [contact manifolds](ContactManifold) of all contact pairs.
+    pub manifold_points: usize,
+    /// The number of [joints].
+    pub joints: usize,
+    /// The number of [islands](Island) found by the [`IslandPlugin`].
+    ///
+    /// The [`IslandPlugin`] is not included in [`PhysicsPlugins`], so this is always zero
+    /// unless the plugin is added separately.
+    pub islands: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn adaptive_substeps_scale_with_speed_and_penetration() {
+        let config = AdaptiveSubsteps {
+            min: 2,
+            max: 16,
+            max_distance_per_substep: 1.0,
+            max_penetration_per_substep: 0.5,
+        };
+        let dt = 1.0;
+
+        assert_eq!(config.substep_count(dt, 0.0, 0.0), 2);
+        assert_eq!(config.substep_count(dt, 5.5, 0.0), 6);
+        assert_eq!(config.substep_count(dt, 0.0, 2.0), 4);
+        assert_eq!(config.substep_count(dt, 1000.0, 0.0), 16);
+        assert_eq!(config.substep_count(dt, Scalar::INFINITY, 0.0), 16);
+    }
+
+    #[test]
+    fn layer_gravity_has_a_multiplier_for_every_layer() {
+        // The last of the 64 layers
+        struct LastLayer;
+        impl PhysicsLayer for LastLayer {
+            fn to_bits(&self) -> u64 {
+                1 << 63
+            }
+            fn all_bits() -> u64 {
+                u64::MAX
+            }
+        }
+
+        let layer_gravity = LayerGravity::default().with_multiplier(LastLayer, 0.25);
+        assert_eq!(layer_gravity.multiplier(LastLayer), 0.25);
+        assert_eq!(
+            layer_gravity.multiplier_for_layers(&CollisionLayers::from_bits(u64::MAX, u64::MAX)),
+            0.25
+        );
+        assert_eq!(
+            layer_gravity.multiplier_for_layers(&CollisionLayers::from_bits(1, u64::MAX)),
+            1.0
+        );
+    }
+}
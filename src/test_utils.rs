@@ -0,0 +1,157 @@
+//! Utilities for writing tests for physics simulations.
+//!
+//! See [`PhysicsTestAppExt`] and [`assert_pose_eq`].
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// An extension trait for stepping the physics simulation of an `App` deterministically in tests.
+///
+/// Each step runs a full `app.update()`, so the user's systems and the rest of the physics plugins run as usual,
+/// but exactly one physics step is run regardless of how much real time has passed. This requires the
+/// [`PhysicsTimestep`] to be [`Fixed`](PhysicsTimestep::Fixed) or [`FixedOnce`](PhysicsTimestep::FixedOnce).
+///
+/// ## Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// # #[cfg(feature = "2d")]
+/// # use bevy_xpbd_2d::{math::*, prelude::*, test_utils::*};
+/// # #[cfg(feature = "3d")]
+/// use bevy_xpbd_3d::{math::*, prelude::*, test_utils::*};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, TransformPlugin, PhysicsPlugins::default()))
+///     .insert_resource(Gravity::ZERO);
+///
+/// let body = app
+///     .world
+///     .spawn((RigidBody::Dynamic, Collider::ball(0.5), LinearVelocity(Vector::X)))
+///     .id();
+///
+/// // Simulate one second
+/// app.step_physics_seconds(1.0);
+///
+/// let position = *app.world.get::<Position>(body).unwrap();
+/// let rotation = *app.world.get::<Rotation>(body).unwrap();
+/// assert_pose_eq((position, rotation), (Position(Vector::X), Rotation::default()), 0.01);
+/// ```
+pub trait PhysicsTestAppExt {
+    /// Runs the given number of physics steps, running one `app.update()` per step.
+    fn step_physics(&mut self, steps: u32) -> &mut Self;
+
+    /// Runs as many physics steps as are needed to simulate the given amount of time,
+    /// rounded to the nearest whole number of steps.
+    fn step_physics_seconds(&mut self, seconds: Scalar) -> &mut Self;
+}
+
+impl PhysicsTestAppExt for App {
+    fn step_physics(&mut self, steps: u32) -> &mut Self {
+        fixed_delta_seconds(&self.world);
+
+        // The queued steps of a paused physics loop are run regardless of the elapsed time
+        let was_paused = self.world.resource::<PhysicsLoop>().paused;
+        self.world.resource_mut::<PhysicsLoop>().pause();
+
+        for _ in 0..steps {
+            self.world.resource_mut::<PhysicsLoop>().step();
+            self.update();
+        }
+
+        self.world.resource_mut::<PhysicsLoop>().paused = was_paused;
+        self
+    }
+
+    fn step_physics_seconds(&mut self, seconds: Scalar) -> &mut Self {
+        let steps = (seconds / fixed_delta_seconds(&self.world)).round() as u32;
+        self.step_physics(steps)
+    }
+}
+
+/// Returns the delta time of the [`PhysicsTimestep`], or panics if it isn't fixed.
+fn fixed_delta_seconds(world: &World) -> Scalar {
+    match world.resource::<PhysicsTimestep>() {
+        PhysicsTimestep::Fixed(dt) | PhysicsTimestep::FixedOnce(dt) => *dt,
+        timestep => panic!(
+            "stepping the physics simulation requires PhysicsTimestep::Fixed or PhysicsTimestep::FixedOnce, but the timestep is {timestep:?}"
+        ),
+    }
+}
+
+/// Returns `true` if the given poses are equal within `epsilon`.
+///
+/// The positions are compared by the distance between them, and the rotations by the angle between them in radians.
+pub fn pose_approx_eq(
+    (position1, rotation1): (Position, Rotation),
+    (position2, rotation2): (Position, Rotation),
+    epsilon: Scalar,
+) -> bool {
+    #[cfg(feature = "2d")]
+    let angle = (rotation1 - rotation2).as_radians().abs();
+    #[cfg(feature = "3d")]
+    let angle = rotation1.0.angle_between(rotation2.0);
+
+    position1.distance(position2.0) <= epsilon && angle <= epsilon
+}
+
+/// Asserts that the given poses are equal within `epsilon`. See [`pose_approx_eq`].
+///
+/// ## Panics
+///
+/// Panics with a message containing both poses if they aren't equal.
+#[track_caller]
+pub fn assert_pose_eq(
+    actual: (Position, Rotation),
+    expected: (Position, Rotation),
+    epsilon: Scalar,
+) {
+    assert!(
+        pose_approx_eq(actual, expected, epsilon),
+        "poses are not equal within {epsilon}\n  actual: {actual:?}\nexpected: {expected:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::create_app;
+
+    #[test]
+    fn step_physics_runs_exact_number_of_steps() {
+        let mut app = create_app();
+        app.insert_resource(Gravity::ZERO);
+
+        let body = app
+            .world
+            .spawn((
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                LinearVelocity(Vector::X * 6.0),
+            ))
+            .id();
+
+        // Steps don't depend on the elapsed time
+        app.step_physics(10);
+
+        let pose = |app: &App| {
+            (
+                *app.world.get::<Position>(body).unwrap(),
+                *app.world.get::<Rotation>(body).unwrap(),
+            )
+        };
+        assert_pose_eq(
+            pose(&app),
+            (Position(Vector::X), Rotation::default()),
+            0.001,
+        );
+
+        app.step_physics_seconds(0.5);
+
+        assert_pose_eq(
+            pose(&app),
+            (Position(Vector::X * 4.0), Rotation::default()),
+            0.001,
+        );
+        assert!(!app.world.resource::<PhysicsLoop>().paused);
+    }
+}
@@ -21,7 +21,11 @@ macro_rules! setup_insta {
     };
 }
 
-fn create_app() -> App {
+/// Creates an app with the physics plugins that is shared by the tests of all modules.
+///
+/// Time only advances when the app is ticked with [`tick_60_fps`]. Tests that only need to run
+/// physics steps can use [`PhysicsTestAppExt::step_physics`](crate::test_utils::PhysicsTestAppExt::step_physics) instead.
+pub(crate) fn create_app() -> App {
     let mut app = App::new();
     app.add_plugins((
         MinimalPlugins,
@@ -33,7 +37,8 @@ fn create_app() -> App {
     app
 }
 
-fn tick_60_fps(app: &mut App) {
+/// Advances the time of an app created with [`create_app`] by 1/60 of a second and updates it.
+pub(crate) fn tick_60_fps(app: &mut App) {
     let mut update_strategy = app.world.resource_mut::<TimeUpdateStrategy>();
     let TimeUpdateStrategy::ManualInstant(prev_time) = *update_strategy else {
         unimplemented!()
@@ -43,6 +48,65 @@ fn tick_60_fps(app: &mut App) {
     app.update();
 }
 
+/// Returns a floor collider that is 1 unit thick and `length` units long along the horizontal axes.
+pub(crate) fn floor_collider(length: Scalar) -> Collider {
+    #[cfg(feature = "2d")]
+    {
+        Collider::cuboid(length, 1.0)
+    }
+    #[cfg(feature = "3d")]
+    {
+        Collider::cuboid(length, 1.0, length)
+    }
+}
+
+/// Returns a cube collider with the given side length.
+pub(crate) fn cube_collider(size: Scalar) -> Collider {
+    #[cfg(feature = "2d")]
+    {
+        Collider::cuboid(size, size)
+    }
+    #[cfg(feature = "3d")]
+    {
+        Collider::cuboid(size, size, size)
+    }
+}
+
+/// Adds a primary window and the plugins that compute the viewports of cameras,
+/// and spawns a camera that looks at the origin. Returns the camera entity.
+///
+/// The window is 1280x720 logical pixels, so the origin is at the center of the viewport.
+#[cfg(any(
+    feature = "picking",
+    feature = "mouse-drag",
+    feature = "camera-raycast"
+))]
+pub(crate) fn spawn_camera_with_window(app: &mut App) -> Entity {
+    use bevy::{asset::AssetPlugin, render::camera::CameraPlugin, window::WindowPlugin};
+
+    app.add_plugins((
+        WindowPlugin::default(),
+        AssetPlugin::default(),
+        CameraPlugin,
+    ));
+    app.add_asset::<Image>();
+
+    #[cfg(feature = "2d")]
+    let camera = app.world.spawn(Camera2dBundle::default()).id();
+    #[cfg(feature = "3d")]
+    let camera = app
+        .world
+        .spawn(Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        })
+        .id();
+
+    // Compute the viewport and the `GlobalTransform` of the camera
+    app.update();
+    camera
+}
+
 #[cfg(feature = "3d")]
 fn setup_cubes_simulation(mut commands: Commands) {
     let mut next_id = 0;
@@ -217,3 +281,72 @@ fn cubes_simulation_is_locally_deterministic() {
         assert_eq!(a, b);
     }
 }
+
+#[test]
+fn prepare_and_sync_sets_order_user_systems() {
+    use bevy::ecs::query::Has;
+
+    #[derive(Resource, Default)]
+    struct Observed {
+        aabb_before_init: Option<bool>,
+        aabb_after_init: Option<bool>,
+        synced_translation: Option<Vector>,
+    }
+
+    fn before_init(
+        mut observed: ResMut<Observed>,
+        query: Query<Has<ColliderAabb>, With<Collider>>,
+    ) {
+        observed.aabb_before_init.get_or_insert(query.single());
+    }
+
+    fn after_init(mut observed: ResMut<Observed>, query: Query<Has<ColliderAabb>, With<Collider>>) {
+        observed.aabb_after_init.get_or_insert(query.single());
+    }
+
+    fn after_sync(mut observed: ResMut<Observed>, query: Query<&Transform, With<Collider>>) {
+        #[cfg(feature = "2d")]
+        let translation = query.single().translation.truncate().adjust_precision();
+        #[cfg(feature = "3d")]
+        let translation = query.single().translation.adjust_precision();
+        observed.synced_translation = Some(translation);
+    }
+
+    let mut app = create_app();
+    app.init_resource::<Observed>()
+        .insert_resource(Gravity::ZERO)
+        .add_systems(
+            PostUpdate,
+            (
+                before_init.before(PrepareSet::InitColliders),
+                after_init.after(PrepareSet::InitColliders),
+                after_sync.after(SyncSet::PositionToTransform),
+            ),
+        );
+
+    let body = app
+        .world
+        .spawn((
+            TransformBundle::default(),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            LinearVelocity(Vector::X),
+        ))
+        .id();
+
+    tick_60_fps(&mut app);
+
+    // The collider components are added in `PrepareSet::InitColliders`
+    let observed = app.world.resource::<Observed>();
+    assert_eq!(observed.aabb_before_init, Some(false));
+    assert_eq!(observed.aabb_after_init, Some(true));
+
+    // The transform has been updated from the position of the body
+    let position = app.world.get::<Position>(body).unwrap();
+    assert!(position.x > 0.0);
+    assert_relative_eq!(
+        observed.synced_translation.unwrap(),
+        position.0,
+        epsilon = 1e-5
+    );
+}
@@ -33,18 +33,24 @@ pub(crate) fn get_rotated_inertia_tensor(inertia_tensor: Matrix3, rot: Quaternio
 }
 
 /// Computes the magnitude of the impulse caused by dynamic friction.
+///
+/// `applied_impulse` is the magnitude of the friction impulse already applied to the contact during
+/// the current substep. The total friction impulse never exceeds the coefficient times the normal impulse,
+/// so friction doesn't grow with the number of velocity iterations.
 pub(crate) fn compute_dynamic_friction(
     tangent_speed: Scalar,
     generalized_inv_mass_sum: Scalar,
     coefficient: Scalar,
     normal_lagrange: Scalar,
+    applied_impulse: Scalar,
     sub_dt: Scalar,
 ) -> Scalar {
     let normal_impulse = normal_lagrange / sub_dt;
+    let remaining_impulse = (coefficient * normal_impulse.abs() - applied_impulse).max(0.0);
 
     // Compute impulse caused by dynamic friction, clamped to never exceed the tangential speed.
     // Note: This is handled differently from the XPBD paper because it treated mass incorrectly.
-    -(coefficient * normal_impulse.abs()).min(tangent_speed / generalized_inv_mass_sum)
+    -remaining_impulse.min(tangent_speed / generalized_inv_mass_sum)
 }
 
 /// Computes the speed correction caused by restitution.